@@ -114,6 +114,11 @@ impl PathFollowingAtom {
 
     /// Render the atom with pulsing effects
     pub fn render(&self, segments: u8) {
+        self.render_with_alpha(segments, 1.0);
+    }
+
+    /// Render with every color's alpha scaled by `alpha_scale` (faint overlay)
+    pub fn render_with_alpha(&self, segments: u8, alpha_scale: f32) {
         if !self.is_alive || !self.has_valid_shapes {
             return;
         }
@@ -135,6 +140,7 @@ impl PathFollowingAtom {
             let fade_amount = 1.0 - fade_ratio;
             pulsing_color.a = fade_amount;
         }
+        pulsing_color.a *= alpha_scale;
 
         // Slight size pulsing based on energy
         let size_multiplier = 1.0 + ((self.pulse_timer * pulse_frequency).sin()
@@ -277,6 +283,11 @@ impl PathFollowingAtom {
 }
 
 /// Manages all atoms, detects intersections, and creates new atoms
+///
+/// Atoms are ring-intersection points and are not drawn by default. They drive three
+/// proton mechanics: H+ lingering near an atom becomes deuterium (neutron formation),
+/// deuterium touching an atom captures its electron and becomes stable H (the atom is
+/// consumed), and two high-energy atoms colliding spawn a new H+ or H-.
 pub struct AtomManager {
     atoms: Vec<Option<PathFollowingAtom>>,
     next_slot: usize,
@@ -339,6 +350,15 @@ impl AtomManager {
         }
     }
 
+    /// Draw all atoms as a faint overlay so the hidden mechanics can be seen
+    pub fn draw_faint(&self, segments: u8) {
+        for i in 0..self.atom_count {
+            if let Some(atom) = &self.atoms[i] {
+                atom.render_with_alpha(segments, atom::FAINT_ALPHA);
+            }
+        }
+    }
+
     /// Clear all atoms
     pub fn clear(&mut self) {
         for atom in &mut self.atoms {
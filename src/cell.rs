@@ -1,4 +1,4 @@
-// Cell membrane simulation - Cell and MembraneComponent structures
+// Cell membrane simulation - Cell, MembraneComponent and Food structures
 // Not yet integrated into the main game
 
 use macroquad::prelude::*;
@@ -14,6 +14,7 @@ pub struct MembraneComponent {
     pub circle_radius: f32,  // Radius of the lipid head (circle)
     pub bar_length: f32,     // Length of the lipid tail (bar)
     pub bar_width: f32,      // Width of the lipid tail (bar)
+    pub tear_age: Option<f32>, // Seconds since the gap to the next ring neighbor tore (None = intact)
 }
 
 impl MembraneComponent {
@@ -27,15 +28,16 @@ impl MembraneComponent {
             circle_radius: LIPID_CIRCLE_RADIUS,
             bar_length: LIPID_BAR_LENGTH,
             bar_width: LIPID_BAR_WIDTH,
+            tear_age: None,
         }
     }
 
-    pub fn update(&mut self, dt: f32) {
+    pub fn update(&mut self, dt: f32, damping: f32) {
         // Apply velocity
         self.position += self.velocity * dt;
 
         // Apply damping to velocity
-        self.velocity *= DAMPING;
+        self.velocity *= damping;
     }
 
     pub fn draw(&self) {
@@ -63,6 +65,73 @@ impl MembraneComponent {
     }
 }
 
+// Membrane force tuning - every cell carries its own copy, so cells of different stiffness can coexist
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MembraneParams {
+    pub head_push_force: f32,
+    pub expansion_push_force: f32,
+    pub neighbor_force_strength: f32,
+    pub separation_force: f32,
+    pub alignment_force: f32,
+    pub forward_flow_strength: f32,
+    pub damping: f32,
+}
+
+impl Default for MembraneParams {
+    fn default() -> Self {
+        MembraneParams {
+            head_push_force: HEAD_PUSH_FORCE,
+            expansion_push_force: EXPANSION_PUSH_FORCE,
+            neighbor_force_strength: NEIGHBOR_FORCE_STRENGTH,
+            separation_force: MEMBRANE_SEPARATION_FORCE,
+            alignment_force: MEMBRANE_ALIGNMENT_FORCE,
+            forward_flow_strength: MEMBRANE_FORWARD_FLOW_STRENGTH,
+            damping: DAMPING,
+        }
+    }
+}
+
+// Free-floating food particle - absorbed once the outer membrane encloses it
+pub struct Food {
+    pub position: Vec2,
+    pub velocity: Vec2,
+}
+
+impl Food {
+    /// Food at `position` drifting in a random direction
+    pub fn new(position: Vec2) -> Self {
+        use macroquad::rand::gen_range;
+        let direction = Vec2::from_angle(gen_range(0.0, std::f32::consts::TAU));
+        Food {
+            position,
+            velocity: direction * gen_range(0.0, FOOD_MAX_SPEED),
+        }
+    }
+
+    /// Drift, bouncing off the window edges
+    pub fn update(&mut self, dt: f32, window_size: (f32, f32)) {
+        self.position += self.velocity * dt;
+        if self.position.x < 0.0 || self.position.x > window_size.0 {
+            self.velocity.x = -self.velocity.x;
+            self.position.x = self.position.x.clamp(0.0, window_size.0);
+        }
+        if self.position.y < 0.0 || self.position.y > window_size.1 {
+            self.velocity.y = -self.velocity.y;
+            self.position.y = self.position.y.clamp(0.0, window_size.1);
+        }
+    }
+
+    pub fn draw(&self) {
+        draw_circle(self.position.x, self.position.y, FOOD_RADIUS, FOOD_COLOR);
+    }
+}
+
+// Food being digested - sinks from where it was caught toward the center while it fades
+struct Digesting {
+    offset: Vec2,    // Position relative to the cell center when absorbed
+    remaining: f32,  // Seconds left (counts down from DIGEST_TIME)
+}
+
 // Cell with membrane
 pub struct Cell {
     pub actual_center: Vec2,      // The actual center position (center of mass)
@@ -74,12 +143,16 @@ pub struct Cell {
     pub expansion_radius: f32,    // Invisible expanding force radius (0 = inactive)
     pub expansion_center: Vec2,   // Fixed position of the expansion zone (stays stationary when movement starts)
     pub expansion_active_time: f32, // Time the expansion has been active during movement
+    pub full_expansion_time: f32, // Time the expansion has been held at full radius (drives division)
     pub inner_membrane: Vec<MembraneComponent>,
     pub outer_membrane: Vec<MembraneComponent>,
+    pub energy: f32,              // Food absorbed so far (split between daughters on division)
+    pub params: MembraneParams,   // Force tuning used by every update (inherited by daughters)
+    digesting: Vec<Digesting>,
 }
 
 impl Cell {
-    pub fn new(center: Vec2, num_components: usize) -> Self {
+    pub fn new(center: Vec2, num_components: usize, params: MembraneParams) -> Self {
         let inner_membrane = Self::create_membrane_ring(center, num_components, INNER_MEMBRANE_RADIUS, true);
         let outer_membrane = Self::create_membrane_ring(center, num_components, OUTER_MEMBRANE_RADIUS, false);
 
@@ -93,8 +166,12 @@ impl Cell {
             expansion_radius: 0.0,
             expansion_center: center,
             expansion_active_time: 0.0,
+            full_expansion_time: 0.0,
             inner_membrane,
             outer_membrane,
+            energy: 0.0,
+            params,
+            digesting: Vec::new(),
         }
     }
 
@@ -163,11 +240,13 @@ impl Cell {
                 } else {
                     self.expansion_radius = OUTER_MEMBRANE_RADIUS;
                     self.expansion_center = self.actual_center; // Update center while stationary
+                    self.full_expansion_time += dt;
                 }
             }
         } else {
             // Cell is moving - keep expansion active for a duration, but stationary
             self.stationary_time = 0.0;
+            self.full_expansion_time = 0.0;
 
             if self.expansion_radius > 0.0 {
                 self.expansion_active_time += dt;
@@ -183,21 +262,22 @@ impl Cell {
     }
 
     pub fn update(&mut self, dt: f32) {
+        let params = self.params;
         self.update_head_physics(dt);
         self.update_center_physics(dt);
         self.update_expansion_state(dt);
 
         // Apply forces to membrane
-        Self::apply_head_push_forces(&mut self.inner_membrane, self.head_position, dt);
-        Self::apply_head_push_forces(&mut self.outer_membrane, self.head_position, dt);
+        Self::apply_head_push_forces(&mut self.inner_membrane, self.head_position, params.head_push_force, dt);
+        Self::apply_head_push_forces(&mut self.outer_membrane, self.head_position, params.head_push_force, dt);
 
         if self.expansion_radius > 0.0 {
-            Self::apply_expansion_forces(&mut self.inner_membrane, self.expansion_center, self.expansion_radius, dt);
-            Self::apply_expansion_forces(&mut self.outer_membrane, self.expansion_center, self.expansion_radius, dt);
+            Self::apply_expansion_forces(&mut self.inner_membrane, self.expansion_center, self.expansion_radius, params.expansion_push_force, dt);
+            Self::apply_expansion_forces(&mut self.outer_membrane, self.expansion_center, self.expansion_radius, params.expansion_push_force, dt);
         }
 
         // Keep membrane layers separated by at least the lipid tail length
-        Self::apply_membrane_separation_forces(&mut self.inner_membrane, &mut self.outer_membrane, dt);
+        Self::apply_membrane_separation_forces(&mut self.inner_membrane, &mut self.outer_membrane, &params, dt);
 
         // Update membrane components
         let movement_direction = if self.head_velocity.length() > MOVEMENT_DIRECTION_THRESHOLD {
@@ -206,11 +286,206 @@ impl Cell {
             Vec2::ZERO
         };
 
-        Self::update_membrane_ring(&mut self.inner_membrane, self.actual_center, self.head_position, movement_direction, INNER_DESIRED_NEIGHBOR_DISTANCE, dt);
-        Self::update_membrane_ring(&mut self.outer_membrane, self.actual_center, self.head_position, movement_direction, OUTER_DESIRED_NEIGHBOR_DISTANCE, dt);
+        Self::update_membrane_ring(&mut self.inner_membrane, self.actual_center, self.head_position, movement_direction, INNER_DESIRED_NEIGHBOR_DISTANCE, &params, dt);
+        Self::update_membrane_ring(&mut self.outer_membrane, self.actual_center, self.head_position, movement_direction, OUTER_DESIRED_NEIGHBOR_DISTANCE, &params, dt);
+
+        // Tear overstretched gaps and heal at most one closed tear per ring
+        if let Some(index) = Self::update_tears(&mut self.inner_membrane, INNER_DESIRED_NEIGHBOR_DISTANCE, dt) {
+            Self::heal_tear(&mut self.inner_membrane, &mut self.outer_membrane, index);
+        }
+        if let Some(index) = Self::update_tears(&mut self.outer_membrane, OUTER_DESIRED_NEIGHBOR_DISTANCE, dt) {
+            Self::heal_tear(&mut self.outer_membrane, &mut self.inner_membrane, index);
+        }
+
+        for digesting in &mut self.digesting {
+            digesting.remaining -= dt;
+        }
+        self.digesting.retain(|digesting| digesting.remaining > 0.0);
+    }
+
+    /// True if `point` lies inside the outer membrane ring (even-odd ray cast against the lipid polygon)
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        let ring = &self.outer_membrane;
+        let mut inside = false;
+        for (i, component) in ring.iter().enumerate() {
+            let a = component.position;
+            let b = ring[(i + 1) % ring.len()].position;
+            if (a.y > point.y) != (b.y > point.y) {
+                let crossing_x = a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x);
+                if point.x < crossing_x {
+                    inside = !inside;
+                }
+            }
+        }
+        inside
+    }
+
+    /// Absorb every food particle the outer membrane encloses, returning how many were taken
+    pub fn absorb_food(&mut self, food: &mut Vec<Food>) -> usize {
+        let before = food.len();
+        food.retain(|particle| {
+            if !self.contains_point(particle.position) {
+                return true;
+            }
+            self.energy += FOOD_ENERGY;
+            self.digesting.push(Digesting {
+                offset: particle.position - self.actual_center,
+                remaining: DIGEST_TIME,
+            });
+            false
+        });
+        before - food.len()
+    }
+
+    /// True once the cell has held full expansion long enough to divide
+    pub fn ready_to_divide(&self) -> bool {
+        self.full_expansion_time >= DIVISION_HOLD_TIME
+    }
+
+    /// Pinch the cell at the midline and split it in two
+    /// This cell keeps the half behind the pinch axis and the returned cell takes the half in front.
+    /// Each half keeps its existing lipids and is closed with a fresh arc from create_membrane_ring.
+    pub fn divide(&mut self) -> Cell {
+        // Pinch axis follows the current movement, or horizontal when at rest
+        let axis = if self.head_velocity.length() > MOVEMENT_DIRECTION_THRESHOLD {
+            self.head_velocity.normalize()
+        } else {
+            Vec2::X
+        };
+
+        let midline = self.actual_center;
+        let num_components = self.inner_membrane.len();
+        let inner = std::mem::take(&mut self.inner_membrane);
+        let outer = std::mem::take(&mut self.outer_membrane);
+
+        // Split by inner/outer pair so both layers stay the same length and index-aligned
+        let mut back: Vec<(MembraneComponent, MembraneComponent)> = Vec::new();
+        let mut front: Vec<(MembraneComponent, MembraneComponent)> = Vec::new();
+        for (inner_component, outer_component) in inner.into_iter().zip(outer) {
+            if (inner_component.position - midline).dot(axis) >= 0.0 {
+                front.push((inner_component, outer_component));
+            } else {
+                back.push((inner_component, outer_component));
+            }
+        }
+
+        let back_center = midline - axis * DIVISION_OFFSET;
+        let front_center = midline + axis * DIVISION_OFFSET;
+
+        let (inner_back, outer_back) = Self::close_membrane_half(back, midline, back_center, axis, num_components);
+        let (inner_front, outer_front) = Self::close_membrane_half(front, midline, front_center, -axis, num_components);
+
+        self.inner_membrane = inner_back;
+        self.outer_membrane = outer_back;
+        self.reset_after_division(back_center);
+
+        let mut daughter = Cell::new(front_center, 0, self.params);
+        daughter.inner_membrane = inner_front;
+        daughter.outer_membrane = outer_front;
+        self.energy /= 2.0;
+        daughter.energy = self.energy;
+        daughter.head_velocity = self.head_velocity;
+        daughter.center_velocity = self.center_velocity;
+        daughter
     }
 
-    fn apply_head_push_forces(membrane: &mut Vec<MembraneComponent>, head_center: Vec2, dt: f32) {
+    /// Move a membrane half to its new center and close the open (pinch) side
+    /// `open_side` points from the new center toward the pinch, where the fresh arc is added
+    fn close_membrane_half(
+        half: Vec<(MembraneComponent, MembraneComponent)>,
+        midline: Vec2,
+        new_center: Vec2,
+        open_side: Vec2,
+        num_components: usize,
+    ) -> (Vec<MembraneComponent>, Vec<MembraneComponent>) {
+        let shift = new_center - midline;
+        let mut pairs: Vec<(MembraneComponent, MembraneComponent)> = half
+            .into_iter()
+            .map(|(mut inner_component, mut outer_component)| {
+                inner_component.position += shift;
+                outer_component.position += shift;
+                // Ring order changes below, so old tears no longer name the right neighbors
+                inner_component.tear_age = None;
+                outer_component.tear_age = None;
+                (inner_component, outer_component)
+            })
+            .collect();
+
+        // Fill the pinch side with new lipids until the ring is back to full size
+        let missing = num_components.saturating_sub(pairs.len());
+        if missing > 0 {
+            let inner_arc = Self::create_membrane_ring(new_center, num_components, INNER_MEMBRANE_RADIUS, true);
+            let outer_arc = Self::create_membrane_ring(new_center, num_components, OUTER_MEMBRANE_RADIUS, false);
+
+            // Take the generated lipids that face the pinch most directly
+            let mut arc: Vec<(f32, MembraneComponent, MembraneComponent)> = inner_arc
+                .into_iter()
+                .zip(outer_arc)
+                .map(|(inner_component, outer_component)| {
+                    let facing = (inner_component.position - new_center).normalize_or_zero().dot(open_side);
+                    (facing, inner_component, outer_component)
+                })
+                .collect();
+            arc.sort_by(|a, b| b.0.total_cmp(&a.0));
+            pairs.extend(arc.into_iter().take(missing).map(|(_, inner_component, outer_component)| (inner_component, outer_component)));
+        }
+
+        // Order around the new center so ring neighbors are adjacent again
+        pairs.sort_by(|a, b| {
+            let angle_a = (a.0.position - new_center).to_angle();
+            let angle_b = (b.0.position - new_center).to_angle();
+            angle_a.total_cmp(&angle_b)
+        });
+
+        pairs.into_iter().unzip()
+    }
+
+    fn reset_after_division(&mut self, center: Vec2) {
+        self.actual_center = center;
+        self.head_position = center;
+        self.expansion_center = center;
+        self.expansion_radius = 0.0;
+        self.expansion_active_time = 0.0;
+        self.full_expansion_time = 0.0;
+        self.stationary_time = 0.0;
+    }
+
+    /// Push apart the outer membranes of every pair of overlapping cells
+    pub fn apply_cell_collision_forces(cells: &mut [Cell], dt: f32) {
+        for i in 0..cells.len() {
+            let (left, right) = cells.split_at_mut(i + 1);
+            let cell_a = &mut left[i];
+
+            for cell_b in right.iter_mut() {
+                // Skip pairs whose outer membranes cannot touch
+                let reach = 2.0 * (OUTER_MEMBRANE_RADIUS + HEAD_RADIUS) + CELL_CONTACT_DISTANCE;
+                if cell_a.actual_center.distance(cell_b.actual_center) > reach {
+                    continue;
+                }
+
+                Self::apply_membrane_repulsion(&mut cell_a.outer_membrane, &mut cell_b.outer_membrane, dt);
+            }
+        }
+    }
+
+    fn apply_membrane_repulsion(membrane_a: &mut [MembraneComponent], membrane_b: &mut [MembraneComponent], dt: f32) {
+        for component_a in membrane_a.iter_mut() {
+            for component_b in membrane_b.iter_mut() {
+                let to_a = component_a.position - component_b.position;
+                let distance = to_a.length();
+
+                if distance > 0.0 && distance < CELL_CONTACT_DISTANCE {
+                    let push_direction = to_a / distance;
+                    let penetration = CELL_CONTACT_DISTANCE - distance;
+                    let impulse = push_direction * penetration * CELL_REPULSION_FORCE * dt;
+                    component_a.velocity += impulse;
+                    component_b.velocity -= impulse;
+                }
+            }
+        }
+    }
+
+    fn apply_head_push_forces(membrane: &mut Vec<MembraneComponent>, head_center: Vec2, push_force: f32, dt: f32) {
         for component in membrane.iter_mut() {
             let to_component = component.position - head_center;
             let distance = to_component.length();
@@ -218,12 +493,12 @@ impl Cell {
             if distance > 0.0 && distance < HEAD_RADIUS {
                 let push_direction = to_component / distance;
                 let penetration = HEAD_RADIUS - distance;
-                component.velocity += push_direction * penetration * HEAD_PUSH_FORCE * dt;
+                component.velocity += push_direction * penetration * push_force * dt;
             }
         }
     }
 
-    fn apply_expansion_forces(membrane: &mut Vec<MembraneComponent>, center: Vec2, expansion_radius: f32, dt: f32) {
+    fn apply_expansion_forces(membrane: &mut Vec<MembraneComponent>, center: Vec2, expansion_radius: f32, push_force: f32, dt: f32) {
         for component in membrane.iter_mut() {
             let to_component = component.position - center;
             let distance = to_component.length();
@@ -231,12 +506,12 @@ impl Cell {
             if distance > 0.0 && distance < expansion_radius {
                 let push_direction = to_component / distance;
                 let penetration = expansion_radius - distance;
-                component.velocity += push_direction * penetration * EXPANSION_PUSH_FORCE * dt;
+                component.velocity += push_direction * penetration * push_force * dt;
             }
         }
     }
 
-    fn apply_membrane_separation_forces(inner_membrane: &mut Vec<MembraneComponent>, outer_membrane: &mut Vec<MembraneComponent>, dt: f32) {
+    fn apply_membrane_separation_forces(inner_membrane: &mut Vec<MembraneComponent>, outer_membrane: &mut Vec<MembraneComponent>, params: &MembraneParams, dt: f32) {
         let min_distance = LIPID_BAR_LENGTH;
 
         // Calculate approximate center based on inner membrane average position
@@ -258,7 +533,7 @@ impl Cell {
                 // Membranes are too close - apply repulsion forces
                 let separation_direction = delta / distance;
                 let penetration = min_distance - distance;
-                let force_magnitude = penetration * MEMBRANE_SEPARATION_FORCE * dt;
+                let force_magnitude = penetration * params.separation_force * dt;
 
                 // Push inner membrane inward, outer membrane outward
                 inner_membrane[i].velocity -= separation_direction * force_magnitude;
@@ -280,7 +555,7 @@ impl Cell {
 
                 // Apply force to pull outer component toward ideal angular position
                 let alignment_delta = ideal_outer_pos - outer_pos;
-                let alignment_force = alignment_delta * MEMBRANE_ALIGNMENT_FORCE * dt;
+                let alignment_force = alignment_delta * params.alignment_force * dt;
                 outer_membrane[i].velocity += alignment_force;
 
                 // Apply opposite force to inner component to conserve momentum
@@ -289,20 +564,20 @@ impl Cell {
         }
     }
 
-    fn update_membrane_ring(membrane: &mut Vec<MembraneComponent>, actual_center: Vec2, head_position: Vec2, movement_direction: Vec2, desired_distance: f32, dt: f32) {
+    fn update_membrane_ring(membrane: &mut Vec<MembraneComponent>, actual_center: Vec2, head_position: Vec2, movement_direction: Vec2, desired_distance: f32, params: &MembraneParams, dt: f32) {
         // Update component physics
         for component in membrane.iter_mut() {
-            Self::update_component_physics(component, actual_center, head_position, movement_direction, dt);
+            Self::update_component_physics(component, actual_center, head_position, movement_direction, params, dt);
         }
 
         // Apply neighbor interaction forces for elastic behavior
-        let neighbor_forces = Self::calculate_neighbor_forces(membrane, desired_distance);
+        let neighbor_forces = Self::calculate_neighbor_forces(membrane, desired_distance, params.neighbor_force_strength);
         for (component, force) in membrane.iter_mut().zip(neighbor_forces.iter()) {
             component.velocity += *force * dt;
         }
     }
 
-    fn calculate_neighbor_forces(membrane: &[MembraneComponent], desired_distance: f32) -> Vec<Vec2> {
+    fn calculate_neighbor_forces(membrane: &[MembraneComponent], desired_distance: f32, strength: f32) -> Vec<Vec2> {
         let num_components = membrane.len();
         let mut forces = vec![Vec2::ZERO; num_components];
 
@@ -311,30 +586,81 @@ impl Cell {
             let prev_idx = if i == 0 { num_components - 1 } else { i - 1 };
             let next_idx = if i == num_components - 1 { 0 } else { i + 1 };
 
-            forces[i] += Self::calculate_spring_force(current_pos, membrane[prev_idx].position, desired_distance);
-            forces[i] += Self::calculate_spring_force(current_pos, membrane[next_idx].position, desired_distance);
+            // Torn gaps keep only a faint pull so the loose ends can drift back together
+            let prev_strength = if membrane[prev_idx].tear_age.is_some() { strength * MEMBRANE_TEAR_RESEAL_STRENGTH } else { strength };
+            let next_strength = if membrane[i].tear_age.is_some() { strength * MEMBRANE_TEAR_RESEAL_STRENGTH } else { strength };
+
+            forces[i] += Self::calculate_spring_force(current_pos, membrane[prev_idx].position, desired_distance, prev_strength);
+            forces[i] += Self::calculate_spring_force(current_pos, membrane[next_idx].position, desired_distance, next_strength);
         }
 
         forces
     }
 
-    fn calculate_spring_force(from: Vec2, to: Vec2, desired_distance: f32) -> Vec2 {
+    /// Tear every overstretched gap and age the existing tears
+    /// Returns the first tear that has been open long enough and whose ends are close enough to heal.
+    fn update_tears(membrane: &mut [MembraneComponent], desired_distance: f32, dt: f32) -> Option<usize> {
+        let num_components = membrane.len();
+        let mut healable = None;
+
+        for i in 0..num_components {
+            let gap = membrane[i].position.distance(membrane[(i + 1) % num_components].position);
+            match membrane[i].tear_age {
+                None if gap > desired_distance * MEMBRANE_TEAR_STRETCH => membrane[i].tear_age = Some(0.0),
+                Some(age) => {
+                    let age = age + dt;
+                    membrane[i].tear_age = Some(age);
+                    if healable.is_none() && age >= MEMBRANE_HEAL_DELAY && gap <= desired_distance * MEMBRANE_HEAL_STRETCH {
+                        healable = Some(i);
+                    }
+                }
+                None => {}
+            }
+        }
+
+        healable
+    }
+
+    /// Close the tear after `index` in `torn` with a new lipid between its ends
+    /// `other` gets a matching lipid at the same index so the inner/outer pairs stay aligned.
+    fn heal_tear(torn: &mut Vec<MembraneComponent>, other: &mut Vec<MembraneComponent>, index: usize) {
+        torn[index].tear_age = None;
+        let patch = Self::interpolate_component(&torn[index], &torn[(index + 1) % torn.len()]);
+        torn.insert(index + 1, patch);
+
+        if index < other.len() {
+            let patch = Self::interpolate_component(&other[index], &other[(index + 1) % other.len()]);
+            other.insert(index + 1, patch);
+        }
+    }
+
+    fn interpolate_component(a: &MembraneComponent, b: &MembraneComponent) -> MembraneComponent {
+        // Average angles as unit vectors so the wrap at 2π is handled
+        let mid_angle = |x: f32, y: f32| (Vec2::from_angle(x) + Vec2::from_angle(y)).to_angle();
+        let circle_angle = mid_angle(a.circle_angle, b.circle_angle).rem_euclid(std::f32::consts::TAU);
+
+        let mut component = MembraneComponent::new(a.position.lerp(b.position, 0.5), mid_angle(a.angle, b.angle), circle_angle, a.ideal_radius);
+        component.velocity = (a.velocity + b.velocity) * 0.5;
+        component
+    }
+
+    fn calculate_spring_force(from: Vec2, to: Vec2, desired_distance: f32, strength: f32) -> Vec2 {
         let delta = to - from;
         let distance = delta.length();
 
         if distance > 0.0 {
             let displacement = distance - desired_distance;
-            (delta / distance) * displacement * NEIGHBOR_FORCE_STRENGTH
+            (delta / distance) * displacement * strength
         } else {
             Vec2::ZERO
         }
     }
 
-    fn update_component_physics(component: &mut MembraneComponent, actual_center: Vec2, head_position: Vec2, movement_direction: Vec2, dt: f32) {
+    fn update_component_physics(component: &mut MembraneComponent, actual_center: Vec2, head_position: Vec2, movement_direction: Vec2, params: &MembraneParams, dt: f32) {
         // Apply membrane surface flow and forward migration during movement
         if movement_direction.length() > MOVEMENT_DIRECTION_THRESHOLD {
             Self::apply_membrane_flow(component, movement_direction, dt);
-            Self::apply_forward_migration(component, head_position, movement_direction, dt);
+            Self::apply_forward_migration(component, head_position, movement_direction, params.forward_flow_strength, dt);
         }
 
         // Update component orientation to point toward/away from actual center
@@ -350,7 +676,7 @@ impl Cell {
         }
 
         // Update position
-        component.update(dt);
+        component.update(dt, params.damping);
     }
 
     fn apply_membrane_flow(component: &mut MembraneComponent, movement_direction: Vec2, dt: f32) {
@@ -371,13 +697,13 @@ impl Cell {
         }
     }
 
-    fn apply_forward_migration(component: &mut MembraneComponent, head_position: Vec2, movement_direction: Vec2, dt: f32) {
+    fn apply_forward_migration(component: &mut MembraneComponent, head_position: Vec2, movement_direction: Vec2, flow_strength: f32, dt: f32) {
         let to_component = component.position - head_position;
         let distance_behind = -to_component.dot(movement_direction);
 
         if distance_behind > 0.0 {
             let flow_factor = (distance_behind / FLOW_DISTANCE_NORMALIZER).min(MAX_FLOW_FACTOR);
-            component.velocity += movement_direction * flow_factor * MEMBRANE_FORWARD_FLOW_STRENGTH * dt;
+            component.velocity += movement_direction * flow_factor * flow_strength * dt;
         }
     }
 
@@ -402,6 +728,33 @@ impl Cell {
         self.input_direction = input;
     }
 
+    /// Steer toward `target` instead of WASD - the head chases the cursor until it is inside the dead zone
+    pub fn handle_mouse_movement(&mut self, target: Vec2) {
+        let offset = target - self.actual_center;
+        self.input_direction = if offset.length() > MOUSE_FOLLOW_DEAD_ZONE {
+            offset.normalize()
+        } else {
+            Vec2::ZERO
+        };
+    }
+
+    /// Filled view - the outer membrane as one translucent cytoplasm polygon, with the usual detail on top
+    /// The polygon is a triangle fan around the ring's centroid, with lipids taken in `circle_angle` order
+    pub fn draw_filled(&self) {
+        let mut ring: Vec<&MembraneComponent> = self.outer_membrane.iter().collect();
+        ring.sort_by(|a, b| a.circle_angle.total_cmp(&b.circle_angle));
+
+        if ring.len() >= 3 {
+            let centroid = ring.iter().map(|c| c.position).sum::<Vec2>() / ring.len() as f32;
+            for (i, component) in ring.iter().enumerate() {
+                let next = ring[(i + 1) % ring.len()];
+                draw_triangle(centroid, component.position, next.position, CYTOPLASM_COLOR);
+            }
+        }
+
+        self.draw();
+    }
+
     pub fn draw(&self) {
         // Draw expansion zone if active (blue circle stays stationary)
         if self.expansion_radius > 0.0 {
@@ -421,8 +774,30 @@ impl Cell {
             component.draw();
         }
 
+        // Mark torn gaps so the membrane's breaking point is visible
+        for ring in [&self.inner_membrane, &self.outer_membrane] {
+            for (i, component) in ring.iter().enumerate() {
+                if component.tear_age.is_some() {
+                    let next = ring[(i + 1) % ring.len()].position;
+                    draw_line(component.position.x, component.position.y, next.x, next.y, MEMBRANE_TEAR_WIDTH, MEMBRANE_TEAR_COLOR);
+                }
+            }
+        }
+
+        // Draw food being digested, shrinking and fading on its way to the center
+        for digesting in &self.digesting {
+            let t = digesting.remaining / DIGEST_TIME;
+            let position = self.actual_center + digesting.offset * t;
+            let color = Color::new(FOOD_COLOR.r, FOOD_COLOR.g, FOOD_COLOR.b, t);
+            draw_circle(position.x, position.y, FOOD_RADIUS * (0.5 + 0.5 * t), color);
+        }
+
         // Draw center markers for reference
         draw_circle(self.actual_center.x, self.actual_center.y, CENTER_MARKER_RADIUS, GREEN);
         draw_circle(self.head_position.x, self.head_position.y, CENTER_MARKER_RADIUS, RED);
+
+        if self.energy > 0.0 {
+            draw_text(&format!("{:.0}", self.energy), self.actual_center.x + 8.0, self.actual_center.y - 8.0, 18.0, ENERGY_TEXT_COLOR);
+        }
     }
 }
@@ -14,6 +14,8 @@ pub const HEAD_ZONE_COLOR: Color = Color::new(1.0, 0.0, 0.0, 0.1);  // Head zone
 pub const HEAD_ZONE_BORDER_COLOR: Color = Color::new(1.0, 0.0, 0.0, 0.3);  // Head zone border
 pub const EXPANSION_ZONE_COLOR: Color = Color::new(0.0, 0.5, 1.0, 0.05);  // Expansion zone fill (transparent blue)
 pub const EXPANSION_ZONE_BORDER_COLOR: Color = Color::new(0.0, 0.5, 1.0, 0.2);  // Expansion zone border
+pub const CYTOPLASM_COLOR: Color = Color::new(0.3, 0.6, 1.0, 0.15);  // Filled-membrane view (V) - fill inside the outer ring
+pub const MEMBRANE_TEAR_COLOR: Color = Color::new(1.0, 0.2, 0.2, 0.8);  // Line drawn across a torn membrane gap
 
 // Lipid component visuals
 pub const LIPID_CIRCLE_RADIUS: f32 = 3.5;  // Radius of the lipid head (hydrophilic)
@@ -25,6 +27,7 @@ pub const CENTER_MARKER_RADIUS: f32 = 3.0;  // Radius of center point markers
 pub const LIPID_HEAD_OUTLINE_WIDTH: f32 = 0.1;  // Line width for lipid head circle outline
 pub const EXPANSION_ZONE_BORDER_WIDTH: f32 = 1.5;  // Line width for expansion zone border
 pub const HEAD_ZONE_BORDER_WIDTH: f32 = 1.0;  // Line width for head zone border
+pub const MEMBRANE_TEAR_WIDTH: f32 = 1.5;  // Line width for the torn-gap marker
 
 // =============================================================================
 // MEMBRANE STRUCTURE
@@ -48,6 +51,7 @@ pub const HEAD_ACCELERATION: f32 = 200.0;    // Head moves faster than center to
 pub const HEAD_DAMPING: f32 = 0.96;          // Head damping
 pub const HEAD_MAX_SPEED: f32 = 100.0;       // Maximum head velocity
 pub const HEAD_STATIONARY_THRESHOLD: f32 = 20.0;  // Velocity below which head is considered stationary
+pub const MOUSE_FOLLOW_DEAD_ZONE: f32 = HEAD_RADIUS;  // Mouse-follow (F) stops steering once the cursor is this close to the center
 
 // =============================================================================
 // CENTER PHYSICS
@@ -66,6 +70,15 @@ pub const NEIGHBOR_FORCE_STRENGTH: f32 = 2000.0;  // How strongly neighbors push
 pub const MEMBRANE_SEPARATION_FORCE: f32 = 1000.0;  // How strongly the inner and outer membranes repel each other to maintain minimum distance
 pub const MEMBRANE_ALIGNMENT_FORCE: f32 = 2500.0;  // How strongly paired inner/outer components stay angularly aligned
 
+// =============================================================================
+// MEMBRANE RUPTURE/REPAIR
+// =============================================================================
+
+pub const MEMBRANE_TEAR_STRETCH: f32 = 6.0;  // A gap wider than this many desired neighbor distances tears
+pub const MEMBRANE_HEAL_STRETCH: f32 = 2.0;  // A torn gap narrower than this many desired distances can heal (room for one new lipid)
+pub const MEMBRANE_HEAL_DELAY: f32 = 1.0;  // Seconds a gap stays torn before it may heal
+pub const MEMBRANE_TEAR_RESEAL_STRENGTH: f32 = 0.02;  // Fraction of the neighbor spring still pulling across a tear, so the ends slowly drift back
+
 // =============================================================================
 // MEMBRANE FLOW
 // =============================================================================
@@ -85,3 +98,31 @@ pub const EXPANSION_PUSH_FORCE: f32 = 800.0;   // How strongly the expansion zon
 pub const EXPANSION_INITIAL_RADIUS: f32 = 40.0;  // Starting radius when expansion begins
 pub const EXPANSION_PERSIST_TIME: f32 = 1.5;  // How long expansion zone stays active after movement starts (seconds)
 pub const STATIONARY_DELAY: f32 = 0.001;     // Seconds head must be stationary before reforming to circle
+
+// =============================================================================
+// CELL-CELL COLLISION
+// =============================================================================
+
+pub const CELL_CONTACT_DISTANCE: f32 = 2.0 * LIPID_CIRCLE_RADIUS;  // Outer lipids of different cells closer than this push apart
+pub const CELL_REPULSION_FORCE: f32 = 1500.0;  // How strongly overlapping outer membranes of different cells repel
+
+// =============================================================================
+// DIVISION
+// =============================================================================
+
+pub const DIVISION_HOLD_TIME: f32 = 6.0;  // Seconds at full expansion before a cell divides on its own
+pub const DIVISION_OFFSET: f32 = OUTER_MEMBRANE_RADIUS;  // How far each daughter moves from the midline along the pinch axis
+pub const MAX_CELLS: usize = 8;  // Automatic division stops once this many cells exist
+
+// =============================================================================
+// FOOD / PHAGOCYTOSIS
+// =============================================================================
+
+pub const FOOD_RADIUS: f32 = 3.0;
+pub const FOOD_COLOR: Color = Color::new(0.4, 1.0, 0.4, 1.0);  // Free-floating food (green)
+pub const FOOD_MAX_SPEED: f32 = 25.0;  // Food drifts at up to this speed
+pub const MAX_FOOD: usize = 30;  // Food stops spawning once this many particles float free
+pub const FOOD_SPAWN_INTERVAL: f32 = 0.8;  // Seconds between food spawns
+pub const FOOD_ENERGY: f32 = 1.0;  // Energy a cell gains per absorbed food particle
+pub const DIGEST_TIME: f32 = 1.2;  // Seconds an absorbed particle takes to sink to the center and vanish
+pub const ENERGY_TEXT_COLOR: Color = Color::new(0.4, 1.0, 0.4, 0.8);
@@ -13,7 +13,6 @@ pub const COLOR_CYCLE_SIZE: usize = 6;
 // ===== MATHEMATICAL CONSTANTS =====
 pub const PI: f32 = std::f32::consts::PI;
 pub const EPSILON: f32 = 0.001;
-pub const COLOR_MAX: f32 = 255.0;
 
 // ===== PROTON PHYSICS =====
 pub mod proton {
@@ -21,11 +20,13 @@ pub mod proton {
     pub const FRICTION: f32 = 1.0;
     pub const BOUNCE_DAMPENING: f32 = 1.0;
     pub const MAX_SPEED: f32 = 200.0;  // Same as white ring speed
+    pub const MAX_STEP_VELOCITY_CHANGE: f32 = MAX_SPEED; // Cap on what one force application may add - a huge dt can't fling bonds apart
 
     // Size
     pub const MIN_RADIUS: f32 = 3.0;
     pub const MAX_RADIUS: f32 = 7.0;
     pub const ENERGY_TO_RADIUS_FACTOR: f32 = 0.01;
+    pub const MAX_NUCLEON_SCALED_RADIUS: f32 = 14.0; // Cap once pond.toml `radius_nucleon_exponent` grows heavy nuclei
 
     // Mass and Energy
     pub const ENERGY_TO_MASS_FACTOR: f32 = 0.1;
@@ -47,6 +48,17 @@ pub mod proton {
     pub const GLOW_LAYER2_RADIUS: f32 = 2.0;
     pub const GLOW_LAYER2_ALPHA: f32 = 0.25;
 
+    // Element-shape render style (I) - sizes relative to the particle's render radius
+    pub const DIAMOND_GLYPH_SCALE: f32 = 1.2; // A diamond covers less area than a circle of the same radius
+    pub const MOLECULE_GLYPH_CORE_RADIUS: f32 = 0.7;
+    pub const MOLECULE_GLYPH_LIGAND_RADIUS: f32 = 0.4;
+    pub const MOLECULE_GLYPH_LIGAND_DISTANCE: f32 = 0.85; // Ligand center distance from the nucleus
+
+    // Lifetime indicator (debug overlay)
+    pub const LIFETIME_ARC_RADIUS: f32 = 2.6; // Arc radius as a multiple of the proton radius
+    pub const LIFETIME_ARC_THICKNESS: f32 = 1.5;
+    pub const LIFETIME_ARC_ENERGY_SCALE: f32 = 100.0; // Energy shown as full white (matches energy rings)
+
     // Colors
     pub const STABLE_HYDROGEN_COLOR: (u8, u8, u8) = (255, 255, 255);
     pub const NEUTRAL_PROTON_COLOR: (u8, u8, u8) = (200, 200, 200);
@@ -55,6 +67,32 @@ pub mod proton {
     pub const NEUTRON_FORMATION_TIME: f32 = 0.1;
     pub const NEUTRON_RADIUS_MULTIPLIER: f32 = 1.2;
 
+    // Leptons (e+ from H+ + H+ → D, e- from tritium decay)
+    pub const POSITRON_COLOR: (u8, u8, u8) = (255, 120, 220);
+    pub const ELECTRON_COLOR: (u8, u8, u8) = (120, 200, 255);
+    pub const LEPTON_ENERGY: f32 = 0.5;
+    pub const LEPTON_LIFETIME: f32 = 0.6;
+    pub const LEPTON_RELEASE_SPEED: f32 = 320.0;
+
+    // Tritium (D + D → T + H+, beta-decays to He3 + e- when its lifetime runs out)
+    pub const TRITIUM_COLOR: (u8, u8, u8) = (150, 255, 170);
+    pub const TRITIUM_LIFETIME: f32 = 12.0;
+    pub const TRITIUM_RADIUS_MULTIPLIER: f32 = 1.4;
+
+    // Collision heat - hard impacts and fusion leave a particle hot, and hot particles cannot crystallize
+    pub const COLLISION_HEAT_MIN_SPEED: f32 = 120.0; // Impact speed (along the contact normal) that starts heating
+    pub const COLLISION_HEAT_PER_SPEED: f32 = 0.005; // Temperature gained per unit of impact speed above the minimum
+    pub const FUSION_HEAT: f32 = 1.0; // Fusion products start fully hot
+    pub const MAX_TEMPERATURE: f32 = 1.0;
+    pub const HOT_TEMPERATURE: f32 = 0.25; // At or above this a particle counts as evaporating
+    pub const HEAT_DECAY_RATE: f32 = 0.5; // Exponential cooling per second (1.0 -> 0.25 in about 2.8s)
+
+    // Oscillation diagnostic - bonded particles whose velocity keeps reversing without dying down
+    pub const OSCILLATION_WINDOW: f32 = 0.5; // Seconds of velocity history judged at a time
+    pub const OSCILLATION_MIN_REVERSALS: u32 = 4; // Direction flips within one window
+    pub const OSCILLATION_MIN_SPEED: f32 = 2.0; // Mean speed below this counts as settled
+    pub const OSCILLATION_DECAY_RATIO: f32 = 0.8; // Still oscillating unless the mean speed fell below this share of the last window's
+
     // Electron Capture
     pub const ELECTRON_CAPTURE_DISTANCE: f32 = 15.0;
 
@@ -64,8 +102,17 @@ pub mod proton {
     // Fusion thresholds
     pub const DEUTERIUM_FUSION_VELOCITY_THRESHOLD: f32 = 0.5;
     pub const HELIUM3_FUSION_VELOCITY_THRESHOLD: f32 = 0.6;
+    pub const PP_CHAIN_VELOCITY_THRESHOLD: f32 = 150.0; // H+ + H+ → D is the slow first step of the chain
+    pub const DD_FUSION_VELOCITY_THRESHOLD: f32 = 60.0; // D + D → T + H+
     pub const FUSION_ENERGY_RELEASE: f32 = 30.0;
 
+    // He3 + He3 → He4 + 2 H+ - the combined energy (and so mass) is split between the products,
+    // the two H+ fly out back to back in the center-of-mass frame and the He4 takes up any remaining recoil
+    pub const HELIUM3_FUSION_HE4_ENERGY_FRACTION: f32 = 0.5;
+    pub const HELIUM3_FUSION_PROTON_ENERGY_FRACTION: f32 = 0.25; // Each
+    pub const HELIUM3_FUSION_RELEASE_SPEED: f32 = 200.0; // H+ speed relative to the center of mass
+    pub const HELIUM3_FUSION_EJECT_OFFSET: f32 = 10.0;
+
     // Helium colors
     pub const HELIUM3_COLOR: (u8, u8, u8) = (255, 200, 100);
     pub const HELIUM4_COLOR: (u8, u8, u8) = (255, 255, 100);
@@ -84,6 +131,15 @@ pub mod proton {
     pub const OXYGEN16_CAPTURE_VELOCITY_THRESHOLD: f32 = 0.6;
     pub const OXYGEN16_BOND_STRENGTH: f32 = 200.0;  // Reduced from 800.0 to allow particles to be further apart
     pub const OXYGEN16_BREAKING_DISTANCE: f32 = 380.0;
+    pub const O16_CONSOLIDATION_TIME: f32 = 3.0;  // Seconds a C12+He4 bond must hold before collapsing into one O16
+    pub const OXYGEN16_RADIUS_MULTIPLIER: f32 = 2.6;
+
+    // Alpha-capture recoil - every He4 capture (O16 through S32) ejects a slow e+ e- pair along the alpha's
+    // path and the product recoils the other way, so momentum is conserved (the pair is neutral, so charge is too)
+    pub const ALPHA_CAPTURE_EJECT_SPEED: f32 = 90.0; // Lepton speed relative to the product
+    pub const ALPHA_CAPTURE_EJECT_ENERGY_FRACTION: f32 = 0.1; // Share of the product's energy the pair carries off
+    pub const ALPHA_CAPTURE_EJECT_GAP: f32 = 4.0; // Space between the product's edge and the spawned leptons
+    pub const ALPHA_CAPTURE_EJECT_SPREAD: f32 = 0.35; // Radians each lepton leaves either side of the alpha's path
 
     // Water (H2O molecule)
     pub const WATER_COLOR: (u8, u8, u8) = (40, 100, 180);
@@ -98,7 +154,7 @@ pub mod proton {
 
     // Water ice formation (geometric patterns: 3=triangle, 4=square, 5=hexagon)
     pub const WATER_ICE_COMPRESSION_DISTANCE: f32 = 90.0;  // Max distance for valid ice formation
-    pub const WATER_ICE_MAX_BONDS: usize = 5;  // Max bonds per H2O (3=triangle, 4=square, 5=hexagon)
+    pub const WATER_ICE_MAX_BONDS: usize = 6;  // Max bonds per H2O (3=triangle, 4=square, 5-6=hexagon) - pond.toml `water_ice_max_bonds` lowers it
     pub const WATER_ICE_FROZEN_REST_LENGTH: f32 = 68.0;  // Perfect hexagonal ice bond length
     pub const WATER_ICE_ANGLE_TOLERANCE: f32 = 0.35;  // ~20 degrees - relaxed tolerance for realistic geometry
     pub const WATER_ICE_ANGLE_TOLERANCE_TO_FROZEN: f32 = 0.52;  // ~30 degrees - more relaxed when bonding to frozen neighbors
@@ -171,6 +227,11 @@ pub mod proton {
     pub const SIH4_COLOR: (u8, u8, u8) = (220, 100, 50);  // Orange-red
     pub const SIH4_RADIUS_MULTIPLIER: f32 = 3.1;
     pub const SIH4_CAPTURE_RANGE: f32 = 50.0;
+
+    // Sulfur Dioxide (SO2) - S32 + 2 O16
+    pub const SO2_COLOR: (u8, u8, u8) = (230, 200, 140);  // Pale sulfur-tan
+    pub const SO2_RADIUS_MULTIPLIER: f32 = 3.4;
+    pub const SO2_CAPTURE_RANGE: f32 = 55.0;
 }
 
 // ===== PROTON MANAGER PHYSICS =====
@@ -184,6 +245,23 @@ pub mod proton_manager {
     pub const CHARGE_REPULSION_STRENGTH: f32 = 1000.0;
     pub const CHARGE_ATTRACTION_STRENGTH: f32 = 800.0;
 
+    // Shared neighbor grid for the crystallization passes - one cell spans the widest neighbor distance
+    pub const NEIGHBOR_GRID_CELL_SIZE: f32 = 110.0;
+
+    // Spawn de-overlap - new particles are pushed out of existing ones before physics runs
+    pub const SPAWN_MIN_SPACING: f32 = 20.0; // Closest a spawned particle may land to a live one
+    pub const SPAWN_SPACING_ATTEMPTS: usize = 64; // Spiral steps tried before giving up and stacking
+    pub const SPAWN_SPIRAL_ANGLE: f32 = 2.399_963; // Golden angle - successive steps never line up
+
+    // Floor for a chosen spawn energy (spawn_element_with_energy) - radius and mass stay positive
+    pub const MIN_SPAWN_ENERGY: f32 = 0.1;
+
+    // Fusion - reactions resolved per frame, each on slots no other reaction that frame touched (pond.toml `max_reactions_per_frame`)
+    pub const MAX_REACTIONS_PER_FRAME: usize = 32;
+
+    // Slot storage - the proton vector doubles on demand until this hard cap (pond.toml `max_protons` overrides), then spawns are refused
+    pub const MAX_PROTON_CAPACITY: usize = 2400;
+
     // Proton bounce behavior at close distances (1-2 pixels)
     pub const PROTON_BOUNCE_DISTANCE: f32 = 1.5;  // Distance at which protons bounce instead of applying forces
     pub const PROTON_BOUNCE_DAMPENING: f32 = 1.0;  // Bounce dampening factor (1.0 = perfect bounce, 0.0 = no bounce)
@@ -196,13 +274,24 @@ pub mod proton_manager {
     pub const HE4_ATTRACTION_RANGE: f32 = 1420.0;
     pub const HE4_ATTRACTION_STRENGTH: f32 = 500.0;
 
+    // Molecule cohesion - weak short-range pull between molecules of the same kind, so vapor condenses
+    // (each strength is a pond.toml `<molecule>_cohesion_strength` key, 0 turns that molecule's cohesion off)
+    pub const MOLECULE_COHESION_RANGE: f32 = 120.0;
+    pub const H2O_COHESION_STRENGTH: f32 = 400.0; // Hydrogen bonds - water beads up the most
+    pub const H2S_COHESION_STRENGTH: f32 = 180.0;
+    pub const MGH2_COHESION_STRENGTH: f32 = 250.0;
+    pub const CH4_COHESION_STRENGTH: f32 = 120.0; // Weakest - methane pools loosely
+    pub const SIH4_COHESION_STRENGTH: f32 = 150.0;
+    pub const SO2_COHESION_STRENGTH: f32 = 300.0;
+
     // Solid collision parameters
     pub const COLLISION_ELASTICITY: f32 = 0.8;
 
     pub const ATOM_ATTRACTION_RANGE: f32 = 220.0;
     pub const ATOM_ATTRACTION_STRENGTH: f32 = 15000.0;
     pub const ATOM_REPULSION_STRENGTH: f32 = 8000.0;
-    pub const NEUTRON_FORMATION_DISTANCE: f32 = 225.0;
+    pub const NEUTRON_FORMATION_RANGE: f32 = 50.0; // H+ this close to an atom starts turning into deuterium
+    pub const NEUTRON_FORMATION_PROBABILITY: f32 = 1.0; // Chance per step that an H+ in range for NEUTRON_FORMATION_TIME converts
 
     pub const MIN_ATOM_ENERGY_THRESHOLD: f32 = 30.0;
     pub const MIN_COMBINED_ENERGY: f32 = 10.0;
@@ -215,16 +304,62 @@ pub mod proton_manager {
 
     pub const FUSION_UPDATE_INTERVAL: i32 = 12;
 
+    // Reaction rate statistics - reactions are counted over this sliding window (seconds)
+    pub const REACTION_RATE_WINDOW: f32 = 5.0;
+
+    // Frames whose total energy (see ProtonManager::total_energy) moves by at least this much are logged to stderr
+    pub const ENERGY_DELTA_LOG_THRESHOLD: f32 = 30.0; // One FUSION_ENERGY_RELEASE
+
+    // Population controller (N) - a PI loop spawns or removes one element to hold a target count
+    pub const POPULATION_GAIN_P: f32 = 1.0; // Particles per second per particle short of (or over) the target
+    pub const POPULATION_GAIN_I: f32 = 0.2; // Particles per second per particle-second of accumulated error
+    pub const POPULATION_INTEGRAL_LIMIT: f32 = 50.0; // Anti-windup clamp on the accumulated error
+    pub const POPULATION_MAX_RATE: f32 = 10.0; // Most particles spawned or removed per second
+    pub const POPULATION_SPAWN_SPEED: f32 = 80.0; // Spawned particles head off in a random direction
+    pub const POPULATION_SPAWN_MARGIN: f32 = 20.0; // Keep spawns this far inside the world edges
+    pub const POPULATION_PROTON_ENERGY: f32 = 10.0; // Energy of controller-spawned free H+
+
     // Red wave repulsion for H- protons
     pub const RED_WAVE_REPULSION_STRENGTH: f32 = 5000.0;
     pub const RED_WAVE_INTERACTION_THRESHOLD: f32 = 100.0; // Speed threshold to be "red"
     pub const RED_WAVE_REPULSION_WIDTH: f32 = 15.0; // Thickness of interaction zone
+    pub const RED_WAVE_MAX_REPULSION_FORCE: f32 = 5000.0; // Cap on the summed push a particle gets from every red ring in one frame
 
     // Red wave melting for H ice
     pub const DARK_RED_WAVE_SPEED_THRESHOLD: f32 = 30.0; // Only lowest 5 red colors
     pub const RED_WAVE_HITS_TO_MELT: u8 = 5; // Number of hits needed to melt ice
     pub const RED_WAVE_HIT_COOLDOWN: f32 = 0.3; // Cooldown between hits to prevent double-counting
 
+    // Wave thermal effects on unfrozen particles - blue waves put energy in, red waves take it out
+    pub const WAVE_THERMAL_BAND: f32 = 20.0; // Distance from ring edge that is affected
+    pub const BLUE_WAVE_HEAT_SPEED_THRESHOLD: f32 = 150.0; // Rings at least this fast count as "blue"
+    pub const BLUE_WAVE_HEAT_RATE: f32 = 3.0; // Heat added per second at full ring strength (see pc::HOT_TEMPERATURE)
+    pub const BLUE_WAVE_KICK_ACCELERATION: f32 = 60.0; // Outward push per second at full ring strength
+    pub const RED_WAVE_DAMPING_RATE: f32 = 2.0; // Fraction of velocity removed per second at full ring strength (red = below RED_WAVE_INTERACTION_THRESHOLD)
+
+    // Temperature field (rings heat/cool a coarse grid that scales evaporation thresholds)
+    pub const TEMPERATURE_CELL_SIZE: f32 = 40.0; // Size of one grid cell in pixels
+    pub const TEMPERATURE_RING_BAND: f32 = 30.0; // Distance from ring edge that receives heat
+    pub const TEMPERATURE_DEPOSIT_RATE: f32 = 1.5; // Heat added per second at full ring strength
+    pub const TEMPERATURE_DECAY_RATE: f32 = 0.4; // Fraction of temperature lost per second
+    pub const TEMPERATURE_MAX: f32 = 1.0; // Hottest cell (blue waves)
+    pub const TEMPERATURE_MIN: f32 = -1.0; // Coldest cell (dark red waves)
+    pub const TEMPERATURE_EVAPORATION_FACTOR: f32 = 0.6; // Max fraction of threshold removed/added by temperature
+
+    // Click-and-drag force tool (Shift = attractor, Ctrl = repulsor)
+    pub const POINT_FORCE_STRENGTH: f32 = 2_000_000.0; // Acceleration at unit distance (falls off as 1/r²)
+    pub const POINT_FORCE_RADIUS: f32 = 300.0; // Protons beyond this distance are unaffected
+    pub const POINT_FORCE_MIN_DISTANCE: f32 = 30.0; // Softening distance so the force stays finite at the cursor
+
+    // Layered (cross-section) view - gravity weighted by nucleon count against a buoyant medium
+    pub const GRAVITY_STRENGTH: f32 = 120.0; // Downward acceleration of an infinitely heavy particle
+    pub const GRAVITY_BUOYANT_NUCLEONS: f32 = 6.0; // Lighter particles float up, heavier ones sink
+    pub const GRAVITY_DRAG: f32 = 1.5; // Fraction of velocity shed per second, so particles settle into layers
+
+    // Ambient medium - linear drag on every unfrozen particle (see Medium)
+    pub const MEDIUM_THIN_DRAG: f32 = 0.3; // Fraction of velocity shed per second - a gas slowly settles
+    pub const MEDIUM_THICK_DRAG: f32 = 1.5; // Liquid-like - fast particles stop within a second or two
+
     // H crystallization (phase transitions)
     pub const H_CRYSTAL_MIN_NEIGHBORS: usize = 3; // Minimum H's to crystallize (1 center + 6 sides)
     pub const H_CRYSTAL_NEIGHBOR_DISTANCE: f32 = 80.0; // Max distance to be neighbors
@@ -267,6 +402,7 @@ pub mod proton_manager {
     pub const C12_BOND_STRENGTH_GRAPHITE: f32 = 65.0; // Strong covalent (graphite)
     pub const C12_BOND_STRENGTH_DIAMOND: f32 = 120.0; // Ultra-strong covalent (diamond)
     pub const C12_BOND_REST_LENGTH: f32 = 60.0;
+    pub const C12_BOND_BREAKING_DISTANCE: f32 = 135.0; // Bonds stretched past this snap
     pub const C12_EVAPORATION_SPEED: f32 = 100.0; // Hard to evaporate
     pub const C12_FROZEN_EVAPORATION_SPEED: f32 = 250.0;
     pub const C12_FREEZE_COOLDOWN: f32 = 12.0;
@@ -289,6 +425,7 @@ pub mod proton_manager {
     pub const NE20_MIN_SPACING: f32 = 38.0;
     pub const NE20_BOND_STRENGTH: f32 = 8.0; // Very weak (noble gas) - slightly stronger than He
     pub const NE20_BOND_REST_LENGTH: f32 = 52.0;
+    pub const NE20_BOND_BREAKING_DISTANCE: f32 = 120.0; // Bonds stretched past this snap
     pub const NE20_EVAPORATION_SPEED: f32 = 15.0; // Low threshold - breaks easily
     pub const NE20_FROZEN_EVAPORATION_SPEED: f32 = 40.0;
     pub const NE20_FREEZE_COOLDOWN: f32 = 4.0;
@@ -303,6 +440,7 @@ pub mod proton_manager {
     pub const MG24_MIN_SPACING: f32 = 45.0;
     pub const MG24_BOND_STRENGTH: f32 = 40.0; // Moderate - bonds bend not break
     pub const MG24_BOND_REST_LENGTH: f32 = 65.0;
+    pub const MG24_BOND_BREAKING_DISTANCE: f32 = 165.0; // Metallic bonds stretch further before snapping
     pub const MG24_EVAPORATION_SPEED: f32 = 110.0; // Higher - harder to break metallic bonds
     pub const MG24_FROZEN_EVAPORATION_SPEED: f32 = 220.0;
     pub const MG24_FREEZE_COOLDOWN: f32 = 10.0;
@@ -317,6 +455,7 @@ pub mod proton_manager {
     pub const SI28_MIN_SPACING: f32 = 48.0;
     pub const SI28_BOND_STRENGTH: f32 = 70.0; // Strong covalent bonds (diamond cubic)
     pub const SI28_BOND_REST_LENGTH: f32 = 62.0;
+    pub const SI28_BOND_BREAKING_DISTANCE: f32 = 140.0; // Bonds stretched past this snap
     pub const SI28_EVAPORATION_SPEED: f32 = 90.0;
     pub const SI28_FROZEN_EVAPORATION_SPEED: f32 = 220.0;
     pub const SI28_FREEZE_COOLDOWN: f32 = 11.0;
@@ -332,6 +471,7 @@ pub mod proton_manager {
     pub const S32_MIN_SPACING: f32 = 40.0;
     pub const S32_BOND_STRENGTH: f32 = 50.0; // Moderate covalent bonds within rings
     pub const S32_BOND_REST_LENGTH: f32 = 55.0; // Distance between bonded S atoms in ring
+    pub const S32_BOND_BREAKING_DISTANCE: f32 = 115.0; // Ring bonds stretched past this snap
     pub const S32_EVAPORATION_SPEED: f32 = 65.0; // Speed to break ring
     pub const S32_FROZEN_EVAPORATION_SPEED: f32 = 150.0;
     pub const S32_FREEZE_COOLDOWN: f32 = 8.0;
@@ -419,6 +559,7 @@ pub mod atom {
     pub const PULSE_INTENSITY_BASE: f32 = 0.3;
     pub const PULSE_INTENSITY_ENERGY_FACTOR: f32 = 0.01;
     pub const SIZE_PULSE_FACTOR: f32 = 0.2;
+    pub const FAINT_ALPHA: f32 = 0.25; // Alpha of the optional atom overlay
     pub const SIZE_PULSE_ENERGY_FACTOR: f32 = 0.01;
 
     pub const ENERGY_DIFFERENCE_AMPLIFICATION: f32 = 0.4;
@@ -445,8 +586,18 @@ pub mod ring {
     pub const DEFAULT_THICKNESS: f32 = 6.0;
 
     pub const BOUNCE_REFLECTION_OPACITY: f32 = 0.7;
-    pub const ALPHA_CALCULATION_DIVISOR: f32 = 800.0;
-    pub const MINIMUM_ALPHA: f32 = 0.1;
+    pub const FUSION_LINE_GAP: f32 = 9.0; // Fusion rings trail a second, fainter line this far inside
+    pub const FUSION_LINE_OPACITY: f32 = 0.5;
+    // Shockwave style - the wake behind the front is this wide over speed, so slow waves look broad
+    pub const SHOCKWAVE_WIDTH_SPEED: f32 = 1500.0;
+    pub const SHOCKWAVE_MIN_WIDTH: f32 = 6.0;
+    pub const SHOCKWAVE_MAX_WIDTH: f32 = 60.0;
+    pub const SHOCKWAVE_BANDS: usize = 6; // Concentric lines that make up the wake's gradient
+    pub const SHOCKWAVE_WAKE_OPACITY: f32 = 0.6; // Wake opacity just behind the front, fading to 0 at its back edge
+    pub const INTERFERENCE_RADIUS: f32 = 5.0; // Highlight drawn where two rings cross
+    pub const INTERFERENCE_OPACITY: f32 = 0.9;
+    pub const ENERGY_DECAY_RADIUS: f32 = 1200.0; // Radius at which a ring's energy is fully spent
+    pub const MAX_RINGS: usize = 256; // Live ring cap - adding past it evicts the oldest ring (pond.toml `max_rings` overrides)
 
     pub const CULL_MARGIN: f32 = 100.0;
     pub const OFF_SCREEN_MARGIN: f32 = 500.0;
@@ -469,6 +620,47 @@ pub mod spatial_grid {
 // ===== RENDERING =====
 pub mod rendering {
     pub const VERTEX_RESERVE_SIZE: usize = 10000;
+    pub const BOND_COUNT_COLOR_MAX: usize = 8; // Bond count shown at full brightness in bond-count color mode
+    pub const CULL_RADIUS_SCALE: f32 = 3.0; // Glow, element multipliers and lifetime arcs reach past the base radius
+    pub const LABEL_CULL_MARGIN: f32 = 30.0; // Half the widest element label
+    pub const LABEL_FONT_SIZE: f32 = 18.0;
+    pub const INSPECT_MIN_RADIUS: f32 = 8.0; // Hover pick radius for particles drawn smaller than this
+    pub const DENSITY_CELL_SIZE: f32 = 40.0; // Bin size of the density heatmap overlay
+    pub const GROWTH_FRONT_FADE_TIME: f32 = 0.75; // Seconds a freshly frozen particle and its bonds stay highlighted
+    pub const GROWTH_FRONT_COLOR: (u8, u8, u8) = (255, 255, 210); // Highlight at the moment of freezing
+    pub const LOAD_FLASH_TIME: f32 = 1.0; // Seconds freshly loaded particles pulse an outline
+    pub const LOAD_FLASH_PULSES: f32 = 3.0; // Pulses over LOAD_FLASH_TIME
+    pub const LOAD_FLASH_MARGIN: f32 = 3.0; // Gap between a particle's edge and its load outline
+    pub const LOAD_FLASH_THICKNESS: f32 = 2.0;
+    pub const LOAD_FLASH_COLOR: (u8, u8, u8) = (120, 220, 255);
+    pub const LAYER_BANDS: usize = 32; // Steps in the layered view's background gradient
+    pub const LAYER_GUIDE_SPACING: f32 = 80.0; // Distance between the layered view's horizontal guide lines
+    pub const VELOCITY_ARROW_SCALE: f32 = 0.25; // Seconds of travel shown by a velocity arrow (length = speed * scale)
+    pub const VELOCITY_ARROW_MIN_LENGTH: f32 = 1.0; // Shorter arrows are skipped
+    pub const VELOCITY_ARROW_HEAD: f32 = 4.0; // Length of each arrowhead barb
+    pub const VELOCITY_ARROW_THICKNESS: f32 = 1.0;
+    pub const VELOCITY_ARROW_COLOR: (u8, u8, u8, u8) = (255, 230, 120, 200);
+}
+
+// ===== FIXED TIMESTEP =====
+pub mod timestep {
+    pub const FIXED_DT: f32 = 1.0 / 120.0; // Physics sub-step length in seconds
+    pub const MAX_SUBSTEPS: u32 = 8; // Cap per render frame so a slow frame can't snowball
+}
+
+// ===== SOUND EFFECTS =====
+pub mod sound {
+    pub const SAMPLE_RATE: u32 = 22050;
+    pub const POP_FREQUENCIES: [f32; 2] = [440.0, 660.0]; // Fusion
+    pub const POP_DURATION: f32 = 0.09;
+    pub const POP_VOLUME: f32 = 0.5;
+    pub const CHIME_FREQUENCIES: [f32; 2] = [1046.5, 1568.0]; // Molecule formation (C6 + G6)
+    pub const CHIME_DURATION: f32 = 0.35;
+    pub const CHIME_VOLUME: f32 = 0.4;
+    pub const TICK_FREQUENCIES: [f32; 1] = [2200.0]; // Crystal freezing
+    pub const TICK_DURATION: f32 = 0.025;
+    pub const TICK_VOLUME: f32 = 0.15;
+    pub const DECAY_RATE: f32 = 6.0; // Envelope e-folds over one duration
 }
 
 // ===== EVENTS =====
@@ -487,8 +679,17 @@ pub const RESET_RING_RADIUS: f32 = ring::RESET_RADIUS;
 pub const MAX_RADIUS_THRESHOLD: f32 = ring::MAX_RADIUS_THRESHOLD;
 pub const DEFAULT_RING_THICKNESS: f32 = ring::DEFAULT_THICKNESS;
 pub const BOUNCE_REFLECTION_OPACITY: f32 = ring::BOUNCE_REFLECTION_OPACITY;
-pub const ALPHA_CALCULATION_DIVISOR: f32 = ring::ALPHA_CALCULATION_DIVISOR;
-pub const MINIMUM_ALPHA: f32 = ring::MINIMUM_ALPHA;
+pub const FUSION_RING_LINE_GAP: f32 = ring::FUSION_LINE_GAP;
+pub const FUSION_RING_LINE_OPACITY: f32 = ring::FUSION_LINE_OPACITY;
+pub const SHOCKWAVE_WIDTH_SPEED: f32 = ring::SHOCKWAVE_WIDTH_SPEED;
+pub const SHOCKWAVE_MIN_WIDTH: f32 = ring::SHOCKWAVE_MIN_WIDTH;
+pub const SHOCKWAVE_MAX_WIDTH: f32 = ring::SHOCKWAVE_MAX_WIDTH;
+pub const SHOCKWAVE_BANDS: usize = ring::SHOCKWAVE_BANDS;
+pub const SHOCKWAVE_WAKE_OPACITY: f32 = ring::SHOCKWAVE_WAKE_OPACITY;
+pub const RING_INTERFERENCE_RADIUS: f32 = ring::INTERFERENCE_RADIUS;
+pub const RING_INTERFERENCE_OPACITY: f32 = ring::INTERFERENCE_OPACITY;
+pub const RING_ENERGY_DECAY_RADIUS: f32 = ring::ENERGY_DECAY_RADIUS;
+pub const MAX_RING_COUNT: usize = ring::MAX_RINGS;
 pub const CULL_MARGIN: f32 = ring::CULL_MARGIN;
 pub const OFF_SCREEN_MARGIN: f32 = ring::OFF_SCREEN_MARGIN;
 pub const WINDOW_WIDTH_MULTIPLIER: f32 = ring::WINDOW_WIDTH_MULTIPLIER;
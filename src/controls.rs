@@ -0,0 +1,244 @@
+// Controls - every key binding in one table
+// The Controls menu and the ? help overlay are drawn from it, and single-key presses are dispatched from it,
+// so a binding can't be documented one way and handled another. Mouse gestures, held keys, Ctrl chords and
+// the number keys are listed too but still handled inline in main.
+
+use macroquad::prelude::*;
+
+/// What a table-dispatched key press does - main applies it
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Action {
+    ClearNonStable,
+    DeleteStableHydrogen,
+    ClearAll,
+    TogglePause,
+    TogglePauseParticles,
+    ToggleMute,
+    ToggleTemperature,
+    CycleBoundary,
+    ToggleGravity,
+    CycleMedium,
+    ToggleShapes,
+    ToggleShockwave,
+    CycleColorMode,
+    TogglePaletteEditor,
+    ToggleTuningPanel,
+    ToggleDensity,
+    ToggleFrameGraph,
+    SweepColors,
+    CycleLegend,
+    ToggleLifetimeArcs,
+    ToggleVelocityArrows,
+    ToggleAtoms,
+    ToggleHelp,
+    SpawnEnergyDown,
+    SpawnEnergyUp,
+}
+
+/// How a control is triggered
+pub enum Binding {
+    /// One key press, matched here - Shift must be held when `shift` is set and must not be on the plain
+    /// binding of a key that has a Shift one (see shift_matches); Ctrl must not be held
+    /// `normal_only` keys are free in Cell mode, where they steer or edit the cell
+    Key { key: KeyCode, shift: bool, normal_only: bool, action: Action },
+    /// Handled inline in main
+    Manual,
+}
+
+pub struct Control {
+    pub keys: &'static str,
+    pub description: &'static str, // Empty = same as the control before it
+    pub binding: Binding,
+}
+
+const fn key(keys: &'static str, description: &'static str, key: KeyCode, action: Action) -> Control {
+    Control { keys, description, binding: Binding::Key { key, shift: false, normal_only: false, action } }
+}
+
+const fn shift_key(keys: &'static str, description: &'static str, key: KeyCode, action: Action) -> Control {
+    Control { keys, description, binding: Binding::Key { key, shift: true, normal_only: false, action } }
+}
+
+const fn normal_key(keys: &'static str, description: &'static str, key: KeyCode, action: Action) -> Control {
+    Control { keys, description, binding: Binding::Key { key, shift: false, normal_only: true, action } }
+}
+
+const fn manual(keys: &'static str, description: &'static str) -> Control {
+    Control { keys, description, binding: Binding::Manual }
+}
+
+/// Every control, one Controls menu line per group of related ones
+pub const CONTROL_LINES: &[&[Control]] = &[
+    &[
+        manual("Left Click", "spawn energy ring"),
+        manual("Wheel", "zoom at cursor"),
+        manual("Home", "fit all"),
+        manual("End", "recenter"),
+    ],
+    &[
+        manual("Right Click & Drag", "spawn selected element"),
+        normal_key("-", "lower spawn energy", KeyCode::Minus, Action::SpawnEnergyDown),
+        normal_key("=", "raise spawn energy", KeyCode::Equal, Action::SpawnEnergyUp),
+        manual("Middle Click", "follow particle"),
+    ],
+    &[manual("S + Right Click", "stamp seed crystal (H1, C12, Ne20, Mg24, Si28, S32)")],
+    &[
+        manual("Shift + Left Drag", "attract protons at cursor"),
+        manual("Ctrl + Left Drag", "repel protons at cursor"),
+        manual("Left Drag a wall corner", "resize the arena (drag back out to the window to reset)"),
+    ],
+    &[
+        manual("Q + Left Drag", "delete non-stable in box"),
+        manual("E + Left Drag", "delete all in box"),
+        manual("U + Left Click", "measure two particles"),
+    ],
+    &[
+        manual("Color Slider (bottom)", "ring color"),
+        manual(", .", ""),
+        normal_key("K", "edit palette", KeyCode::K, Action::TogglePaletteEditor),
+        normal_key("X", "sweep every color from cursor", KeyCode::X, Action::SweepColors),
+    ],
+    &[
+        key("R", "clear all non-stable particles", KeyCode::R, Action::ClearNonStable),
+        key("Space", "", KeyCode::Space, Action::ClearNonStable),
+        key("H", "delete stable hydrogen", KeyCode::H, Action::DeleteStableHydrogen),
+    ],
+    &[
+        key("Z", "clear all", KeyCode::Z, Action::ClearAll),
+        manual("Ctrl+C", "copy state"),
+        manual("Ctrl+V", "paste state"),
+        manual("Ctrl+S", "export pond.svg"),
+    ],
+    &[manual("1-9", "select discovered element"), manual("0", "free protons"), manual("[ ]", "step through them")],
+    &[
+        key("T", "temperature field", KeyCode::T, Action::ToggleTemperature),
+        manual("N", "hold population of selected (or H+)"),
+        manual("[ ]", "population target"),
+    ],
+    &[
+        key("B", "bond / oscillation colors", KeyCode::B, Action::CycleColorMode),
+        key("L", "lifetime arcs", KeyCode::L, Action::ToggleLifetimeArcs),
+        normal_key("V", "velocity arrows", KeyCode::V, Action::ToggleVelocityArrows),
+        normal_key("A", "atoms", KeyCode::A, Action::ToggleAtoms),
+        normal_key("D", "density", KeyCode::D, Action::ToggleDensity),
+        key("G", "frame graph", KeyCode::G, Action::ToggleFrameGraph),
+    ],
+    &[
+        key("O", "cycle boundary mode", KeyCode::O, Action::CycleBoundary),
+        normal_key("W", "medium drag", KeyCode::W, Action::CycleMedium),
+        key("I", "element shapes", KeyCode::I, Action::ToggleShapes),
+        shift_key("Shift+I", "shockwave rings", KeyCode::I, Action::ToggleShockwave),
+        normal_key("Y", "gravity layers", KeyCode::Y, Action::ToggleGravity),
+        key("J", "legend", KeyCode::J, Action::CycleLegend),
+    ],
+    &[
+        key("F1", "tuning panel (physics sliders, unlock all elements)", KeyCode::F1, Action::ToggleTuningPanel),
+        shift_key("?", "this help", KeyCode::Slash, Action::ToggleHelp),
+    ],
+    &[
+        key("P", "pause all", KeyCode::P, Action::TogglePause),
+        shift_key("Shift+P", "pause particles only", KeyCode::P, Action::TogglePauseParticles),
+        key("M", "mute sound", KeyCode::M, Action::ToggleMute),
+    ],
+    &[manual("Esc", "exit game")],
+];
+
+/// Every control in menu order
+pub fn all() -> impl Iterator<Item = &'static Control> {
+    CONTROL_LINES.iter().flat_map(|line| line.iter())
+}
+
+/// One Controls menu line - the keys, then what each does (e.g. "P / M: Pause all / mute sound")
+pub fn line_text(line: &[Control]) -> String {
+    let keys: Vec<&str> = line.iter().map(|c| c.keys).collect();
+    let descriptions: Vec<&str> = line.iter().map(|c| c.description).filter(|d| !d.is_empty()).collect();
+    format!("{}: {}", keys.join(" / "), capitalized(&descriptions.join(" / ")))
+}
+
+/// (keys, description) for every control, each standing alone - shared descriptions are repeated
+pub fn help_entries() -> Vec<(&'static str, String)> {
+    let mut previous = "";
+    all()
+        .map(|control| {
+            if !control.description.is_empty() {
+                previous = control.description;
+            }
+            (control.keys, capitalized(previous))
+        })
+        .collect()
+}
+
+/// Actions whose key was pressed this frame
+pub fn pressed_actions(normal_mode: bool) -> Vec<Action> {
+    let shift_held = is_key_down(KeyCode::LeftShift) || is_key_down(KeyCode::RightShift);
+    let ctrl_held = is_key_down(KeyCode::LeftControl) || is_key_down(KeyCode::RightControl);
+    if ctrl_held {
+        return Vec::new(); // Ctrl chords are handled in main
+    }
+    all()
+        .filter_map(|control| match control.binding {
+            Binding::Key { key, shift, normal_only, action } => {
+                (shift_matches(key, shift, shift_held) && (normal_mode || !normal_only) && is_key_pressed(key)).then_some(action)
+            }
+            Binding::Manual => None,
+        })
+        .collect()
+}
+
+/// Whether a binding of `key` fires with Shift held or not - Shift only has to be up for keys with a
+/// Shift variant (P, I), so every other key still works while Shift is held for a mouse gesture
+pub fn shift_matches(key: KeyCode, shift: bool, shift_held: bool) -> bool {
+    let has_shift_variant = || all().any(|c| matches!(c.binding, Binding::Key { key: k, shift: true, .. } if k == key));
+    if shift {
+        shift_held
+    } else {
+        !shift_held || !has_shift_variant()
+    }
+}
+
+fn capitalized(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_two_controls_share_a_key_press() {
+        let bindings: Vec<_> = all()
+            .filter_map(|control| match control.binding {
+                Binding::Key { key, shift, normal_only, .. } => Some((control.keys, key, shift, normal_only)),
+                Binding::Manual => None,
+            })
+            .collect();
+        for (i, a) in bindings.iter().enumerate() {
+            for b in &bindings[i + 1..] {
+                assert!((a.1, a.2) != (b.1, b.2), "{} and {} are the same key press", a.0, b.0);
+            }
+        }
+        // Cell mode steers and edits the cell with these (cell.rs, main) - a table key on one must stay normal-only
+        let cell_keys = [KeyCode::W, KeyCode::S, KeyCode::A, KeyCode::D, KeyCode::F, KeyCode::C, KeyCode::X, KeyCode::V];
+        for (keys, key, _, normal_only) in &bindings {
+            assert!(!cell_keys.contains(key) || *normal_only, "{} is also a Cell mode key", keys);
+        }
+        // Normal mode handles these inline (held for a mouse gesture, Ctrl chords, element selection, ...)
+        let inline_keys = [KeyCode::S, KeyCode::Q, KeyCode::E, KeyCode::U, KeyCode::N, KeyCode::Comma, KeyCode::Period,
+            KeyCode::Home, KeyCode::End, KeyCode::LeftBracket, KeyCode::RightBracket, KeyCode::Escape];
+        for (keys, key, _, _) in &bindings {
+            assert!(!inline_keys.contains(key), "{} is also handled inline", keys);
+        }
+        // Holding Shift only blocks the plain binding of a key that has a Shift one
+        assert!(shift_matches(KeyCode::R, false, true));
+        assert!(!shift_matches(KeyCode::P, false, true));
+        assert!(shift_matches(KeyCode::P, true, true));
+        assert!(!shift_matches(KeyCode::P, true, false));
+        // Every dispatched control is documented on its own line of the help overlay
+        assert_eq!(help_entries().len(), all().count());
+        assert!(help_entries().iter().all(|(_, description)| !description.is_empty()));
+    }
+}
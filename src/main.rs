@@ -6,18 +6,33 @@ mod proton;
 mod ring;
 mod atom;
 mod proton_manager;
+mod parallel;
+mod sim_config;
+mod snapshot;
+mod profile;
+mod scenario;
+mod sound;
+mod controls;
 
 // Cell-related modules (not yet integrated into the game)
 mod cell_constants;
 mod cell;
 
 use macroquad::prelude::*;
-use ring::RingManager;
+use ring::{RingManager, RingOrigin, RingStyle};
 use atom::AtomManager;
-use proton_manager::ProtonManager;
-use cell::Cell;
+use proton::Proton;
+use scenario::{Scenario, SCENARIOS};
+use proton_manager::{ProtonManager, ColorMode, ReactionFlags, RenderStyle, SimEvent, PopulationTarget, FREE_PROTON_NAME};
+use cell::{Cell, Food, MembraneParams};
+use sim_config::SimConfig;
+use profile::Profile;
+use sound::SoundEffects;
 use cell_constants as cc;
-use std::collections::HashSet;
+use constants::proton_manager as pm;
+use constants::timestep;
+use controls::Action;
+use std::collections::{HashSet, VecDeque};
 
 // Game Mode
 #[derive(PartialEq)]
@@ -31,15 +46,64 @@ enum GameMode {
 enum MenuState {
     None,
     Elements,
+    Scenarios,
     Controls,
 }
 
+// Pause tiers - P stops everything, Shift+P freezes the particles while rings keep expanding
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PauseState {
+    Running,
+    Particles,
+    All,
+}
+
+impl PauseState {
+    /// Protons and atoms hold still (spawning and the force tool are off too)
+    fn particles_paused(self) -> bool {
+        self != PauseState::Running
+    }
+
+    fn rings_paused(self) -> bool {
+        self == PauseState::All
+    }
+
+    /// P toggles a full pause, Shift+P toggles a particles-only pause (either key resumes from any pause)
+    fn toggled(self, particles_only: bool) -> Self {
+        match (self, particles_only) {
+            (PauseState::Running, false) => PauseState::All,
+            (PauseState::Running, true) => PauseState::Particles,
+            _ => PauseState::Running,
+        }
+    }
+}
+
+// Element legend (J) - discovered elements, every element, or nothing
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum LegendMode {
+    Discovered,
+    All,
+    Hidden,
+}
+
+impl LegendMode {
+    fn next(self) -> Self {
+        match self {
+            LegendMode::Discovered => LegendMode::All,
+            LegendMode::All => LegendMode::Hidden,
+            LegendMode::Hidden => LegendMode::Discovered,
+        }
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 enum ElementType {
     H1,
+    T,
     He3,
     He4,
     C12,
+    O16,
     Ne20,
     Mg24,
     Si28,
@@ -49,15 +113,18 @@ enum ElementType {
     MgH2,
     CH4,
     SiH4,
+    SO2,
 }
 
 impl ElementType {
     fn name(&self) -> &str {
         match self {
             ElementType::H1 => "H1",
+            ElementType::T => "T",
             ElementType::He3 => "He3",
             ElementType::He4 => "He4",
             ElementType::C12 => "C12",
+            ElementType::O16 => "O16",
             ElementType::Ne20 => "Ne20",
             ElementType::Mg24 => "Mg24",
             ElementType::Si28 => "Si28",
@@ -67,15 +134,18 @@ impl ElementType {
             ElementType::MgH2 => "MgH2",
             ElementType::CH4 => "CH4",
             ElementType::SiH4 => "SiH4",
+            ElementType::SO2 => "SO2",
         }
     }
 
     fn color(&self) -> Color {
         match self {
             ElementType::H1 => Color::from_rgba(255, 255, 255, 255),
+            ElementType::T => Color::from_rgba(150, 255, 170, 255),
             ElementType::He3 => Color::from_rgba(255, 200, 100, 255),
             ElementType::He4 => Color::from_rgba(255, 255, 100, 255),
             ElementType::C12 => Color::from_rgba(100, 100, 100, 255),
+            ElementType::O16 => Color::from_rgba(100, 180, 255, 255),
             ElementType::Ne20 => Color::from_rgba(255, 100, 150, 255),
             ElementType::Mg24 => Color::from_rgba(200, 200, 220, 255),
             ElementType::Si28 => Color::from_rgba(160, 130, 90, 255),
@@ -85,15 +155,18 @@ impl ElementType {
             ElementType::MgH2 => Color::from_rgba(180, 180, 190, 255),
             ElementType::CH4 => Color::from_rgba(120, 200, 150, 255),
             ElementType::SiH4 => Color::from_rgba(220, 100, 50, 255),
+            ElementType::SO2 => Color::from_rgba(230, 200, 140, 255),
         }
     }
 
     fn all() -> Vec<ElementType> {
         vec![
             ElementType::H1,
+            ElementType::T,
             ElementType::He3,
             ElementType::He4,
             ElementType::C12,
+            ElementType::O16,
             ElementType::Ne20,
             ElementType::Mg24,
             ElementType::Si28,
@@ -103,10 +176,37 @@ impl ElementType {
             ElementType::MgH2,
             ElementType::CH4,
             ElementType::SiH4,
+            ElementType::SO2,
         ]
     }
 }
 
+// Number keys select the Nth element the player can spawn, in Elements menu order
+const ELEMENT_KEYS: [KeyCode; 9] = [
+    KeyCode::Key1, KeyCode::Key2, KeyCode::Key3, KeyCode::Key4, KeyCode::Key5,
+    KeyCode::Key6, KeyCode::Key7, KeyCode::Key8, KeyCode::Key9,
+];
+
+/// Elements the keyboard can select - the discovered ones, or all of them with the debug palette on
+fn selectable_elements(discovered: &HashSet<ElementType>, unlock_all: bool) -> Vec<ElementType> {
+    ElementType::all().into_iter().filter(|e| unlock_all || discovered.contains(e)).collect()
+}
+
+/// The element `step` places away from `current` in `selectable`, wrapping around
+/// With nothing selected, stepping forward starts at the first and back at the last
+fn step_selection(current: Option<ElementType>, selectable: &[ElementType], step: isize) -> Option<ElementType> {
+    if selectable.is_empty() {
+        return current;
+    }
+    let len = selectable.len() as isize;
+    let next = match current.and_then(|e| selectable.iter().position(|&s| s == e)) {
+        Some(i) => (i as isize + step).rem_euclid(len),
+        None if step > 0 => 0,
+        None => len - 1,
+    };
+    Some(selectable[next as usize])
+}
+
 #[derive(Clone)]
 struct Button {
     x: f32,
@@ -145,7 +245,7 @@ impl ColorSlider {
         let relative_x = (mouse_x - self.x).max(0.0).min(self.width);
         let ratio = relative_x / self.width;
         let index = (ratio * self.num_colors as f32) as usize;
-        index.min(self.num_colors - 1)
+        index.min(self.num_colors.saturating_sub(1))
     }
 
     fn draw(&self, current_color_index: usize, colors: &[Color]) {
@@ -201,13 +301,445 @@ impl Button {
     }
 }
 
-fn draw_elements_menu(discovered: &HashSet<ElementType>, counts: &std::collections::HashMap<String, usize>, window_size: (f32, f32)) {
+/// Draggable slider bound to one live value of `T` (F1 tuning panel)
+struct ConfigSlider<T = SimConfig> {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    label: &'static str,
+    min: f32,
+    max: f32,
+    get: fn(&T) -> f32,
+    set: fn(&mut T, f32),
+    is_dragging: bool,
+}
+
+impl<T> ConfigSlider<T> {
+    fn new(label: &'static str, min: f32, max: f32, get: fn(&T) -> f32, set: fn(&mut T, f32)) -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+            width: DEBUG_PANEL_WIDTH - 40.0,
+            height: 14.0,
+            label,
+            min,
+            max,
+            get,
+            set,
+            is_dragging: false,
+        }
+    }
+
+    fn contains_point(&self, px: f32, py: f32) -> bool {
+        px >= self.x && px <= self.x + self.width && py >= self.y && py <= self.y + self.height
+    }
+
+    fn get_value_from_position(&self, mouse_x: f32) -> f32 {
+        let relative_x = (mouse_x - self.x).clamp(0.0, self.width);
+        self.min + (relative_x / self.width) * (self.max - self.min)
+    }
+
+    fn draw(&self, value: f32) {
+        // Label and current value above the track
+        draw_text(&format!("{}: {:.2}", self.label, value), self.x, self.y - 6.0, 18.0, WHITE);
+
+        // Track
+        draw_rectangle(self.x, self.y, self.width, self.height, Color::from_rgba(60, 60, 60, 220));
+        draw_rectangle_lines(self.x, self.y, self.width, self.height, 1.0, WHITE);
+
+        // Handle
+        let ratio = ((value - self.min) / (self.max - self.min)).clamp(0.0, 1.0);
+        let handle_x = self.x + ratio * self.width;
+        let handle_y = self.y + self.height / 2.0;
+        draw_circle(handle_x, handle_y, 7.0, BLACK);
+        draw_circle(handle_x, handle_y, 5.0, if self.is_dragging { YELLOW } else { LIGHTGRAY });
+    }
+}
+
+// Tuning panel layout (top left, below the Elements button)
+const DEBUG_PANEL_X: f32 = 10.0;
+const DEBUG_PANEL_Y: f32 = 60.0;
+const DEBUG_PANEL_WIDTH: f32 = 340.0;
+const DEBUG_PANEL_ROW_HEIGHT: f32 = 44.0;
+const DEBUG_PANEL_HEADER_HEIGHT: f32 = 36.0;
+
+// Ctrl+S writes the scene here, next to pond.toml
+const SVG_EXPORT_PATH: &str = "pond.svg";
+
+// Normal-mode camera - the mouse wheel zooms about the cursor, never past the window's world
+const CAMERA_MAX_ZOOM: f32 = 4.0;
+const CAMERA_ZOOM_STEP: f32 = 1.15; // Zoom factor per wheel notch
+const CAMERA_FIT_MARGIN: f32 = 40.0; // Screen-space border left around the particles when fitting them all in view
+
+/// Visible part of the world: `offset` is the world point at the screen's top-left corner
+struct ViewCamera {
+    offset: Vec2,
+    zoom: f32,
+}
+
+impl ViewCamera {
+    fn new() -> Self {
+        Self { offset: Vec2::ZERO, zoom: 1.0 }
+    }
+
+    fn view_rect(&self, window_size: (f32, f32)) -> Rect {
+        Rect::new(self.offset.x, self.offset.y, window_size.0 / self.zoom, window_size.1 / self.zoom)
+    }
+
+    fn screen_to_world(&self, point: Vec2) -> Vec2 {
+        self.offset + point / self.zoom
+    }
+
+    fn world_to_screen(&self, point: Vec2) -> Vec2 {
+        (point - self.offset) * self.zoom
+    }
+
+    fn screen_rect_to_world(&self, rect: Rect) -> Rect {
+        let min = self.screen_to_world(rect.point());
+        Rect::new(min.x, min.y, rect.w / self.zoom, rect.h / self.zoom)
+    }
+
+    /// Scale by `factor`, keeping the world point under `anchor` (screen space) in place
+    fn zoom_at(&mut self, anchor: Vec2, factor: f32, window_size: (f32, f32)) {
+        let world_anchor = self.screen_to_world(anchor);
+        self.zoom = (self.zoom * factor).clamp(1.0, CAMERA_MAX_ZOOM);
+        self.offset = world_anchor - anchor / self.zoom;
+        self.clamp_to_world(window_size);
+    }
+
+    /// Put `point` (world space) in the middle of the screen, as far as the world edges allow
+    fn center_on(&mut self, point: Vec2, window_size: (f32, f32)) {
+        let view = self.view_rect(window_size);
+        self.offset = point - vec2(view.w, view.h) / 2.0;
+        self.clamp_to_world(window_size);
+    }
+
+    /// Zoom and pan so all of `bounds` (world space) is on screen, as close as the zoom limits allow
+    fn fit(&mut self, bounds: Rect, window_size: (f32, f32)) {
+        let fit_zoom = ((window_size.0 - 2.0 * CAMERA_FIT_MARGIN) / bounds.w)
+            .min((window_size.1 - 2.0 * CAMERA_FIT_MARGIN) / bounds.h);
+        self.zoom = fit_zoom.clamp(1.0, CAMERA_MAX_ZOOM);
+        self.center_on(bounds.center(), window_size);
+    }
+
+    /// Keep the view inside the window-sized world
+    fn clamp_to_world(&mut self, window_size: (f32, f32)) {
+        let view = self.view_rect(window_size);
+        self.offset.x = self.offset.x.clamp(0.0, window_size.0 - view.w);
+        self.offset.y = self.offset.y.clamp(0.0, window_size.1 - view.h);
+    }
+
+    /// Draws the view rect across the window with y pointing down, matching world_to_screen
+    /// (Camera2D::from_display_rect flips y, which would mirror the world against the mouse tools)
+    fn camera(&self, window_size: (f32, f32)) -> Camera2D {
+        let view = self.view_rect(window_size);
+        Camera2D { target: view.center(), zoom: vec2(2.0 / view.w, 2.0 / view.h), ..Default::default() }
+    }
+}
+
+// Particle follow (middle click) - locking on zooms in at least this far so the camera can move
+const FOLLOW_MIN_ZOOM: f32 = 2.0;
+
+/// Particle the camera follows, by lineage id, and every label it has carried since it was picked
+struct TrackedParticle {
+    id: u64,
+    lineage: Vec<String>,
+}
+
+/// Bottom-right panel for the followed particle - current label plus its fusion lineage
+fn draw_tracking_panel(tracked: &TrackedParticle, proton: &Proton, window_size: (f32, f32)) {
+    let lines = [
+        format!("Following #{}: {}", tracked.id, proton.get_element_label()),
+        format!("Lineage: {}", tracked.lineage.join(" -> ")),
+        format!("Speed: {:.1}  Energy: {:.1}", proton.velocity().length(), proton.energy()),
+        "Middle click empty space to stop".to_string(),
+    ];
+    let font_size = 18.0;
+    let line_height = 20.0;
+    let width = lines
+        .iter()
+        .map(|line| measure_text(line, None, font_size as u16, 1.0).width)
+        .fold(0.0, f32::max)
+        + 16.0;
+    let height = lines.len() as f32 * line_height + 10.0;
+
+    let x = window_size.0 - width - 10.0;
+    let y = window_size.1 - height - 70.0;
+    draw_rectangle(x, y, width, height, Color::from_rgba(20, 20, 30, 220));
+    draw_rectangle_lines(x, y, width, height, 1.0, proton.color());
+    for (i, line) in lines.iter().enumerate() {
+        let color = if i == 0 { proton.color() } else if i == lines.len() - 1 { GRAY } else { WHITE };
+        draw_text(line, x + 8.0, y + 20.0 + i as f32 * line_height, font_size, color);
+    }
+}
+
+/// Layered view backdrop - a top-to-bottom gradient from light to heavy, with faint guide lines between layers
+fn draw_density_layers(world_size: (f32, f32)) {
+    let (width, height) = world_size;
+    let top = Color::from_rgba(10, 16, 40, 255);
+    let bottom = Color::from_rgba(48, 22, 12, 255);
+    let band = height / constants::rendering::LAYER_BANDS as f32;
+    for i in 0..constants::rendering::LAYER_BANDS {
+        let t = i as f32 / (constants::rendering::LAYER_BANDS - 1) as f32;
+        let color = Color::from_vec(top.to_vec().lerp(bottom.to_vec(), t));
+        draw_rectangle(0.0, i as f32 * band, width, band + 1.0, color);
+    }
+
+    let guide = Color::new(1.0, 1.0, 1.0, 0.06);
+    let mut y = constants::rendering::LAYER_GUIDE_SPACING;
+    while y < height {
+        draw_line(0.0, y, width, y, 1.0, guide);
+        y += constants::rendering::LAYER_GUIDE_SPACING;
+    }
+}
+
+/// Translucent blue (sparse) to red (dense) tint over every occupied bin of `cell_size`
+fn draw_density_heatmap(proton_manager: &ProtonManager, cell_size: f32) {
+    let cells = proton_manager.density_grid(cell_size);
+    let Some(max_count) = cells.iter().map(|(_, count)| *count).max() else { return };
+
+    for ((cx, cy), count) in cells {
+        let t = count as f32 / max_count as f32;
+        let color = Color::new(t, 0.2 * (1.0 - t), 1.0 - t, 0.15 + 0.35 * t);
+        draw_rectangle(cx as f32 * cell_size, cy as f32 * cell_size, cell_size, cell_size, color);
+    }
+}
+
+// Frame-time graph (G) - top right, below the Controls button
+const FRAME_HISTORY_LEN: usize = 120;
+const FRAME_GRAPH_WIDTH: f32 = 240.0;
+const FRAME_GRAPH_HEIGHT: f32 = 80.0;
+const FRAME_GRAPH_MAX_MS: f32 = 50.0; // Spikes above this are clipped to the top edge
+const FRAME_BUDGET_MS: f32 = 1000.0 / 60.0;
+
+/// Once-per-second FPS plus the raw time of the last FRAME_HISTORY_LEN frames (in ms)
+struct FrameStats {
+    fps: f32,
+    fps_timer: f32,
+    frame_count: u32,
+    history: VecDeque<f32>,
+}
+
+impl FrameStats {
+    fn new() -> Self {
+        Self {
+            fps: 0.0,
+            fps_timer: 0.0,
+            frame_count: 0,
+            history: VecDeque::with_capacity(FRAME_HISTORY_LEN),
+        }
+    }
+
+    fn record(&mut self, delta_time: f32) {
+        self.fps_timer += delta_time;
+        self.frame_count += 1;
+        if self.fps_timer >= 1.0 {
+            self.fps = self.frame_count as f32 / self.fps_timer;
+            self.fps_timer = 0.0;
+            self.frame_count = 0;
+        }
+
+        if self.history.len() == FRAME_HISTORY_LEN {
+            self.history.pop_front();
+        }
+        self.history.push_back(delta_time * 1000.0);
+    }
+
+    /// (min, avg, max) frame time in ms over the history
+    fn min_avg_max(&self) -> (f32, f32, f32) {
+        if self.history.is_empty() {
+            return (0.0, 0.0, 0.0);
+        }
+        let min = self.history.iter().copied().fold(f32::INFINITY, f32::min);
+        let max = self.history.iter().copied().fold(0.0, f32::max);
+        let avg = self.history.iter().sum::<f32>() / self.history.len() as f32;
+        (min, avg, max)
+    }
+}
+
+/// Line graph of ms per frame, oldest on the left - frames over the 60 FPS budget turn red
+fn draw_frametime_graph(stats: &FrameStats, x: f32, y: f32) {
+    draw_rectangle(x, y, FRAME_GRAPH_WIDTH, FRAME_GRAPH_HEIGHT, Color::from_rgba(20, 20, 20, 200));
+    draw_rectangle_lines(x, y, FRAME_GRAPH_WIDTH, FRAME_GRAPH_HEIGHT, 1.0, WHITE);
+
+    let y_of = |ms: f32| y + FRAME_GRAPH_HEIGHT - (ms / FRAME_GRAPH_MAX_MS).min(1.0) * FRAME_GRAPH_HEIGHT;
+    let budget_y = y_of(FRAME_BUDGET_MS);
+    draw_line(x, budget_y, x + FRAME_GRAPH_WIDTH, budget_y, 1.0, Color::from_rgba(120, 120, 120, 200));
+
+    let step = FRAME_GRAPH_WIDTH / (FRAME_HISTORY_LEN - 1) as f32;
+    for (i, (a, b)) in stats.history.iter().zip(stats.history.iter().skip(1)).enumerate() {
+        let color = if *b > FRAME_BUDGET_MS * 1.5 { RED } else { GREEN };
+        draw_line(x + i as f32 * step, y_of(*a), x + (i + 1) as f32 * step, y_of(*b), 1.5, color);
+    }
+
+    let (_, avg, max) = stats.min_avg_max();
+    draw_text(&format!("{:.1} ms avg / {:.1} max", avg, max), x + 6.0, y + 16.0, 16.0, WHITE);
+}
+
+// Population controller (N) - starting target and how far [ / ] move it
+const POPULATION_DEFAULT_COUNT: usize = 50;
+const POPULATION_COUNT_STEP: usize = 10;
+const POPULATION_PANEL_WIDTH: f32 = 240.0;
+const POPULATION_PANEL_HEIGHT: f32 = 52.0;
+
+fn draw_population_panel(target: &PopulationTarget, count: usize, x: f32, y: f32) {
+    draw_rectangle(x, y, POPULATION_PANEL_WIDTH, POPULATION_PANEL_HEIGHT, Color::from_rgba(30, 30, 30, 200));
+    draw_rectangle_lines(x, y, POPULATION_PANEL_WIDTH, POPULATION_PANEL_HEIGHT, 1.0, GRAY);
+    let color = if count == target.count { GREEN } else { YELLOW };
+    draw_text(&format!("Holding {}: {} / {}", target.element, count, target.count), x + 8.0, y + 20.0, 20.0, color);
+    draw_text("N: stop  |  [ / ]: target -/+", x + 8.0, y + 42.0, 16.0, LIGHTGRAY);
+}
+
+// Element legend (J) - a color swatch and name per element, stacked up from `bottom`
+const LEGEND_WIDTH: f32 = 110.0;
+const LEGEND_ROW_HEIGHT: f32 = 18.0;
+
+fn draw_element_legend(elements: &[ElementType], title: &str, x: f32, bottom: f32) {
+    let height = (elements.len() + 1) as f32 * LEGEND_ROW_HEIGHT + 10.0;
+    let y = bottom - height;
+    draw_rectangle(x, y, LEGEND_WIDTH, height, Color::from_rgba(20, 20, 20, 180));
+    draw_rectangle_lines(x, y, LEGEND_WIDTH, height, 1.0, GRAY);
+    draw_text(title, x + 8.0, y + 19.0, 16.0, LIGHTGRAY);
+
+    for (i, element) in elements.iter().enumerate() {
+        let row_y = y + 5.0 + (i + 1) as f32 * LEGEND_ROW_HEIGHT;
+        draw_circle(x + 14.0, row_y + LEGEND_ROW_HEIGHT / 2.0, 5.0, element.color());
+        draw_text(element.name(), x + 26.0, row_y + 14.0, 16.0, WHITE);
+    }
+}
+
+// Palette editor layout (K) - RGB tracks for the selected segment, directly above the color slider
+const PALETTE_EDITOR_HEIGHT: f32 = 145.0;
+const PALETTE_CHANNEL_ROW_HEIGHT: f32 = 30.0;
+
+fn palette_editor_rect(slider: &ColorSlider) -> Rect {
+    Rect::new(slider.x, slider.y - PALETTE_EDITOR_HEIGHT - 10.0, slider.width, PALETTE_EDITOR_HEIGHT)
+}
+
+/// Track for one channel (0 = red, 1 = green, 2 = blue) - shared by drawing and drag handling
+fn palette_channel_rect(channel: usize, panel: Rect) -> Rect {
+    Rect::new(panel.x + 50.0, panel.y + 58.0 + channel as f32 * PALETTE_CHANNEL_ROW_HEIGHT, panel.w - 80.0, 14.0)
+}
+
+fn draw_palette_editor(panel: Rect, index: usize, color: Color, frequency_info: &str) {
+    draw_rectangle(panel.x, panel.y, panel.w, panel.h, Color::from_rgba(20, 20, 20, 220));
+    draw_rectangle_lines(panel.x, panel.y, panel.w, panel.h, 2.0, WHITE);
+    draw_text(&format!("PALETTE (K saves) - segment {}", index + 1), panel.x + 20.0, panel.y + 26.0, 22.0, YELLOW);
+    draw_rectangle(panel.x + panel.w - 60.0, panel.y + 10.0, 40.0, 22.0, color);
+    draw_rectangle_lines(panel.x + panel.w - 60.0, panel.y + 10.0, 40.0, 22.0, 1.0, WHITE);
+    draw_text(frequency_info, panel.x + 20.0, panel.y + 46.0, 16.0, LIGHTGRAY);
+
+    let channels = [("R", color.r, RED), ("G", color.g, GREEN), ("B", color.b, BLUE)];
+    for (channel, (label, value, tint)) in channels.into_iter().enumerate() {
+        let track = palette_channel_rect(channel, panel);
+        draw_text(&format!("{} {:>3}", label, (value * 255.0).round() as u8), panel.x + 8.0, track.y + 12.0, 16.0, WHITE);
+        draw_rectangle(track.x, track.y, track.w, track.h, Color::from_rgba(60, 60, 60, 220));
+        draw_rectangle(track.x, track.y, track.w * value, track.h, tint);
+        draw_rectangle_lines(track.x, track.y, track.w, track.h, 1.0, WHITE);
+
+        let handle_x = track.x + value * track.w;
+        draw_circle(handle_x, track.y + track.h / 2.0, 7.0, BLACK);
+        draw_circle(handle_x, track.y + track.h / 2.0, 5.0, LIGHTGRAY);
+    }
+}
+
+/// Sliders for the constants that matter most when hunting for carbon
+fn create_config_sliders() -> Vec<ConfigSlider> {
+    let mut sliders: Vec<ConfigSlider> = vec![
+        ConfigSlider::new("Charge attraction", 0.0, 4000.0, |c| c.charge_attraction_strength, |c, v| c.charge_attraction_strength = v),
+        ConfigSlider::new("Charge repulsion", 0.0, 4000.0, |c| c.charge_repulsion_strength, |c, v| c.charge_repulsion_strength = v),
+        ConfigSlider::new("H attraction", 0.0, 3000.0, |c| c.h_attraction_strength, |c, v| c.h_attraction_strength = v),
+        ConfigSlider::new("He4 attraction", 0.0, 2500.0, |c| c.he4_attraction_strength, |c, v| c.he4_attraction_strength = v),
+        ConfigSlider::new("H+ + H+ threshold", 0.0, 400.0, |c| c.pp_chain_velocity_threshold, |c, v| c.pp_chain_velocity_threshold = v),
+        ConfigSlider::new("D + D threshold", 0.0, 200.0, |c| c.dd_fusion_velocity_threshold, |c, v| c.dd_fusion_velocity_threshold = v),
+        ConfigSlider::new("D fusion threshold", 0.0, 2.0, |c| c.deuterium_fusion_velocity_threshold, |c, v| c.deuterium_fusion_velocity_threshold = v),
+        ConfigSlider::new("He3 fusion threshold", 0.0, 2.0, |c| c.helium3_fusion_velocity_threshold, |c, v| c.helium3_fusion_velocity_threshold = v),
+        ConfigSlider::new("Triple-alpha threshold", 0.0, 2.0, |c| c.triple_alpha_velocity_threshold, |c, v| c.triple_alpha_velocity_threshold = v),
+        ConfigSlider::new("Evaporation scale", 0.1, 3.0, |c| c.evaporation_speed_scale, |c, v| c.evaporation_speed_scale = v),
+        ConfigSlider::new("Reactions per frame", 1.0, 128.0, |c| c.max_reactions_per_frame, |c, v| c.max_reactions_per_frame = v),
+    ];
+    layout_config_sliders(&mut sliders);
+    sliders
+}
+
+/// Membrane sliders shown on the tuning panel in Cell mode - they edit the params given to new cells
+fn create_membrane_sliders() -> Vec<ConfigSlider<MembraneParams>> {
+    let mut sliders: Vec<ConfigSlider<MembraneParams>> = vec![
+        ConfigSlider::new("Neighbor stiffness", 0.0, 5000.0, |p| p.neighbor_force_strength, |p, v| p.neighbor_force_strength = v),
+        ConfigSlider::new("Layer separation", 0.0, 2000.0, |p| p.separation_force, |p, v| p.separation_force = v),
+        ConfigSlider::new("Layer alignment", 0.0, 500.0, |p| p.alignment_force, |p, v| p.alignment_force = v),
+        ConfigSlider::new("Forward flow", 0.0, 100.0, |p| p.forward_flow_strength, |p, v| p.forward_flow_strength = v),
+        ConfigSlider::new("Head push", 0.0, 2000.0, |p| p.head_push_force, |p, v| p.head_push_force = v),
+        ConfigSlider::new("Expansion push", 0.0, 1000.0, |p| p.expansion_push_force, |p, v| p.expansion_push_force = v),
+        ConfigSlider::new("Damping", 0.8, 1.0, |p| p.damping, |p, v| p.damping = v),
+    ];
+    layout_config_sliders(&mut sliders);
+    sliders
+}
+
+fn layout_config_sliders<T>(sliders: &mut [ConfigSlider<T>]) {
+    for (i, slider) in sliders.iter_mut().enumerate() {
+        slider.x = DEBUG_PANEL_X + 20.0;
+        slider.y = DEBUG_PANEL_Y + DEBUG_PANEL_HEADER_HEIGHT + 20.0 + i as f32 * DEBUG_PANEL_ROW_HEIGHT;
+    }
+}
+
+/// Press/drag/release handling for one panel of sliders, writing straight into `target`
+fn update_config_sliders<T>(sliders: &mut [ConfigSlider<T>], target: &mut T, mouse_pos: (f32, f32)) {
+    // Start dragging a slider
+    if is_mouse_button_pressed(MouseButton::Left) {
+        for slider in sliders.iter_mut() {
+            if slider.contains_point(mouse_pos.0, mouse_pos.1) {
+                slider.is_dragging = true;
+            }
+        }
+    }
+
+    // Continue dragging
+    if is_mouse_button_down(MouseButton::Left) {
+        for slider in sliders.iter().filter(|s| s.is_dragging) {
+            let value = slider.get_value_from_position(mouse_pos.0);
+            (slider.set)(target, value);
+        }
+    }
+
+    // Stop dragging
+    if is_mouse_button_released(MouseButton::Left) {
+        for slider in sliders.iter_mut() {
+            slider.is_dragging = false;
+        }
+    }
+}
+
+fn debug_panel_height(slider_count: usize) -> f32 {
+    DEBUG_PANEL_HEADER_HEIGHT + slider_count as f32 * DEBUG_PANEL_ROW_HEIGHT + 10.0
+}
+
+fn debug_panel_contains(slider_count: usize, px: f32, py: f32) -> bool {
+    (DEBUG_PANEL_X..=DEBUG_PANEL_X + DEBUG_PANEL_WIDTH).contains(&px)
+        && (DEBUG_PANEL_Y..=DEBUG_PANEL_Y + debug_panel_height(slider_count)).contains(&py)
+}
+
+fn draw_debug_panel<T>(title: &str, sliders: &[ConfigSlider<T>], target: &T) {
+    let height = debug_panel_height(sliders.len());
+    draw_rectangle(DEBUG_PANEL_X, DEBUG_PANEL_Y, DEBUG_PANEL_WIDTH, height, Color::from_rgba(20, 20, 20, 220));
+    draw_rectangle_lines(DEBUG_PANEL_X, DEBUG_PANEL_Y, DEBUG_PANEL_WIDTH, height, 2.0, WHITE);
+    draw_text(title, DEBUG_PANEL_X + 20.0, DEBUG_PANEL_Y + 26.0, 22.0, YELLOW);
+
+    for slider in sliders {
+        slider.draw((slider.get)(target));
+    }
+}
+
+/// Elements menu - `debug_palette` unlocks every element (tuning panel open) for testing the ladder from the middle
+fn draw_elements_menu(discovered: &HashSet<ElementType>, counts: &std::collections::HashMap<String, usize>, profile: &Profile, window_size: (f32, f32), debug_palette: bool) {
     // Semi-transparent background overlay
     draw_rectangle(0.0, 0.0, window_size.0, window_size.1, Color::from_rgba(0, 0, 0, 180));
 
     // Menu panel
-    let menu_width = 500.0;
-    let menu_height = 500.0;
+    let menu_width = ELEMENTS_MENU_SIZE;
+    let menu_height = ELEMENTS_MENU_SIZE;
     let menu_x = (window_size.0 - menu_width) / 2.0;
     let menu_y = (window_size.1 - menu_height) / 2.0;
 
@@ -215,52 +747,457 @@ fn draw_elements_menu(discovered: &HashSet<ElementType>, counts: &std::collectio
     draw_rectangle_lines(menu_x, menu_y, menu_width, menu_height, 3.0, WHITE);
 
     // Title
-    let title = "DISCOVERED ELEMENTS";
+    let all_elements = ElementType::all();
+    let title = if debug_palette {
+        "DEBUG PALETTE (all elements)".to_string()
+    } else {
+        format!("ELEMENTS ({}/{} discovered)", discovered.len(), all_elements.len())
+    };
+    let title_dims = measure_text(&title, None, 30, 1.0);
+    draw_text(&title, menu_x + (menu_width - title_dims.width) / 2.0, menu_y + 40.0, 30.0, YELLOW);
+
+    // Every element in two columns - undiscovered ones are locked silhouettes
+    for (i, element) in all_elements.into_iter().enumerate() {
+        let entry = element_entry_rect(i, menu_x, menu_y);
+        let center_y = entry.y + entry.h / 2.0;
+
+        if discovered.contains(&element) {
+            let count = counts.get(element.name()).unwrap_or(&0);
+            draw_circle(entry.x + 30.0, center_y, 12.0, element.color());
+            let label = format!("{} ({})", element.name(), count);
+            draw_text(&label, entry.x + 60.0, center_y + 7.0, 24.0, WHITE);
+            let best = format!("max {}", profile.max_count(element.name()));
+            draw_text(&best, entry.x + 68.0 + measure_text(&label, None, 24, 1.0).width, center_y + 7.0, 16.0, GRAY);
+        } else if debug_palette {
+            // Spawnable for testing, but dimmed so discovery progress stays readable
+            draw_circle(entry.x + 30.0, center_y, 12.0, element.color());
+            draw_text(element.name(), entry.x + 60.0, center_y + 7.0, 24.0, GRAY);
+        } else {
+            draw_circle(entry.x + 30.0, center_y, 12.0, Color::from_rgba(60, 60, 60, 255));
+            draw_lock_icon(entry.x + 30.0, center_y);
+            draw_text("???", entry.x + 60.0, center_y + 7.0, 24.0, DARKGRAY);
+        }
+    }
+
+    // Instructions
+    let instructions = "Click an element to select it | Click outside to close";
+    let inst_dims = measure_text(instructions, None, 18, 1.0);
+    draw_text(instructions, menu_x + (menu_width - inst_dims.width) / 2.0, menu_y + menu_height - 20.0, 18.0, GRAY);
+}
+
+// Scenarios menu layout - one row per built-in scenario
+const SCENARIO_MENU_WIDTH: f32 = 560.0;
+const SCENARIO_MENU_HEADER: f32 = 70.0;
+const SCENARIO_ROW_HEIGHT: f32 = 64.0;
+
+fn scenario_menu_rect(window_size: (f32, f32)) -> Rect {
+    let height = SCENARIO_MENU_HEADER + SCENARIOS.len() as f32 * SCENARIO_ROW_HEIGHT + 40.0;
+    Rect::new((window_size.0 - SCENARIO_MENU_WIDTH) / 2.0, (window_size.1 - height) / 2.0, SCENARIO_MENU_WIDTH, height)
+}
+
+fn scenario_entry_rect(index: usize, menu: Rect) -> Rect {
+    Rect::new(menu.x + 20.0, menu.y + SCENARIO_MENU_HEADER + index as f32 * SCENARIO_ROW_HEIGHT, menu.w - 40.0, SCENARIO_ROW_HEIGHT - 8.0)
+}
+
+fn draw_scenarios_menu(window_size: (f32, f32)) {
+    draw_rectangle(0.0, 0.0, window_size.0, window_size.1, Color::from_rgba(0, 0, 0, 180));
+
+    let menu = scenario_menu_rect(window_size);
+    draw_rectangle(menu.x, menu.y, menu.w, menu.h, Color::from_rgba(30, 30, 30, 255));
+    draw_rectangle_lines(menu.x, menu.y, menu.w, menu.h, 3.0, WHITE);
+
+    let title = "SCENARIOS";
     let title_dims = measure_text(title, None, 30, 1.0);
-    draw_text(title, menu_x + (menu_width - title_dims.width) / 2.0, menu_y + 40.0, 30.0, YELLOW);
+    draw_text(title, menu.x + (menu.w - title_dims.width) / 2.0, menu.y + 40.0, 30.0, YELLOW);
+
+    for (i, scenario) in SCENARIOS.iter().enumerate() {
+        let entry = scenario_entry_rect(i, menu);
+        draw_rectangle(entry.x, entry.y, entry.w, entry.h, Color::from_rgba(50, 50, 50, 255));
+        draw_rectangle_lines(entry.x, entry.y, entry.w, entry.h, 1.0, GRAY);
+        draw_text(scenario.name, entry.x + 12.0, entry.y + 24.0, 24.0, WHITE);
+        draw_text(scenario.summary, entry.x + 12.0, entry.y + 46.0, 18.0, LIGHTGRAY);
+    }
 
-    // Element list - two columns layout
-    let line_height = 40.0;
-    let column_width = menu_width / 2.0;
-    let elements_per_column = 9;
+    let instructions = "Click a scenario to clear the pond and load it | Click outside to close";
+    let inst_dims = measure_text(instructions, None, 18, 1.0);
+    draw_text(instructions, menu.x + (menu.w - inst_dims.width) / 2.0, menu.y + menu.h - 16.0, 18.0, GRAY);
+}
 
-    let mut discovered_index = 0;
+// Guide text for the loaded scenario - bottom left, above the Cell button; click it to dismiss
+const SCENARIO_GUIDE_WIDTH: f32 = 560.0;
+const SCENARIO_GUIDE_LINE_HEIGHT: f32 = 22.0;
 
-    for element in ElementType::all() {
-        if discovered.contains(&element) {
-            let count = counts.get(element.name()).unwrap_or(&0);
-            let text = format!("{} ({})", element.name(), count);
+fn scenario_guide_rect(scenario: &Scenario, window_size: (f32, f32)) -> Rect {
+    let height = (scenario.guide.len() + 2) as f32 * SCENARIO_GUIDE_LINE_HEIGHT + 8.0;
+    Rect::new(10.0, window_size.1 - 60.0 - height, SCENARIO_GUIDE_WIDTH, height)
+}
+
+fn draw_scenario_guide(scenario: &Scenario, window_size: (f32, f32)) {
+    let panel = scenario_guide_rect(scenario, window_size);
+    draw_rectangle(panel.x, panel.y, panel.w, panel.h, Color::from_rgba(30, 30, 30, 220));
+    draw_rectangle_lines(panel.x, panel.y, panel.w, panel.h, 2.0, YELLOW);
+
+    let mut y = panel.y + SCENARIO_GUIDE_LINE_HEIGHT;
+    draw_text(scenario.name, panel.x + 10.0, y, 22.0, YELLOW);
+    for line in scenario.guide {
+        y += SCENARIO_GUIDE_LINE_HEIGHT;
+        draw_text(line, panel.x + 10.0, y, 18.0, WHITE);
+    }
+    draw_text("Click to dismiss", panel.x + 10.0, y + SCENARIO_GUIDE_LINE_HEIGHT, 16.0, GRAY);
+}
+
+// Help overlay (?) - every control from the controls table, in two columns
+const HELP_ROW_HEIGHT: f32 = 20.0;
+const HELP_COLUMN_WIDTH: f32 = 520.0;
+const HELP_KEY_WIDTH: f32 = 170.0;
+
+fn draw_help_overlay(window_size: (f32, f32)) {
+    let entries = controls::help_entries();
+    let rows = entries.len().div_ceil(2);
+    let (width, height) = (2.0 * HELP_COLUMN_WIDTH + 20.0, (rows + 3) as f32 * HELP_ROW_HEIGHT);
+    let (x, y) = ((window_size.0 - width) / 2.0, (window_size.1 - height) / 2.0);
+    draw_rectangle(x, y, width, height, Color::from_rgba(20, 20, 20, 235));
+    draw_rectangle_lines(x, y, width, height, 2.0, YELLOW);
+    draw_text("KEYS", x + 10.0, y + HELP_ROW_HEIGHT + 4.0, 24.0, YELLOW);
+
+    for (i, (keys, description)) in entries.iter().enumerate() {
+        let column_x = x + 10.0 + (i / rows) as f32 * HELP_COLUMN_WIDTH;
+        let row_y = y + (i % rows + 2) as f32 * HELP_ROW_HEIGHT + 4.0;
+        draw_text(keys, column_x, row_y, 18.0, YELLOW);
+        draw_text(description, column_x + HELP_KEY_WIDTH, row_y, 18.0, WHITE);
+    }
+    draw_text("? to close", x + 10.0, y + height - 6.0, 16.0, GRAY);
+}
 
-            // Determine column and position
-            let column = discovered_index / elements_per_column;
-            let row_in_column = discovered_index % elements_per_column;
+// Elements menu layout - two columns of rows
+const ELEMENT_ROW_HEIGHT: f32 = 40.0;
+const ELEMENTS_PER_COLUMN: usize = 9;
+const ELEMENTS_MENU_SIZE: f32 = 500.0;
+
+/// Clickable area of one Elements menu entry, by index into ElementType::all() (shared by drawing and click handling)
+fn element_entry_rect(index: usize, menu_x: f32, menu_y: f32) -> Rect {
+    let column = index / ELEMENTS_PER_COLUMN;
+    let row = index % ELEMENTS_PER_COLUMN;
+    let column_width = ELEMENTS_MENU_SIZE / 2.0;
+    Rect::new(
+        menu_x + column as f32 * column_width,
+        menu_y + 80.0 + row as f32 * ELEMENT_ROW_HEIGHT - ELEMENT_ROW_HEIGHT / 2.0,
+        column_width,
+        ELEMENT_ROW_HEIGHT,
+    )
+}
 
-            let x_offset = menu_x + (column as f32 * column_width);
-            let y_offset = menu_y + 80.0 + (row_in_column as f32 * line_height);
+/// Small padlock centered on (x, y) - marks an undiscovered element
+fn draw_lock_icon(x: f32, y: f32) {
+    let color = Color::from_rgba(150, 150, 150, 255);
+    draw_circle_lines(x, y - 3.0, 4.0, 2.0, color); // Shackle (lower half hidden by the body)
+    draw_rectangle(x - 6.0, y - 2.0, 12.0, 9.0, color);
+}
+
+// Bond graph of the hovered particle - orange stands out against the blue and grey bond lines
+const INSPECT_BOND_COLOR: Color = Color::new(1.0, 0.55, 0.15, 1.0);
+
+/// Hover readout for the particle under the cursor - drawn next to the mouse, kept on screen
+fn draw_inspect_tooltip(proton: &Proton, bond_count: usize, mouse: (f32, f32), window_size: (f32, f32)) {
+    let lines = [
+        proton.get_element_label(),
+        format!("Charge: {}  Neutrons: {}", proton.charge(), proton.neutron_count()),
+        format!("Speed: {:.1}", proton.velocity().length()),
+        format!("Energy: {:.1}  Mass: {:.1}", proton.energy(), proton.mass()),
+        format!("Bonds: {}", bond_count),
+    ];
+    let font_size = 18.0;
+    let line_height = 20.0;
+    let width = lines
+        .iter()
+        .map(|line| measure_text(line, None, font_size as u16, 1.0).width)
+        .fold(0.0, f32::max)
+        + 16.0;
+    let height = lines.len() as f32 * line_height + 10.0;
+
+    let x = (mouse.0 + 16.0).min(window_size.0 - width);
+    let y = (mouse.1 + 16.0).min(window_size.1 - height);
+    draw_rectangle(x, y, width, height, Color::from_rgba(20, 20, 30, 220));
+    draw_rectangle_lines(x, y, width, height, 1.0, proton.color());
+    for (i, line) in lines.iter().enumerate() {
+        let color = if i == 0 { proton.color() } else { WHITE };
+        draw_text(line, x + 8.0, y + 20.0 + i as f32 * line_height, font_size, color);
+    }
+}
 
-            // Draw element circle
-            draw_circle(x_offset + 30.0, y_offset, 12.0, element.color());
+/// Every bond of the hovered particle as a line to its partner, with each partner outlined
+fn draw_bond_graph(proton: &Proton, partners: &[&Proton], camera: &ViewCamera) {
+    let center = camera.world_to_screen(proton.position());
+    for partner in partners {
+        let pos = camera.world_to_screen(partner.position());
+        draw_line(center.x, center.y, pos.x, pos.y, 2.0, INSPECT_BOND_COLOR);
+        draw_circle_lines(pos.x, pos.y, partner.radius() * camera.zoom + 4.0, 1.5, INSPECT_BOND_COLOR);
+    }
+}
 
-            // Draw element text
-            draw_text(&text, x_offset + 60.0, y_offset + 7.0, 24.0, WHITE);
+// Measure tool (hold U, left click two particles) - below this speed a particle counts as standing still
+const MEASURE_MOVING_SPEED: f32 = 0.5;
 
-            discovered_index += 1;
+/// Ring around each picked particle, and between a pair their distance and relative speed
+/// against the fusion `threshold` for the two (green once it is reached)
+fn draw_measurement(picked: &[&Proton], threshold: Option<(&str, f32)>, camera: &ViewCamera) {
+    for proton in picked {
+        let pos = camera.world_to_screen(proton.position());
+        draw_circle_lines(pos.x, pos.y, proton.radius() * camera.zoom + 6.0, 1.5, SKYBLUE);
+    }
+    let [a, b] = picked else { return };
+
+    let (from, to) = (camera.world_to_screen(a.position()), camera.world_to_screen(b.position()));
+    let relative_speed = (a.velocity() - b.velocity()).length();
+    let moving = a.velocity().length() > MEASURE_MOVING_SPEED && b.velocity().length() > MEASURE_MOVING_SPEED;
+    let color = match threshold {
+        Some((_, needed)) if moving && relative_speed > needed => GREEN,
+        _ => SKYBLUE,
+    };
+    draw_line(from.x, from.y, to.x, to.y, 1.5, color);
+
+    let mut lines = vec![format!("Distance: {:.1}", a.position().distance(b.position()))];
+    if moving {
+        lines.push(match threshold {
+            Some((reaction, needed)) => format!("Relative speed: {:.1} / {:.1} ({})", relative_speed, needed, reaction),
+            None => format!("Relative speed: {:.1} (no reaction)", relative_speed),
+        });
+    }
+    let mid = (from + to) / 2.0;
+    for (i, line) in lines.iter().enumerate() {
+        let y = mid.y - 10.0 + i as f32 * 20.0;
+        let width = measure_text(line, None, 18, 1.0).width;
+        draw_rectangle(mid.x + 8.0, y - 15.0, width + 8.0, 20.0, Color::from_rgba(20, 20, 30, 200));
+        draw_text(line, mid.x + 12.0, y, 18.0, color);
+    }
+}
+
+// Arena walls - drag a corner handle to pull the walls in from the window edges
+const ARENA_HANDLE_SIZE: f32 = 8.0; // Screen pixels
+const ARENA_HANDLE_GRAB: f32 = 14.0; // How close (screen pixels) a press must land to pick up a corner
+const ARENA_MIN_SIZE: f32 = 120.0;
+const ARENA_COLOR: Color = Color::new(0.6, 0.8, 1.0, 0.7);
+
+/// Corners clockwise from the top left - the one opposite corner `i` is `(i + 2) % 4`
+fn arena_corners(arena: Rect) -> [Vec2; 4] {
+    [
+        vec2(arena.left(), arena.top()),
+        vec2(arena.right(), arena.top()),
+        vec2(arena.right(), arena.bottom()),
+        vec2(arena.left(), arena.bottom()),
+    ]
+}
+
+/// The corner handle under `pointer` (screen space), if any
+fn arena_corner_at(arena: Rect, camera: &ViewCamera, pointer: Vec2) -> Option<usize> {
+    arena_corners(arena)
+        .iter()
+        .position(|&corner| camera.world_to_screen(corner).distance(pointer) <= ARENA_HANDLE_GRAB)
+}
+
+/// Arena spanned by the `fixed` corner and the dragged one at `pointer`, inside the window and at least
+/// ARENA_MIN_SIZE across where the window allows - None once it covers the whole window again
+fn dragged_arena(fixed: Vec2, pointer: Vec2, window_size: (f32, f32)) -> Option<Rect> {
+    // (low edge, length) along one axis, growing away from the fixed corner
+    let axis = |fixed: f32, pointer: f32, limit: f32| {
+        let far = if pointer < fixed { pointer.min(fixed - ARENA_MIN_SIZE) } else { pointer.max(fixed + ARENA_MIN_SIZE) };
+        let far = far.clamp(0.0, limit);
+        (fixed.min(far), (fixed - far).abs())
+    };
+    let (x, w) = axis(fixed.x, pointer.x, window_size.0);
+    let (y, h) = axis(fixed.y, pointer.y, window_size.1);
+    let whole_window = x <= 0.0 && y <= 0.0 && w >= window_size.0 && h >= window_size.1;
+    (!whole_window).then(|| Rect::new(x, y, w, h))
+}
+
+/// Wall outline (only once moved in) and the corner handles - drawn in world space
+fn draw_arena(arena: Rect, custom: bool, zoom: f32) {
+    if custom {
+        draw_rectangle_lines(arena.x, arena.y, arena.w, arena.h, 2.0 / zoom, ARENA_COLOR);
+    }
+    let size = ARENA_HANDLE_SIZE / zoom;
+    let handle_color = if custom { ARENA_COLOR } else { Color::new(ARENA_COLOR.r, ARENA_COLOR.g, ARENA_COLOR.b, 0.3) };
+    for corner in arena_corners(arena) {
+        draw_rectangle(corner.x - size / 2.0, corner.y - size / 2.0, size, size, handle_color);
+    }
+}
+
+// Right-drag element spawn - launch velocity is the drag vector times this
+const SPAWN_DRAG_VELOCITY_SCALE: f32 = 2.0;
+
+// Spawn energy multipliers stepped through with - and =, applied to each element's default energy
+// Three He4 at x8 clear the triple-alpha energy threshold that their default energy falls short of
+const SPAWN_ENERGY_SCALES: [f32; 6] = [0.25, 0.5, 1.0, 2.0, 4.0, 8.0];
+const DEFAULT_SPAWN_ENERGY_STEP: usize = 2; // x1 - the element's own default
+
+/// Next spawn energy multiplier up (`step` 1) or down (-1), held at either end
+fn step_spawn_energy(current: usize, step: i32) -> usize {
+    if step > 0 {
+        (current + 1).min(SPAWN_ENERGY_SCALES.len() - 1)
+    } else {
+        current.saturating_sub(1)
+    }
+}
+
+// S + right click seed crystal - lattice shells around the center point
+const SEED_LATTICE_RINGS: usize = 2;
+
+// Spawn budget - rings and element spawns draw on one refilling pool, so click-spam can't flood the caps
+const SPAWN_BUDGET_MAX: f32 = 12.0; // Spawns available in one burst
+const SPAWN_BUDGET_REFILL: f32 = 6.0; // Spawns regained per second
+const RING_SPAWN_COST: f32 = 1.0;
+const ELEMENT_SPAWN_COST: f32 = 1.0;
+const LATTICE_SPAWN_COST: f32 = 6.0;
+const SPAWN_BUDGET_CURSOR_RADIUS: f32 = 10.0;
+
+/// Refilling allowance of player spawns
+struct SpawnBudget {
+    available: f32,
+}
+
+impl SpawnBudget {
+    fn new() -> Self {
+        Self { available: SPAWN_BUDGET_MAX }
+    }
+
+    fn refill(&mut self, delta_time: f32) {
+        self.available = (self.available + SPAWN_BUDGET_REFILL * delta_time).min(SPAWN_BUDGET_MAX);
+    }
+
+    /// Take `cost` from the budget if it is all there
+    fn try_spend(&mut self, cost: f32) -> bool {
+        if self.available < cost {
+            return false;
         }
+        self.available -= cost;
+        true
     }
 
-    // Instructions
-    let instructions = "Click an element to select it | Click outside to close";
-    let inst_dims = measure_text(instructions, None, 18, 1.0);
-    draw_text(instructions, menu_x + (menu_width - inst_dims.width) / 2.0, menu_y + menu_height - 20.0, 18.0, GRAY);
+    fn is_exhausted(&self) -> bool {
+        self.available < RING_SPAWN_COST.min(ELEMENT_SPAWN_COST)
+    }
+}
+
+// Frequency sweep (X) - every palette color in turn, slowest to fastest, over this many seconds
+const SWEEP_DURATION: f32 = 2.0;
+
+/// Rings sent through the whole palette from one spot, to compare every wave speed against the same target
+struct FrequencySweep {
+    position: Vec2,
+    next_index: usize, // Palette entry the next ring takes
+    elapsed: f32,
+}
+
+impl FrequencySweep {
+    fn new(position: Vec2) -> Self {
+        Self { position, next_index: 0, elapsed: 0.0 }
+    }
+
+    /// Advance by `dt`, adding every ring now due - true once the fastest color has gone out
+    fn step(&mut self, dt: f32, ring_manager: &mut RingManager) -> bool {
+        let colors = ring_manager.colors().to_vec();
+        let interval = SWEEP_DURATION / colors.len() as f32;
+        while self.next_index < colors.len() && self.elapsed >= self.next_index as f32 * interval {
+            ring_manager.add_ring_with_color(self.position, colors[self.next_index], RingOrigin::Click);
+            self.next_index += 1;
+        }
+        self.elapsed += dt;
+        self.next_index >= colors.len()
+    }
+}
+
+/// Budget gauge at the cursor - an arc for what is left, a dimmed disc while nothing can be spawned
+fn draw_spawn_budget_cursor(budget: &SpawnBudget, mouse: (f32, f32)) {
+    if budget.available >= SPAWN_BUDGET_MAX {
+        return;
+    }
+    let fraction = budget.available / SPAWN_BUDGET_MAX;
+    if budget.is_exhausted() {
+        draw_circle(mouse.0, mouse.1, SPAWN_BUDGET_CURSOR_RADIUS, Color::from_rgba(0, 0, 0, 140));
+        draw_circle_lines(mouse.0, mouse.1, SPAWN_BUDGET_CURSOR_RADIUS, 2.0, Color::from_rgba(120, 120, 120, 200));
+    }
+    let color = if budget.is_exhausted() { Color::from_rgba(120, 120, 120, 200) } else { Color::from_rgba(255, 255, 255, 140) };
+    draw_arc(mouse.0, mouse.1, 32, SPAWN_BUDGET_CURSOR_RADIUS + 3.0, -90.0, 2.0, fraction * 360.0, color);
+}
+
+/// Banner above the color slider while every particle slot is taken
+fn draw_capacity_banner(limit: usize, window_size: (f32, f32), y: f32) {
+    let text = format!("Particle limit reached ({}) - new spawns are refused. Delete elements (Elements menu) or raise max_protons", limit);
+    let dims = measure_text(&text, None, 18, 1.0);
+    let x = (window_size.0 - dims.width) / 2.0;
+    draw_rectangle(x - 10.0, y - 22.0, dims.width + 20.0, 30.0, Color::from_rgba(60, 10, 10, 220));
+    draw_text(&text, x, y, 18.0, Color::from_rgba(255, 140, 120, 255));
+}
+
+/// Aiming arrow for a right-drag spawn (screen space) - thicker and labelled with the launch speed in world units
+/// Turns green once the launch speed reaches `fusion_threshold` (the element's fusion speed, if it has one)
+fn draw_drag_arrow(start: Vec2, end: Vec2, zoom: f32, color: Color, fusion_threshold: Option<f32>) {
+    let drag = end - start;
+    let length = drag.length();
+    if length < 1.0 {
+        return;
+    }
+
+    let speed = length / zoom * SPAWN_DRAG_VELOCITY_SCALE;
+    let color = match fusion_threshold {
+        Some(threshold) if speed >= threshold => GREEN,
+        _ => color,
+    };
+    let thickness = (1.5 + speed / 150.0).min(6.0);
+    let dir = drag / length;
+    let head = (8.0 + thickness * 2.0).min(length);
+    let base = end - dir * head;
+    let side = dir.perp() * head * 0.5;
+
+    draw_circle_lines(start.x, start.y, 6.0, 1.5, color);
+    draw_line(start.x, start.y, base.x, base.y, thickness, color);
+    draw_triangle(end, base + side, base - side, color);
+    draw_text(&format!("{:.0}", speed), end.x + 10.0, end.y - 10.0, 18.0, color);
 }
 
-fn draw_controls_menu(fps: f32, ring_manager: &RingManager, atom_manager: &AtomManager, proton_manager: &ProtonManager, window_size: (f32, f32), color_info: &str) {
+// Controls menu panel (drawing and click-outside handling share it)
+const CONTROLS_MENU_WIDTH: f32 = 1200.0;
+const CONTROLS_MENU_HEIGHT: f32 = 716.0;
+
+// Reaction toggle column on the right of the Controls menu
+const REACTION_COLUMN_OFFSET: f32 = 580.0;
+// Reaction rate column, right of the toggles
+const RATE_COLUMN_OFFSET: f32 = 900.0;
+const REACTION_ROW_HEIGHT: f32 = 28.0;
+
+/// Clickable area of one reaction toggle row (shared by drawing and click handling)
+fn reaction_toggle_rect(index: usize, menu_x: f32, menu_y: f32) -> Rect {
+    Rect::new(
+        menu_x + REACTION_COLUMN_OFFSET,
+        menu_y + 97.0 + index as f32 * REACTION_ROW_HEIGHT,
+        300.0,
+        REACTION_ROW_HEIGHT - 4.0,
+    )
+}
+
+// Delete-element chips below the reaction toggles
+const DELETE_CHIPS_PER_ROW: usize = 4;
+const DELETE_CHIP_WIDTH: f32 = 75.0;
+const DELETE_CHIP_HEIGHT: f32 = 30.0;
+
+/// Clickable chip that deletes every particle of `ElementType::all()[index]`
+fn element_delete_rect(index: usize, menu_x: f32, menu_y: f32) -> Rect {
+    let top = menu_y + 97.0 + ReactionFlags::ALL.len() as f32 * REACTION_ROW_HEIGHT + 45.0;
+    Rect::new(
+        menu_x + REACTION_COLUMN_OFFSET + (index % DELETE_CHIPS_PER_ROW) as f32 * DELETE_CHIP_WIDTH,
+        top + (index / DELETE_CHIPS_PER_ROW) as f32 * DELETE_CHIP_HEIGHT,
+        DELETE_CHIP_WIDTH - 5.0,
+        DELETE_CHIP_HEIGHT - 5.0,
+    )
+}
+
+fn draw_controls_menu(frame_stats: &FrameStats, ring_manager: &RingManager, atom_manager: &AtomManager, proton_manager: &ProtonManager, window_size: (f32, f32), sound_muted: bool, energy_delta: f32) {
     // Semi-transparent background overlay
     draw_rectangle(0.0, 0.0, window_size.0, window_size.1, Color::from_rgba(0, 0, 0, 180));
 
     // Menu panel
-    let menu_width = 600.0;
-    let menu_height = 550.0;
+    let menu_width = CONTROLS_MENU_WIDTH;
+    let menu_height = CONTROLS_MENU_HEIGHT;
     let menu_x = (window_size.0 - menu_width) / 2.0;
     let menu_y = (window_size.1 - menu_height) / 2.0;
 
@@ -277,39 +1214,114 @@ fn draw_controls_menu(fps: f32, ring_manager: &RingManager, atom_manager: &AtomM
     draw_text("STATS:", menu_x + 20.0, y_offset, 24.0, LIGHTGRAY);
     y_offset += 35.0;
 
-    draw_text(&format!("FPS: {:.0}", fps), menu_x + 40.0, y_offset, 20.0, GREEN);
+    let (min_ms, avg_ms, max_ms) = frame_stats.min_avg_max();
+    draw_text(
+        &format!("FPS: {:.0}  |  Frame ms: {:.1} / {:.1} / {:.1} (min/avg/max)", frame_stats.fps, min_ms, avg_ms, max_ms),
+        menu_x + 40.0, y_offset, 20.0, GREEN,
+    );
     y_offset += 28.0;
-    draw_text(&format!("Rings: {}", ring_manager.get_ring_count()), menu_x + 40.0, y_offset, 20.0, GREEN);
+    let rings_full = ring_manager.get_ring_count() >= ring_manager.max_rings();
+    let evicted = match ring_manager.evicted_count() {
+        0 => String::new(),
+        count => format!(", {} evicted", count),
+    };
+    draw_text(
+        &format!(
+            "Rings: {}/{} ({} fusion{})  |  Energy: {:.0} (last frame: {:+.1})",
+            ring_manager.get_ring_count(),
+            ring_manager.max_rings(),
+            ring_manager.count_from(RingOrigin::Fusion),
+            evicted,
+            proton_manager.total_energy(),
+            energy_delta,
+        ),
+        menu_x + 40.0, y_offset, 20.0, if rings_full { ORANGE } else { GREEN },
+    );
     y_offset += 28.0;
-    draw_text(&format!("Atoms: {}", atom_manager.get_atom_count()), menu_x + 40.0, y_offset, 20.0, GREEN);
+    let atom_events = proton_manager.atom_events();
+    draw_text(
+        &format!(
+            "Atoms: {} (this frame: {} H+ -> D, {} e- captures; {} H+ -> D in all)",
+            atom_manager.get_atom_count(),
+            atom_events.neutron_formations,
+            atom_events.electron_captures,
+            proton_manager.neutron_formation_count(),
+        ),
+        menu_x + 40.0, y_offset, 20.0, GREEN,
+    );
     y_offset += 28.0;
-    draw_text(&format!("Protons: {}", proton_manager.get_proton_count()), menu_x + 40.0, y_offset, 20.0, GREEN);
+    let (slots_used, slots_allocated, slot_limit) = proton_manager.capacity_usage();
+    let slots_full = slots_used >= slot_limit;
+    draw_text(
+        &format!(
+            "Protons: {}  |  Decays: {}  |  Slots: {}/{} (max {}){}",
+            proton_manager.get_proton_count(),
+            proton_manager.decay_count(),
+            slots_used,
+            slots_allocated,
+            slot_limit,
+            if slots_full { " FULL" } else { "" },
+        ),
+        menu_x + 40.0, y_offset, 20.0, if slots_full { RED } else { GREEN },
+    );
     y_offset += 28.0;
-    draw_text(&format!("Current: {}", color_info), menu_x + 40.0, y_offset, 18.0, LIGHTGRAY);
+    draw_text(&format!("Current: {}", ring_manager.get_current_frequency_info()), menu_x + 40.0, y_offset, 18.0, LIGHTGRAY);
+    y_offset += 28.0;
+    draw_text(
+        &format!(
+            "Boundary: {}  |  Medium: {}{}  |  Sound: {}  |  Oscillating: {}",
+            proton_manager.boundary_mode().name(),
+            proton_manager.medium().name(),
+            if proton_manager.is_gravity_enabled() { ", layered" } else { "" },
+            if sound_muted { "muted" } else { "on" },
+            proton_manager.oscillating_count(),
+        ),
+        menu_x + 40.0, y_offset, 18.0, LIGHTGRAY,
+    );
 
     // Controls section
     y_offset += 40.0;
     draw_text("CONTROLS:", menu_x + 20.0, y_offset, 24.0, LIGHTGRAY);
     y_offset += 35.0;
 
-    let controls = vec![
-        "Left Click: Spawn energy ring",
-        "Right Click & Drag: Spawn selected element with velocity",
-        "Color Slider (bottom): Click/drag to change ring color",
-        "Mouse Wheel: Cycle through ring colors",
-        "R: Clear all non-stable particles",
-        "Space: Clear all non-stable particles",
-        "H: Delete all stable hydrogen",
-        "Z: Clear all protons",
-        "P: Pause/unpause simulation",
-        "Esc: Exit game",
-    ];
-
-    for control in controls {
-        draw_text(control, menu_x + 40.0, y_offset, 18.0, WHITE);
+    for line in controls::CONTROL_LINES {
+        draw_text(&controls::line_text(line), menu_x + 40.0, y_offset, 18.0, WHITE);
         y_offset += 26.0;
     }
 
+    // Reaction toggles
+    draw_text("REACTIONS:", menu_x + REACTION_COLUMN_OFFSET, menu_y + 80.0, 24.0, LIGHTGRAY);
+    let enabled_reactions = proton_manager.reaction_flags();
+    for (i, (flag, label)) in ReactionFlags::ALL.iter().enumerate() {
+        let row = reaction_toggle_rect(i, menu_x, menu_y);
+        let (marker, color) = if enabled_reactions.contains(*flag) { ("[x]", GREEN) } else { ("[ ]", GRAY) };
+        draw_text(&format!("{} {}", marker, label), row.x, row.y + 18.0, 18.0, color);
+    }
+
+    // Reaction rates - shows where the cascade stalls (e.g. plenty of He4 but no triple-alpha)
+    draw_text(
+        &format!("RATES (/s, last {:.0}s):", pm::REACTION_RATE_WINDOW),
+        menu_x + RATE_COLUMN_OFFSET, menu_y + 80.0, 24.0, LIGHTGRAY,
+    );
+    for (i, (label, rate)) in proton_manager.reaction_rates().into_iter().enumerate() {
+        let color = if rate > 0.0 { GREEN } else { GRAY };
+        let y = menu_y + 115.0 + i as f32 * 24.0;
+        draw_text(label, menu_x + RATE_COLUMN_OFFSET, y, 18.0, color);
+        draw_text(&format!("{:.1}", rate), menu_x + RATE_COLUMN_OFFSET + 220.0, y, 18.0, color);
+    }
+
+    // Delete one species - handy when it is eating the slot budget
+    let first_chip = element_delete_rect(0, menu_x, menu_y);
+    draw_text("DELETE ELEMENT (click):", first_chip.x, first_chip.y - 12.0, 24.0, LIGHTGRAY);
+    let counts = proton_manager.get_element_counts();
+    for (i, element) in ElementType::all().into_iter().enumerate() {
+        let chip = element_delete_rect(i, menu_x, menu_y);
+        let count = counts.get(element.name()).copied().unwrap_or(0);
+        let (border, text) = if count > 0 { (element.color(), WHITE) } else { (DARKGRAY, GRAY) };
+        draw_rectangle_lines(chip.x, chip.y, chip.w, chip.h, 1.5, border);
+        draw_text(&format!("{} {}", element.name(), count), chip.x + 5.0, chip.y + 18.0, 16.0, text);
+    }
+
     // Instructions
     let instructions = "Click outside to close";
     let inst_dims = measure_text(instructions, None, 18, 1.0);
@@ -328,37 +1340,93 @@ fn window_conf() -> Conf {
 
 #[macroquad::main(window_conf)]
 async fn main() {
+    // Closing the window leaves the main loop like Escape does, so shutdown work (saving the profile) still runs
+    prevent_quit();
+
     // Initialize managers
     let mut ring_manager = RingManager::new();
     let mut atom_manager = AtomManager::new(100);
-    let mut proton_manager = ProtonManager::new(300);
-
-    let mut frame_count = 0;
-    let mut fps_timer = 0.0;
-    let mut fps = 0.0;
-    let mut paused = false;
+    // Physics overrides and the ring palette from pond.toml (compiled defaults when absent)
+    let sim_config = SimConfig::load(sim_config::DEFAULT_CONFIG_PATH);
+    ring_manager.set_palette(&sim_config.ring_colors);
+    ring_manager.set_max_rings(sim_config.max_rings as usize);
+    let mut proton_manager = ProtonManager::new(300, &sim_config);
+    // Event sounds (silent unless built with the audio feature, muted with M)
+    let mut sound_effects = SoundEffects::load().await;
+
+    let mut frame_stats = FrameStats::new();
+    let mut spawn_budget = SpawnBudget::new();
+    let mut show_frame_graph = false;
+    let mut legend_mode = LegendMode::Discovered;
+    let mut frequency_sweep: Option<FrequencySweep> = None;
+    let mut show_help = false;
+    let mut spawn_energy = DEFAULT_SPAWN_ENERGY_STEP; // Index into SPAWN_ENERGY_SCALES
+    let mut pause = PauseState::Running;
+    let mut physics_accumulator = 0.0; // Unsimulated real time carried between frames
+    let mut energy_delta = 0.0; // Change in ProtonManager::total_energy over the last simulated frame
+    let mut color_mode = ColorMode::Element;
+    let mut render_style = RenderStyle::Circles;
 
     // Game mode
     let mut game_mode = GameMode::Normal;
-    let mut cell: Option<Cell> = None;
+    let mut cells: Vec<Cell> = Vec::new();
+    let mut food: Vec<Food> = Vec::new();
+    let mut food_spawn_timer = 0.0;
 
     // UI State
     let mut menu_state = MenuState::None;
-    let mut discovered_elements: HashSet<ElementType> = HashSet::new();
+    // Discoveries carry over from earlier sessions (saved again on exit)
+    let mut profile = Profile::load(profile::DEFAULT_PROFILE_PATH);
+    let mut discovered_elements: HashSet<ElementType> = ElementType::all()
+        .into_iter()
+        .filter(|e| profile.discovered().any(|name| name == e.name()))
+        .collect();
     let mut selected_element: Option<ElementType> = None;
+    let mut active_scenario: Option<&'static Scenario> = None; // Its guide text stays up until dismissed
 
     // Right-click drag state for element spawning
     let mut right_click_start: Option<Vec2> = None;
     let mut is_dragging_right = false;
 
+    // Rubber-band selection state (Q = delete non-stable, E = delete everything)
+    let mut selection_start: Option<Vec2> = None;
+    let mut selection_clears_all = false;
+
+    // The corner that stays put while another arena corner is dragged
+    let mut arena_drag: Option<Vec2> = None;
+
     // Create buttons
     let elements_button = Button::new(10.0, 10.0, 120.0, 40.0, "Elements");
+    let scenarios_button = Button::new(140.0, 10.0, 120.0, 40.0, "Scenarios");
     let controls_button = Button::new(0.0, 10.0, 120.0, 40.0, "Controls"); // x will be set in loop
     let cell_button = Button::new(0.0, 0.0, 120.0, 40.0, "Cell"); // Will be positioned at bottom left
 
     // Create color slider (positioned at bottom, will be updated each frame)
     let mut color_slider = ColorSlider::new(0.0, 0.0, 0.0, 30.0, constants::COLOR_PALETTE_SIZE);
 
+    // Palette editor (toggled with K) - which RGB track is being dragged
+    let mut palette_editing = false;
+    let mut palette_drag_channel: Option<usize> = None;
+
+    // Live tuning panel (toggled with F1)
+    let mut config_sliders = create_config_sliders();
+    let mut show_debug_panel = false;
+    let mut membrane_sliders = create_membrane_sliders();
+    let mut membrane_params = MembraneParams::default(); // Template for cells created from now on
+    let mut cell_mouse_follow = false; // Cell mode: F switches steering between WASD and chasing the cursor
+    let mut cell_filled = false; // Cell mode: V draws membranes as filled cytoplasm blobs
+
+    // Faint atom overlay (toggled with A) - atoms are otherwise invisible
+    let mut show_atoms = false;
+
+    // Particle density heatmap (toggled with D)
+    let mut show_density = false;
+
+    // Normal-mode world view (mouse wheel zoom)
+    let mut view_camera = ViewCamera::new();
+    let mut tracked: Option<TrackedParticle> = None;
+    let mut measured: Vec<u64> = Vec::new(); // Ids of the particles picked with the measure tool (at most two)
+
     loop {
         let delta_time = get_frame_time();
         let window_size = (screen_width(), screen_height());
@@ -379,91 +1447,204 @@ async fn main() {
         color_slider.y = window_size.1 - color_slider.height - slider_margin;
         color_slider.width = slider_width;
 
-        // FPS counter
-        fps_timer += delta_time;
-        frame_count += 1;
-        if fps_timer >= 1.0 {
-            fps = frame_count as f32 / fps_timer;
-            fps_timer = 0.0;
-            frame_count = 0;
-        }
+        // FPS counter and frame-time history
+        frame_stats.record(delta_time);
+        spawn_budget.refill(delta_time);
 
-        // Update discovered elements
+        // Update discovered elements (and the best counts kept in the profile)
         let element_counts = proton_manager.get_element_counts();
-        for (element_name, _) in &element_counts {
-            let element_type = match element_name.as_str() {
-                "H1" => Some(ElementType::H1),
-                "He3" => Some(ElementType::He3),
-                "He4" => Some(ElementType::He4),
-                "C12" => Some(ElementType::C12),
-                "Ne20" => Some(ElementType::Ne20),
-                "Mg24" => Some(ElementType::Mg24),
-                "Si28" => Some(ElementType::Si28),
-                "S32" => Some(ElementType::S32),
-                "H2O" => Some(ElementType::H2O),
-                "H2S" => Some(ElementType::H2S),
-                "MgH2" => Some(ElementType::MgH2),
-                "CH4" => Some(ElementType::CH4),
-                "SiH4" => Some(ElementType::SiH4),
-                _ => None,
-            };
-            if let Some(et) = element_type {
+        for (element_name, count) in &element_counts {
+            if let Some(et) = ElementType::all().into_iter().find(|e| e.name() == element_name) {
                 discovered_elements.insert(et);
+                profile.record(element_name, *count);
             }
         }
 
         // Update systems based on game mode
         match game_mode {
             GameMode::Normal => {
-                // Update systems (only if not paused)
-                if !paused {
-                    ring_manager.update(delta_time, window_size);
-                    atom_manager.update(delta_time, ring_manager.get_all_rings(), window_size);
-                    proton_manager.update(delta_time, window_size, &mut atom_manager, &mut ring_manager);
+                // Update systems in fixed sub-steps - rings stop only on a full pause, particles on either tier
+                if !pause.rings_paused() {
+                    let particles_running = !pause.particles_paused();
+                    if particles_running {
+                        proton_manager.reset_atom_events();
+                    }
+                    let energy_before = proton_manager.total_energy();
+                    let mut events: Vec<SimEvent> = Vec::new();
+                    physics_accumulator += delta_time;
+                    let mut substeps = 0;
+                    while physics_accumulator >= timestep::FIXED_DT && substeps < timestep::MAX_SUBSTEPS {
+                        if frequency_sweep.as_mut().is_some_and(|sweep| sweep.step(timestep::FIXED_DT, &mut ring_manager)) {
+                            frequency_sweep = None;
+                        }
+                        ring_manager.update(timestep::FIXED_DT, window_size);
+                        if particles_running {
+                            atom_manager.update(timestep::FIXED_DT, ring_manager.get_all_rings(), window_size);
+                            proton_manager.update(timestep::FIXED_DT, window_size, &mut atom_manager, &mut ring_manager);
+                            // Each step starts a fresh event list, so collect them before the next one
+                            events.extend(proton_manager.drain_events());
+                        }
+                        physics_accumulator -= timestep::FIXED_DT;
+                        substeps += 1;
+                    }
+
+                    // Hit the cap - drop the backlog instead of trying to catch up next frame
+                    if physics_accumulator >= timestep::FIXED_DT {
+                        physics_accumulator = 0.0;
+                    }
+
+                    let energy_after = proton_manager.total_energy();
+                    energy_delta = energy_after - energy_before;
+                    if energy_delta.abs() >= pm::ENERGY_DELTA_LOG_THRESHOLD {
+                        eprintln!("energy: {:+.1} this frame ({:.1} -> {:.1})", energy_delta, energy_before, energy_after);
+                    }
+
+                    sound_effects.play_events(&events);
+                }
+
+                // Follow the tracked particle - its id survives in-place fusion, and tracking ends once it is gone
+                if let Some(tracking) = tracked.as_mut() {
+                    if let Some(proton) = proton_manager.find_by_id(tracking.id) {
+                        let label = proton.get_element_label();
+                        if tracking.lineage.last() != Some(&label) {
+                            tracking.lineage.push(label);
+                        }
+                        view_camera.center_on(proton.position(), window_size);
+                    } else {
+                        tracked = None;
+                    }
                 }
+                let followed = tracked.as_ref().and_then(|t| proton_manager.find_by_id(t.id).map(|p| (t, p)));
 
                 // Render
                 clear_background(BLACK);
 
-                // Draw everything
+                // Draw the world through the camera
+                set_camera(&view_camera.camera(window_size));
+                if proton_manager.is_gravity_enabled() {
+                    draw_density_layers(window_size);
+                }
                 ring_manager.draw(18);
-                // atom_manager.draw(12);  // Atoms are hidden - only used for backend calculations
-                proton_manager.draw(24);
-                proton_manager.draw_labels();
+                draw_arena(proton_manager.arena_rect(window_size), proton_manager.has_custom_arena(), view_camera.zoom);
+                // Atoms are hidden by default - they only drive neutron formation and electron capture
+                if show_atoms {
+                    atom_manager.draw_faint(12);
+                }
+                let view = view_camera.view_rect(window_size);
+                proton_manager.draw(24, color_mode, render_style, view);
+                proton_manager.draw_labels(view);
+                if show_density {
+                    draw_density_heatmap(&proton_manager, constants::rendering::DENSITY_CELL_SIZE);
+                }
+                if let Some((_, proton)) = followed {
+                    let pos = proton.position();
+                    draw_circle_lines(pos.x, pos.y, proton.radius() + 6.0, 2.0 / view_camera.zoom, YELLOW);
+                }
+                set_default_camera();
 
                 // Draw UI - buttons and menus
 
                 // Draw buttons (always visible)
                 elements_button.draw();
+                scenarios_button.draw();
                 controls_button_positioned.draw();
                 cell_button_positioned.draw();
 
                 // Draw color slider (always visible)
-                color_slider.draw(ring_manager.get_current_color_index(), &constants::RING_COLORS);
+                color_slider.draw(ring_manager.get_current_color_index(), ring_manager.colors());
+
+                // Draw palette editor
+                if palette_editing && menu_state == MenuState::None {
+                    draw_palette_editor(
+                        palette_editor_rect(&color_slider),
+                        ring_manager.get_current_color_index(),
+                        ring_manager.get_current_color(),
+                        &ring_manager.get_current_frequency_info(),
+                    );
+                }
+
+                // Draw tuning panel
+                if show_debug_panel && menu_state == MenuState::None {
+                    draw_debug_panel("TUNING (F1)", &config_sliders, proton_manager.config());
+                }
+
+                // Draw frame-time graph
+                if show_frame_graph {
+                    draw_frametime_graph(&frame_stats, window_size.0 - FRAME_GRAPH_WIDTH - 10.0, 60.0);
+                }
+
+                // Draw population controller status (below the frame graph when that is up)
+                if let Some((target, count)) = proton_manager.population() {
+                    let y = if show_frame_graph { 60.0 + FRAME_GRAPH_HEIGHT + 30.0 } else { 60.0 };
+                    draw_population_panel(target, count, window_size.0 - POPULATION_PANEL_WIDTH - 10.0, y);
+                }
+
+                // Draw the element legend above the Cell button (the tuning panel takes the left side while open)
+                if legend_mode != LegendMode::Hidden && menu_state == MenuState::None && !show_debug_panel {
+                    let show_all = legend_mode == LegendMode::All;
+                    let title = if show_all { "All (J)" } else { "Discovered (J)" };
+                    let elements = selectable_elements(&discovered_elements, show_all);
+                    draw_element_legend(&elements, title, 10.0, cell_button_positioned.y - 10.0);
+                }
+
+                // Draw followed-particle panel
+                if let Some((tracking, proton)) = followed {
+                    draw_tracking_panel(tracking, proton, window_size);
+                }
+
+                // Draw the loaded scenario's guide
+                if let Some(scenario) = active_scenario {
+                    if menu_state == MenuState::None {
+                        draw_scenario_guide(scenario, window_size);
+                    }
+                }
+
+                // Spawn feedback - the budget gauge at the cursor, and a banner once no slot is free
+                if menu_state == MenuState::None {
+                    draw_spawn_budget_cursor(&spawn_budget, mouse_position());
+                }
+                let (slots_used, _, slot_limit) = proton_manager.capacity_usage();
+                if slots_used >= slot_limit {
+                    draw_capacity_banner(slot_limit, window_size, color_slider.y - 16.0);
+                }
 
                 // Draw selected element indicator
                 if let Some(elem) = selected_element {
-                    let text = format!("Selected: {}", elem.name());
+                    let scale = SPAWN_ENERGY_SCALES[spawn_energy];
+                    let energy = format!("x{} = {}", scale, ProtonManager::default_spawn_energy(elem.name()).unwrap_or(0.0) * scale);
+                    let text = format!("Selected: {}  |  energy {} (- / =)", elem.name(), energy);
                     let text_dims = measure_text(&text, None, 24, 1.0);
                     let text_x = (window_size.0 - text_dims.width) / 2.0;
                     draw_rectangle(text_x - 10.0, 10.0, text_dims.width + 20.0, 40.0, Color::from_rgba(30, 30, 30, 200));
                     draw_text(&text, text_x, 35.0, 24.0, elem.color());
+
+                    // Speed it must hit its reaction partner at - the drag arrow turns green past it
+                    if let Some((reaction, threshold)) = proton_manager.fusion_threshold(elem.name()) {
+                        let hint = format!("{}: relative speed >= {:.2}", reaction, threshold);
+                        let hint_dims = measure_text(&hint, None, 18, 1.0);
+                        let hint_x = (window_size.0 - hint_dims.width) / 2.0;
+                        draw_rectangle(hint_x - 8.0, 52.0, hint_dims.width + 16.0, 26.0, Color::from_rgba(30, 30, 30, 200));
+                        draw_text(&hint, hint_x, 70.0, 18.0, LIGHTGRAY);
+                    }
                 }
 
                 // Draw menus
                 match menu_state {
                     MenuState::Elements => {
-                        draw_elements_menu(&discovered_elements, &element_counts, window_size);
+                        draw_elements_menu(&discovered_elements, &element_counts, &profile, window_size, show_debug_panel);
+                    },
+                    MenuState::Scenarios => {
+                        draw_scenarios_menu(window_size);
                     },
                     MenuState::Controls => {
-                        draw_controls_menu(fps, &ring_manager, &atom_manager, &proton_manager, window_size, &ring_manager.get_current_frequency_info());
+                        draw_controls_menu(&frame_stats, &ring_manager, &atom_manager, &proton_manager, window_size, sound_effects.is_muted(), energy_delta);
                     },
                     MenuState::None => {},
                 }
 
                 // Show PAUSED indicator
-                if paused {
-                    let pause_text = "PAUSED";
+                if pause != PauseState::Running {
+                    let pause_text = if pause.rings_paused() { "PAUSED" } else { "PARTICLES PAUSED" };
                     let pause_font_size = 60.0;
                     let text_dims = measure_text(pause_text, None, pause_font_size as u16, 1.0);
                     let pause_x = (window_size.0 - text_dims.width) / 2.0;
@@ -475,17 +1656,113 @@ async fn main() {
                     draw_text(pause_text, pause_x + 2.0, pause_y - 2.0, pause_font_size, BLACK);
                     draw_text(pause_text, pause_x - 2.0, pause_y + 2.0, pause_font_size, BLACK);
                     draw_text(pause_text, pause_x, pause_y, pause_font_size, RED);
+
+                    let hint = if pause.rings_paused() {
+                        "Hover to inspect - Q/E drag to select"
+                    } else {
+                        "Rings still running - click to spawn, Shift+P to resume"
+                    };
+                    let hint_dims = measure_text(hint, None, 20, 1.0);
+                    draw_text(hint, (window_size.0 - hint_dims.width) / 2.0, pause_y + 30.0, 20.0, LIGHTGRAY);
+                }
+
+                // Measure tool overlay - forget picks that no longer exist
+                measured.retain(|&id| proton_manager.find_by_id(id).is_some());
+                let picked: Vec<&Proton> = measured.iter().filter_map(|&id| proton_manager.find_by_id(id)).collect();
+                let threshold = match picked[..] {
+                    [a, b] => proton_manager.pair_fusion_threshold(a, b),
+                    _ => None,
+                };
+                draw_measurement(&picked, threshold, &view_camera);
+
+                // Hover inspection - reads state only, so it runs whether or not the simulation is paused
+                if menu_state == MenuState::None && selection_start.is_none() {
+                    let (mx, my) = mouse_position();
+                    let hovered = proton_manager
+                        .slot_at(view_camera.screen_to_world(vec2(mx, my)))
+                        .and_then(|slot| proton_manager.particle_in_slot(slot).map(|proton| (slot, proton)));
+                    if let Some((slot, proton)) = hovered {
+                        let partners: Vec<&Proton> =
+                            proton_manager.bonds_of(slot).into_iter().filter_map(|s| proton_manager.particle_in_slot(s)).collect();
+                        draw_bond_graph(proton, &partners, &view_camera);
+                        draw_inspect_tooltip(proton, partners.len(), (mx, my), window_size);
+                    }
                 }
             },
             GameMode::Cell => {
                 // Cell mode - simple black background with cell
                 clear_background(BLACK);
 
-                // Handle cell movement with WASD
-                if let Some(ref mut cell_instance) = cell {
-                    cell_instance.handle_movement();
+                // Handle cell movement with WASD, or chase the cursor when mouse-follow (F) is on
+                if is_key_pressed(KeyCode::F) {
+                    cell_mouse_follow = !cell_mouse_follow;
+                }
+                let (mouse_x, mouse_y) = mouse_position();
+                for cell_instance in cells.iter_mut() {
+                    if cell_mouse_follow {
+                        cell_instance.handle_mouse_movement(vec2(mouse_x, mouse_y));
+                    } else {
+                        cell_instance.handle_movement();
+                    }
                     cell_instance.update(delta_time);
-                    cell_instance.draw();
+                }
+                Cell::apply_cell_collision_forces(&mut cells, delta_time);
+
+                // Top up drifting food outside the cells, then let each cell engulf what its membrane encloses
+                food_spawn_timer += delta_time;
+                if food_spawn_timer >= cc::FOOD_SPAWN_INTERVAL {
+                    food_spawn_timer = 0.0;
+                    let position = vec2(rand::gen_range(0.0, window_size.0), rand::gen_range(0.0, window_size.1));
+                    if food.len() < cc::MAX_FOOD && !cells.iter().any(|c| c.contains_point(position)) {
+                        food.push(Food::new(position));
+                    }
+                }
+                for particle in food.iter_mut() {
+                    particle.update(delta_time, window_size);
+                }
+                for cell_instance in cells.iter_mut() {
+                    cell_instance.absorb_food(&mut food);
+                }
+
+                // Spawn a new cell at the mouse with C
+                if is_key_pressed(KeyCode::C) {
+                    cells.push(Cell::new(vec2(mouse_x, mouse_y), cc::NUM_MEMBRANE_COMPONENTS, membrane_params));
+                }
+
+                // Divide every cell with X, or on its own after holding full expansion
+                let force_division = is_key_pressed(KeyCode::X);
+                let mut cell_count = cells.len();
+                let mut daughters = Vec::new();
+                for cell_instance in cells.iter_mut() {
+                    let auto_division = cell_instance.ready_to_divide() && cell_count < cc::MAX_CELLS;
+                    if force_division || auto_division {
+                        daughters.push(cell_instance.divide());
+                        cell_count += 1;
+                    }
+                }
+                cells.extend(daughters);
+
+                for particle in &food {
+                    particle.draw();
+                }
+                if is_key_pressed(KeyCode::V) {
+                    cell_filled = !cell_filled;
+                }
+                for cell_instance in &cells {
+                    if cell_filled {
+                        cell_instance.draw_filled();
+                    } else {
+                        cell_instance.draw();
+                    }
+                }
+
+                let steering = if cell_mouse_follow { "Steering: mouse (F for WASD)" } else { "Steering: WASD (F for mouse)" };
+                let view = if cell_filled { "V: lipids only" } else { "V: filled membrane" };
+                draw_text(&format!("{}  |  {}", steering, view), cell_button_positioned.x + cell_button.width + 15.0, window_size.1 - 25.0, 18.0, LIGHTGRAY);
+
+                // Membrane tuning panel
+                if show_debug_panel {
+                    draw_debug_panel("MEMBRANE - new cells (C)", &membrane_sliders, &membrane_params);
                 }
 
                 // Draw cell button to allow return to normal mode
@@ -493,33 +1770,57 @@ async fn main() {
             },
         }
 
-        // Input handling
-        if is_key_pressed(KeyCode::Escape) {
-            break;
+        if show_help {
+            draw_help_overlay(window_size);
         }
 
-        // Toggle pause with P key
-        if is_key_pressed(KeyCode::P) {
-            paused = !paused;
+        // Input handling (closing the window quits the same way, so the profile is saved)
+        if is_key_pressed(KeyCode::Escape) || is_quit_requested() {
+            break;
         }
 
-        // Mouse input handling
+        // Mouse input handling - `mouse_pos` is screen space (UI), `mouse_world` is where it points in the simulation
         let mouse_pos = mouse_position();
+        let mouse_world = view_camera.screen_to_world(vec2(mouse_pos.0, mouse_pos.1));
+
+        // Force tool: Shift + left drag attracts, Ctrl + left drag repels
+        let attract_held = is_key_down(KeyCode::LeftShift) || is_key_down(KeyCode::RightShift);
+        let repel_held = is_key_down(KeyCode::LeftControl) || is_key_down(KeyCode::RightControl);
+        let force_tool_active = game_mode == GameMode::Normal && menu_state == MenuState::None && (attract_held || repel_held);
+
+        // Selection box: hold Q (non-stable only) or E (everything) and left drag
+        let selection_key_held = is_key_down(KeyCode::Q) || is_key_down(KeyCode::E);
+
+        // Measure tool: hold U and left click two particles
+        let measure_key_held = game_mode == GameMode::Normal && menu_state == MenuState::None && is_key_down(KeyCode::U);
+
+        // Clicks on the tuning panel should not fall through to the simulation
+        let debug_panel_hovered = show_debug_panel
+            && game_mode == GameMode::Normal
+            && menu_state == MenuState::None
+            && debug_panel_contains(config_sliders.len(), mouse_pos.0, mouse_pos.1);
+        let palette_editor_hovered = palette_editing
+            && game_mode == GameMode::Normal
+            && menu_state == MenuState::None
+            && palette_editor_rect(&color_slider).contains(vec2(mouse_pos.0, mouse_pos.1));
 
         // Left click handling
+        let arena = proton_manager.arena_rect(window_size);
+        let arena_corner = arena_corner_at(arena, &view_camera, vec2(mouse_pos.0, mouse_pos.1));
         if is_mouse_button_pressed(MouseButton::Left) {
             // Handle cell button click (works in both modes)
             if cell_button_positioned.contains_point(mouse_pos.0, mouse_pos.1) {
                 if game_mode == GameMode::Normal {
                     // Switch to cell mode - create cell at screen center
                     let center = vec2(window_size.0 / 2.0, window_size.1 / 2.0);
-                    cell = Some(Cell::new(center, cc::NUM_MEMBRANE_COMPONENTS));
+                    cells = vec![Cell::new(center, cc::NUM_MEMBRANE_COMPONENTS, membrane_params)];
                     game_mode = GameMode::Cell;
                     menu_state = MenuState::None; // Close any open menus
                 } else {
                     // Switch back to normal mode
                     game_mode = GameMode::Normal;
-                    cell = None;
+                    cells.clear();
+                    food.clear();
                 }
             } else {
                 match menu_state {
@@ -529,47 +1830,41 @@ async fn main() {
                             // Check button clicks
                             if elements_button.contains_point(mouse_pos.0, mouse_pos.1) {
                                 menu_state = MenuState::Elements;
+                            } else if scenarios_button.contains_point(mouse_pos.0, mouse_pos.1) {
+                                menu_state = MenuState::Scenarios;
                             } else if controls_button_positioned.contains_point(mouse_pos.0, mouse_pos.1) {
                                 menu_state = MenuState::Controls;
-                            } else if !paused {
-                                // Spawn ring if not clicking UI
-                                ring_manager.add_ring(vec2(mouse_pos.0, mouse_pos.1));
+                            } else if active_scenario.is_some_and(|s| scenario_guide_rect(s, window_size).contains(vec2(mouse_pos.0, mouse_pos.1))) {
+                                active_scenario = None;
+                            } else if let Some(corner) = arena_corner.filter(|_| !force_tool_active && !selection_key_held && !measure_key_held) {
+                                arena_drag = Some(arena_corners(arena)[(corner + 2) % 4]);
+                            } else if !pause.rings_paused() && !force_tool_active && !debug_panel_hovered && !palette_editor_hovered && !selection_key_held && !measure_key_held {
+                                // Spawn ring if not clicking UI (and the spawn budget allows)
+                                if spawn_budget.try_spend(RING_SPAWN_COST) {
+                                    ring_manager.add_ring(mouse_world);
+                                }
                             }
                         }
                     },
                 MenuState::Elements => {
                     // Check if clicking an element in the menu
-                    let menu_width = 500.0;
-                    let menu_height = 500.0;
+                    let menu_width = ELEMENTS_MENU_SIZE;
+                    let menu_height = ELEMENTS_MENU_SIZE;
                     let menu_x = (window_size.0 - menu_width) / 2.0;
                     let menu_y = (window_size.1 - menu_height) / 2.0;
 
                     // Check if clicking inside menu
                     if mouse_pos.0 >= menu_x && mouse_pos.0 <= menu_x + menu_width &&
                        mouse_pos.1 >= menu_y && mouse_pos.1 <= menu_y + menu_height {
-                        // Check which element was clicked - two columns layout
-                        let line_height = 40.0;
-                        let column_width = menu_width / 2.0;
-                        let elements_per_column = 9;
-                        let mut discovered_index = 0;
-
-                        for element in ElementType::all() {
-                            if discovered_elements.contains(&element) {
-                                // Determine column and position
-                                let column = discovered_index / elements_per_column;
-                                let row_in_column = discovered_index % elements_per_column;
-
-                                let x_offset = menu_x + (column as f32 * column_width);
-                                let y_offset = menu_y + 80.0 + (row_in_column as f32 * line_height);
-
-                                // Check if mouse is over this element
-                                if mouse_pos.0 >= x_offset && mouse_pos.0 <= x_offset + column_width &&
-                                   mouse_pos.1 >= y_offset - line_height / 2.0 && mouse_pos.1 < y_offset + line_height / 2.0 {
-                                    selected_element = Some(element);
-                                    menu_state = MenuState::None;
-                                    break;
-                                }
-                                discovered_index += 1;
+                        // Select the clicked element - locked (undiscovered) entries ignore clicks unless the debug palette is on
+                        let clicked = ElementType::all()
+                            .into_iter()
+                            .enumerate()
+                            .find(|(i, _)| element_entry_rect(*i, menu_x, menu_y).contains(vec2(mouse_pos.0, mouse_pos.1)));
+                        if let Some((_, element)) = clicked {
+                            if discovered_elements.contains(&element) || show_debug_panel {
+                                selected_element = Some(element);
+                                menu_state = MenuState::None;
                             }
                         }
                     } else {
@@ -577,44 +1872,176 @@ async fn main() {
                         menu_state = MenuState::None;
                     }
                 },
+                MenuState::Scenarios => {
+                    let menu = scenario_menu_rect(window_size);
+                    if menu.contains(vec2(mouse_pos.0, mouse_pos.1)) {
+                        // Load the clicked scenario: fresh pond, its element selected, camera on the middle of the world
+                        let clicked = (0..SCENARIOS.len())
+                            .find(|&i| scenario_entry_rect(i, menu).contains(vec2(mouse_pos.0, mouse_pos.1)));
+                        if let Some(i) = clicked {
+                            let center = vec2(window_size.0 / 2.0, window_size.1 / 2.0);
+                            if let Some(scenario) = proton_manager.load_scenario(SCENARIOS[i].name, center) {
+                                ring_manager.clear();
+                                atom_manager.clear();
+                                selected_element = ElementType::all().into_iter().find(|e| e.name() == scenario.selected_element);
+                                tracked = None;
+                                view_camera.zoom = scenario.zoom.clamp(1.0, CAMERA_MAX_ZOOM);
+                                view_camera.center_on(center, window_size);
+                                active_scenario = Some(scenario);
+                            }
+                            menu_state = MenuState::None;
+                        }
+                    } else {
+                        menu_state = MenuState::None;
+                    }
+                },
                 MenuState::Controls => {
                     // Check if clicking outside menu to close
-                    let menu_width = 600.0;
-                    let menu_height = 550.0;
+                    let menu_width = CONTROLS_MENU_WIDTH;
+                    let menu_height = CONTROLS_MENU_HEIGHT;
                     let menu_x = (window_size.0 - menu_width) / 2.0;
                     let menu_y = (window_size.1 - menu_height) / 2.0;
 
                     if mouse_pos.0 < menu_x || mouse_pos.0 > menu_x + menu_width ||
                        mouse_pos.1 < menu_y || mouse_pos.1 > menu_y + menu_height {
                         menu_state = MenuState::None;
+                    } else {
+                        // Toggle a reaction when its row is clicked
+                        for (i, (flag, _)) in ReactionFlags::ALL.iter().enumerate() {
+                            if reaction_toggle_rect(i, menu_x, menu_y).contains(vec2(mouse_pos.0, mouse_pos.1)) {
+                                proton_manager.toggle_reaction(*flag);
+                            }
+                        }
+
+                        // Delete every particle of the clicked element
+                        for (i, element) in ElementType::all().into_iter().enumerate() {
+                            if element_delete_rect(i, menu_x, menu_y).contains(vec2(mouse_pos.0, mouse_pos.1)) {
+                                let name = element.name();
+                                proton_manager.delete_element(|p| ProtonManager::element_name(p) == Some(name));
+                            }
+                        }
                     }
                 },
                 }
             }
         }
 
+        // Arena corner drag - the walls follow the pointer until the button is let go
+        if let Some(fixed) = arena_drag {
+            proton_manager.set_arena(dragged_arena(fixed, mouse_world, window_size));
+            if !is_mouse_button_down(MouseButton::Left) {
+                arena_drag = None;
+            }
+        }
+
+        // Middle click locks the camera onto the particle under the cursor (empty space lets go)
+        if game_mode == GameMode::Normal && menu_state == MenuState::None && is_mouse_button_pressed(MouseButton::Middle) {
+            tracked = proton_manager.particle_at(mouse_world).map(|proton| TrackedParticle {
+                id: proton.id(),
+                lineage: vec![proton.get_element_label()],
+            });
+            if tracked.is_some() && view_camera.zoom < FOLLOW_MIN_ZOOM {
+                view_camera.zoom_at(vec2(mouse_pos.0, mouse_pos.1), FOLLOW_MIN_ZOOM / view_camera.zoom, window_size);
+            }
+        }
+
+        // Measure tool picks - a third pick starts a new pair, clicking empty space clears them
+        if measure_key_held && is_mouse_button_pressed(MouseButton::Left) && !debug_panel_hovered {
+            match proton_manager.particle_at(mouse_world) {
+                Some(proton) if !measured.contains(&proton.id()) => {
+                    if measured.len() == 2 {
+                        measured.clear();
+                    }
+                    measured.push(proton.id());
+                }
+                Some(_) => {}
+                None => measured.clear(),
+            }
+        }
+
+        // Rubber-band selection delete - deliberately not gated on `pause` so a frozen moment can be trimmed
+        if game_mode == GameMode::Normal && menu_state == MenuState::None {
+            if selection_key_held && is_mouse_button_pressed(MouseButton::Left) && !debug_panel_hovered {
+                selection_start = Some(vec2(mouse_pos.0, mouse_pos.1));
+                selection_clears_all = is_key_down(KeyCode::E);
+            }
+
+            if let Some(start) = selection_start {
+                let end = vec2(mouse_pos.0, mouse_pos.1);
+                let min = start.min(end);
+                let size = (start - end).abs();
+                let selection = Rect::new(min.x, min.y, size.x, size.y);
+
+                if is_mouse_button_released(MouseButton::Left) {
+                    let world_selection = view_camera.screen_rect_to_world(selection);
+                    if selection_clears_all {
+                        proton_manager.clear_all_in_rect(world_selection);
+                    } else {
+                        proton_manager.clear_in_rect(world_selection);
+                    }
+                    selection_start = None;
+                } else {
+                    let box_color = if selection_clears_all { RED } else { ORANGE };
+                    draw_rectangle(selection.x, selection.y, selection.w, selection.h, Color::new(box_color.r, box_color.g, box_color.b, 0.15));
+                    draw_rectangle_lines(selection.x, selection.y, selection.w, selection.h, 2.0, box_color);
+                }
+            }
+        } else {
+            selection_start = None;
+        }
+
+        // Apply the force tool while the left button is held (spawns and forces are the only paused-gated input)
+        if force_tool_active && !pause.particles_paused() && is_mouse_button_down(MouseButton::Left) {
+            let strength = if repel_held { -pm::POINT_FORCE_STRENGTH } else { pm::POINT_FORCE_STRENGTH };
+            proton_manager.apply_point_force(mouse_world, strength * delta_time, pm::POINT_FORCE_RADIUS);
+
+            let tool_color = if repel_held { Color::new(1.0, 0.4, 0.4, 0.4) } else { Color::new(0.4, 0.7, 1.0, 0.4) };
+            draw_circle_lines(mouse_pos.0, mouse_pos.1, pm::POINT_FORCE_RADIUS * view_camera.zoom, 1.5, tool_color);
+        }
+
         // Right click drag for element spawning (only in Normal mode when not paused and element is selected)
-        if game_mode == GameMode::Normal && !paused && selected_element.is_some() && menu_state == MenuState::None {
+        // Holding S instead stamps a stationary seed crystal of the selected element at the cursor
+        if game_mode == GameMode::Normal && !pause.particles_paused() && selected_element.is_some() && menu_state == MenuState::None {
             if is_mouse_button_pressed(MouseButton::Right) {
-                right_click_start = Some(vec2(mouse_pos.0, mouse_pos.1));
-                is_dragging_right = true;
+                if is_key_down(KeyCode::S) {
+                    if let Some(elem) = selected_element {
+                        if spawn_budget.try_spend(LATTICE_SPAWN_COST) {
+                            proton_manager.stamp_lattice(elem.name(), mouse_world, SEED_LATTICE_RINGS);
+                        }
+                    }
+                } else {
+                    right_click_start = Some(vec2(mouse_pos.0, mouse_pos.1));
+                    is_dragging_right = true;
+                }
             }
 
             if is_dragging_right && is_mouse_button_down(MouseButton::Right) {
-                // Currently dragging, could draw a line showing the drag vector if desired
+                if let (Some(start_pos), Some(elem)) = (right_click_start, selected_element) {
+                    let threshold = proton_manager.fusion_threshold(elem.name()).map(|(_, threshold)| threshold);
+                    draw_drag_arrow(start_pos, vec2(mouse_pos.0, mouse_pos.1), view_camera.zoom, elem.color(), threshold);
+                }
             }
 
             if is_dragging_right && is_mouse_button_released(MouseButton::Right) {
                 // Spawn element with velocity based on drag
                 if let Some(start_pos) = right_click_start {
                     let end_pos = vec2(mouse_pos.0, mouse_pos.1);
-                    let drag_vector = end_pos - start_pos;
+                    let drag_vector = (end_pos - start_pos) / view_camera.zoom;
 
-                    // Velocity is proportional to drag distance (scale by 2 for better feel)
-                    let velocity = drag_vector * 2.0;
+                    // Velocity is proportional to drag distance (scaled up for better feel)
+                    let velocity = drag_vector * SPAWN_DRAG_VELOCITY_SCALE;
 
                     if let Some(elem) = selected_element {
-                        proton_manager.spawn_element(elem.name(), start_pos, velocity);
+                        if spawn_budget.try_spend(ELEMENT_SPAWN_COST) {
+                            let position = view_camera.screen_to_world(start_pos);
+                            match ProtonManager::default_spawn_energy(elem.name()) {
+                                Some(default) => {
+                                    let energy = default * SPAWN_ENERGY_SCALES[spawn_energy];
+                                    proton_manager.spawn_element_with_energy(elem.name(), position, velocity, energy);
+                                }
+                                None => proton_manager.spawn_element(elem.name(), position, velocity),
+                            }
+                        }
                     }
                 }
 
@@ -623,6 +2050,40 @@ async fn main() {
             }
         }
 
+        // Tuning panel slider interaction - live physics config in Normal mode, new-cell membrane params in Cell mode
+        if show_debug_panel && game_mode == GameMode::Normal && menu_state == MenuState::None {
+            update_config_sliders(&mut config_sliders, proton_manager.config_mut(), mouse_pos);
+        }
+        if show_debug_panel && game_mode == GameMode::Cell {
+            update_config_sliders(&mut membrane_sliders, &mut membrane_params, mouse_pos);
+        }
+
+        // Palette editor - dragging an RGB track recolors the selected slider segment
+        if palette_editing && game_mode == GameMode::Normal && menu_state == MenuState::None {
+            let panel = palette_editor_rect(&color_slider);
+            if is_mouse_button_pressed(MouseButton::Left) {
+                palette_drag_channel = (0..3).find(|&channel| palette_channel_rect(channel, panel).contains(vec2(mouse_pos.0, mouse_pos.1)));
+            }
+
+            if let Some(channel) = palette_drag_channel {
+                if is_mouse_button_down(MouseButton::Left) {
+                    let track = palette_channel_rect(channel, panel);
+                    let value = ((mouse_pos.0 - track.x) / track.w).clamp(0.0, 1.0);
+                    let mut color = ring_manager.get_current_color();
+                    match channel {
+                        0 => color.r = value,
+                        1 => color.g = value,
+                        _ => color.b = value,
+                    }
+                    ring_manager.set_palette_color(ring_manager.get_current_color_index(), color);
+                }
+            }
+
+            if is_mouse_button_released(MouseButton::Left) {
+                palette_drag_channel = None;
+            }
+        }
+
         // Color slider interaction (only in Normal mode)
         if game_mode == GameMode::Normal && menu_state == MenuState::None {
             // Start dragging slider
@@ -643,42 +2104,202 @@ async fn main() {
                 color_slider.is_dragging = false;
             }
 
-            // Mouse wheel color cycling
+            // Step through ring colors with , and .
+            if is_key_pressed(KeyCode::Period) {
+                ring_manager.cycle_to_next_color();
+            } else if is_key_pressed(KeyCode::Comma) {
+                ring_manager.cycle_to_previous_color();
+            }
+
+            // Mouse wheel zooms about the cursor (works while paused)
             let mouse_wheel = mouse_wheel();
             if mouse_wheel.1 > 0.0 {
-                // Mouse wheel up - next color
-                ring_manager.cycle_to_next_color();
+                view_camera.zoom_at(vec2(mouse_pos.0, mouse_pos.1), CAMERA_ZOOM_STEP, window_size);
             } else if mouse_wheel.1 < 0.0 {
-                // Mouse wheel down - previous color
-                ring_manager.cycle_to_previous_color();
+                view_camera.zoom_at(vec2(mouse_pos.0, mouse_pos.1), 1.0 / CAMERA_ZOOM_STEP, window_size);
+            }
+
+            // Home fits every particle on screen, End recenters on their centroid - both let go of a followed particle
+            if is_key_pressed(KeyCode::Home) {
+                if let Some(bounds) = proton_manager.bounding_box() {
+                    tracked = None;
+                    view_camera.fit(bounds, window_size);
+                }
+            } else if is_key_pressed(KeyCode::End) {
+                if let Some(centroid) = proton_manager.centroid() {
+                    tracked = None;
+                    view_camera.center_on(centroid, window_size);
+                }
             }
         }
 
-        // Clear all with R key
-        if is_key_pressed(KeyCode::R) {
-            ring_manager.clear();
-            atom_manager.clear();
-            proton_manager.clear();
+        // Ctrl+C / Ctrl+V: copy the particle state to the clipboard as base64, or load one from it
+        // Ctrl+S: write the whole pond as a vector image
+        let ctrl_held = is_key_down(KeyCode::LeftControl) || is_key_down(KeyCode::RightControl);
+        if game_mode == GameMode::Normal && ctrl_held {
+            if is_key_pressed(KeyCode::C) {
+                miniquad::window::clipboard_set(&proton_manager.to_base64());
+            }
+            if is_key_pressed(KeyCode::V) {
+                if let Some(text) = miniquad::window::clipboard_get() {
+                    if let Err(err) = proton_manager.load_base64(&text) {
+                        eprintln!("clipboard: {}", err);
+                    }
+                }
+            }
+            if is_key_pressed(KeyCode::S) {
+                if let Err(err) = proton_manager.export_svg(SVG_EXPORT_PATH, window_size) {
+                    eprintln!("{}: {}", SVG_EXPORT_PATH, err);
+                }
+            }
         }
 
-        // Clear all with Space bar
-        if is_key_pressed(KeyCode::Space) {
-            ring_manager.clear();
-            atom_manager.clear();
-            proton_manager.clear();
+        // 1-9 select a discovered element and 0 goes back to free protons
+        if game_mode == GameMode::Normal {
+            let selectable = selectable_elements(&discovered_elements, show_debug_panel);
+            if let Some(n) = ELEMENT_KEYS.iter().position(|&key| is_key_pressed(key)) {
+                if let Some(&element) = selectable.get(n) {
+                    selected_element = Some(element);
+                }
+            } else if is_key_pressed(KeyCode::Key0) {
+                selected_element = None;
+            }
+
+            // [ and ] step through them while no population is held (they move its target otherwise)
+            if proton_manager.population().is_none() {
+                if is_key_pressed(KeyCode::RightBracket) {
+                    selected_element = step_selection(selected_element, &selectable, 1);
+                } else if is_key_pressed(KeyCode::LeftBracket) {
+                    selected_element = step_selection(selected_element, &selectable, -1);
+                }
+            }
         }
 
-        // Delete all stable H protons with H key
-        if is_key_pressed(KeyCode::H) {
-            proton_manager.delete_stable_hydrogen();
+        // N holds the selected element's population (free H+ with nothing selected); [ and ] move the target
+        if game_mode == GameMode::Normal {
+            if is_key_pressed(KeyCode::N) {
+                let target = match proton_manager.population() {
+                    Some(_) => None,
+                    None => Some(PopulationTarget {
+                        element: selected_element.map_or_else(|| FREE_PROTON_NAME.to_string(), |e| e.name().to_string()),
+                        count: POPULATION_DEFAULT_COUNT,
+                    }),
+                };
+                proton_manager.set_population_target(target);
+            }
+            if let Some((target, _)) = proton_manager.population() {
+                let count = if is_key_pressed(KeyCode::RightBracket) {
+                    Some(target.count + POPULATION_COUNT_STEP)
+                } else if is_key_pressed(KeyCode::LeftBracket) {
+                    Some(target.count.saturating_sub(POPULATION_COUNT_STEP))
+                } else {
+                    None
+                };
+                if let Some(count) = count {
+                    let element = target.element.clone();
+                    proton_manager.set_population_target(Some(PopulationTarget { element, count }));
+                }
+            }
         }
 
-        // Clear all protons with Z key (including immortal elements)
-        if is_key_pressed(KeyCode::Z) {
-            proton_manager.clear_all();
+        // Single-key toggles and commands, dispatched from the controls table (see controls.rs)
+        for action in controls::pressed_actions(game_mode == GameMode::Normal) {
+            match action {
+                Action::ClearNonStable => {
+                    ring_manager.clear();
+                    atom_manager.clear();
+                    proton_manager.clear();
+                }
+                Action::DeleteStableHydrogen => proton_manager.delete_stable_hydrogen(),
+                // Including immortal elements
+                Action::ClearAll => proton_manager.clear_all(),
+                Action::TogglePause => pause = pause.toggled(false),
+                Action::TogglePauseParticles => pause = pause.toggled(true),
+                Action::ToggleMute => sound_effects.toggle_mute(),
+                Action::ToggleTemperature => {
+                    let enabled = proton_manager.is_temperature_enabled();
+                    proton_manager.set_temperature_enabled(!enabled);
+                }
+                Action::CycleBoundary => {
+                    let mode = proton_manager.boundary_mode().next();
+                    proton_manager.set_boundary_mode(mode);
+                }
+                Action::ToggleGravity => {
+                    let enabled = proton_manager.is_gravity_enabled();
+                    proton_manager.set_gravity(!enabled);
+                }
+                Action::CycleMedium => {
+                    let medium = proton_manager.medium().next();
+                    proton_manager.set_medium(medium);
+                }
+                Action::ToggleShapes => {
+                    render_style = if render_style == RenderStyle::Circles {
+                        RenderStyle::Shapes
+                    } else {
+                        RenderStyle::Circles
+                    };
+                }
+                Action::ToggleShockwave => {
+                    ring_manager.set_style(if ring_manager.style() == RingStyle::Line {
+                        RingStyle::Shockwave
+                    } else {
+                        RingStyle::Line
+                    });
+                }
+                Action::CycleColorMode => color_mode = color_mode.next(),
+                // Closing the palette editor saves the palette to pond.toml
+                Action::TogglePaletteEditor => {
+                    if palette_editing {
+                        if let Err(err) = SimConfig::save_ring_colors(sim_config::DEFAULT_CONFIG_PATH, ring_manager.colors()) {
+                            eprintln!("{}: {}", sim_config::DEFAULT_CONFIG_PATH, err);
+                        }
+                    }
+                    palette_editing = !palette_editing;
+                    palette_drag_channel = None;
+                }
+                Action::ToggleTuningPanel => show_debug_panel = !show_debug_panel,
+                Action::ToggleDensity => show_density = !show_density,
+                Action::ToggleFrameGraph => show_frame_graph = !show_frame_graph,
+                Action::SweepColors => frequency_sweep = Some(FrequencySweep::new(mouse_world)),
+                Action::CycleLegend => legend_mode = legend_mode.next(),
+                Action::ToggleLifetimeArcs => {
+                    let enabled = proton_manager.is_lifetime_display_enabled();
+                    proton_manager.set_lifetime_display(!enabled);
+                }
+                Action::ToggleVelocityArrows => {
+                    let enabled = proton_manager.is_velocity_display_enabled();
+                    proton_manager.set_velocity_display(!enabled);
+                }
+                Action::ToggleAtoms => show_atoms = !show_atoms,
+                Action::ToggleHelp => show_help = !show_help,
+                Action::SpawnEnergyDown => spawn_energy = step_spawn_energy(spawn_energy, -1),
+                Action::SpawnEnergyUp => spawn_energy = step_spawn_energy(spawn_energy, 1),
+            }
         }
 
         next_frame().await
     }
+
+    if let Err(err) = profile.save(profile::DEFAULT_PROFILE_PATH) {
+        eprintln!("{}: {}", profile::DEFAULT_PROFILE_PATH, err);
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn view_camera_draws_world_points_where_world_to_screen_puts_them() {
+        let window = (800.0, 600.0);
+        let mut camera = ViewCamera::new();
+        camera.zoom_at(vec2(200.0, 150.0), 2.5, window);
+        let matrix = camera.camera(window).matrix();
+        for point in [vec2(150.0, 120.0), vec2(300.0, 90.0), vec2(180.0, 250.0)] {
+            // Clip space to window pixels, y down - what the GPU does with the camera's matrix
+            let clip = matrix.transform_point3(vec3(point.x, point.y, 0.0));
+            let drawn = vec2((clip.x + 1.0) / 2.0 * window.0, (1.0 - clip.y) / 2.0 * window.1);
+            assert!(drawn.distance(camera.world_to_screen(point)) < 1e-2, "{:?} drawn at {:?}", point, drawn);
+        }
+    }
+}
@@ -0,0 +1,28 @@
+// Parallel - Index-parallel map for the pure compute phases of ProtonManager::update
+//
+// With the `parallel` feature the work is spread over rayon's thread pool; without it the same
+// closure runs on this thread. Each index is computed on its own and the results come back in
+// index order. Mutation stays with the caller, which applies the returned values sequentially
+// (compute-then-apply), so nothing is shared between threads but read-only particle data.
+
+/// `f(0), f(1), .., f(count - 1)` in order, computed on the rayon pool
+#[cfg(feature = "parallel")]
+pub fn map_indices<T, F>(count: usize, f: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(usize) -> T + Send + Sync,
+{
+    use rayon::prelude::*;
+    (0..count).into_par_iter().map(f).collect()
+}
+
+/// `f(0), f(1), .., f(count - 1)` in order, computed on this thread
+/// Same bounds as the parallel build, so code that compiles here compiles there
+#[cfg(not(feature = "parallel"))]
+pub fn map_indices<T, F>(count: usize, f: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(usize) -> T + Send + Sync,
+{
+    (0..count).map(f).collect()
+}
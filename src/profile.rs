@@ -0,0 +1,99 @@
+// Profile - What the player has discovered, kept across sessions in pond_profile.txt
+//
+// Separate from snapshots and the sim itself, so clearing the pond never forgets a discovery.
+// The file is a header line followed by one line per element ever made, with the most of it
+// seen alive at once:
+//
+//     pond-profile 1
+//     <element> <max count>
+//
+// A missing file starts a fresh profile; so does a corrupt one (reported, then overwritten on exit).
+
+use std::collections::BTreeMap;
+
+pub const DEFAULT_PROFILE_PATH: &str = "pond_profile.txt";
+const HEADER: &str = "pond-profile 1";
+
+/// Every element the player has made, with its highest live count
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Profile {
+    max_counts: BTreeMap<String, usize>,
+    changed: bool, // Something new since the last load or save
+}
+
+impl Profile {
+    /// Load the profile at `path`, starting fresh if it is missing or unreadable
+    pub fn load(path: &str) -> Self {
+        let Ok(text) = std::fs::read_to_string(path) else { return Self::default() };
+        match Self::parse(&text) {
+            Ok(profile) => profile,
+            Err(err) => {
+                eprintln!("{}: {} - starting a fresh profile", path, err);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut lines = text.lines();
+        if lines.next().map(str::trim) != Some(HEADER) {
+            return Err("not a pond profile".to_string());
+        }
+
+        let mut profile = Self::default();
+        for (i, line) in lines.enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [name, count] = fields[..] else { return Err(format!("bad entry on line {}", i + 2)) };
+            let count = count.parse().map_err(|_| format!("bad count on line {}", i + 2))?;
+            profile.max_counts.insert(name.to_string(), count);
+        }
+        Ok(profile)
+    }
+
+    /// Write the profile to `path` if anything changed since it was loaded
+    pub fn save(&mut self, path: &str) -> std::io::Result<()> {
+        if !self.changed {
+            return Ok(());
+        }
+        std::fs::write(path, self.to_text())?;
+        self.changed = false;
+        Ok(())
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = String::from(HEADER);
+        for (name, count) in &self.max_counts {
+            text.push_str(&format!("\n{} {}", name, count));
+        }
+        text.push('\n');
+        text
+    }
+
+    /// Note that `count` of `name` are alive right now
+    pub fn record(&mut self, name: &str, count: usize) {
+        match self.max_counts.get_mut(name) {
+            Some(best) if *best >= count => {}
+            Some(best) => {
+                *best = count;
+                self.changed = true;
+            }
+            None => {
+                self.max_counts.insert(name.to_string(), count);
+                self.changed = true;
+            }
+        }
+    }
+
+    /// Elements ever made, by name
+    pub fn discovered(&self) -> impl Iterator<Item = &str> {
+        self.max_counts.keys().map(String::as_str)
+    }
+
+    /// Most of `name` ever alive at once (0 if never made)
+    pub fn max_count(&self, name: &str) -> usize {
+        self.max_counts.get(name).copied().unwrap_or(0)
+    }
+}
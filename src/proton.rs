@@ -4,6 +4,158 @@
 use macroquad::prelude::*;
 use crate::constants::*;
 use crate::constants::proton as pc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// Source of particle serials and ids - 0 is never handed out, so it can stand for an empty slot
+static NEXT_SERIAL: AtomicU64 = AtomicU64::new(1);
+
+/// How protons behave at the window edges
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BoundaryMode {
+    Bounce, // Reflect off the edges (default)
+    Wrap,   // Leave one edge, reappear on the opposite edge (toroidal world)
+    Open,   // No walls - protons drift off-screen and are culled
+}
+
+impl BoundaryMode {
+    pub fn name(&self) -> &str {
+        match self {
+            BoundaryMode::Bounce => "Bounce",
+            BoundaryMode::Wrap => "Wrap",
+            BoundaryMode::Open => "Open",
+        }
+    }
+
+    /// Next mode in the Bounce -> Wrap -> Open cycle
+    pub fn next(&self) -> BoundaryMode {
+        match self {
+            BoundaryMode::Bounce => BoundaryMode::Wrap,
+            BoundaryMode::Wrap => BoundaryMode::Open,
+            BoundaryMode::Open => BoundaryMode::Bounce,
+        }
+    }
+}
+
+/// What a particle bounces like in solid collisions - see SimConfig::elasticity
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Material {
+    Gas,      // Hydrogen isotopes, noble gases, leptons, and molecules other than water
+    Ice,      // H2O
+    Metal,    // Mg24, Si28, Na23, K39, Ca40
+    NonMetal, // C12, O16, S32, N14, P31
+}
+
+impl Material {
+    pub const ALL: [Material; 4] = [Material::Gas, Material::Ice, Material::Metal, Material::NonMetal];
+
+    /// Lowercase name used in pond.toml keys
+    pub fn name(self) -> &'static str {
+        match self {
+            Material::Gas => "gas",
+            Material::Ice => "ice",
+            Material::Metal => "metal",
+            Material::NonMetal => "nonmetal",
+        }
+    }
+}
+
+/// Canonical identity of a particle, worked out once from its flags and nucleus - see Proton::element_kind
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ElementKind {
+    Lepton,
+    FreeHydrogen, // H+, H- and neutral H that has not settled into stable H1
+    Deuterium,
+    Hydrogen1,
+    Tritium,
+    Helium3,
+    Helium4,
+    Carbon12,
+    Nitrogen14,
+    Oxygen16,
+    Neon20,
+    Sodium23,
+    Magnesium24,
+    Silicon28,
+    Phosphorus31,
+    Sulfur32,
+    Potassium39,
+    Calcium40,
+    Water,
+    HydrogenSulfide,
+    MagnesiumHydride,
+    Methane,
+    Silane,
+    SulfurDioxide,
+    Other,
+}
+
+impl ElementKind {
+    /// Survives the dead-particle cleanup and a normal clear, and is left out of the proton count
+    /// Not the same as an infinite lifetime - He3 never decays either, but a clear still removes it
+    pub fn is_immortal(self) -> bool {
+        matches!(
+            self,
+            ElementKind::Hydrogen1
+                | ElementKind::Helium4
+                | ElementKind::Carbon12
+                | ElementKind::Oxygen16
+                | ElementKind::Neon20
+                | ElementKind::Magnesium24
+                | ElementKind::Silicon28
+                | ElementKind::Sulfur32
+                | ElementKind::Water
+                | ElementKind::HydrogenSulfide
+                | ElementKind::MagnesiumHydride
+                | ElementKind::Methane
+                | ElementKind::Silane
+                | ElementKind::SulfurDioxide
+        )
+    }
+
+    /// Name the element is counted under in the Elements menu and profile
+    pub fn counted_name(self) -> Option<&'static str> {
+        match self {
+            ElementKind::Hydrogen1 => Some("H1"),
+            ElementKind::Tritium => Some("T"),
+            ElementKind::Helium3 => Some("He3"),
+            ElementKind::Helium4 => Some("He4"),
+            ElementKind::Carbon12 => Some("C12"),
+            ElementKind::Oxygen16 => Some("O16"),
+            ElementKind::Neon20 => Some("Ne20"),
+            ElementKind::Magnesium24 => Some("Mg24"),
+            ElementKind::Silicon28 => Some("Si28"),
+            ElementKind::Sulfur32 => Some("S32"),
+            ElementKind::Water => Some("H2O"),
+            ElementKind::HydrogenSulfide => Some("H2S"),
+            ElementKind::MagnesiumHydride => Some("MgH2"),
+            ElementKind::Methane => Some("CH4"),
+            ElementKind::Silane => Some("SiH4"),
+            ElementKind::SulfurDioxide => Some("SO2"),
+            _ => None,
+        }
+    }
+}
+
+/// Velocity history behind Proton::is_oscillating - a read-only diagnostic, never fed back into physics
+#[derive(Clone, Default)]
+struct OscillationWatch {
+    last_velocity: Vec2,
+    window_time: f32,
+    reversals: u32,
+    speed_sum: f32,
+    samples: u32,
+    previous_mean_speed: f32, // 0 until a first window has closed
+    oscillating: bool,
+}
+
+/// Element-class shape drawn by Proton::render_shape
+#[derive(Clone, Copy)]
+enum Glyph {
+    Circle,  // Noble gases, hydrogen isotopes, leptons
+    Hexagon, // Metals
+    Diamond, // Non-metals
+    Molecule { ligands: u8, ligand_color: Color },
+}
 
 #[derive(Clone)]
 pub struct Proton {
@@ -26,6 +178,8 @@ pub struct Proton {
     charge: i32,
     neutron_count: i32,
     is_stable_hydrogen: bool,
+    is_lepton: bool, // Short-lived e+ / e- emitted by reactions and decays (never fuses)
+    decay_pending: bool, // Unstable isotope whose lifetime ran out - ProtonManager resolves the decay
     wave_field_timer: f32,
 
     // Sleeping system for optimization
@@ -37,13 +191,21 @@ pub struct Proton {
     vibration_phase: f32, // For vibration animation
     red_wave_hits: u8, // Count of dark red wave hits (for melting)
     freeze_cooldown: f32, // Time before can crystallize again after melting
+    serial: u64, // Unique per constructed particle - tells a reused slot apart from its previous occupant
+    id: u64, // Lineage handle - starts as the serial, inherited by the product when this particle fuses in place
+    temperature: f32, // Collision/fusion heat (0 = cold) - separate from the manager's spatial temperature field
     last_red_wave_hit_time: f32, // Tracks time of last hit to prevent double-counting
+    crystallized_at: Option<f32>, // Manager elapsed time when this particle last froze into a crystal (None while unfrozen)
+    spawned_at: Option<f32>, // Manager elapsed time when this particle was loaded from a save (None if born in the sim)
+    oscillation: OscillationWatch,
     h_crystal_group: Option<usize>, // Group ID for connected H crystals (for rigid body movement)
 
     // Oxygen-16 bonding system (C12 + He4 molecular bond)
     is_oxygen16_bonded: bool,
     oxygen_bond_partner: Option<usize>, // Index of bonded partner particle
     oxygen_bond_rest_length: f32, // Rest length of O16 bond
+    oxygen_bond_age: f32, // Time the pair has stayed bonded (drives consolidation)
+    is_oxygen16: bool, // Consolidated single-particle O16 (charge 8, neutron_count 8)
 
     // Water molecule flag and hydrogen bonding system
     is_h2o: bool,
@@ -70,6 +232,7 @@ pub struct Proton {
     is_mgh2: bool,     // Magnesium Hydride (Mg24 + 2H)
     is_ch4: bool,      // Methane (C12 + 4H)
     is_sih4: bool,     // Silane (Si28 + 4H)
+    is_so2: bool,      // Sulfur Dioxide (S32 + 2 O16)
 
     // Universal phase transition system for all elements
     // He3 (charge=1, neutron_count=2) phase transitions
@@ -162,12 +325,34 @@ pub struct Proton {
     ca40_freeze_cooldown: f32,
 }
 
+/// The per-element crystal bond lists of a proton, borrowed with `&` or `&mut` - the one place that names them all
+macro_rules! crystal_bond_lists {
+    ($proton:ident, $($borrow:tt)+) => {
+        [
+            $($borrow)+ $proton.crystal_bonds,
+            $($borrow)+ $proton.he3_crystal_bonds,
+            $($borrow)+ $proton.he4_crystal_bonds,
+            $($borrow)+ $proton.c12_crystal_bonds,
+            $($borrow)+ $proton.ne20_crystal_bonds,
+            $($borrow)+ $proton.mg24_crystal_bonds,
+            $($borrow)+ $proton.si28_crystal_bonds,
+            $($borrow)+ $proton.s32_crystal_bonds,
+            $($borrow)+ $proton.n14_crystal_bonds,
+            $($borrow)+ $proton.p31_crystal_bonds,
+            $($borrow)+ $proton.na23_crystal_bonds,
+            $($borrow)+ $proton.k39_crystal_bonds,
+            $($borrow)+ $proton.ca40_crystal_bonds,
+        ]
+    };
+}
+
 impl Proton {
     pub fn new(position: Vec2, velocity: Vec2, color: Color, energy: f32, charge: i32) -> Self {
         let radius = Self::calculate_radius(energy);
         let mass = Self::calculate_mass(energy);
         let max_lifetime = pc::DEFAULT_LIFETIME;
         let fade_start_time = max_lifetime * pc::FADE_START_RATIO;
+        let serial = NEXT_SERIAL.fetch_add(1, Ordering::Relaxed);
 
         Self {
             position,
@@ -185,6 +370,8 @@ impl Proton {
             charge,
             neutron_count: 0,
             is_stable_hydrogen: false,
+            is_lepton: false,
+            decay_pending: false,
             wave_field_timer: 0.0,
             is_sleeping: false,
             is_crystallized: false,
@@ -192,11 +379,19 @@ impl Proton {
             vibration_phase: 0.0,
             red_wave_hits: 0,
             freeze_cooldown: 0.0,
+            serial,
+            id: serial,
+            temperature: 0.0,
             last_red_wave_hit_time: -999.0,
+            crystallized_at: None,
+            spawned_at: None,
+            oscillation: OscillationWatch::default(),
             h_crystal_group: None,
             is_oxygen16_bonded: false,
             oxygen_bond_partner: None,
             oxygen_bond_rest_length: 0.0,
+            oxygen_bond_age: 0.0,
+            is_oxygen16: false,
             is_h2o: false,
             water_polar_angle: 0.0,
             water_h_bonds: Vec::new(),
@@ -211,6 +406,7 @@ impl Proton {
             is_mgh2: false,
             is_ch4: false,
             is_sih4: false,
+            is_so2: false,
             // Phase transition initializations
             is_he3_crystallized: false,
             he3_crystal_bonds: Vec::new(),
@@ -270,11 +466,17 @@ impl Proton {
         }
     }
 
-    pub fn update(&mut self, delta_time: f32, window_size: (f32, f32)) {
+    /// Advance one step inside the `arena` walls (the whole window unless the player resized them)
+    pub fn update(&mut self, delta_time: f32, arena: Rect, boundary_mode: BoundaryMode) {
         if !self.is_alive {
             return;
         }
 
+        // Never carry a non-finite velocity into the force passes
+        if !self.velocity.is_finite() {
+            self.velocity = Vec2::ZERO;
+        }
+
         // Always update visual pulse
         self.pulse_timer += delta_time;
 
@@ -283,6 +485,9 @@ impl Proton {
             self.vibration_phase += delta_time * 5.0; // 5 rad/s
         }
 
+        // Collision heat cools off exponentially
+        self.temperature *= (-pc::HEAT_DECAY_RATE * delta_time).exp();
+
         // Update freeze cooldown for H
         if self.freeze_cooldown > 0.0 {
             self.freeze_cooldown -= delta_time;
@@ -336,9 +541,13 @@ impl Proton {
         // Update lifetime
         self.lifetime += delta_time;
 
-        // Check death from age
+        // Check death from age - unstable isotopes decay instead of vanishing
         if self.max_lifetime >= 0.0 && self.lifetime >= self.max_lifetime {
-            self.is_alive = false;
+            if self.is_tritium() {
+                self.decay_pending = true;
+            } else {
+                self.is_alive = false;
+            }
             return;
         }
 
@@ -353,43 +562,52 @@ impl Proton {
             self.velocity *= 1.00;  // Per-frame damping for water molecules
         }
 
-        // Straight-line movement
+        // Straight-line movement - a step that overflows leaves the particle where it was, at rest
+        let previous_position = self.position;
         self.position += self.velocity * delta_time;
+        if !self.position.is_finite() {
+            self.position = previous_position;
+            self.velocity = Vec2::ZERO;
+        }
 
-        // Boundary collisions
-        self.handle_boundary_collision(window_size);
+        // Boundary handling
+        match boundary_mode {
+            BoundaryMode::Bounce => self.handle_boundary_collision(arena),
+            BoundaryMode::Wrap => self.handle_boundary_wrap(arena),
+            BoundaryMode::Open => {}
+        }
 
         // Off-screen culling
         const CULL_MARGIN: f32 = 200.0;
-        if self.position.x < -CULL_MARGIN || self.position.x > window_size.0 + CULL_MARGIN ||
-           self.position.y < -CULL_MARGIN || self.position.y > window_size.1 + CULL_MARGIN {
+        if self.position.x < arena.left() - CULL_MARGIN || self.position.x > arena.right() + CULL_MARGIN ||
+           self.position.y < arena.top() - CULL_MARGIN || self.position.y > arena.bottom() + CULL_MARGIN {
             if !self.is_stable_hydrogen && !self.is_stable_helium4() && !self.is_stable_carbon12() {
                 self.is_alive = false;
             }
         }
     }
 
-    fn handle_boundary_collision(&mut self, window_size: (f32, f32)) {
+    fn handle_boundary_collision(&mut self, arena: Rect) {
         let mut collided = false;
 
         // Left/right
-        if self.position.x - self.radius < 0.0 {
-            self.position.x = self.radius;
+        if self.position.x - self.radius < arena.left() {
+            self.position.x = arena.left() + self.radius;
             self.velocity.x = -self.velocity.x * pc::BOUNCE_DAMPENING;
             collided = true;
-        } else if self.position.x + self.radius > window_size.0 {
-            self.position.x = window_size.0 - self.radius;
+        } else if self.position.x + self.radius > arena.right() {
+            self.position.x = arena.right() - self.radius;
             self.velocity.x = -self.velocity.x * pc::BOUNCE_DAMPENING;
             collided = true;
         }
 
         // Top/bottom
-        if self.position.y - self.radius < 0.0 {
-            self.position.y = self.radius;
+        if self.position.y - self.radius < arena.top() {
+            self.position.y = arena.top() + self.radius;
             self.velocity.y = -self.velocity.y * pc::BOUNCE_DAMPENING;
             collided = true;
-        } else if self.position.y + self.radius > window_size.1 {
-            self.position.y = window_size.1 - self.radius;
+        } else if self.position.y + self.radius > arena.bottom() {
+            self.position.y = arena.bottom() - self.radius;
             self.velocity.y = -self.velocity.y * pc::BOUNCE_DAMPENING;
             collided = true;
         }
@@ -399,24 +617,52 @@ impl Proton {
         }
     }
 
-    pub fn try_neutron_formation(&mut self, delta_time: f32, near_atom: bool) {
-        if self.charge != 1 {
-            return;
+    /// Wrap position to the opposite edge (velocity preserved)
+    fn handle_boundary_wrap(&mut self, arena: Rect) {
+        if self.position.x < arena.left() {
+            self.position.x += arena.w;
+        } else if self.position.x >= arena.right() {
+            self.position.x -= arena.w;
+        }
+
+        if self.position.y < arena.top() {
+            self.position.y += arena.h;
+        } else if self.position.y >= arena.bottom() {
+            self.position.y -= arena.h;
+        }
+    }
+
+    /// Returns true if the proton turned into deuterium this step
+    /// After NEUTRON_FORMATION_TIME near an atom, each step converts with chance `probability`
+    pub fn try_neutron_formation(&mut self, delta_time: f32, near_atom: bool, probability: f32) -> bool {
+        if self.charge != 1 || self.is_lepton {
+            return false;
         }
 
         if !near_atom {
             self.wave_field_timer = 0.0;
-            return;
+            return false;
         }
 
         self.wave_field_timer += delta_time;
 
-        if self.wave_field_timer >= pc::NEUTRON_FORMATION_TIME {
-            self.neutron_count = 1;
-            self.charge = 0;
-            self.radius *= pc::NEUTRON_RADIUS_MULTIPLIER;
-            self.wave_field_timer = 0.0;
+        // A certain conversion draws no random number, so the default setting stays deterministic
+        if self.wave_field_timer >= pc::NEUTRON_FORMATION_TIME
+            && (probability >= 1.0 || macroquad::rand::gen_range(0.0, 1.0) < probability)
+        {
+            self.become_deuterium();
+            return true;
         }
+
+        false
+    }
+
+    /// Turn a bare proton into deuterium (charge 0, one neutron)
+    pub fn become_deuterium(&mut self) {
+        self.neutron_count = 1;
+        self.charge = 0;
+        self.radius *= pc::NEUTRON_RADIUS_MULTIPLIER;
+        self.wave_field_timer = 0.0;
     }
 
     pub fn try_capture_electron(&mut self, electron_pos: Vec2) -> bool {
@@ -438,7 +684,9 @@ impl Proton {
     pub fn get_element_label(&self) -> String {
         // Check molecular flags first (take precedence)
         // Hydrogen compounds first
-        if self.is_sih4 {
+        if self.is_so2 {
+            "SO2".to_string()
+        } else if self.is_sih4 {
             "SiH4".to_string()
         } else if self.is_ch4 {
             "CH4".to_string()
@@ -458,7 +706,7 @@ impl Proton {
             "Mg24".to_string()
         } else if self.is_neon20 {
             "Ne20".to_string()
-        } else if self.is_oxygen16_bonded {
+        } else if self.is_oxygen16 || self.is_oxygen16_bonded {
             "O16".to_string()
         }
         // Biological elements
@@ -480,6 +728,10 @@ impl Proton {
             "He4".to_string()
         } else if self.charge == 1 && self.neutron_count == 2 {
             "He3".to_string()
+        } else if self.is_lepton {
+            if self.charge > 0 { "e+".to_string() } else { "e-".to_string() }
+        } else if self.is_tritium() {
+            "T".to_string()
         } else if self.charge == -1 {
             "H-".to_string()
         } else if self.charge == 0 && self.neutron_count == 1 {
@@ -494,10 +746,244 @@ impl Proton {
     }
 
     pub fn render(&self, segments: i32) {
+        self.render_with_color(segments, None);
+    }
+
+    /// Render with an optional color that replaces the element color (debug views)
+    pub fn render_with_color(&self, segments: i32, color_override: Option<Color>) {
+        if !self.is_alive {
+            return;
+        }
+
+        let (render_color, render_radius) = self.appearance(color_override);
+        self.draw_glowing_poly(segments as u8, render_radius, 0.0, render_color);
+    }
+
+    /// Like render_with_color, but shaped by element class so species stay apart at small sizes:
+    /// metals draw as hexagons, non-metals as diamonds, molecules as a nucleus ringed by its ligands,
+    /// noble gases and everything else as circles
+    pub fn render_shape(&self, segments: i32, color_override: Option<Color>) {
         if !self.is_alive {
             return;
         }
 
+        let (color, radius) = self.appearance(color_override);
+        match self.glyph() {
+            Glyph::Circle => self.draw_glowing_poly(segments as u8, radius, 0.0, color),
+            Glyph::Hexagon => self.draw_glowing_poly(6, radius, 30.0, color),
+            Glyph::Diamond => self.draw_glowing_poly(4, radius * pc::DIAMOND_GLYPH_SCALE, 0.0, color),
+            Glyph::Molecule { ligands, ligand_color } => {
+                let mut glow = color;
+                glow.a *= pc::GLOW_LAYER1_ALPHA;
+                draw_poly(self.position.x, self.position.y, segments as u8, radius * pc::GLOW_LAYER1_RADIUS, 0.0, glow);
+
+                // Two ligands sit bent like water, four spread evenly - each angle measured from straight down
+                let angles: &[f32] = match ligands {
+                    2 => &[-52.0, 52.0],
+                    _ => &[45.0, 135.0, 225.0, 315.0],
+                };
+                let mut ligand = ligand_color;
+                ligand.a = color.a;
+                for angle in angles {
+                    let offset = Vec2::from_angle(angle.to_radians()).rotate(Vec2::Y) * radius * pc::MOLECULE_GLYPH_LIGAND_DISTANCE;
+                    let pos = self.position + offset;
+                    draw_poly(pos.x, pos.y, segments as u8, radius * pc::MOLECULE_GLYPH_LIGAND_RADIUS, 0.0, ligand);
+                }
+                draw_poly(self.position.x, self.position.y, segments as u8, radius * pc::MOLECULE_GLYPH_CORE_RADIUS, 0.0, color);
+            }
+        }
+    }
+
+    /// Core plus two glow layers, all the same polygon
+    fn draw_glowing_poly(&self, sides: u8, radius: f32, rotation: f32, color: Color) {
+        let (x, y) = (self.position.x, self.position.y);
+        for (layer_radius, layer_color) in Self::glow_layers(radius, color) {
+            draw_poly(x, y, sides, layer_radius, rotation, layer_color);
+        }
+    }
+
+    /// Core then the two glow layers, as (radius, color) in drawing order
+    fn glow_layers(radius: f32, color: Color) -> [(f32, Color); 3] {
+        let mut glow1 = color;
+        glow1.a *= pc::GLOW_LAYER1_ALPHA;
+        let mut glow2 = color;
+        glow2.a *= pc::GLOW_LAYER2_ALPHA;
+        [
+            (radius, color),
+            (radius * pc::GLOW_LAYER1_RADIUS, glow1),
+            (radius * pc::GLOW_LAYER2_RADIUS, glow2),
+        ]
+    }
+
+    /// The layers render_with_color draws without an override (for exporters that redraw the scene)
+    pub fn circle_layers(&self) -> [(f32, Color); 3] {
+        let (color, radius) = self.appearance(None);
+        Self::glow_layers(radius, color)
+    }
+
+    /// Check that the element flags agree with charge and neutron_count
+    /// Reactions set all three by hand, so a typo at one site would silently break predicates like is_neon20
+    pub fn validate(&self) -> Result<(), String> {
+        let nucleus = (self.charge, self.neutron_count);
+
+        // Identity flags - at most one is set, and it fixes the nucleus
+        let identities = [
+            ("stable hydrogen", self.is_stable_hydrogen, (0, 1)),
+            ("O16", self.is_oxygen16, (8, 8)),
+            ("H2O", self.is_h2o, (8, 10)),
+            ("Ne20", self.is_neon20, (10, 10)),
+            ("Mg24", self.is_magnesium24, (12, 12)),
+            ("Si28", self.is_silicon28, (14, 14)),
+            ("S32", self.is_sulfur32, (16, 16)),
+            ("H2S", self.is_h2s, (16, 18)),
+            ("MgH2", self.is_mgh2, (12, 14)),
+            ("CH4", self.is_ch4, (6, 10)),
+            ("SiH4", self.is_sih4, (14, 18)),
+            ("SO2", self.is_so2, (32, 32)),
+            ("N14", self.is_nitrogen14, (7, 7)),
+            ("P31", self.is_phosphorus31, (15, 16)),
+            ("Na23", self.is_sodium23, (11, 12)),
+            ("K39", self.is_potassium39, (19, 20)),
+            ("Ca40", self.is_calcium40, (20, 20)),
+        ];
+        let mut set = identities.iter().filter(|(_, flag, _)| *flag);
+        if let Some(&(name, _, expected)) = set.next() {
+            if let Some((other, _, _)) = set.next() {
+                return Err(format!("flagged as both {} and {}", name, other));
+            }
+            if nucleus != expected {
+                return Err(format!("flagged {} but charge/neutrons are {:?}, not {:?}", name, nucleus, expected));
+            }
+        }
+
+        // Phase flags only make sense on their own element
+        let phases = [
+            ("frozen water", self.is_water_frozen, self.is_h2o),
+            ("O16 bond", self.is_oxygen16_bonded, nucleus == (6, 6) || nucleus == (2, 2)),
+            ("lepton", self.is_lepton, self.charge.abs() == 1 && self.neutron_count == 0),
+            ("He3 crystal", self.is_he3_crystallized, nucleus == (1, 2)),
+            ("He4 crystal", self.is_he4_crystallized, nucleus == (2, 2)),
+            ("C12 crystal", self.is_c12_crystallized, nucleus == (6, 6)),
+            ("Ne20 crystal", self.is_ne20_crystallized, self.is_neon20),
+            ("Mg24 crystal", self.is_mg24_crystallized, self.is_magnesium24),
+            ("Si28 crystal", self.is_si28_crystallized, self.is_silicon28),
+            ("S32 crystal", self.is_s32_crystallized, self.is_sulfur32),
+        ];
+        match phases.iter().find(|(_, flag, valid)| *flag && !*valid) {
+            Some((name, _, _)) => Err(format!("{} flag set on charge/neutrons {:?}", name, nucleus)),
+            None => Ok(()),
+        }
+    }
+
+    /// Setter guard - debug builds stop at the setter that leaves the flags disagreeing with the nucleus
+    fn debug_validate(&self) {
+        debug_assert!(self.validate().is_ok(), "inconsistent particle: {}", self.validate().unwrap_err());
+    }
+
+    /// Kept by the dead-particle cleanup and a normal clear - only `clear_all` removes it (see ElementKind::is_immortal)
+    pub fn is_immortal(&self) -> bool {
+        self.element_kind().is_immortal()
+    }
+
+    /// What this particle is - molecule and element flags win over the bare nucleus they share
+    /// (CH4 is charge 6 like C12, with the four H atoms' neutrons on top)
+    pub fn element_kind(&self) -> ElementKind {
+        if self.is_lepton {
+            ElementKind::Lepton
+        } else if self.is_so2 {
+            ElementKind::SulfurDioxide
+        } else if self.is_sih4 {
+            ElementKind::Silane
+        } else if self.is_ch4 {
+            ElementKind::Methane
+        } else if self.is_h2s {
+            ElementKind::HydrogenSulfide
+        } else if self.is_mgh2 {
+            ElementKind::MagnesiumHydride
+        } else if self.is_h2o {
+            ElementKind::Water
+        } else if self.is_sulfur32 {
+            ElementKind::Sulfur32
+        } else if self.is_silicon28 {
+            ElementKind::Silicon28
+        } else if self.is_magnesium24 {
+            ElementKind::Magnesium24
+        } else if self.is_neon20 {
+            ElementKind::Neon20
+        } else if self.is_oxygen16 {
+            ElementKind::Oxygen16
+        } else if self.is_nitrogen14 {
+            ElementKind::Nitrogen14
+        } else if self.is_phosphorus31 {
+            ElementKind::Phosphorus31
+        } else if self.is_sodium23 {
+            ElementKind::Sodium23
+        } else if self.is_potassium39 {
+            ElementKind::Potassium39
+        } else if self.is_calcium40 {
+            ElementKind::Calcium40
+        } else if self.is_stable_hydrogen {
+            ElementKind::Hydrogen1
+        } else {
+            match (self.charge, self.neutron_count) {
+                (6, 6) => ElementKind::Carbon12,
+                (2, 2) => ElementKind::Helium4,
+                (1, 2) => ElementKind::Helium3,
+                (0, 2) => ElementKind::Tritium,
+                (0, 1) => ElementKind::Deuterium,
+                (-1..=1, 0) => ElementKind::FreeHydrogen,
+                _ => ElementKind::Other,
+            }
+        }
+    }
+
+    /// Collision material - follows the same element classes as the glyph
+    pub fn material(&self) -> Material {
+        if self.is_h2o {
+            Material::Ice
+        } else if self.is_h2s || self.is_mgh2 || self.is_ch4 || self.is_sih4 || self.is_so2 {
+            Material::Gas
+        } else {
+            match self.glyph() {
+                Glyph::Hexagon => Material::Metal,
+                Glyph::Diamond => Material::NonMetal,
+                _ if self.is_oxygen16_bonded => Material::NonMetal,
+                _ => Material::Gas,
+            }
+        }
+    }
+
+    /// Shape class for render_shape
+    fn glyph(&self) -> Glyph {
+        let hydrogen = Color::from_rgba(255, 255, 255, 255);
+        if self.is_h2o || self.is_h2s || self.is_mgh2 {
+            Glyph::Molecule { ligands: 2, ligand_color: hydrogen }
+        } else if self.is_ch4 || self.is_sih4 {
+            Glyph::Molecule { ligands: 4, ligand_color: hydrogen }
+        } else if self.is_so2 {
+            let (r, g, b) = pc::OXYGEN16_COLOR;
+            Glyph::Molecule { ligands: 2, ligand_color: Color::from_rgba(r, g, b, 255) }
+        } else if self.is_magnesium24
+            || self.is_silicon28
+            || self.is_sodium23
+            || self.is_potassium39
+            || self.is_calcium40
+        {
+            Glyph::Hexagon
+        } else if self.is_oxygen16
+            || self.is_sulfur32
+            || self.is_nitrogen14
+            || self.is_phosphorus31
+            || (self.charge == 6 && self.neutron_count == 6)
+        {
+            Glyph::Diamond
+        } else {
+            Glyph::Circle
+        }
+    }
+
+    /// Color and radius this frame - element and charge tint, optional override, pulse and lifetime fade
+    fn appearance(&self, color_override: Option<Color>) -> (Color, f32) {
         let mut render_color = self.color;
         let mut render_radius = self.radius;
 
@@ -505,8 +991,15 @@ impl Proton {
         if self.is_stable_hydrogen {
             render_color = Color::from_rgba(255, 255, 255, 255);
             render_radius *= pc::STABLE_HYDROGEN_RADIUS_MULTIPLIER;
+        } else if self.is_tritium() {
+            let (r, g, b) = pc::TRITIUM_COLOR;
+            render_color = Color::from_rgba(r, g, b, 255);
+            render_radius *= pc::TRITIUM_RADIUS_MULTIPLIER;
         } else if self.charge == 0 {
             render_color = Color::from_rgba(200, 200, 200, 255);
+        } else if self.is_lepton {
+            let (r, g, b) = if self.charge > 0 { pc::POSITRON_COLOR } else { pc::ELECTRON_COLOR };
+            render_color = Color::from_rgba(r, g, b, 255);
         } else if self.charge == 1 {
             let r = (render_color.r * pc::BARE_PROTON_RED_TINT).min(1.0);
             render_color.r = r;
@@ -516,7 +1009,11 @@ impl Proton {
         }
 
         // Hydrogen compound molecules - check first (higher priority)
-        if self.is_sih4 {
+        if self.is_so2 {
+            render_color = Color::from_rgba(230, 200, 140, 255);
+            render_radius *= pc::SO2_RADIUS_MULTIPLIER;
+        }
+        else if self.is_sih4 {
             render_color = Color::from_rgba(220, 100, 50, 255);
             render_radius *= pc::SIH4_RADIUS_MULTIPLIER;
         }
@@ -567,6 +1064,11 @@ impl Proton {
             render_color = Color::from_rgba(255, 100, 150, 255);
             render_radius *= pc::NEON20_RADIUS_MULTIPLIER;
         }
+        // Consolidated Oxygen-16
+        else if self.is_oxygen16 {
+            render_color = Color::from_rgba(100, 180, 255, 255);
+            render_radius *= pc::OXYGEN16_RADIUS_MULTIPLIER;
+        }
         // Oxygen-16 bonded pair - check third as it overrides base element colors
         else if self.is_oxygen16_bonded {
             render_color = Color::from_rgba(100, 180, 255, 255);
@@ -609,6 +1111,10 @@ impl Proton {
             render_radius *= pc::HELIUM4_RADIUS_MULTIPLIER;
         }
 
+        if let Some(color) = color_override {
+            render_color = color;
+        }
+
         // Pulsing effect
         let pulse_frequency = pc::PULSE_FREQUENCY_BASE + (self.energy * pc::PULSE_FREQUENCY_ENERGY_FACTOR);
         let pulse = (self.pulse_timer * pulse_frequency).sin() * pc::PULSE_INTENSITY + pc::PULSE_BASE;
@@ -621,18 +1127,12 @@ impl Proton {
             render_color.a = fade_amount;
         }
 
-        // Draw core
-        draw_poly(self.position.x, self.position.y, segments as u8, render_radius, 0.0, render_color);
-
-        // Glow layer 1
-        let mut glow1 = render_color;
-        glow1.a *= pc::GLOW_LAYER1_ALPHA;
-        draw_poly(self.position.x, self.position.y, segments as u8, render_radius * pc::GLOW_LAYER1_RADIUS, 0.0, glow1);
+        (render_color, render_radius)
+    }
 
-        // Glow layer 2
-        let mut glow2 = render_color;
-        glow2.a *= pc::GLOW_LAYER2_ALPHA;
-        draw_poly(self.position.x, self.position.y, segments as u8, render_radius * pc::GLOW_LAYER2_RADIUS, 0.0, glow2);
+    /// Element color as drawn without an override, for tints that start from it
+    pub fn element_color(&self) -> Color {
+        self.appearance(None).0
     }
 
     fn calculate_radius(energy: f32) -> f32 {
@@ -644,37 +1144,94 @@ impl Proton {
         energy * pc::ENERGY_TO_MASS_FACTOR
     }
 
+    /// Draw a thin arc whose sweep shows the remaining lifetime of a non-stable proton
+    /// Tinted red (low energy) to white (high energy); stable protons draw nothing
+    pub fn render_lifetime_arc(&self) {
+        if !self.is_alive || self.max_lifetime < 0.0 {
+            return;
+        }
+
+        let remaining = (1.0 - self.lifetime / self.max_lifetime).clamp(0.0, 1.0);
+        let energy_level = (self.energy / pc::LIFETIME_ARC_ENERGY_SCALE).clamp(0.0, 1.0);
+        let color = Color::new(1.0, energy_level, energy_level, 0.8);
+
+        // Start at 12 o'clock and sweep clockwise
+        draw_arc(
+            self.position.x,
+            self.position.y,
+            24,
+            self.radius * pc::LIFETIME_ARC_RADIUS,
+            -90.0,
+            pc::LIFETIME_ARC_THICKNESS,
+            360.0 * remaining,
+            color,
+        );
+    }
+
     // Getters
     pub fn is_alive(&self) -> bool { self.is_alive && !self.marked_for_deletion }
     pub fn is_marked_for_deletion(&self) -> bool { self.marked_for_deletion }
     pub fn position(&self) -> Vec2 { self.position }
     pub fn velocity(&self) -> Vec2 { self.velocity }
     pub fn radius(&self) -> f32 { self.radius }
+    pub fn set_radius(&mut self, radius: f32) { self.radius = radius; }
     pub fn energy(&self) -> f32 { self.energy }
+    /// Change the energy - size and mass follow it, as they do at creation
+    pub fn set_energy(&mut self, energy: f32) {
+        self.energy = energy;
+        self.radius = Self::calculate_radius(energy);
+        self.mass = Self::calculate_mass(energy);
+    }
     pub fn mass(&self) -> f32 { self.mass }
     pub fn color(&self) -> Color { self.color }
     pub fn charge(&self) -> i32 { self.charge }
     pub fn neutron_count(&self) -> i32 { self.neutron_count }
     pub fn is_stable_hydrogen(&self) -> bool { self.is_stable_hydrogen }
-    pub fn set_stable_hydrogen(&mut self, stable: bool) { self.is_stable_hydrogen = stable; }
-    pub fn is_stable_helium4(&self) -> bool { self.charge == 2 && self.neutron_count == 2 }
-    pub fn is_stable_carbon12(&self) -> bool { self.charge == 6 && self.neutron_count == 6 }
+    pub fn set_stable_hydrogen(&mut self, stable: bool) { self.is_stable_hydrogen = stable; self.debug_validate(); }
+    pub fn is_lepton(&self) -> bool { self.is_lepton }
+    pub fn set_lepton(&mut self, lepton: bool) { self.is_lepton = lepton; self.debug_validate(); }
+    pub fn is_tritium(&self) -> bool { self.element_kind() == ElementKind::Tritium }
+    pub fn is_decay_pending(&self) -> bool { self.decay_pending }
+    pub fn is_stable_helium4(&self) -> bool { self.element_kind() == ElementKind::Helium4 }
+    pub fn is_stable_carbon12(&self) -> bool { self.element_kind() == ElementKind::Carbon12 }
     pub fn is_sleeping(&self) -> bool { self.is_sleeping }
     pub fn is_crystallized(&self) -> bool { self.is_crystallized }
     pub fn crystal_bonds(&self) -> &Vec<usize> { &self.crystal_bonds }
     pub fn vibration_phase(&self) -> f32 { self.vibration_phase }
+    pub fn serial(&self) -> u64 { self.serial }
+    pub fn id(&self) -> u64 { self.id }
+    pub fn inherit_id(&mut self, ancestor: &Proton) { self.id = ancestor.id; }
+    pub fn temperature(&self) -> f32 { self.temperature }
+    pub fn is_hot(&self) -> bool { self.temperature >= pc::HOT_TEMPERATURE }
+    pub fn add_heat(&mut self, heat: f32) { self.temperature = (self.temperature + heat).min(pc::MAX_TEMPERATURE); }
 
     // Setters
+    pub fn set_position(&mut self, position: Vec2) {
+        if position.is_finite() {
+            self.position = position;
+        }
+    }
     pub fn set_velocity(&mut self, velocity: Vec2) {
-        self.velocity = velocity;
+        // A NaN/inf velocity stops the particle instead of poisoning its position
+        self.velocity = if velocity.is_finite() { velocity } else { Vec2::ZERO };
         self.is_sleeping = false;
     }
     pub fn add_velocity(&mut self, delta_velocity: Vec2) {
-        self.velocity += delta_velocity;
+        if delta_velocity.is_finite() {
+            self.velocity += delta_velocity;
+        }
         self.is_sleeping = false;
     }
+    /// Accelerate by `force` over `delta_time` - one step can change the velocity by at most MAX_STEP_VELOCITY_CHANGE
+    pub fn apply_force(&mut self, force: Vec2, delta_time: f32) {
+        self.add_velocity((force / self.mass * delta_time).clamp_length_max(pc::MAX_STEP_VELOCITY_CHANGE));
+    }
     pub fn mark_for_deletion(&mut self) { self.marked_for_deletion = true; }
-    pub fn set_neutron_count(&mut self, count: i32) { self.neutron_count = count; }
+    pub fn set_neutron_count(&mut self, count: i32) {
+        debug_assert!(count >= 0, "negative neutron count {}", count);
+        self.neutron_count = count;
+        self.debug_validate();
+    }
     pub fn set_max_lifetime(&mut self, lifetime: f32) { self.max_lifetime = lifetime; }
     pub fn wake(&mut self) { self.is_sleeping = false; }
     pub fn set_crystallized(&mut self, crystallized: bool) { self.is_crystallized = crystallized; }
@@ -696,6 +1253,45 @@ impl Proton {
     pub fn set_freeze_cooldown(&mut self, cooldown: f32) { self.freeze_cooldown = cooldown; }
     pub fn last_red_wave_hit_time(&self) -> f32 { self.last_red_wave_hit_time }
     pub fn set_last_red_wave_hit_time(&mut self, time: f32) { self.last_red_wave_hit_time = time; }
+    /// Record this step's velocity for the oscillation diagnostic - `bonded` when at least one bond holds the particle
+    /// Each window that sees enough reversals at a mean speed that hasn't died down since the last one flags it
+    pub fn track_oscillation(&mut self, delta_time: f32, bonded: bool) {
+        let watch = &mut self.oscillation;
+        if !bonded {
+            *watch = OscillationWatch::default();
+            return;
+        }
+
+        if self.velocity.dot(watch.last_velocity) < 0.0 {
+            watch.reversals += 1;
+        }
+        watch.last_velocity = self.velocity;
+        watch.speed_sum += self.velocity.length();
+        watch.samples += 1;
+        watch.window_time += delta_time;
+
+        if watch.window_time >= pc::OSCILLATION_WINDOW {
+            let mean_speed = watch.speed_sum / watch.samples as f32;
+            watch.oscillating = watch.reversals >= pc::OSCILLATION_MIN_REVERSALS
+                && mean_speed >= pc::OSCILLATION_MIN_SPEED
+                && watch.previous_mean_speed > 0.0
+                && mean_speed >= watch.previous_mean_speed * pc::OSCILLATION_DECAY_RATIO;
+            *watch = OscillationWatch {
+                last_velocity: watch.last_velocity,
+                previous_mean_speed: mean_speed,
+                oscillating: watch.oscillating,
+                ..OscillationWatch::default()
+            };
+        }
+    }
+
+    /// Bonded and vibrating without settling, as of the last closed window (see track_oscillation)
+    pub fn is_oscillating(&self) -> bool { self.oscillation.oscillating }
+
+    pub fn crystallized_at(&self) -> Option<f32> { self.crystallized_at }
+    pub fn set_crystallized_at(&mut self, time: Option<f32>) { self.crystallized_at = time; }
+    pub fn spawned_at(&self) -> Option<f32> { self.spawned_at }
+    pub fn set_spawned_at(&mut self, time: Option<f32>) { self.spawned_at = time; }
     pub fn h_crystal_group(&self) -> Option<usize> { self.h_crystal_group }
     pub fn set_h_crystal_group(&mut self, group: Option<usize>) { self.h_crystal_group = group; }
 
@@ -835,28 +1431,33 @@ impl Proton {
 
     // Oxygen-16 bonding getters/setters
     pub fn is_oxygen16_bonded(&self) -> bool { self.is_oxygen16_bonded }
-    pub fn set_oxygen16_bonded(&mut self, bonded: bool) { self.is_oxygen16_bonded = bonded; }
+    pub fn set_oxygen16_bonded(&mut self, bonded: bool) { self.is_oxygen16_bonded = bonded; self.debug_validate(); }
     pub fn oxygen_bond_partner(&self) -> Option<usize> { self.oxygen_bond_partner }
     pub fn set_oxygen_bond_partner(&mut self, partner: Option<usize>) { self.oxygen_bond_partner = partner; }
     pub fn oxygen_bond_rest_length(&self) -> f32 { self.oxygen_bond_rest_length }
     pub fn set_oxygen_bond_rest_length(&mut self, length: f32) { self.oxygen_bond_rest_length = length; }
+    pub fn oxygen_bond_age(&self) -> f32 { self.oxygen_bond_age }
+    pub fn age_oxygen_bond(&mut self, delta_time: f32) { self.oxygen_bond_age += delta_time; }
+    pub fn is_oxygen16(&self) -> bool { self.is_oxygen16 }
+    pub fn set_oxygen16(&mut self, is_oxygen: bool) { self.is_oxygen16 = is_oxygen; self.debug_validate(); }
     pub fn clear_oxygen_bond(&mut self) {
         self.is_oxygen16_bonded = false;
         self.oxygen_bond_partner = None;
         self.oxygen_bond_rest_length = 0.0;
+        self.oxygen_bond_age = 0.0;
     }
 
     // Water molecule getters/setters
     pub fn is_h2o(&self) -> bool { self.is_h2o }
-    pub fn set_h2o(&mut self, is_water: bool) { self.is_h2o = is_water; }
+    pub fn set_h2o(&mut self, is_water: bool) { self.is_h2o = is_water; self.debug_validate(); }
     pub fn water_polar_angle(&self) -> f32 { self.water_polar_angle }
     pub fn set_water_polar_angle(&mut self, angle: f32) { self.water_polar_angle = angle; }
     pub fn water_h_bonds(&self) -> &Vec<usize> { &self.water_h_bonds }
     pub fn water_h_bonds_mut(&mut self) -> &mut Vec<usize> { &mut self.water_h_bonds }
     pub fn water_bond_rest_lengths(&self) -> &Vec<f32> { &self.water_bond_rest_lengths }
     pub fn add_water_h_bond(&mut self, index: usize, rest_length: f32) {
-        // All H2O can form up to 5 bonds (regardless of liquid or frozen state)
-        // 0-3 bonds = liquid, 4-5 bonds = frozen
+        // All H2O can form up to 6 bonds (regardless of liquid or frozen state)
+        // 0-3 bonds = liquid, 4-6 bonds = frozen - the manager may cap it lower
         if !self.water_h_bonds.contains(&index) && self.water_h_bonds.len() < pc::WATER_ICE_MAX_BONDS {
             self.water_h_bonds.push(index);
             self.water_bond_rest_lengths.push(rest_length);
@@ -867,36 +1468,99 @@ impl Proton {
         self.water_bond_rest_lengths.clear();
     }
     pub fn is_water_frozen(&self) -> bool { self.is_water_frozen }
-    pub fn set_water_frozen(&mut self, frozen: bool) { self.is_water_frozen = frozen; }
+    pub fn set_water_frozen(&mut self, frozen: bool) { self.is_water_frozen = frozen; self.debug_validate(); }
     pub fn ice_crystal_group(&self) -> Option<usize> { self.ice_crystal_group }
     pub fn set_ice_crystal_group(&mut self, group: Option<usize>) { self.ice_crystal_group = group; }
 
     // Neon-20 getters/setters
     pub fn is_neon20(&self) -> bool { self.is_neon20 }
-    pub fn set_neon20(&mut self, is_neon: bool) { self.is_neon20 = is_neon; }
+    pub fn set_neon20(&mut self, is_neon: bool) { self.is_neon20 = is_neon; self.debug_validate(); }
 
     // Magnesium-24 getters/setters
     pub fn is_magnesium24(&self) -> bool { self.is_magnesium24 }
-    pub fn set_magnesium24(&mut self, is_mg: bool) { self.is_magnesium24 = is_mg; }
+    pub fn set_magnesium24(&mut self, is_mg: bool) { self.is_magnesium24 = is_mg; self.debug_validate(); }
 
     // Silicon-28 getters/setters
     pub fn is_silicon28(&self) -> bool { self.is_silicon28 }
-    pub fn set_silicon28(&mut self, is_si: bool) { self.is_silicon28 = is_si; }
+    pub fn set_silicon28(&mut self, is_si: bool) { self.is_silicon28 = is_si; self.debug_validate(); }
 
     // Sulfur-32 getters/setters
     pub fn is_sulfur32(&self) -> bool { self.is_sulfur32 }
-    pub fn set_sulfur32(&mut self, is_s: bool) { self.is_sulfur32 = is_s; }
+    pub fn set_sulfur32(&mut self, is_s: bool) { self.is_sulfur32 = is_s; self.debug_validate(); }
 
     // Hydrogen compound molecule getters/setters
     pub fn is_h2s(&self) -> bool { self.is_h2s }
-    pub fn set_h2s(&mut self, is_h2s: bool) { self.is_h2s = is_h2s; }
+    pub fn set_h2s(&mut self, is_h2s: bool) { self.is_h2s = is_h2s; self.debug_validate(); }
 
     pub fn is_mgh2(&self) -> bool { self.is_mgh2 }
-    pub fn set_mgh2(&mut self, is_mgh2: bool) { self.is_mgh2 = is_mgh2; }
+    pub fn set_mgh2(&mut self, is_mgh2: bool) { self.is_mgh2 = is_mgh2; self.debug_validate(); }
 
     pub fn is_ch4(&self) -> bool { self.is_ch4 }
-    pub fn set_ch4(&mut self, is_ch4: bool) { self.is_ch4 = is_ch4; }
+    pub fn set_ch4(&mut self, is_ch4: bool) { self.is_ch4 = is_ch4; self.debug_validate(); }
 
     pub fn is_sih4(&self) -> bool { self.is_sih4 }
-    pub fn set_sih4(&mut self, is_sih4: bool) { self.is_sih4 = is_sih4; }
+    pub fn set_sih4(&mut self, is_sih4: bool) { self.is_sih4 = is_sih4; self.debug_validate(); }
+
+    pub fn is_so2(&self) -> bool { self.is_so2 }
+    pub fn set_so2(&mut self, is_so2: bool) { self.is_so2 = is_so2; self.debug_validate(); }
+
+    /// Locked into any crystal lattice (including frozen water)
+    pub fn is_frozen(&self) -> bool {
+        self.is_crystallized
+            || self.is_he3_crystallized
+            || self.is_he4_crystallized
+            || self.is_c12_crystallized
+            || self.is_ne20_crystallized
+            || self.is_mg24_crystallized
+            || self.is_si28_crystallized
+            || self.is_s32_crystallized
+            || self.is_n14_crystallized
+            || self.is_p31_crystallized
+            || self.is_na23_crystallized
+            || self.is_k39_crystallized
+            || self.is_ca40_crystallized
+            || self.is_water_frozen
+    }
+
+    /// Total number of bonds across every bond type (crystal, water, O16 partner)
+    pub fn total_bond_count(&self) -> usize {
+        crystal_bond_lists!(self, &).iter().map(|bonds| bonds.len()).sum::<usize>()
+            + self.water_h_bonds.len()
+            + if self.oxygen_bond_partner.is_some() { 1 } else { 0 }
+    }
+
+    /// Partner slots across every bond type (crystal, water, O16 partner) - one entry per bond, so repeats are possible
+    pub fn bond_slots(&self) -> Vec<usize> {
+        crystal_bond_lists!(self, &)
+        .into_iter()
+        .chain([&self.water_h_bonds])
+        .flatten()
+        .copied()
+        .chain(self.oxygen_bond_partner)
+        .collect()
+    }
+
+    /// Drop every bond (crystal, water, O16 partner) whose partner slot fails `keep`
+    pub fn retain_bonds(&mut self, keep: impl Fn(usize) -> bool) {
+        for bonds in crystal_bond_lists!(self, &mut) {
+            bonds.retain(|&idx| keep(idx));
+        }
+
+        // Water bonds carry a parallel rest-length list
+        let mut i = 0;
+        while i < self.water_h_bonds.len() {
+            if keep(self.water_h_bonds[i]) {
+                i += 1;
+            } else {
+                self.water_h_bonds.remove(i);
+                if i < self.water_bond_rest_lengths.len() {
+                    self.water_bond_rest_lengths.remove(i);
+                }
+            }
+        }
+
+        if self.oxygen_bond_partner.is_some_and(|idx| !keep(idx)) {
+            self.clear_oxygen_bond();
+        }
+    }
 }
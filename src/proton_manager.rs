@@ -4,32 +4,525 @@
 use macroquad::prelude::*;
 use crate::constants::*;
 use crate::constants::proton_manager as pm;
-use crate::proton::Proton;
+use crate::proton::{Proton, BoundaryMode, ElementKind, Material};
 use crate::atom::AtomManager;
-use crate::ring::RingManager;
+use crate::parallel;
+use crate::ring::{RingManager, RingOrigin};
+use crate::sim_config::SimConfig;
+use crate::scenario::{self, Scenario};
+use crate::snapshot::{self, ParticleRecord};
+
+/// How protons are colored when drawn
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ColorMode {
+    Element,     // Normal per-element colors
+    BondCount,   // Debug: grey (0 bonds) -> bright (many bonds)
+    Oscillation, // Debug: bonded particles that keep vibrating without settling, everything else dimmed
+}
+
+impl ColorMode {
+    /// Next mode in the B key cycle
+    pub fn next(self) -> Self {
+        match self {
+            ColorMode::Element => ColorMode::BondCount,
+            ColorMode::BondCount => ColorMode::Oscillation,
+            ColorMode::Oscillation => ColorMode::Element,
+        }
+    }
+}
+
+/// How protons are shaped when drawn
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum RenderStyle {
+    Circles, // Every species as a glowing circle
+    Shapes,  // Per-class glyphs (see Proton::render_shape)
+}
+
+/// Ambient medium the particles move through - how much linear drag slows them down
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Medium {
+    Vacuum, // No drag (default)
+    Thin,   // Light drag - gases settle over time
+    Thick,  // Heavy drag - a liquid-like feel
+}
+
+impl Medium {
+    pub fn name(&self) -> &str {
+        match self {
+            Medium::Vacuum => "vacuum",
+            Medium::Thin => "thin",
+            Medium::Thick => "thick",
+        }
+    }
+
+    /// Next medium in the Vacuum -> Thin -> Thick cycle
+    pub fn next(&self) -> Medium {
+        match self {
+            Medium::Vacuum => Medium::Thin,
+            Medium::Thin => Medium::Thick,
+            Medium::Thick => Medium::Vacuum,
+        }
+    }
+
+    /// Fraction of velocity shed per second
+    pub fn drag(&self) -> f32 {
+        match self {
+            Medium::Vacuum => 0.0,
+            Medium::Thin => pm::MEDIUM_THIN_DRAG,
+            Medium::Thick => pm::MEDIUM_THICK_DRAG,
+        }
+    }
+}
+
+/// Set of reactions that handle_nuclear_fusion is allowed to run (all enabled by default)
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ReactionFlags(u32);
+
+impl ReactionFlags {
+    pub const PP_CHAIN: ReactionFlags = ReactionFlags(1 << 0); // H+ + H+ → D + e+
+    pub const DEUTERIUM_FUSION: ReactionFlags = ReactionFlags(1 << 1); // D + H+ → He3
+    pub const DD_FUSION: ReactionFlags = ReactionFlags(1 << 2); // D + D → T + H+
+    pub const HE3_HE3: ReactionFlags = ReactionFlags(1 << 3); // He3 + He3 → He4 + 2 H+
+    pub const NEGATIVE_PROTON: ReactionFlags = ReactionFlags(1 << 4); // H- + H+ → D + e-
+    pub const TRIPLE_ALPHA: ReactionFlags = ReactionFlags(1 << 5); // 3 He4 → C12
+    pub const ALPHA_CAPTURE: ReactionFlags = ReactionFlags(1 << 6); // C12 → O16 → Ne20 → Mg24 → Si28 → S32
+    pub const WATER_FORMATION: ReactionFlags = ReactionFlags(1 << 7); // O16 + 2 H → H2O
+    pub const HYDRIDE_FORMATION: ReactionFlags = ReactionFlags(1 << 8); // H2S, MgH2, CH4, SiH4
+    pub const OXIDE_FORMATION: ReactionFlags = ReactionFlags(1 << 9); // S32 + 2 O16 → SO2
+
+    /// Every flag with its menu label, in cascade order
+    pub const ALL: [(ReactionFlags, &'static str); 10] = [
+        (Self::PP_CHAIN, "H+ + H+ -> D + e+"),
+        (Self::DEUTERIUM_FUSION, "D + H+ -> He3"),
+        (Self::DD_FUSION, "D + D -> T + H+"),
+        (Self::HE3_HE3, "He3 + He3 -> He4"),
+        (Self::NEGATIVE_PROTON, "H- + H+ -> D + e-"),
+        (Self::TRIPLE_ALPHA, "Triple-alpha (3 He4 -> C12)"),
+        (Self::ALPHA_CAPTURE, "Alpha capture (C12 -> ... -> S32)"),
+        (Self::WATER_FORMATION, "Water (O16 + 2H -> H2O)"),
+        (Self::HYDRIDE_FORMATION, "Hydrides (H2S, MgH2, CH4, SiH4)"),
+        (Self::OXIDE_FORMATION, "SO2 (S32 + 2 O16)"),
+    ];
+
+    pub fn all() -> Self {
+        Self::ALL.iter().fold(ReactionFlags(0), |acc, (flag, _)| ReactionFlags(acc.0 | flag.0))
+    }
+
+    pub fn contains(&self, flag: ReactionFlags) -> bool {
+        self.0 & flag.0 == flag.0
+    }
+
+    pub fn toggle(&mut self, flag: ReactionFlags) {
+        self.0 ^= flag.0;
+    }
+}
+
+/// One tally for the reaction rate statistics (see ProtonManager::reaction_rates)
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReactionKind {
+    ProtonProton,
+    DeuteriumProton,
+    DeuteriumDeuterium,
+    Helium3Helium3,
+    NegativeProton,
+    TripleAlpha,
+    CarbonAlpha,
+    OxygenAlpha,
+    NeonAlpha,
+    MagnesiumAlpha,
+    SiliconAlpha,
+    Water,
+    HydrogenSulfide,
+    MagnesiumHydride,
+    Methane,
+    Silane,
+    SulfurDioxide,
+}
+
+impl ReactionKind {
+    /// Every kind with its stats label, in cascade order
+    pub const ALL: [(ReactionKind, &'static str); 17] = [
+        (Self::ProtonProton, "H+ + H+ -> D"),
+        (Self::DeuteriumProton, "D + H+ -> He3"),
+        (Self::DeuteriumDeuterium, "D + D -> T"),
+        (Self::Helium3Helium3, "He3 + He3 -> He4"),
+        (Self::NegativeProton, "H- + H+ -> D"),
+        (Self::TripleAlpha, "3 He4 -> C12"),
+        (Self::CarbonAlpha, "C12 + He4 -> O16"),
+        (Self::OxygenAlpha, "O16 + He4 -> Ne20"),
+        (Self::NeonAlpha, "Ne20 + He4 -> Mg24"),
+        (Self::MagnesiumAlpha, "Mg24 + He4 -> Si28"),
+        (Self::SiliconAlpha, "Si28 + He4 -> S32"),
+        (Self::Water, "H2O formed"),
+        (Self::HydrogenSulfide, "H2S formed"),
+        (Self::MagnesiumHydride, "MgH2 formed"),
+        (Self::Methane, "CH4 formed"),
+        (Self::Silane, "SiH4 formed"),
+        (Self::SulfurDioxide, "SO2 formed"),
+    ];
+}
+
+/// Two-body reactions resolved in the pair phase of fusion
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum PairReaction {
+    ProtonProton,    // H+ + H+ → D + e+
+    DeuteriumDeuterium, // D + D → T + H+
+    DeuteriumProton, // D + H+ → He3
+    Helium3Helium3,  // He3 + He3 → He4 + 2 H+
+    NegativeProton,  // H- + H+ → D + e-
+}
+
+impl PairReaction {
+    fn flag(self) -> ReactionFlags {
+        match self {
+            PairReaction::ProtonProton => ReactionFlags::PP_CHAIN,
+            PairReaction::DeuteriumDeuterium => ReactionFlags::DD_FUSION,
+            PairReaction::DeuteriumProton => ReactionFlags::DEUTERIUM_FUSION,
+            PairReaction::Helium3Helium3 => ReactionFlags::HE3_HE3,
+            PairReaction::NegativeProton => ReactionFlags::NEGATIVE_PROTON,
+        }
+    }
+
+    fn kind(self) -> ReactionKind {
+        match self {
+            PairReaction::ProtonProton => ReactionKind::ProtonProton,
+            PairReaction::DeuteriumDeuterium => ReactionKind::DeuteriumDeuterium,
+            PairReaction::DeuteriumProton => ReactionKind::DeuteriumProton,
+            PairReaction::Helium3Helium3 => ReactionKind::Helium3Helium3,
+            PairReaction::NegativeProton => ReactionKind::NegativeProton,
+        }
+    }
+}
+
+/// Uniform grid of live particle slots, bucketed by position
+/// Built once per update and shared by every crystallization pass (see crystal_neighbor_lists)
+struct NeighborGrid {
+    cell_size: f32,
+    cells: std::collections::HashMap<(i32, i32), Vec<usize>>,
+}
+
+impl NeighborGrid {
+    fn build(protons: &[Option<Proton>], cell_size: f32) -> Self {
+        let live = protons.iter().enumerate().filter_map(|(i, proton)| {
+            proton.as_ref().filter(|p| p.is_alive()).map(|p| (i, p.position()))
+        });
+        Self::from_points(live, cell_size)
+    }
+
+    /// Grid over any (index, position) points - the indices mean whatever the caller looks them up in
+    fn from_points(points: impl IntoIterator<Item = (usize, Vec2)>, cell_size: f32) -> Self {
+        let mut grid = Self { cell_size, cells: std::collections::HashMap::new() };
+        for (i, position) in points {
+            grid.cells.entry(grid.cell_of(position)).or_default().push(i);
+        }
+        grid
+    }
+
+    fn cell_of(&self, pos: Vec2) -> (i32, i32) {
+        ((pos.x / self.cell_size).floor() as i32, (pos.y / self.cell_size).floor() as i32)
+    }
+
+    /// Slots in every cell that can hold a particle within `range` of `pos` (a superset - callers check distance)
+    fn candidates(&self, pos: Vec2, range: f32) -> impl Iterator<Item = usize> + '_ {
+        let reach = (range / self.cell_size).ceil() as i32;
+        let (cx, cy) = self.cell_of(pos);
+        (cx - reach..=cx + reach)
+            .flat_map(move |x| (cy - reach..=cy + reach).map(move |y| (x, y)))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .copied()
+    }
+}
+
+/// A nucleus or ligand taking part in molecule formation
+/// A bonded O16 pair is one part spanning two slots (`partner` is the second)
+#[derive(Clone, Copy)]
+struct MoleculePart {
+    slot: usize,
+    partner: Option<usize>,
+    position: Vec2, // Midpoint for a bonded pair
+    mass: f32,
+    energy: f32,
+    momentum: Vec2,
+}
+
+/// "Central element captures N ligands within range" - one entry per molecule
+struct MoleculeRecipe {
+    flag: ReactionFlags,
+    kind: ReactionKind,
+    center: fn(&Proton) -> bool,
+    ligand: fn(&Proton) -> bool,
+    ligand_count: usize,
+    capture_range: f32,
+    color: (u8, u8, u8),
+    charge: i32,
+    neutron_count: i32,
+    mark: fn(&mut Proton, bool),
+}
+
+impl MoleculeRecipe {
+    /// The stable molecule formed at `position`
+    fn build(&self, position: Vec2, velocity: Vec2, energy: f32) -> Proton {
+        let (r, g, b) = self.color;
+        let mut molecule = Proton::new(position, velocity, Color::from_rgba(r, g, b, 255), energy, self.charge);
+        molecule.set_neutron_count(self.neutron_count);
+        molecule.set_max_lifetime(-1.0); // Molecules are stable
+        (self.mark)(&mut molecule, true);
+        molecule
+    }
+}
+
+/// Direction the captured He4 was travelling relative to its target
+/// (falls back to the line between them when they meet at rest)
+fn alpha_approach(he4_pos: Vec2, he4_vel: Vec2, target_pos: Vec2, target_vel: Vec2) -> Vec2 {
+    (he4_vel - target_vel)
+        .try_normalize()
+        .or((target_pos - he4_pos).try_normalize())
+        .unwrap_or(Vec2::X)
+}
+
+/// Free H atom (not locked in a crystal) that a molecule can capture - a bare neutral D is not an atom
+fn is_free_hydrogen(p: &Proton) -> bool {
+    p.is_stable_hydrogen() && !p.is_crystallized()
+}
+
+/// Ascending order for sort keys that can be NaN after a bad force step - NaN of either sign sorts after
+/// every number, so nearest-first picks never take a poisoned neighbor and the sort never panics
+fn nan_last(a: f32, b: f32) -> std::cmp::Ordering {
+    a.is_nan().cmp(&b.is_nan()).then(a.total_cmp(&b))
+}
+
+/// O16 in either form - consolidated particle or bonded (C12, He4) pair
+fn is_any_oxygen16(p: &Proton) -> bool {
+    p.is_oxygen16() || p.is_oxygen16_bonded()
+}
+
+/// Molecule formations in the order they are tried each update
+const MOLECULES: [MoleculeRecipe; 6] = [
+    // H2O: O16 + 2 H. Each H atom is charge 0 with one neutron, so the sums are charge 8, neutrons 8 + 2 = 10
+    MoleculeRecipe {
+        flag: ReactionFlags::WATER_FORMATION,
+        kind: ReactionKind::Water,
+        center: is_any_oxygen16,
+        ligand: is_free_hydrogen,
+        ligand_count: 2,
+        capture_range: proton::WATER_CAPTURE_RANGE,
+        color: proton::WATER_COLOR,
+        charge: 8,
+        neutron_count: 10,
+        mark: Proton::set_h2o,
+    },
+    // H2S: S32 + 2 H. Charge 16, neutrons 16 + 2 = 18
+    MoleculeRecipe {
+        flag: ReactionFlags::HYDRIDE_FORMATION,
+        kind: ReactionKind::HydrogenSulfide,
+        center: Proton::is_sulfur32,
+        ligand: is_free_hydrogen,
+        ligand_count: 2,
+        capture_range: proton::H2S_CAPTURE_RANGE,
+        color: proton::H2S_COLOR,
+        charge: 16,
+        neutron_count: 18,
+        mark: Proton::set_h2s,
+    },
+    // MgH2: Mg24 + 2 H. Charge 12, neutrons 12 + 2 = 14
+    MoleculeRecipe {
+        flag: ReactionFlags::HYDRIDE_FORMATION,
+        kind: ReactionKind::MagnesiumHydride,
+        center: Proton::is_magnesium24,
+        ligand: is_free_hydrogen,
+        ligand_count: 2,
+        capture_range: proton::MGH2_CAPTURE_RANGE,
+        color: proton::MGH2_COLOR,
+        charge: 12,
+        neutron_count: 14,
+        mark: Proton::set_mgh2,
+    },
+    // CH4: C12 (not part of an O16 pair) + 4 H. Charge 6, neutrons 6 + 4 = 10
+    MoleculeRecipe {
+        flag: ReactionFlags::HYDRIDE_FORMATION,
+        kind: ReactionKind::Methane,
+        center: |p| p.is_stable_carbon12() && !p.is_oxygen16_bonded(),
+        ligand: is_free_hydrogen,
+        ligand_count: 4,
+        capture_range: proton::CH4_CAPTURE_RANGE,
+        color: proton::CH4_COLOR,
+        charge: 6,
+        neutron_count: 10,
+        mark: Proton::set_ch4,
+    },
+    // SiH4: Si28 + 4 H. Charge 14, neutrons 14 + 4 = 18
+    MoleculeRecipe {
+        flag: ReactionFlags::HYDRIDE_FORMATION,
+        kind: ReactionKind::Silane,
+        center: Proton::is_silicon28,
+        ligand: is_free_hydrogen,
+        ligand_count: 4,
+        capture_range: proton::SIH4_CAPTURE_RANGE,
+        color: proton::SIH4_COLOR,
+        charge: 14,
+        neutron_count: 18,
+        mark: Proton::set_sih4,
+    },
+    // SO2: S32 + 2 O16. 16 + 8 + 8 = 32 of each
+    MoleculeRecipe {
+        flag: ReactionFlags::OXIDE_FORMATION,
+        kind: ReactionKind::SulfurDioxide,
+        center: Proton::is_sulfur32,
+        ligand: is_any_oxygen16,
+        ligand_count: 2,
+        capture_range: proton::SO2_CAPTURE_RANGE,
+        color: proton::SO2_COLOR,
+        charge: 32,
+        neutron_count: 32,
+        mark: Proton::set_so2,
+    },
+];
+
+/// Ranking for competing fusion candidates within a frame
+/// Faster closing speed wins, then the closer pair, then the reaction site position,
+/// so the chosen reaction never depends on which slots the particles happen to occupy
+#[derive(Clone, Copy, Debug)]
+struct ReactionPriority {
+    rel_speed: f32,
+    distance: f32,
+    site: Vec2,
+}
+
+impl ReactionPriority {
+    fn new(rel_speed: f32, distance: f32, site: Vec2) -> Self {
+        Self { rel_speed, distance, site }
+    }
+
+    /// Ordering where Greater means higher priority
+    fn compare(&self, other: &ReactionPriority) -> std::cmp::Ordering {
+        self.rel_speed.total_cmp(&other.rel_speed)
+            .then_with(|| other.distance.total_cmp(&self.distance))
+            .then_with(|| other.site.x.total_cmp(&self.site.x))
+            .then_with(|| other.site.y.total_cmp(&self.site.y))
+    }
+}
+
+/// Atom-driven events counted since the last reset (see ProtonManager::reset_atom_events)
+#[derive(Clone, Copy, Default, Debug)]
+pub struct AtomEventCounts {
+    pub neutron_formations: u32, // H+ → D next to an atom
+    pub electron_captures: u32,  // D + atom → stable H
+}
+
+/// Name the population controller uses for free protons (every other target is a spawnable element name)
+pub const FREE_PROTON_NAME: &str = "H+";
+
+/// Keep about `count` particles of `element` alive - see ProtonManager::set_population_target
+#[derive(Clone, Debug, PartialEq)]
+pub struct PopulationTarget {
+    pub element: String,
+    pub count: usize,
+}
+
+/// What happened in a SimEvent
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SimEventKind {
+    Fusion,         // A nuclear reaction produced a new nucleus
+    Decay,          // An unstable isotope decayed into its daughter
+    MoleculeFormed, // A center captured its ligands (H2O, hydrides, SO2)
+    CrystalFrozen,  // A particle froze into a crystal
+    Melted,         // A frozen particle melted out of its crystal
+}
+
+/// Discrete simulation event queued for the frontend (sounds, logging) - see drain_events
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimEvent {
+    pub kind: SimEventKind,
+    pub position: Vec2,
+    pub element: String, // Label of the product, or of the particle that froze or melted
+}
+
+/// One oxygen-16 nucleus taking part in a reaction - either a legacy bonded (C12, He4)
+/// pair or a consolidated O16 particle (which has no partner slot)
+#[derive(Clone, Copy)]
+struct Oxygen16 {
+    slot: usize,
+    partner: Option<usize>,
+    center: Vec2,
+    mass: f32,
+    energy: f32,
+    radius: f32,
+    vel1: Vec2,
+    vel2: Vec2,
+}
 
 pub struct ProtonManager {
     protons: Vec<Option<Proton>>,
-    next_slot: usize,
-    max_protons: usize,
+    free_slots: Vec<usize>, // Emptied slots, most recent last - insert_proton pops these before growing
+    capacity_limit: usize, // Hard cap the slot vector may grow to (see insert_proton)
     spawn_cooldowns: Vec<(Vec2, f32)>,
     elapsed_time: f32, // Total elapsed time for tracking wave hits
+    temperature_enabled: bool, // When false, evaporation uses the fixed per-element thresholds
+    temperature_grid: Vec<f32>, // Coarse temperature field (row-major, -1 cold .. +1 hot)
+    temperature_cols: usize,
+    temperature_rows: usize,
+    boundary_mode: BoundaryMode,
+    arena: Option<Rect>, // Walls set by the player - None keeps them on the window edges
+    world: Rect, // Arena from the last update (its size is the toroidal period)
+    show_lifetime: bool, // Draw remaining-lifetime arcs around non-stable protons
+    show_velocity: bool, // Draw velocity arrows on non-frozen protons
+    gravity_enabled: bool, // Layered view - heavy particles sink, light ones float (see apply_gravity)
+    medium: Medium, // Ambient drag applied in update_proton_physics
+    config: SimConfig, // Runtime-tunable force, range, fusion and evaporation values
+    reaction_flags: ReactionFlags, // Which fusion/formation reactions may run
+    atom_events: AtomEventCounts, // Neutron formations and electron captures since the last reset
+    decay_count: u32, // Radioactive decays since startup
+    neutron_formation_count: u32, // H+ -> D conversions next to atoms since startup
+    events: Vec<SimEvent>, // Queued during the current update - cleared when the next one starts
+    reaction_log: std::collections::VecDeque<(f32, ReactionKind)>, // (elapsed_time, kind) within the rate window, oldest first
+    neighbor_grid: NeighborGrid, // Rebuilt before the crystallization passes each update
+    slot_serials: Vec<u64>, // Occupant serial of every slot at the last bond integrity check (0 = empty)
+    population_target: Option<PopulationTarget>, // Count the population controller holds (STEP 9)
+    population_integral: f32, // Accumulated count error in particle-seconds, clamped
+    population_budget: f32, // Fractional spawns (+) or removals (-) accrued but not yet made
 }
 
 impl ProtonManager {
-    pub fn new(max_protons: usize) -> Self {
-        let mut protons = Vec::with_capacity(max_protons);
-        for _ in 0..max_protons {
+    /// Start with `initial_slots` slots - the vector grows on demand up to `config.max_protons`
+    pub fn new(initial_slots: usize, config: &SimConfig) -> Self {
+        let mut protons = Vec::with_capacity(initial_slots);
+        for _ in 0..initial_slots {
             protons.push(None);
         }
 
-        Self {
+        let mut manager = Self {
             protons,
-            next_slot: 0,
-            max_protons,
+            free_slots: (0..initial_slots).rev().collect(),
+            capacity_limit: initial_slots,
             spawn_cooldowns: Vec::new(),
             elapsed_time: 0.0,
-        }
+            temperature_enabled: false,
+            temperature_grid: Vec::new(),
+            temperature_cols: 0,
+            temperature_rows: 0,
+            boundary_mode: BoundaryMode::Bounce,
+            arena: None,
+            world: Rect::new(0.0, 0.0, 0.0, 0.0),
+            show_lifetime: false,
+            show_velocity: false,
+            gravity_enabled: false,
+            medium: Medium::Vacuum,
+            config: config.clone(),
+            reaction_flags: ReactionFlags::all(),
+            atom_events: AtomEventCounts::default(),
+            decay_count: 0,
+            neutron_formation_count: 0,
+            events: Vec::new(),
+            reaction_log: std::collections::VecDeque::new(),
+            neighbor_grid: NeighborGrid::build(&[], pm::NEIGHBOR_GRID_CELL_SIZE),
+            slot_serials: Vec::new(),
+            population_target: None,
+            population_integral: 0.0,
+            population_budget: 0.0,
+        };
+        manager.set_capacity(config.max_protons as usize);
+        manager
     }
 
     /// Main update - physics, interactions, and spawning from atoms
@@ -40,8 +533,12 @@ impl ProtonManager {
         atom_manager: &mut AtomManager,
         ring_manager: &mut RingManager,
     ) {
+        // Events are per step - undrained ones from the previous step are dropped
+        self.events.clear();
+
         // Track elapsed time
         self.elapsed_time += delta_time;
+        self.world = self.arena_rect(window_size);
 
         // Update cooldowns
         self.update_cooldowns(delta_time);
@@ -49,12 +546,33 @@ impl ProtonManager {
         // STEP 1: Simple straight-line physics
         self.update_proton_physics(delta_time, window_size);
 
+        // Particles spawned since the last step get their configured size before any distance check
+        self.refresh_radii();
+
+        // STEP 1.5: Radioactive decay of isotopes whose lifetime ran out
+        self.handle_radioactive_decay(ring_manager);
+
         // STEP 2: Charge-based forces (H+/H- interactions and H clustering)
         self.apply_charge_forces(delta_time);
 
+        // STEP 2.4: Temperature field (rings deposit heat, grid decays)
+        self.update_temperature_field(delta_time, window_size, ring_manager);
+
         // STEP 2.5: Red wave repulsion (only affects H-)
         self.apply_red_wave_repulsion(delta_time, ring_manager);
 
+        // STEP 2.5.1: Wave thermal effects (blue waves heat, red waves damp unfrozen particles)
+        self.apply_wave_thermal_effects(delta_time, ring_manager);
+
+        // Bonds into slots that were freed or refilled since the last step are dropped before any bond force runs
+        self.repair_dangling_bonds();
+
+        // One spatial grid for every crystallization pass - positions don't change until they all ran
+        self.neighbor_grid = NeighborGrid::build(&self.protons, pm::NEIGHBOR_GRID_CELL_SIZE);
+
+        // Frozen state before the phase transitions, to spot particles that freeze this step
+        let was_frozen: Vec<bool> = self.protons.iter().map(|p| p.as_ref().is_some_and(Proton::is_frozen)).collect();
+
         // STEP 2.6: H crystallization (phase transitions)
         self.update_h_crystallization(delta_time);
 
@@ -100,29 +618,32 @@ impl ProtonManager {
         // STEP 2.8: Water hydrogen bonds (polarity-based bonding)
         self.update_water_hydrogen_bonds(delta_time);
 
-        // STEP 4: Neutron formation (proximity to atoms)
-        for i in 0..self.protons.len() {
-            // First, collect info about the proton
-            let (should_check, proton_pos) = {
-                if let Some(proton) = &self.protons[i] {
-                    if proton.is_alive() && proton.charge() == 1 {
-                        (true, proton.position())
-                    } else {
-                        (false, Vec2::ZERO)
-                    }
-                } else {
-                    (false, Vec2::ZERO)
-                }
-            };
-
-            if should_check {
-                let near_atom = self.is_near_atom(proton_pos, atom_manager);
-                if let Some(proton) = &mut self.protons[i] {
-                    proton.try_neutron_formation(delta_time, near_atom);
+        for (slot, was) in was_frozen.into_iter().enumerate() {
+            let frozen = self.protons[slot].as_ref().is_some_and(Proton::is_frozen);
+            if frozen && !was {
+                self.push_event(SimEventKind::CrystalFrozen, slot);
+            } else if was && !frozen {
+                self.push_event(SimEventKind::Melted, slot);
+            }
+            // Freeze time drives the growth-front highlight in draw
+            if frozen != was {
+                let stamp = frozen.then_some(self.elapsed_time);
+                if let Some(proton) = self.protons[slot].as_mut() {
+                    proton.set_crystallized_at(stamp);
                 }
             }
         }
 
+        // STEP 4: Neutron formation (proximity to atoms)
+        let atom_positions: Vec<Vec2> = atom_manager
+            .get_atoms()
+            .iter()
+            .flatten()
+            .filter(|atom| atom.is_alive())
+            .map(|atom| atom.get_position())
+            .collect();
+        self.form_neutrons(delta_time, &atom_positions);
+
         // STEP 5: Electron capture (for neutral protons)
         for i in 0..self.protons.len() {
             // First, collect info about the proton
@@ -148,6 +669,7 @@ impl ProtonManager {
 
                     if captured {
                         self.mark_atom_at_position(atom_pos, atom_manager);
+                        self.atom_events.electron_captures += 1;
                     }
                 }
             }
@@ -156,6 +678,9 @@ impl ProtonManager {
         // STEP 6: Nuclear fusion (must happen before solid collisions to allow reactions)
         self.handle_nuclear_fusion(ring_manager);
 
+        // Fusion products are resized before they collide
+        self.refresh_radii();
+
         // STEP 6.5: Solid collisions (H+, H-, H, He4, etc. bounce like walls at close range)
         // This happens AFTER fusion so reactions can occur first
         self.handle_solid_collisions();
@@ -163,290 +688,291 @@ impl ProtonManager {
         // STEP 7: Spawn from atom collisions
         self.detect_and_spawn_from_atom_collisions(atom_manager);
 
-        // STEP 8: Cleanup dead protons
-        for proton_opt in &mut self.protons {
-            if let Some(proton) = proton_opt {
-                if !proton.is_alive() || proton.is_marked_for_deletion() {
-                    // Never remove stable particles: H1, He4, C12, O16 bonded, H2O, Ne20, Mg24, Si28, S32, and hydrogen compounds
-                    if !proton.is_stable_hydrogen()
-                        && !proton.is_stable_helium4()
-                        && !proton.is_stable_carbon12()
-                        && !proton.is_oxygen16_bonded()
-                        && !proton.is_h2o()
-                        && !proton.is_neon20()
-                        && !proton.is_magnesium24()
-                        && !proton.is_silicon28()
-                        && !proton.is_sulfur32()
-                        && !proton.is_h2s()
-                        && !proton.is_mgh2()
-                        && !proton.is_ch4()
-                        && !proton.is_sih4() {
-                        *proton_opt = None;
-                    }
-                }
-            }
-        }
-    }
-
-    /// Draw all protons
-    pub fn draw(&self, segments: i32) {
-        // First draw crystal bonds (H)
-        self.draw_crystal_bonds();
-
-        // Then draw oxygen bonds
-        self.draw_oxygen_bonds();
+        // STEP 8: Cleanup dead protons - never immortal ones, see ElementKind::is_immortal
+        self.delete_element(|p| (!p.is_alive() || p.is_marked_for_deletion()) && !p.is_immortal());
 
-        // Then draw water hydrogen bonds
-        self.draw_water_hydrogen_bonds();
+        // STEP 9: Population controller tops up or thins out its element
+        self.update_population(delta_time);
 
-        // Draw Ne20 bonds (pink/magenta)
-        self.draw_ne20_bonds();
-
-        // Draw C12 bonds (gray)
-        self.draw_c12_bonds();
-
-        // Draw Si28 bonds (brown)
-        self.draw_si28_bonds();
+        // Diagnostics: watch bonded particles for vibration that never dies down (read-only)
+        for proton in self.protons.iter_mut().flatten() {
+            let bonded = proton.total_bond_count() > 0;
+            proton.track_oscillation(delta_time, bonded);
+        }
 
-        // Draw Mg24 bonds (light blue-gray)
-        self.draw_mg24_bonds();
+        // Debug builds: every reaction and transition this step must have left consistent element flags
+        #[cfg(debug_assertions)]
+        for (slot, proton) in self.protons.iter().enumerate() {
+            if let Some(Err(err)) = proton.as_ref().map(Proton::validate) {
+                panic!("slot {} after update: {}", slot, err);
+            }
+        }
+    }
 
-        // Draw S32 bonds (yellow)
-        self.draw_s32_bonds();
+    /// Draw bonds and protons that overlap `view` (the visible world rect)
+    pub fn draw(&self, segments: i32, color_mode: ColorMode, style: RenderStyle, view: Rect) {
+        // Draw every bond line first, in one pass
+        for (from, to, color, thickness) in self.collect_bond_segments() {
+            let bounds = Rect::new(from.x.min(to.x), from.y.min(to.y), (from.x - to.x).abs(), (from.y - to.y).abs());
+            if bounds.overlaps(&view) {
+                draw_line(from.x, from.y, to.x, to.y, thickness, color);
+            }
+        }
 
         // Then draw protons on top
         for proton_opt in &self.protons {
             if let Some(proton) = proton_opt {
-                if proton.is_alive() {
-                    proton.render(segments);
-                }
-            }
-        }
-    }
+                if proton.is_alive() && Self::is_in_view(view, proton.position(), proton.radius() * rendering::CULL_RADIUS_SCALE) {
+                    let color_override = match color_mode {
+                        ColorMode::Element => match self.growth_front_glow(proton) {
+                            glow if glow > 0.0 => Some(Self::growth_front_tint(proton.element_color(), glow)),
+                            _ => None,
+                        },
+                        ColorMode::BondCount => Some(Self::bond_count_color(proton.total_bond_count())),
+                        ColorMode::Oscillation if proton.is_oscillating() => Some(Color::from_rgba(255, 90, 40, 255)),
+                        ColorMode::Oscillation => Some(Color::from_rgba(70, 70, 70, 255)),
+                    };
+                    match style {
+                        RenderStyle::Circles => proton.render_with_color(segments, color_override),
+                        RenderStyle::Shapes => proton.render_shape(segments, color_override),
+                    }
 
-    /// Draw crystal bond lines for hexagonal ice structure
-    fn draw_crystal_bonds(&self) {
-        for (i, proton_opt) in self.protons.iter().enumerate() {
-            if let Some(proton) = proton_opt {
-                if proton.is_alive() && proton.is_crystallized() {
-                    let pos1 = proton.position();
-                    let bonds = proton.crystal_bonds();
+                    let flash = self.load_flash(proton);
+                    if flash > 0.0 {
+                        let (r, g, b) = rendering::LOAD_FLASH_COLOR;
+                        let color = Color::new(r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, flash);
+                        let pos = proton.position();
+                        draw_circle_lines(pos.x, pos.y, proton.radius() + rendering::LOAD_FLASH_MARGIN, rendering::LOAD_FLASH_THICKNESS, color);
+                    }
 
-                    // Draw bond lines to each bonded neighbor
-                    for bond_idx in bonds {
-                        // Only draw each bond once (from lower index to higher)
-                        if *bond_idx > i {
-                            if let Some(other_proton) = &self.protons[*bond_idx] {
-                                if other_proton.is_alive() && other_proton.is_crystallized() {
-                                    let pos2 = other_proton.position();
-
-                                    // Draw thin white/cyan line for bond
-                                    let bond_color = Color::from_rgba(180, 220, 255, 180);
-                                    draw_line(pos1.x, pos1.y, pos2.x, pos2.y, 1.5, bond_color);
-                                }
-                            }
-                        }
+                    if self.show_lifetime {
+                        proton.render_lifetime_arc();
+                    }
+
+                    if self.show_velocity && !proton.is_frozen() {
+                        Self::draw_velocity_arrow(proton.position(), proton.velocity());
                     }
                 }
             }
         }
     }
 
-    /// Draw oxygen bond lines for O16 bonded pairs (C12 + He4)
-    fn draw_oxygen_bonds(&self) {
-        for (i, proton_opt) in self.protons.iter().enumerate() {
-            if let Some(proton) = proton_opt {
-                if proton.is_alive() && proton.is_oxygen16_bonded() {
-                    if let Some(partner_idx) = proton.oxygen_bond_partner() {
-                        // Only draw each bond once (from lower index to higher)
-                        if partner_idx > i {
-                            if let Some(partner) = &self.protons[partner_idx] {
-                                if partner.is_alive() && partner.is_oxygen16_bonded() {
-                                    let pos1 = proton.position();
-                                    let pos2 = partner.position();
+    /// Arrow from `position` along `velocity`, VELOCITY_ARROW_SCALE seconds of travel long
+    fn draw_velocity_arrow(position: Vec2, velocity: Vec2) {
+        let shaft = velocity * rendering::VELOCITY_ARROW_SCALE;
+        if shaft.length() < rendering::VELOCITY_ARROW_MIN_LENGTH {
+            return;
+        }
 
-                                    // Draw light blue line for O16 bond
-                                    let bond_color = Color::from_rgba(100, 180, 255, 200);
-                                    draw_line(pos1.x, pos1.y, pos2.x, pos2.y, 2.0, bond_color);
-                                }
-                            }
-                        }
-                    }
-                }
-            }
+        let (r, g, b, a) = rendering::VELOCITY_ARROW_COLOR;
+        let color = Color::from_rgba(r, g, b, a);
+        let tip = position + shaft;
+        draw_line(position.x, position.y, tip.x, tip.y, rendering::VELOCITY_ARROW_THICKNESS, color);
+
+        // Two barbs swept back from the tip
+        let back = -shaft.normalize() * rendering::VELOCITY_ARROW_HEAD;
+        for barb in [Vec2::from_angle(0.5).rotate(back), Vec2::from_angle(-0.5).rotate(back)] {
+            draw_line(tip.x, tip.y, tip.x + barb.x, tip.y + barb.y, rendering::VELOCITY_ARROW_THICKNESS, color);
         }
     }
 
-    /// Draw water hydrogen bond lines for H2O polar bonding
-    fn draw_water_hydrogen_bonds(&self) {
-        for (i, proton_opt) in self.protons.iter().enumerate() {
-            if let Some(proton) = proton_opt {
-                if proton.is_alive() && proton.is_h2o() {
-                    let pos1 = proton.position();
-                    let bonds = proton.water_h_bonds();
+    /// True if a circle of `margin` around `pos` touches the visible rect
+    fn is_in_view(view: Rect, pos: Vec2, margin: f32) -> bool {
+        pos.x + margin >= view.x
+            && pos.x - margin <= view.x + view.w
+            && pos.y + margin >= view.y
+            && pos.y - margin <= view.y + view.h
+    }
 
-                    // Draw bond lines to each bonded water molecule
-                    for bond_idx in bonds {
-                        // Only draw each bond once (from lower index to higher)
-                        if *bond_idx > i {
-                            if let Some(other_proton) = &self.protons[*bond_idx] {
-                                if other_proton.is_alive() && other_proton.is_h2o() {
-                                    let pos2 = other_proton.position();
-
-                                    // Check if both molecules are frozen (ice bond)
-                                    let both_frozen = proton.is_water_frozen() && other_proton.is_water_frozen();
-
-                                    // Draw line - brighter and thicker for frozen ice bonds
-                                    let (bond_color, thickness) = if both_frozen {
-                                        (Color::from_rgba(180, 220, 255, 200), 2.5) // Bright cyan for ice
-                                    } else {
-                                        (Color::from_rgba(100, 150, 200, 120), 1.2) // Faint blue for liquid
-                                    };
-                                    draw_line(pos1.x, pos1.y, pos2.x, pos2.y, thickness, bond_color);
-                                }
-                            }
-                        }
-                    }
-                }
+    /// 1 on the step a particle froze, fading to 0 over GROWTH_FRONT_FADE_TIME
+    fn growth_front_glow(&self, proton: &Proton) -> f32 {
+        proton.crystallized_at().map_or(0.0, |at| {
+            (1.0 - (self.elapsed_time - at) / rendering::GROWTH_FRONT_FADE_TIME).clamp(0.0, 1.0)
+        })
+    }
+
+    /// Opacity of the load outline - pulses LOAD_FLASH_PULSES times while fading out over LOAD_FLASH_TIME
+    fn load_flash(&self, proton: &Proton) -> f32 {
+        proton.spawned_at().map_or(0.0, |at| {
+            let age = self.elapsed_time - at;
+            if !(0.0..rendering::LOAD_FLASH_TIME).contains(&age) {
+                return 0.0;
             }
-        }
+            let pulse = 0.5 + 0.5 * (age / rendering::LOAD_FLASH_TIME * rendering::LOAD_FLASH_PULSES * std::f32::consts::TAU).cos();
+            pulse * (1.0 - age / rendering::LOAD_FLASH_TIME)
+        })
     }
 
-    /// Draw Ne20 bond lines (pink/magenta bonds for neon crystals)
-    fn draw_ne20_bonds(&self) {
-        for (i, proton_opt) in self.protons.iter().enumerate() {
-            if let Some(proton) = proton_opt {
-                if proton.is_alive() && proton.is_neon20() && proton.is_ne20_crystallized() {
-                    let pos1 = proton.position();
-                    let bonds = proton.ne20_crystal_bonds();
+    /// `color` brightened towards the growth-front highlight by `glow` (0-1)
+    fn growth_front_tint(color: Color, glow: f32) -> Color {
+        let (r, g, b) = rendering::GROWTH_FRONT_COLOR;
+        Color::from_vec(color.to_vec().lerp(Color::from_rgba(r, g, b, 255).to_vec(), glow))
+    }
 
-                    for bond_idx in bonds {
-                        if *bond_idx > i {
-                            if let Some(other_proton) = &self.protons[*bond_idx] {
-                                if other_proton.is_alive() && other_proton.is_neon20() && other_proton.is_ne20_crystallized() {
-                                    let pos2 = other_proton.position();
-                                    // Pink/magenta color from Ne20 element
-                                    let bond_color = Color::from_rgba(255, 150, 200, 180);
-                                    draw_line(pos1.x, pos1.y, pos2.x, pos2.y, 2.0, bond_color);
-                                }
-                            }
-                        }
-                    }
-                }
-            }
+    /// Debug tint for a bond count - grey with no bonds, brightening towards white as bonds increase
+    fn bond_count_color(bond_count: usize) -> Color {
+        if bond_count == 0 {
+            return Color::from_rgba(90, 90, 90, 255);
         }
+
+        let t = (bond_count as f32 / rendering::BOND_COUNT_COLOR_MAX as f32).min(1.0);
+        // Blue (1 bond) -> green -> yellow -> white (max bonds)
+        Color::new(
+            (t * 2.0).min(1.0),
+            0.4 + 0.6 * t,
+            if t < 0.5 { 1.0 - t * 2.0 } else { (t - 0.5) * 2.0 },
+            1.0,
+        )
     }
 
-    /// Draw C12 bond lines (gray bonds for carbon graphite)
-    fn draw_c12_bonds(&self) {
+    /// Every bond line to draw this frame as (from, to, color, thickness)
+    /// Each bond is emitted once, from its lower-index end
+    fn collect_bond_segments(&self) -> Vec<(Vec2, Vec2, Color, f32)> {
+        let mut segments = Vec::new();
+
         for (i, proton_opt) in self.protons.iter().enumerate() {
-            if let Some(proton) = proton_opt {
-                if proton.is_alive() && proton.is_stable_carbon12() && proton.is_c12_crystallized() {
-                    let pos1 = proton.position();
-                    let bonds = proton.c12_crystal_bonds();
+            let Some(proton) = proton_opt else { continue };
+            if !proton.is_alive() {
+                continue;
+            }
 
-                    for bond_idx in bonds {
-                        if *bond_idx > i {
-                            if let Some(other_proton) = &self.protons[*bond_idx] {
-                                if other_proton.is_alive() && other_proton.is_stable_carbon12() && other_proton.is_c12_crystallized() {
-                                    let pos2 = other_proton.position();
-                                    // Gray/silver color for carbon bonds
-                                    let bond_color = Color::from_rgba(160, 160, 160, 200);
-                                    draw_line(pos1.x, pos1.y, pos2.x, pos2.y, 2.5, bond_color);
-                                }
-                            }
-                        }
-                    }
-                }
+            // H crystal bonds - thin white/cyan
+            if proton.is_crystallized() {
+                self.push_bond_segments(&mut segments, i, proton.crystal_bonds(), |p| p.is_crystallized(),
+                    Color::from_rgba(180, 220, 255, 180), 1.5);
             }
-        }
-    }
 
-    /// Draw Si28 bond lines (brown bonds for silicon diamond cubic)
-    fn draw_si28_bonds(&self) {
-        for (i, proton_opt) in self.protons.iter().enumerate() {
-            if let Some(proton) = proton_opt {
-                if proton.is_alive() && proton.is_silicon28() && proton.is_si28_crystallized() {
-                    let pos1 = proton.position();
-                    let bonds = proton.si28_crystal_bonds();
+            // O16 bonded pair (C12 + He4) - light blue
+            if proton.is_oxygen16_bonded() {
+                if let Some(partner_idx) = proton.oxygen_bond_partner() {
+                    self.push_bond_segments(&mut segments, i, &[partner_idx], |p| p.is_oxygen16_bonded(),
+                        Color::from_rgba(100, 180, 255, 200), 2.0);
+                }
+            }
 
-                    for bond_idx in bonds {
-                        if *bond_idx > i {
-                            if let Some(other_proton) = &self.protons[*bond_idx] {
-                                if other_proton.is_alive() && other_proton.is_silicon28() && other_proton.is_si28_crystallized() {
-                                    let pos2 = other_proton.position();
-                                    // Brown/tan color for silicon bonds
-                                    let bond_color = Color::from_rgba(190, 160, 120, 190);
-                                    draw_line(pos1.x, pos1.y, pos2.x, pos2.y, 2.0, bond_color);
-                                }
-                            }
-                        }
+            // Water hydrogen bonds - brighter and thicker once both molecules are frozen
+            if proton.is_h2o() {
+                for &bond_idx in proton.water_h_bonds() {
+                    if let Some(other) = self.bond_partner(i, bond_idx, |p| p.is_h2o()) {
+                        let (color, thickness) = if proton.is_water_frozen() && other.is_water_frozen() {
+                            (Color::from_rgba(180, 220, 255, 200), 2.5) // Bright cyan for ice
+                        } else {
+                            (Color::from_rgba(100, 150, 200, 120), 1.2) // Faint blue for liquid
+                        };
+                        let glow = self.growth_front_glow(proton).max(self.growth_front_glow(other));
+                        segments.push((proton.position(), other.position(), Self::growth_front_tint(color, glow), thickness));
                     }
                 }
             }
+
+            // Ne20 - pink/magenta
+            if proton.is_neon20() && proton.is_ne20_crystallized() {
+                self.push_bond_segments(&mut segments, i, proton.ne20_crystal_bonds(), |p| p.is_neon20() && p.is_ne20_crystallized(),
+                    Color::from_rgba(255, 150, 200, 180), 2.0);
+            }
+
+            // C12 - gray/silver
+            if proton.is_stable_carbon12() && proton.is_c12_crystallized() {
+                self.push_bond_segments(&mut segments, i, proton.c12_crystal_bonds(), |p| p.is_stable_carbon12() && p.is_c12_crystallized(),
+                    Color::from_rgba(160, 160, 160, 200), 2.5);
+            }
+
+            // Si28 - brown/tan
+            if proton.is_silicon28() && proton.is_si28_crystallized() {
+                self.push_bond_segments(&mut segments, i, proton.si28_crystal_bonds(), |p| p.is_silicon28() && p.is_si28_crystallized(),
+                    Color::from_rgba(190, 160, 120, 190), 2.0);
+            }
+
+            // Mg24 - light metallic blue-gray
+            if proton.is_magnesium24() && proton.is_mg24_crystallized() {
+                self.push_bond_segments(&mut segments, i, proton.mg24_crystal_bonds(), |p| p.is_magnesium24() && p.is_mg24_crystallized(),
+                    Color::from_rgba(210, 210, 230, 185), 2.2);
+            }
+
+            // S32 - yellow
+            if proton.is_sulfur32() && proton.is_s32_crystallized() {
+                self.push_bond_segments(&mut segments, i, proton.s32_crystal_bonds(), |p| p.is_sulfur32() && p.is_s32_crystallized(),
+                    Color::from_rgba(230, 230, 120, 180), 2.0);
+            }
         }
+
+        segments
     }
 
-    /// Draw Mg24 bond lines (light blue-gray bonds for magnesium metal)
-    fn draw_mg24_bonds(&self) {
-        for (i, proton_opt) in self.protons.iter().enumerate() {
-            if let Some(proton) = proton_opt {
-                if proton.is_alive() && proton.is_magnesium24() && proton.is_mg24_crystallized() {
-                    let pos1 = proton.position();
-                    let bonds = proton.mg24_crystal_bonds();
+    /// Partner at `bond_idx` if this bond should be drawn from slot `i` (higher index, alive, matching `partner_ok`,
+    /// and still the occupant the bond was made with)
+    fn bond_partner(&self, i: usize, bond_idx: usize, partner_ok: fn(&Proton) -> bool) -> Option<&Proton> {
+        if bond_idx <= i || !self.is_bond_target(bond_idx) {
+            return None;
+        }
+        self.protons[bond_idx].as_ref().filter(|p| p.is_alive() && partner_ok(p))
+    }
 
-                    for bond_idx in bonds {
-                        if *bond_idx > i {
-                            if let Some(other_proton) = &self.protons[*bond_idx] {
-                                if other_proton.is_alive() && other_proton.is_magnesium24() && other_proton.is_mg24_crystallized() {
-                                    let pos2 = other_proton.position();
-                                    // Light metallic blue-gray for magnesium
-                                    let bond_color = Color::from_rgba(210, 210, 230, 185);
-                                    draw_line(pos1.x, pos1.y, pos2.x, pos2.y, 2.2, bond_color);
-                                }
-                            }
-                        }
-                    }
-                }
+    fn slot_serial(&self, idx: usize) -> u64 {
+        self.protons.get(idx).and_then(Option::as_ref).map_or(0, Proton::serial)
+    }
+
+    /// True if slot `idx` holds the same particle it held at the last integrity check
+    fn is_bond_target(&self, idx: usize) -> bool {
+        let serial = self.slot_serial(idx);
+        serial != 0 && self.slot_serials.get(idx) == Some(&serial)
+    }
+
+    /// Integrity check: clear every bond index whose slot was emptied or handed to a different
+    /// particle since the last check, then record the current occupants
+    fn repair_dangling_bonds(&mut self) {
+        let valid: Vec<bool> = (0..self.protons.len()).map(|idx| self.is_bond_target(idx)).collect();
+        if valid.iter().any(|ok| !ok) {
+            for proton in self.protons.iter_mut().flatten() {
+                proton.retain_bonds(|idx| valid.get(idx).copied().unwrap_or(false));
             }
         }
+        self.slot_serials = (0..self.protons.len()).map(|idx| self.slot_serial(idx)).collect();
     }
 
-    /// Draw S32 bond lines (yellow bonds for sulfur crystals)
-    fn draw_s32_bonds(&self) {
-        for (i, proton_opt) in self.protons.iter().enumerate() {
-            if let Some(proton) = proton_opt {
-                if proton.is_alive() && proton.is_sulfur32() && proton.is_s32_crystallized() {
-                    let pos1 = proton.position();
-                    let bonds = proton.s32_crystal_bonds();
+    /// Bring slot `idx` up to date with the integrity check before bonding to it mid-step
+    /// Bonds that still point at a previous occupant are cleared first
+    fn claim_bond_slot(&mut self, idx: usize) {
+        if self.is_bond_target(idx) {
+            return;
+        }
+        for proton in self.protons.iter_mut().flatten() {
+            proton.retain_bonds(|bond_idx| bond_idx != idx);
+        }
+        if self.slot_serials.len() < self.protons.len() {
+            self.slot_serials.resize(self.protons.len(), 0);
+        }
+        self.slot_serials[idx] = self.slot_serial(idx);
+    }
 
-                    for bond_idx in bonds {
-                        if *bond_idx > i {
-                            if let Some(other_proton) = &self.protons[*bond_idx] {
-                                if other_proton.is_alive() && other_proton.is_sulfur32() && other_proton.is_s32_crystallized() {
-                                    let pos2 = other_proton.position();
-                                    // Yellow color for sulfur bonds
-                                    let bond_color = Color::from_rgba(230, 230, 120, 180);
-                                    draw_line(pos1.x, pos1.y, pos2.x, pos2.y, 2.0, bond_color);
-                                }
-                            }
-                        }
-                    }
-                }
+    fn push_bond_segments(
+        &self,
+        segments: &mut Vec<(Vec2, Vec2, Color, f32)>,
+        i: usize,
+        bonds: &[usize],
+        partner_ok: fn(&Proton) -> bool,
+        color: Color,
+        thickness: f32,
+    ) {
+        let Some(proton) = self.protons[i].as_ref() else { return };
+        for &bond_idx in bonds {
+            if let Some(other) = self.bond_partner(i, bond_idx, partner_ok) {
+                let glow = self.growth_front_glow(proton).max(self.growth_front_glow(other));
+                segments.push((proton.position(), other.position(), Self::growth_front_tint(color, glow), thickness));
             }
         }
     }
 
     /// Draw labels centered on protons
-    pub fn draw_labels(&self) {
+    pub fn draw_labels(&self, view: Rect) {
         for proton_opt in &self.protons {
             if let Some(proton) = proton_opt {
-                if proton.is_alive() {
+                if proton.is_alive() && Self::is_in_view(view, proton.position(), rendering::LABEL_CULL_MARGIN) {
                     let label = proton.get_element_label();
                     let pos = proton.position();
 
                     // Measure text dimensions for centering
-                    let font_size = 18.0;
+                    let font_size = rendering::LABEL_FONT_SIZE;
                     let text_dims = measure_text(&label, None, font_size as u16, 1.0);
 
                     // Center text on proton (both horizontally and vertically)
@@ -464,70 +990,304 @@ impl ProtonManager {
         }
     }
 
-    /// Clear all protons (except stable ones)
+    /// Write the whole pond (not just the visible part) to `path` as an SVG - see to_svg
+    pub fn export_svg(&self, path: &str, window_size: (f32, f32)) -> std::io::Result<()> {
+        std::fs::write(path, self.to_svg(window_size))
+    }
+
+    /// Vector snapshot of the pond on a black window-sized canvas: bonds as lines, particles as their
+    /// glowing circles in element colors, and labels on top - the same geometry draw and draw_labels use
+    pub fn to_svg(&self, window_size: (f32, f32)) -> String {
+        use std::fmt::Write;
+
+        let (width, height) = window_size;
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">\n\
+             <rect width=\"{w}\" height=\"{h}\" fill=\"black\"/>\n",
+            w = width,
+            h = height,
+        );
+        let paint = |color: Color| {
+            let [r, g, b, _]: [u8; 4] = color.into();
+            (format!("rgb({},{},{})", r, g, b), color.a)
+        };
+
+        for (from, to, color, thickness) in self.collect_bond_segments() {
+            let (rgb, alpha) = paint(color);
+            let _ = writeln!(
+                svg,
+                "<line x1=\"{:.2}\" y1=\"{:.2}\" x2=\"{:.2}\" y2=\"{:.2}\" stroke=\"{}\" stroke-opacity=\"{:.3}\" stroke-width=\"{}\"/>",
+                from.x, from.y, to.x, to.y, rgb, alpha, thickness,
+            );
+        }
+
+        let alive: Vec<&Proton> = self.protons.iter().flatten().filter(|p| p.is_alive()).collect();
+        for proton in &alive {
+            let pos = proton.position();
+            for (radius, color) in proton.circle_layers() {
+                let (rgb, alpha) = paint(color);
+                let _ = writeln!(
+                    svg,
+                    "<circle cx=\"{:.2}\" cy=\"{:.2}\" r=\"{:.2}\" fill=\"{}\" fill-opacity=\"{:.3}\"/>",
+                    pos.x, pos.y, radius, rgb, alpha,
+                );
+            }
+        }
+
+        // White text with a black outline, centered on the particle
+        for proton in &alive {
+            let pos = proton.position();
+            let _ = writeln!(
+                svg,
+                "<text x=\"{:.2}\" y=\"{:.2}\" font-size=\"{}\" font-family=\"monospace\" text-anchor=\"middle\" \
+                 dominant-baseline=\"central\" fill=\"white\" stroke=\"black\" stroke-width=\"2\" paint-order=\"stroke\">{}</text>",
+                pos.x, pos.y, rendering::LABEL_FONT_SIZE, proton.get_element_label(),
+            );
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// Clear all protons (except immortal ones)
     pub fn clear(&mut self) {
-        for proton_opt in &mut self.protons {
-            if let Some(proton) = proton_opt {
-                // Preserve stable H1, He4, C12, O16 bonded, H2O, Ne20, Mg24, Si28, S32, and hydrogen compounds
-                if !proton.is_stable_hydrogen()
-                    && !proton.is_stable_helium4()
-                    && !proton.is_stable_carbon12()
-                    && !proton.is_oxygen16_bonded()
-                    && !proton.is_h2o()
-                    && !proton.is_neon20()
-                    && !proton.is_magnesium24()
-                    && !proton.is_silicon28()
-                    && !proton.is_sulfur32()
-                    && !proton.is_h2s()
-                    && !proton.is_mgh2()
-                    && !proton.is_ch4()
-                    && !proton.is_sih4() {
-                    *proton_opt = None;
-                }
-            }
-        }
-        self.next_slot = 0;
+        self.delete_element(|p| !p.is_immortal());
         self.spawn_cooldowns.clear();
     }
 
+    /// Alive particles per `cell_size` bin, keyed by cell coordinates (empty cells are left out)
+    pub fn density_grid(&self, cell_size: f32) -> Vec<((i32, i32), usize)> {
+        NeighborGrid::build(&self.protons, cell_size)
+            .cells
+            .into_iter()
+            .map(|(cell, slots)| (cell, slots.len()))
+            .collect()
+    }
+
+    /// The alive particle drawn under `point`, nearest centre first (used for hover inspection)
+    pub fn particle_at(&self, point: Vec2) -> Option<&Proton> {
+        self.slot_at(point).and_then(|slot| self.particle_in_slot(slot))
+    }
+
+    /// Slot of the particle_at `point`
+    pub fn slot_at(&self, point: Vec2) -> Option<usize> {
+        self.protons
+            .iter()
+            .enumerate()
+            .filter_map(|(slot, p)| p.as_ref().map(|p| (slot, p)))
+            .filter(|(_, p)| p.is_alive() && p.position().distance(point) <= p.radius().max(rendering::INSPECT_MIN_RADIUS))
+            .min_by(|(_, a), (_, b)| a.position().distance(point).total_cmp(&b.position().distance(point)))
+            .map(|(slot, _)| slot)
+    }
+
+    /// The alive particle in `slot`, if any
+    pub fn particle_in_slot(&self, slot: usize) -> Option<&Proton> {
+        self.protons.get(slot).and_then(Option::as_ref).filter(|p| p.is_alive())
+    }
+
+    /// Alive partners of the particle in `slot` across every bond type, sorted and each listed once
+    pub fn bonds_of(&self, slot: usize) -> Vec<usize> {
+        let Some(proton) = self.particle_in_slot(slot) else { return Vec::new() };
+        let mut bonds: Vec<usize> = proton
+            .bond_slots()
+            .into_iter()
+            .filter(|&partner| partner != slot && self.particle_in_slot(partner).is_some())
+            .collect();
+        bonds.sort_unstable();
+        bonds.dedup();
+        bonds
+    }
+
+    /// Smallest box holding every alive particle, radius included - `None` for an empty pond
+    pub fn bounding_box(&self) -> Option<Rect> {
+        let mut alive = self.protons.iter().flatten().filter(|p| p.is_alive());
+        let first = alive.next()?;
+        let (mut min, mut max) = (first.position() - first.radius(), first.position() + first.radius());
+        for proton in alive {
+            min = min.min(proton.position() - proton.radius());
+            max = max.max(proton.position() + proton.radius());
+        }
+        Some(Rect::new(min.x, min.y, max.x - min.x, max.y - min.y))
+    }
+
+    /// Mean position of every alive particle - `None` for an empty pond
+    pub fn centroid(&self) -> Option<Vec2> {
+        let (sum, count) = self.protons
+            .iter()
+            .flatten()
+            .filter(|p| p.is_alive())
+            .fold((Vec2::ZERO, 0), |(sum, count), p| (sum + p.position(), count + 1));
+        (count > 0).then(|| sum / count as f32)
+    }
+
+    /// The alive particle carrying lineage `id` (it may have fused into a heavier element since)
+    pub fn find_by_id(&self, id: u64) -> Option<&Proton> {
+        self.protons.iter().flatten().find(|p| p.is_alive() && p.id() == id)
+    }
+
+    /// Delete non-stable protons whose position lies inside `rect` (stable elements are kept)
+    pub fn clear_in_rect(&mut self, rect: Rect) {
+        self.delete_element(|p| rect.contains(p.position()) && !p.is_immortal());
+    }
+
+    /// Delete every proton inside `rect`, including stable/immortal elements
+    pub fn clear_all_in_rect(&mut self, rect: Rect) {
+        self.delete_element(|p| rect.contains(p.position()));
+    }
+
     /// Delete all stable H protons
     pub fn delete_stable_hydrogen(&mut self) {
-        for proton_opt in &mut self.protons {
-            if let Some(proton) = proton_opt {
-                if proton.is_stable_hydrogen() {
-                    *proton_opt = None;
-                }
+        self.delete_element(Proton::is_stable_hydrogen);
+    }
+
+    /// Delete every particle matching `predicate`, stable elements included - returns how many were removed
+    pub fn delete_element(&mut self, predicate: impl Fn(&Proton) -> bool) -> usize {
+        let mut deleted = 0;
+        for slot in 0..self.protons.len() {
+            if self.protons[slot].as_ref().is_some_and(&predicate) {
+                self.remove_slot(slot);
+                deleted += 1;
             }
         }
+        deleted
     }
 
-    /// Clear ALL protons including stable/immortal elements
+    /// Clear ALL protons, immortal elements included
     pub fn clear_all(&mut self) {
-        for proton_opt in &mut self.protons {
-            *proton_opt = None;
+        self.delete_element(|_| true);
+    }
+
+    /// Every living particle as a base64 snapshot string (see snapshot.rs for the format)
+    pub fn to_base64(&self) -> String {
+        let records: Vec<ParticleRecord> = self.protons
+            .iter()
+            .flatten()
+            .filter(|p| p.is_alive())
+            .map(|p| ParticleRecord {
+                // Stable H and deuterium share the "H" display label
+                label: if p.is_stable_hydrogen() { "H1".to_string() } else { p.get_element_label() },
+                position: p.position().into(),
+                velocity: p.velocity().into(),
+                energy: p.energy(),
+                charge: p.charge(),
+                neutron_count: p.neutron_count(),
+            })
+            .collect();
+        snapshot::encode(&records)
+    }
+
+    /// Replace every particle with the contents of a to_base64 string
+    /// Leaves the current state untouched if the string doesn't decode; returns the number loaded
+    pub fn load_base64(&mut self, encoded: &str) -> Result<usize, String> {
+        let records = snapshot::decode(encoded)?;
+        Ok(self.load_records(&records))
+    }
+
+    /// Replace every particle with `records`; returns the number loaded (the rest don't fit under the slot cap)
+    /// Loaded particles are stamped so draw can flash them for LOAD_FLASH_TIME
+    pub fn load_records(&mut self, records: &[ParticleRecord]) -> usize {
+        self.clear_all();
+        let mut loaded = 0;
+        for record in records {
+            let mut proton = Self::restore_particle(record);
+            proton.set_spawned_at(Some(self.elapsed_time));
+            if self.insert_proton(proton) {
+                loaded += 1;
+            }
+        }
+        loaded
+    }
+
+    /// Replace every particle with the starting setup of the built-in scenario `name`, laid out around `center`
+    /// Returns the scenario so the caller can apply its selected element, camera and guide text
+    pub fn load_scenario(&mut self, name: &str, center: Vec2) -> Option<&'static Scenario> {
+        let scenario = scenario::find(name)?;
+        self.load_records(&(scenario.particles)(center));
+        Some(scenario)
+    }
+
+    /// Rebuild a snapshot particle - by element name when spawnable, otherwise from its raw nucleus
+    fn restore_particle(record: &ParticleRecord) -> Proton {
+        let position = Vec2::from(record.position);
+        let velocity = Vec2::from(record.velocity);
+
+        // A bonded O16 half is labelled "O16" too - only rebuild by name when the nucleus matches
+        let built = Self::build_element(&record.label, position, velocity)
+            .filter(|p| p.charge() == record.charge && p.neutron_count() == record.neutron_count);
+        if let Some(mut proton) = built {
+            proton.set_energy(record.energy);
+            return proton;
+        }
+
+        match record.label.as_str() {
+            "e+" | "e-" => Self::new_lepton(position, velocity, record.charge),
+            "H" => {
+                let mut deuterium = Proton::new(position, velocity, Color::from_rgba(200, 200, 200, 255), record.energy, 1);
+                deuterium.become_deuterium();
+                deuterium.set_max_lifetime(proton::INFINITE_LIFETIME);
+                deuterium
+            }
+            _ => {
+                // Half of a bonded O16 pair comes back as its bare C12 / He4
+                let mut nucleus = Proton::new(position, velocity, WHITE, record.energy, record.charge);
+                nucleus.set_neutron_count(record.neutron_count);
+                nucleus
+            }
         }
     }
 
-    /// Get proton count (excluding stable hydrogen, He4, C12, O16 bonded, H2O, Ne20, Mg24, Si28, S32, and hydrogen compounds)
+    /// Internal energy plus kinetic energy (1/2 m v²) of every living particle
+    /// Physics doesn't enforce conservation - this only makes the bookkeeping visible
+    pub fn total_energy(&self) -> f32 {
+        self.protons
+            .iter()
+            .flatten()
+            .filter(|p| p.is_alive())
+            .map(|p| p.energy() + 0.5 * p.mass() * p.velocity().length_squared())
+            .sum()
+    }
+
+    /// Total charge and nucleon count of every living particle, leptons included (see totals_of)
+    /// A bonded (C12, He4) pair adds up to exactly one O16, so it needs no special case
+    pub fn conserved_totals(&self) -> (i64, i64) {
+        self.protons
+            .iter()
+            .flatten()
+            .filter(|p| p.is_alive())
+            .map(Self::totals_of)
+            .fold((0, 0), |(charge, neutrons), (c, n)| (charge + c, neutrons + n))
+    }
+
+    /// (charge, nucleons) one particle carries - the light kinds' raw fields are labels rather than counts
+    /// (neutral D is a bare deuteron, H1 a deuterium atom), and hydrides keep one nucleon per ligand in neither field
+    fn totals_of(proton: &Proton) -> (i64, i64) {
+        let charge = proton.charge() as i64;
+        let ligands = match proton.element_kind() {
+            ElementKind::Lepton => return (charge, 0),
+            ElementKind::FreeHydrogen => return (charge, 1),
+            ElementKind::Deuterium => return (1, 2),
+            ElementKind::Hydrogen1 => return (0, 2),
+            ElementKind::Tritium => return (1, 3),
+            ElementKind::Helium3 => return (2, 3),
+            ElementKind::Water | ElementKind::HydrogenSulfide | ElementKind::MagnesiumHydride => 2,
+            ElementKind::Methane | ElementKind::Silane => 4,
+            _ => 0,
+        };
+        (charge, charge + proton.neutron_count() as i64 + ligands)
+    }
+
+    /// Bonded particles flagged as vibrating without settling (see Proton::is_oscillating)
+    pub fn oscillating_count(&self) -> usize {
+        self.protons.iter().flatten().filter(|p| p.is_alive() && p.is_oscillating()).count()
+    }
+
+    /// Get proton count (excluding immortal elements and compounds - see ElementKind::is_immortal)
     pub fn get_proton_count(&self) -> usize {
         self.protons
             .iter()
             .filter(|p| {
                 if let Some(proton) = p {
-                    proton.is_alive()
-                        && !proton.is_stable_hydrogen()
-                        && !proton.is_stable_helium4()
-                        && !proton.is_stable_carbon12()
-                        && !proton.is_oxygen16_bonded()
-                        && !proton.is_h2o()
-                        && !proton.is_neon20()
-                        && !proton.is_magnesium24()
-                        && !proton.is_silicon28()
-                        && !proton.is_sulfur32()
-                        && !proton.is_h2s()
-                        && !proton.is_mgh2()
-                        && !proton.is_ch4()
-                        && !proton.is_sih4()
+                    proton.is_alive() && !proton.is_immortal()
                 } else {
                     false
                 }
@@ -537,181 +1297,211 @@ impl ProtonManager {
 
     /// Update physics for all protons
     fn update_proton_physics(&mut self, delta_time: f32, window_size: (f32, f32)) {
+        let arena = self.arena_rect(window_size);
+        // Frozen particles are already held still by their lattice, so the medium leaves them alone
+        let drag_factor = (1.0 - self.medium.drag() * delta_time).max(0.0);
         for proton_opt in &mut self.protons {
             if let Some(proton) = proton_opt {
                 if proton.is_alive() {
-                    proton.update(delta_time, window_size);
+                    if self.gravity_enabled {
+                        Self::apply_gravity(proton, delta_time);
+                    }
+                    if drag_factor < 1.0 && !proton.is_frozen() {
+                        proton.set_velocity(proton.velocity() * drag_factor);
+                    }
+                    proton.update(delta_time, arena, self.boundary_mode);
                 }
             }
         }
     }
 
+    /// Apply pond.toml's nucleon radius scaling to every particle (no-op while it is off)
+    fn refresh_radii(&mut self) {
+        for proton in self.protons.iter_mut().flatten() {
+            let nucleons = proton.charge().abs() + proton.neutron_count();
+            if let Some(radius) = self.config.nucleon_scaled_radius(proton.energy(), nucleons) {
+                proton.set_radius(radius);
+            }
+        }
+    }
+
+    /// Layered view: pull a particle down by its nucleon count against the buoyant medium, with drag
+    /// so the pond settles into strata - hydrogen and helium on top, the heaviest elements at the bottom
+    /// Crystals keep their lattice in place and leptons are too short-lived to settle
+    fn apply_gravity(proton: &mut Proton, delta_time: f32) {
+        if proton.is_frozen() || proton.is_lepton() {
+            return;
+        }
+        let nucleons = (proton.charge().abs() + proton.neutron_count()).max(1) as f32;
+        let weight = (1.0 - pm::GRAVITY_BUOYANT_NUCLEONS / nucleons).max(-1.0);
+        let velocity = proton.velocity() + vec2(0.0, weight * pm::GRAVITY_STRENGTH * delta_time);
+        proton.set_velocity(velocity * (-pm::GRAVITY_DRAG * delta_time).exp());
+    }
+
+    /// Vector from one position to another
+    /// In Wrap mode this uses the nearest toroidal image so pairs across an edge
+    /// attract/bond through the seam instead of across the whole screen
+    fn separation(&self, from: Vec2, to: Vec2) -> Vec2 {
+        Self::wrapped_separation(self.boundary_mode, self.world, from, to)
+    }
+
+    fn wrapped_separation(boundary_mode: BoundaryMode, world: Rect, from: Vec2, to: Vec2) -> Vec2 {
+        let mut delta = to - from;
+
+        if boundary_mode == BoundaryMode::Wrap {
+            let (width, height) = (world.w, world.h);
+            if delta.x > width * 0.5 {
+                delta.x -= width;
+            } else if delta.x < -width * 0.5 {
+                delta.x += width;
+            }
+            if delta.y > height * 0.5 {
+                delta.y -= height;
+            } else if delta.y < -height * 0.5 {
+                delta.y += height;
+            }
+        }
+
+        delta
+    }
+
     /// Apply charge-based forces between protons
     fn apply_charge_forces(&mut self, delta_time: f32) {
-        // Collect all charged proton data (H+ and H-) - now including radius for bounce threshold
-        let mut charged_protons: Vec<(usize, Vec2, i32, f32, f32)> = Vec::new();
-        // Collect neutral H (deuterium) data - now including radius
-        let mut neutral_h: Vec<(usize, Vec2, f32, f32)> = Vec::new();
-        // Collect He4 data - now including radius
-        let mut he4_protons: Vec<(usize, Vec2, f32, f32)> = Vec::new();
+        // Force groups, as (slot, position, charge, radius)
+        type Member = (usize, Vec2, i32, f32);
+        // H+ (charge=1) and H- (charge=-1) push and pull each other by charge
+        let mut charged_protons: Vec<Member> = Vec::new();
+        // Neutral H (deuterium) clusters
+        let mut neutral_h: Vec<Member> = Vec::new();
+        // He4 clusters
+        let mut he4_protons: Vec<Member> = Vec::new();
+        // Liquid molecules, one cohesion group per molecule kind (frozen ice is held by its bonds instead)
+        let mut molecules: Vec<(ElementKind, Vec<Member>)> = Vec::new();
 
         for (i, proton_opt) in self.protons.iter().enumerate() {
             if let Some(proton) = proton_opt {
                 if proton.is_alive() {
                     let charge = proton.charge();
                     let neutron_count = proton.neutron_count();
+                    let member = (i, proton.position(), charge, proton.radius());
 
-                    // H+ (charge=1) and H- (charge=-1) participate in charge forces
                     if charge == 1 || charge == -1 {
-                        charged_protons.push((i, proton.position(), charge, proton.mass(), proton.radius()));
-                    }
-                    // H (charge=0, neutron=1) participates in clustering
-                    else if charge == 0 && neutron_count == 1 {
-                        neutral_h.push((i, proton.position(), proton.mass(), proton.radius()));
-                    }
-                    // He4 (charge=2, neutron=2) participates in clustering
-                    else if charge == 2 && neutron_count == 2 {
-                        he4_protons.push((i, proton.position(), proton.mass(), proton.radius()));
+                        charged_protons.push(member);
+                    } else if charge == 0 && neutron_count == 1 {
+                        neutral_h.push(member);
+                    } else if charge == 2 && neutron_count == 2 {
+                        he4_protons.push(member);
+                    } else if !proton.is_water_frozen() && !proton.is_crystallized() {
+                        let kind = proton.element_kind();
+                        if self.config.cohesion_strength(kind) > 0.0 {
+                            match molecules.iter_mut().find(|(k, _)| *k == kind) {
+                                Some((_, group)) => group.push(member),
+                                None => molecules.push((kind, vec![member])),
+                            }
+                        }
                     }
                 }
             }
         }
 
-        // Calculate forces for all pairs
-        let mut forces: Vec<Vec2> = vec![Vec2::ZERO; self.protons.len()];
-
-        for i in 0..charged_protons.len() {
-            for j in (i + 1)..charged_protons.len() {
-                let (idx1, pos1, charge1, mass1, r1) = charged_protons[i];
-                let (idx2, pos2, charge2, mass2, r2) = charged_protons[j];
-
-                let delta = pos2 - pos1;
-                let dist_squared = delta.length_squared();
-                let dist = dist_squared.sqrt();
-
-                // Skip if too far apart
-                if dist > pm::CHARGE_INTERACTION_RANGE {
-                    continue;
-                }
-
-                // Skip if within bounce distance - forces must stop at same threshold where bouncing starts
-                // Bounce threshold = r1 + r2 + PROTON_BOUNCE_DISTANCE
-                let bounce_threshold = r1 + r2 + pm::PROTON_BOUNCE_DISTANCE;
-                if dist < bounce_threshold {
-                    continue;
-                }
-
-                // Avoid division by zero
-                if dist < 1.0 {
-                    continue;
-                }
-
-                let dir = delta / dist;
-
-                // Same charge = repulsion, opposite charge = attraction
-                let force_magnitude = if charge1 == charge2 {
-                    // Repulsion (H+ repels H+, H- repels H-)
-                    -pm::CHARGE_REPULSION_STRENGTH / (dist_squared + 1.0)
-                } else {
-                    // Attraction (H+ attracts H-)
-                    pm::CHARGE_ATTRACTION_STRENGTH / (dist_squared + 1.0)
-                };
+        // Compute every group's forces first (read-only, per particle), then apply them below
+        let repulsion = self.config.charge_repulsion_strength;
+        let attraction = self.config.charge_attraction_strength;
+        let h_strength = self.config.h_attraction_strength;
+        let he4_strength = self.config.he4_attraction_strength;
 
-                let force = dir * force_magnitude;
+        // Same charge = repulsion (H+ repels H+, H- repels H-), opposite charge = attraction
+        let charge_forces = self.gather_group_forces(&charged_protons, self.config.charge_interaction_range, |charge1, charge2| {
+            if charge1 == charge2 { -repulsion } else { attraction }
+        });
+        let h_forces = self.gather_group_forces(&neutral_h, self.config.h_attraction_range, |_, _| h_strength);
+        let he4_forces = self.gather_group_forces(&he4_protons, self.config.he4_attraction_range, |_, _| he4_strength);
 
-                // Apply equal and opposite forces
-                forces[idx1] += force;
-                forces[idx2] -= force;
+        let mut forces: Vec<Vec2> = vec![Vec2::ZERO; self.protons.len()];
+        for (idx, force) in charge_forces.into_iter().chain(h_forces).chain(he4_forces) {
+            forces[idx] += force;
+        }
+        // Same-kind molecules condense - water into droplets, methane into pools
+        for (kind, group) in &molecules {
+            let strength = self.config.cohesion_strength(*kind);
+            for (idx, force) in self.gather_group_forces(group, self.config.molecule_cohesion_range, |_, _| strength) {
+                forces[idx] += force;
             }
         }
 
-        // Calculate H attraction forces (neutral deuterium clustering)
-        for i in 0..neutral_h.len() {
-            for j in (i + 1)..neutral_h.len() {
-                let (idx1, pos1, _mass1, r1) = neutral_h[i];
-                let (idx2, pos2, _mass2, r2) = neutral_h[j];
-
-                let delta = pos2 - pos1;
-                let dist_squared = delta.length_squared();
-                let dist = dist_squared.sqrt();
-
-                // Skip if too far apart
-                if dist > pm::H_ATTRACTION_RANGE {
-                    continue;
-                }
-
-                // Skip if within bounce distance - forces must stop at same threshold where bouncing starts
-                let bounce_threshold = r1 + r2 + pm::PROTON_BOUNCE_DISTANCE;
-                if dist < bounce_threshold {
-                    continue;
-                }
-
-                // Avoid division by zero
-                if dist < 1.0 {
-                    continue;
+        // Apply accumulated forces to velocities
+        for (i, force) in forces.iter().enumerate() {
+            if force.length_squared() > 0.0001 {
+                if let Some(proton) = &mut self.protons[i] {
+                    if proton.is_alive() {
+                        proton.apply_force(*force, delta_time);
+                    }
                 }
-
-                let dir = delta / dist;
-
-                // Attraction force for H clustering
-                let force_magnitude = pm::H_ATTRACTION_STRENGTH / (dist_squared + 1.0);
-                let force = dir * force_magnitude;
-
-                // Apply equal and opposite forces
-                forces[idx1] += force;
-                forces[idx2] -= force;
             }
         }
+    }
 
-        // Calculate He4 attraction forces (helium clustering)
-        for i in 0..he4_protons.len() {
-            for j in (i + 1)..he4_protons.len() {
-                let (idx1, pos1, _mass1, r1) = he4_protons[i];
-                let (idx2, pos2, _mass2, r2) = he4_protons[j];
+    /// Net force on each member of one force group from every other member in `range`
+    /// Members are (slot, position, charge, radius); `strength(charge1, charge2)` is positive for attraction
+    fn gather_group_forces(
+        &self,
+        members: &[(usize, Vec2, i32, f32)],
+        range: f32,
+        strength: impl Fn(i32, i32) -> f32 + Send + Sync,
+    ) -> Vec<(usize, Vec2)> {
+        let (boundary_mode, world) = (self.boundary_mode, self.world);
 
-                let delta = pos2 - pos1;
-                let dist_squared = delta.length_squared();
-                let dist = dist_squared.sqrt();
+        // Force on member `a` from member `b`
+        let pair_force = |a: usize, b: usize| -> Vec2 {
+            let (_, pos1, charge1, r1) = members[a];
+            let (_, pos2, charge2, r2) = members[b];
 
-                // Skip if too far apart
-                if dist > pm::HE4_ATTRACTION_RANGE {
-                    continue;
-                }
+            let delta = Self::wrapped_separation(boundary_mode, world, pos1, pos2);
+            let dist_squared = delta.length_squared();
+            let dist = dist_squared.sqrt();
 
-                // Skip if within bounce distance - forces must stop at same threshold where bouncing starts
-                let bounce_threshold = r1 + r2 + pm::PROTON_BOUNCE_DISTANCE;
-                if dist < bounce_threshold {
-                    continue;
-                }
+            // Skip if too far apart
+            if dist > range {
+                return Vec2::ZERO;
+            }
 
-                // Avoid division by zero
-                if dist < 1.0 {
-                    continue;
-                }
+            // Skip if within bounce distance - forces must stop at same threshold where bouncing starts
+            // Bounce threshold = r1 + r2 + PROTON_BOUNCE_DISTANCE
+            let bounce_threshold = r1 + r2 + pm::PROTON_BOUNCE_DISTANCE;
+            if dist < bounce_threshold {
+                return Vec2::ZERO;
+            }
 
-                let dir = delta / dist;
+            // Avoid division by zero
+            if dist < 1.0 {
+                return Vec2::ZERO;
+            }
 
-                // Attraction force for He4 clustering
-                let force_magnitude = pm::HE4_ATTRACTION_STRENGTH / (dist_squared + 1.0);
-                let force = dir * force_magnitude;
+            delta / dist * strength(charge1, charge2) / (dist_squared + 1.0)
+        };
 
-                // Apply equal and opposite forces
-                forces[idx1] += force;
-                forces[idx2] -= force;
-            }
-        }
+        // Threaded: every member sums its own pairs, so no two threads write the same force
+        // (each pair is evaluated twice - worth it once the work is split across cores)
+        #[cfg(feature = "parallel")]
+        let forces = parallel::map_indices(members.len(), |a| {
+            (0..members.len()).filter(|&b| b != a).map(|b| pair_force(a, b)).sum::<Vec2>()
+        });
 
-        // Apply accumulated forces to velocities
-        for (i, force) in forces.iter().enumerate() {
-            if force.length_squared() > 0.0001 {
-                if let Some(proton) = &mut self.protons[i] {
-                    if proton.is_alive() {
-                        let acceleration = *force / proton.mass();
-                        proton.add_velocity(acceleration * delta_time);
-                    }
+        // Single thread: each pair once, equal and opposite
+        #[cfg(not(feature = "parallel"))]
+        let forces = {
+            let mut forces = vec![Vec2::ZERO; members.len()];
+            for a in 0..members.len() {
+                for b in (a + 1)..members.len() {
+                    let force = pair_force(a, b);
+                    forces[a] += force;
+                    forces[b] -= force;
                 }
             }
-        }
+            forces
+        };
+
+        members.iter().map(|member| member.0).zip(forces).collect()
     }
 
     /// Apply repulsion force from red (low-frequency) waves to H-, He3, He4, and H protons
@@ -730,8 +1520,8 @@ impl ProtonManager {
                     let charge = proton.charge();
                     let neutron_count = proton.neutron_count();
 
-                    // Skip O16 bonded particles
-                    if proton.is_oxygen16_bonded() {
+                    // Skip O16 (bonded pairs and consolidated)
+                    if proton.is_oxygen16_bonded() || proton.is_oxygen16() {
                         continue;
                     }
 
@@ -744,7 +1534,7 @@ impl ProtonManager {
                         || proton.is_h2o(); // H2O molecules
 
                     if is_affected {
-                        let is_frozen = proton.is_crystallized();
+                        let is_frozen = proton.is_crystallized() || proton.is_water_frozen();
                         affected_protons.push((i, proton.position(), proton.mass(), is_frozen));
                     }
                 }
@@ -764,6 +1554,12 @@ impl ProtonManager {
                     continue; // Skip fast/blue rings
                 }
 
+                // Faded waves have no energy left to push with
+                let ring_energy = ring.get_energy();
+                if ring_energy <= 0.0 {
+                    continue;
+                }
+
                 // Get ring center and radius
                 let ring_center = ring.get_center();
                 let ring_radius = ring.get_radius();
@@ -786,8 +1582,8 @@ impl ProtonManager {
                             hit_by_dark_red[*idx] = true;
                         }
 
-                        // Apply radial repulsion force
-                        let force_magnitude = pm::RED_WAVE_REPULSION_STRENGTH * proximity_factor;
+                        // Apply radial repulsion force, weakened as the wave dissipates
+                        let force_magnitude = self.config.red_wave_repulsion_strength * proximity_factor * ring_energy;
                         forces[*idx] += dir * force_magnitude;
                     }
                 }
@@ -795,10 +1591,11 @@ impl ProtonManager {
         }
 
         // Process dark red wave hits and melting
+        let mut melted_water: Vec<usize> = Vec::new();
         for (i, was_hit) in hit_by_dark_red.iter().enumerate() {
             if *was_hit {
                 if let Some(proton) = &mut self.protons[i] {
-                    if proton.is_alive() && proton.is_crystallized() {
+                    if proton.is_alive() && (proton.is_crystallized() || proton.is_water_frozen()) {
                         // Check if enough time has passed since last hit (prevent double-counting same wave)
                         let time_since_last_hit = self.elapsed_time - proton.last_red_wave_hit_time();
 
@@ -809,17 +1606,25 @@ impl ProtonManager {
 
                             // Check if we've reached melting threshold
                             if proton.red_wave_hits() >= pm::RED_WAVE_HITS_TO_MELT {
-                                // MELT: Break crystal bonds and decrystallize
-                                proton.set_crystallized(false);
-                                proton.clear_crystal_bonds();
+                                // MELT: Break crystal bonds and decrystallize - ice drops its hydrogen bonds instead
+                                if proton.is_water_frozen() {
+                                    proton.clear_water_h_bonds();
+                                    proton.set_water_frozen(false);
+                                    proton.set_ice_crystal_group(None);
+                                    melted_water.push(i);
+                                } else {
+                                    proton.set_crystallized(false);
+                                    proton.clear_crystal_bonds();
+                                }
                                 proton.reset_red_wave_hits();
                                 proton.set_freeze_cooldown(pm::H_CRYSTAL_FREEZE_COOLDOWN);
 
-                                // Add outward "melting" velocity
+                                // Add outward "melting" velocity - the only push a melting crystal gets this frame
                                 if forces[i].length() > 0.01 {
                                     let escape_dir = forces[i].normalize();
                                     proton.add_velocity(escape_dir * 30.0);
                                 }
+                                forces[i] = Vec2::ZERO;
                             }
                         }
                     }
@@ -827,118 +1632,594 @@ impl ProtonManager {
             }
         }
 
-        // Apply repulsion forces to non-frozen protons
+        // Frozen neighbours keep their bonds between frames, so drop their side of a melted molecule's bonds
+        if !melted_water.is_empty() {
+            for proton in self.protons.iter_mut().flatten() {
+                proton.retain_bonds(|idx| !melted_water.contains(&idx));
+            }
+        }
+
+        // Apply repulsion forces to non-frozen protons, capped so overlapping rings can't stack into a huge impulse
+        // Frozen particles of any lattice stay put - they only move once a dark red wave melts them
         for (i, force) in forces.iter().enumerate() {
             if force.length_squared() > 0.0001 {
                 if let Some(proton) = &mut self.protons[i] {
-                    if proton.is_alive() && !proton.is_crystallized() {
-                        let acceleration = *force / proton.mass();
-                        proton.add_velocity(acceleration * delta_time);
+                    if proton.is_alive() && !proton.is_frozen() {
+                        proton.apply_force(force.clamp_length_max(pm::RED_WAVE_MAX_REPULSION_FORCE), delta_time);
                     }
                 }
             }
         }
     }
 
-    /// Update H crystallization (gas/liquid/solid phase transitions)
-    /// Universal 8-Phase Framework for H element
-    /// Creates simple hexagons: 1 center + 6 sides arranged equidistantly
-    fn update_h_crystallization(&mut self, delta_time: f32) {
-        // ===== PHASE 1: Collect all H atoms =====
-        let mut h_protons: Vec<(usize, Vec2, Vec2)> = Vec::new();
-        for (i, proton_opt) in self.protons.iter().enumerate() {
-            if let Some(proton) = proton_opt {
-                if proton.is_alive() && proton.charge() == 0 && proton.neutron_count() == 1 {
-                    h_protons.push((i, proton.position(), proton.velocity()));
-                }
-            }
-        }
-
-        // ===== PHASE 2: Check evaporation (velocity-based phase change) =====
-        for (idx, _, vel) in &h_protons {
-            let speed = vel.length();
+    /// Blue (fast) waves heat unfrozen particles under their edge and nudge them outward, which keeps them
+    /// from freezing; red (slow) waves damp their velocity so they settle and can crystallize
+    /// Both weaken as the wave dissipates. Crystallized particles and leptons are left alone
+    fn apply_wave_thermal_effects(&mut self, delta_time: f32, ring_manager: &RingManager) {
+        let rings = ring_manager.get_all_rings();
 
-            // Use different evaporation thresholds for crystallized vs gas/liquid H
-            let evaporation_threshold = if let Some(proton) = &self.protons[*idx] {
-                if proton.is_crystallized() {
-                    pm::H_FROZEN_EVAPORATION_SPEED  // Crystallized H is much harder to evaporate
-                } else {
-                    pm::H_EVAPORATION_SPEED
-                }
-            } else {
-                pm::H_EVAPORATION_SPEED
-            };
+        for proton in self.protons.iter_mut().flatten() {
+            if !proton.is_alive() || proton.is_crystallized() || proton.is_lepton() {
+                continue;
+            }
 
-            if speed > evaporation_threshold {
-                // Moving too fast - break all bonds (evaporation/sublimation)
-                if let Some(proton) = &mut self.protons[*idx] {
-                    proton.set_crystallized(false);
-                    proton.clear_crystal_bonds();
-                    proton.reset_red_wave_hits();
-                    proton.set_h_crystal_group(None);
+            for ring in rings {
+                let ring_speed = ring.get_growth_speed();
+                let is_blue = ring_speed >= pm::BLUE_WAVE_HEAT_SPEED_THRESHOLD;
+                let is_red = ring_speed <= pm::RED_WAVE_INTERACTION_THRESHOLD;
+                let ring_energy = ring.get_energy();
+                if !(is_blue || is_red) || ring_energy <= 0.0 {
+                    continue;
                 }
-            }
-        }
 
-        // ===== PHASE 3: Clear old bonds (for non-crystallized or cooldown particles) =====
-        for (idx, _, _) in &h_protons {
-            if let Some(proton) = &self.protons[*idx] {
-                // Skip if on cooldown - these can't form new bonds
-                if proton.freeze_cooldown() > 0.0 {
-                    if let Some(p) = &mut self.protons[*idx] {
-                        p.set_crystallized(false);
-                        p.clear_crystal_bonds();
-                        p.set_h_crystal_group(None);
-                    }
+                let delta = proton.position() - ring.get_center();
+                let dist_to_center = delta.length();
+                let dist_to_edge = (dist_to_center - ring.get_radius()).abs();
+                if dist_to_edge >= pm::WAVE_THERMAL_BAND {
                     continue;
                 }
+                let strength = (1.0 - dist_to_edge / pm::WAVE_THERMAL_BAND) * ring_energy * delta_time;
 
-                // Crystallized H keeps bonds (acts as seed crystal)
-                // Non-crystallized H clears bonds each frame to rebuild
-                if !proton.is_crystallized() {
-                    if let Some(p) = &mut self.protons[*idx] {
-                        p.clear_crystal_bonds();
-                        p.set_h_crystal_group(None);
+                if is_blue {
+                    proton.add_heat(pm::BLUE_WAVE_HEAT_RATE * strength);
+                    if dist_to_center > 1.0 {
+                        proton.add_velocity(delta / dist_to_center * pm::BLUE_WAVE_KICK_ACCELERATION * strength);
                     }
+                } else {
+                    let damping = (1.0 - pm::RED_WAVE_DAMPING_RATE * strength).max(0.0);
+                    proton.set_velocity(proton.velocity() * damping);
                 }
             }
         }
+    }
 
-        // ===== PHASE 4: Form new bonds (neighbor detection and cluster formation) =====
-        // Build neighbor lists for each H (with minimum spacing filter)
-        let mut neighbor_lists: Vec<Vec<usize>> = vec![Vec::new(); self.protons.len()];
-        for i in 0..h_protons.len() {
-            for j in (i + 1)..h_protons.len() {
-                let (idx1, pos1, _) = h_protons[i];
-                let (idx2, pos2, _) = h_protons[j];
+    /// Update the coarse temperature field - rings heat (blue) or cool (dark red) cells near their edge
+    /// Does nothing unless the temperature field is enabled
+    fn update_temperature_field(&mut self, delta_time: f32, window_size: (f32, f32), ring_manager: &RingManager) {
+        if !self.temperature_enabled {
+            return;
+        }
 
-                let dist = pos1.distance(pos2);
+        // Resize grid to match the window (resets the field)
+        let cols = (window_size.0 / pm::TEMPERATURE_CELL_SIZE).ceil().max(1.0) as usize;
+        let rows = (window_size.1 / pm::TEMPERATURE_CELL_SIZE).ceil().max(1.0) as usize;
+        if cols != self.temperature_cols || rows != self.temperature_rows {
+            self.temperature_cols = cols;
+            self.temperature_rows = rows;
+            self.temperature_grid = vec![0.0; cols * rows];
+        }
 
-                // Only count as neighbors if within range AND not too close
-                if dist >= pm::H_CRYSTAL_MIN_SPACING && dist < pm::H_CRYSTAL_NEIGHBOR_DISTANCE {
-                    neighbor_lists[idx1].push(idx2);
-                    neighbor_lists[idx2].push(idx1);
-                }
-            }
+        // Decay towards neutral
+        let decay = (1.0 - pm::TEMPERATURE_DECAY_RATE * delta_time).max(0.0);
+        for temperature in &mut self.temperature_grid {
+            *temperature *= decay;
         }
 
-        // Find clusters of exactly 7 H particles and assign center + 6 sides
-        let mut is_center: Vec<bool> = vec![false; self.protons.len()];
-        let mut center_bonds: Vec<Vec<usize>> = vec![Vec::new(); self.protons.len()];
+        // Deposit heat from every ring into cells near its circumference
+        for ring in ring_manager.get_all_rings() {
+            // Map ring speed to heat: slowest (dark red) = -1, fastest (blue) = +1
+            let speed_factor = (ring.get_growth_speed() - MIN_RING_SPEED) / (MAX_RING_SPEED - MIN_RING_SPEED);
+            let heat = (speed_factor.clamp(0.0, 1.0) * 2.0 - 1.0) * pm::TEMPERATURE_DEPOSIT_RATE * delta_time;
 
-        for (idx, pos, _) in &h_protons {
-            // Skip if on cooldown (already handled in Phase 3)
-            let on_cooldown = if let Some(proton) = &self.protons[*idx] {
-                proton.freeze_cooldown() > 0.0
-            } else {
-                false
-            };
+            let ring_center = ring.get_center();
+            let ring_radius = ring.get_radius();
 
-            if on_cooldown {
-                continue;
+            for row in 0..rows {
+                for col in 0..cols {
+                    let cell_center = Vec2::new(
+                        (col as f32 + 0.5) * pm::TEMPERATURE_CELL_SIZE,
+                        (row as f32 + 0.5) * pm::TEMPERATURE_CELL_SIZE,
+                    );
+                    let dist_to_edge = (cell_center.distance(ring_center) - ring_radius).abs();
+
+                    if dist_to_edge < pm::TEMPERATURE_RING_BAND {
+                        let proximity_factor = 1.0 - (dist_to_edge / pm::TEMPERATURE_RING_BAND);
+                        let cell = &mut self.temperature_grid[row * cols + col];
+                        *cell = (*cell + heat * proximity_factor).clamp(pm::TEMPERATURE_MIN, pm::TEMPERATURE_MAX);
+                    }
+                }
             }
+        }
+    }
 
-            let neighbors = &neighbor_lists[*idx];
+    /// Temperature of the grid cell under a position (0.0 when disabled or off-grid)
+    pub fn temperature_at(&self, pos: Vec2) -> f32 {
+        if !self.temperature_enabled || pos.x < 0.0 || pos.y < 0.0 {
+            return 0.0;
+        }
+
+        let col = (pos.x / pm::TEMPERATURE_CELL_SIZE) as usize;
+        let row = (pos.y / pm::TEMPERATURE_CELL_SIZE) as usize;
+        if col >= self.temperature_cols || row >= self.temperature_rows {
+            return 0.0;
+        }
+
+        self.temperature_grid[row * self.temperature_cols + col]
+    }
+
+    /// Multiplier for evaporation thresholds at a position - hot cells melt easier, cold cells harder
+    fn temperature_scale_at(&self, pos: Vec2) -> f32 {
+        1.0 - self.temperature_at(pos) * pm::TEMPERATURE_EVAPORATION_FACTOR
+    }
+
+    /// Combined evaporation multiplier: configured speed scale times the local temperature
+    fn evaporation_scale_at(&self, pos: Vec2) -> f32 {
+        self.config.evaporation_speed_scale * self.temperature_scale_at(pos)
+    }
+
+    /// Enable or disable the temperature field (disabled uses constant evaporation thresholds)
+    pub fn set_temperature_enabled(&mut self, enabled: bool) {
+        self.temperature_enabled = enabled;
+        if !enabled {
+            self.temperature_grid.iter_mut().for_each(|t| *t = 0.0);
+        }
+    }
+
+    pub fn is_temperature_enabled(&self) -> bool {
+        self.temperature_enabled
+    }
+
+    pub fn config(&self) -> &SimConfig {
+        &self.config
+    }
+
+    /// Live config - changes take effect on the next update
+    pub fn config_mut(&mut self) -> &mut SimConfig {
+        &mut self.config
+    }
+
+    pub fn reaction_flags(&self) -> ReactionFlags {
+        self.reaction_flags
+    }
+
+    /// Neutron formations and electron captures since the last reset_atom_events call
+    pub fn atom_events(&self) -> AtomEventCounts {
+        self.atom_events
+    }
+
+    /// Start a new counting window for atom_events (call once per rendered frame)
+    pub fn reset_atom_events(&mut self) {
+        self.atom_events = AtomEventCounts::default();
+    }
+
+    /// Radioactive decays since startup
+    pub fn decay_count(&self) -> u32 {
+        self.decay_count
+    }
+
+    /// H+ -> D conversions next to atoms since startup
+    pub fn neutron_formation_count(&self) -> u32 {
+        self.neutron_formation_count
+    }
+
+    /// Reactions per second for every ReactionKind (in ReactionKind::ALL order),
+    /// averaged over the last REACTION_RATE_WINDOW seconds of simulation time
+    pub fn reaction_rates(&self) -> Vec<(&'static str, f32)> {
+        let window_start = self.elapsed_time - pm::REACTION_RATE_WINDOW;
+        ReactionKind::ALL
+            .iter()
+            .map(|&(kind, label)| {
+                let count = self.reaction_log.iter().filter(|&&(t, k)| k == kind && t > window_start).count();
+                (label, count as f32 / pm::REACTION_RATE_WINDOW)
+            })
+            .collect()
+    }
+
+    /// Tally one reaction for reaction_rates, dropping tallies that left the window
+    fn record_reaction(&mut self, kind: ReactionKind) {
+        let window_start = self.elapsed_time - pm::REACTION_RATE_WINDOW;
+        while self.reaction_log.front().is_some_and(|&(t, _)| t <= window_start) {
+            self.reaction_log.pop_front();
+        }
+        self.reaction_log.push_back((self.elapsed_time, kind));
+    }
+
+    /// Take every event queued since the last call (call once per rendered frame)
+    pub fn drain_events(&mut self) -> std::vec::Drain<'_, SimEvent> {
+        self.events.drain(..)
+    }
+
+    /// Hold `target` from the next update on (None turns the controller off) - the controller starts fresh
+    pub fn set_population_target(&mut self, target: Option<PopulationTarget>) {
+        self.population_target = target;
+        self.population_integral = 0.0;
+        self.population_budget = 0.0;
+    }
+
+    /// The controller's target and how many of its element are alive right now
+    pub fn population(&self) -> Option<(&PopulationTarget, usize)> {
+        let target = self.population_target.as_ref()?;
+        Some((target, self.population_count(&target.element)))
+    }
+
+    fn population_count(&self, element: &str) -> usize {
+        self.protons.iter().flatten().filter(|p| p.is_alive() && Self::counts_as(p, element)).count()
+    }
+
+    /// Whether the population controller counts `proton` as `element`
+    fn counts_as(proton: &Proton, element: &str) -> bool {
+        if element == FREE_PROTON_NAME {
+            !proton.is_lepton() && proton.charge() == 1 && proton.neutron_count() == 0
+        } else {
+            Self::element_name(proton) == Some(element)
+        }
+    }
+
+    /// Rate-limited PI controller: the count error plus its (clamped) integral sets a spawn or removal rate,
+    /// and whole particles are spawned or removed as that rate accrues - never past the target
+    fn update_population(&mut self, delta_time: f32) {
+        let Some(target) = self.population_target.clone() else {
+            return;
+        };
+        let mut count = self.population_count(&target.element);
+        let error = target.count as f32 - count as f32;
+
+        self.population_integral = (self.population_integral + error * delta_time)
+            .clamp(-pm::POPULATION_INTEGRAL_LIMIT, pm::POPULATION_INTEGRAL_LIMIT);
+        let rate = (error * pm::POPULATION_GAIN_P + self.population_integral * pm::POPULATION_GAIN_I)
+            .clamp(-pm::POPULATION_MAX_RATE, pm::POPULATION_MAX_RATE);
+        self.population_budget += rate * delta_time;
+
+        use macroquad::rand::gen_range;
+        while self.population_budget >= 1.0 && count < target.count {
+            let margin = pm::POPULATION_SPAWN_MARGIN;
+            let position = self.world.point() + vec2(
+                gen_range(margin, (self.world.w - margin).max(margin)),
+                gen_range(margin, (self.world.h - margin).max(margin)),
+            );
+            let velocity = Vec2::from_angle(gen_range(0.0, std::f32::consts::TAU)) * pm::POPULATION_SPAWN_SPEED;
+            if !self.spawn_population_member(&target.element, position, velocity) {
+                break; // Slot cap reached or not a spawnable name
+            }
+            self.population_budget -= 1.0;
+            count += 1;
+        }
+
+        while self.population_budget <= -1.0 && count > target.count {
+            // Thin out loose particles before breaking up crystals
+            let matches = |p: &Proton| p.is_alive() && Self::counts_as(p, &target.element);
+            let slot = self.protons.iter().rposition(|p| p.as_ref().is_some_and(|p| matches(p) && !p.is_frozen()))
+                .or_else(|| self.protons.iter().rposition(|p| p.as_ref().is_some_and(matches)));
+            let Some(slot) = slot else { break };
+            self.remove_slot(slot);
+            self.population_budget += 1.0;
+            count -= 1;
+        }
+
+        // No backlog - a burst of losses is refilled at the rate limit, not all at once
+        self.population_budget = self.population_budget.clamp(-1.0, 1.0);
+    }
+
+    fn spawn_population_member(&mut self, element: &str, position: Vec2, velocity: Vec2) -> bool {
+        let position = self.spawn_clear_position(position);
+        if element == FREE_PROTON_NAME {
+            return self.spawn_proton(position, velocity, WHITE, pm::POPULATION_PROTON_ENERGY, 1);
+        }
+        match Self::build_element(element, position, velocity) {
+            Some(proton) => self.insert_proton(proton),
+            None => false,
+        }
+    }
+
+    /// Queue a `kind` event at the particle now in `slot`
+    fn push_event(&mut self, kind: SimEventKind, slot: usize) {
+        if let Some(p) = &self.protons[slot] {
+            self.events.push(SimEvent { kind, position: p.position(), element: p.get_element_label() });
+        }
+    }
+
+    /// The reaction a freshly spawned `element_type` takes part in and the relative speed it needs
+    /// (None for elements that only decay, bond or form molecules)
+    pub fn fusion_threshold(&self, element_type: &str) -> Option<(&'static str, f32)> {
+        let (kind, threshold) = match element_type {
+            "He3" => (ReactionKind::Helium3Helium3, self.config.helium3_fusion_velocity_threshold),
+            "He4" => (ReactionKind::TripleAlpha, self.config.triple_alpha_velocity_threshold),
+            "C12" => (ReactionKind::CarbonAlpha, proton::OXYGEN16_CAPTURE_VELOCITY_THRESHOLD),
+            "O16" => (ReactionKind::OxygenAlpha, proton::NEON20_CAPTURE_VELOCITY_THRESHOLD),
+            "Ne20" => (ReactionKind::NeonAlpha, proton::MAGNESIUM24_CAPTURE_VELOCITY_THRESHOLD),
+            "Mg24" => (ReactionKind::MagnesiumAlpha, proton::SILICON28_CAPTURE_VELOCITY_THRESHOLD),
+            "Si28" => (ReactionKind::SiliconAlpha, proton::SULFUR32_CAPTURE_VELOCITY_THRESHOLD),
+            _ => return None,
+        };
+        ReactionKind::ALL.iter().find(|(k, _)| *k == kind).map(|&(_, label)| (label, threshold))
+    }
+
+    /// The reaction `a` and `b` could fuse in and the relative speed it needs (None if they can't react together)
+    pub fn pair_fusion_threshold(&self, a: &Proton, b: &Proton) -> Option<(&'static str, f32)> {
+        use ElementKind::{Carbon12, Deuterium, FreeHydrogen, Helium3, Helium4, Magnesium24, Neon20, Oxygen16, Silicon28};
+
+        // Free hydrogen is told apart by charge, like the pair phase of fusion
+        let key = |p: &Proton| (p.element_kind(), p.charge());
+        let (kind, threshold) = match (key(a), key(b)) {
+            ((FreeHydrogen, 1), (FreeHydrogen, 1)) => (ReactionKind::ProtonProton, self.config.pp_chain_velocity_threshold),
+            ((Deuterium, _), (Deuterium, _)) => (ReactionKind::DeuteriumDeuterium, self.config.dd_fusion_velocity_threshold),
+            ((Deuterium, _), (FreeHydrogen, 1)) | ((FreeHydrogen, 1), (Deuterium, _)) => {
+                (ReactionKind::DeuteriumProton, self.config.deuterium_fusion_velocity_threshold)
+            }
+            ((FreeHydrogen, -1), (FreeHydrogen, 1)) | ((FreeHydrogen, 1), (FreeHydrogen, -1)) => (ReactionKind::NegativeProton, 0.0),
+            ((Helium3, _), (Helium3, _)) => return self.fusion_threshold("He3"),
+            ((Helium4, _), (Helium4, _)) => return self.fusion_threshold("He4"),
+            // Alpha capture is listed under the heavier partner
+            ((Helium4, _), (heavier @ (Carbon12 | Oxygen16 | Neon20 | Magnesium24 | Silicon28), _))
+            | ((heavier @ (Carbon12 | Oxygen16 | Neon20 | Magnesium24 | Silicon28), _), (Helium4, _)) => {
+                return heavier.counted_name().and_then(|name| self.fusion_threshold(name));
+            }
+            _ => return None,
+        };
+        ReactionKind::ALL.iter().find(|(k, _)| *k == kind).map(|&(_, label)| (label, threshold))
+    }
+
+    /// Turn one reaction on or off
+    pub fn toggle_reaction(&mut self, flag: ReactionFlags) {
+        self.reaction_flags.toggle(flag);
+    }
+
+    /// Show or hide the lifetime arcs drawn around non-stable protons
+    pub fn set_lifetime_display(&mut self, enabled: bool) {
+        self.show_lifetime = enabled;
+    }
+
+    pub fn is_lifetime_display_enabled(&self) -> bool {
+        self.show_lifetime
+    }
+
+    /// Show or hide the velocity arrows drawn on non-frozen protons
+    pub fn set_velocity_display(&mut self, enabled: bool) {
+        self.show_velocity = enabled;
+    }
+
+    pub fn is_velocity_display_enabled(&self) -> bool {
+        self.show_velocity
+    }
+
+    /// Switch the layered (gravity) view on or off
+    pub fn set_gravity(&mut self, enabled: bool) {
+        self.gravity_enabled = enabled;
+    }
+
+    pub fn is_gravity_enabled(&self) -> bool {
+        self.gravity_enabled
+    }
+
+    pub fn boundary_mode(&self) -> BoundaryMode {
+        self.boundary_mode
+    }
+
+    pub fn medium(&self) -> Medium {
+        self.medium
+    }
+
+    pub fn set_medium(&mut self, medium: Medium) {
+        self.medium = medium;
+    }
+
+    pub fn set_boundary_mode(&mut self, mode: BoundaryMode) {
+        self.boundary_mode = mode;
+    }
+
+    /// Walls the particles bounce off (or wrap across) - the player's arena, or the whole window
+    pub fn arena_rect(&self, window_size: (f32, f32)) -> Rect {
+        self.arena.unwrap_or(Rect::new(0.0, 0.0, window_size.0, window_size.1))
+    }
+
+    /// Move the walls in to `arena` - None puts them back on the window edges
+    pub fn set_arena(&mut self, arena: Option<Rect>) {
+        self.arena = arena;
+    }
+
+    pub fn has_custom_arena(&self) -> bool {
+        self.arena.is_some()
+    }
+
+    /// Pull (positive strength) or push (negative strength) non-frozen protons toward a point
+    /// Falls off as 1/r² and is ignored beyond `radius`; strength is applied as a velocity change
+    pub fn apply_point_force(&mut self, center: Vec2, strength: f32, radius: f32) {
+        for proton in self.protons.iter_mut().flatten() {
+            if !proton.is_alive() || proton.is_crystallized() {
+                continue;
+            }
+
+            let to_center = Self::wrapped_separation(self.boundary_mode, self.world, proton.position(), center);
+            let distance = to_center.length();
+            if distance < EPSILON || distance > radius {
+                continue;
+            }
+
+            let softened = distance.max(pm::POINT_FORCE_MIN_DISTANCE);
+            let dir = to_center / distance;
+            proton.add_velocity(dir * strength / (softened * softened));
+        }
+    }
+
+    /// Remove crystal bonds whose partner is gone or farther than `breaking_distance`
+    /// A particle that loses a bond is un-crystallized so the next pass rebuilds its bonds
+    fn break_stretched_bonds(
+        &mut self,
+        atoms: &[(usize, Vec2, Vec2)],
+        breaking_distance: f32,
+        bonds: fn(&Proton) -> &Vec<usize>,
+        set_bonds: fn(&mut Proton, Vec<usize>),
+        set_crystallized: fn(&mut Proton, bool),
+    ) {
+        let breaking_distance_sq = breaking_distance * breaking_distance;
+
+        for (idx, _, _) in atoms {
+            let (kept, broke_any) = match &self.protons[*idx] {
+                Some(proton) => {
+                    let pos = proton.position();
+                    let current = bonds(proton);
+                    let kept: Vec<usize> = current
+                        .iter()
+                        .copied()
+                        .filter(|&bond_idx| match &self.protons[bond_idx] {
+                            Some(partner) => partner.is_alive() && partner.position().distance_squared(pos) <= breaking_distance_sq,
+                            None => false,
+                        })
+                        .collect();
+                    let broke_any = kept.len() < current.len();
+                    (kept, broke_any)
+                }
+                None => continue,
+            };
+
+            if broke_any {
+                if let Some(proton) = &mut self.protons[*idx] {
+                    set_bonds(proton, kept);
+                    set_crystallized(proton, false);
+                }
+            }
+        }
+    }
+
+    /// Collision/fusion heat makes a particle evaporate as if it were moving too fast
+    fn is_hot_slot(&self, idx: usize) -> bool {
+        self.protons[idx].as_ref().is_some_and(Proton::is_hot)
+    }
+
+    /// Warm the particle in `idx`, if any
+    fn heat_slot(&mut self, idx: usize, heat: f32) {
+        if let Some(proton) = &mut self.protons[idx] {
+            proton.add_heat(heat);
+        }
+    }
+
+    /// Neighbor lists for one crystallization pass: for each of `atoms`, the other `atoms`
+    /// at least `min_spacing` and less than `max_distance` away, in slot order
+    /// Looks candidates up in the shared neighbor grid instead of comparing every pair
+    fn crystal_neighbor_lists(&self, atoms: &[(usize, Vec2, Vec2)], min_spacing: f32, max_distance: f32) -> Vec<Vec<usize>> {
+        let mut member_positions: Vec<Option<Vec2>> = vec![None; self.protons.len()];
+        for &(idx, pos, _) in atoms {
+            member_positions[idx] = Some(pos);
+        }
+
+        let grid = &self.neighbor_grid;
+        let member_positions = &member_positions;
+        let found = parallel::map_indices(atoms.len(), |a| {
+            let (idx, pos, _) = atoms[a];
+            let mut neighbors: Vec<usize> = grid
+                .candidates(pos, max_distance)
+                .filter(|&other| {
+                    let Some(other_pos) = member_positions[other] else { return false };
+                    let dist = pos.distance(other_pos);
+                    other != idx && dist >= min_spacing && dist < max_distance
+                })
+                .collect();
+            neighbors.sort_unstable();
+            (idx, neighbors)
+        });
+
+        let mut neighbor_lists: Vec<Vec<usize>> = vec![Vec::new(); self.protons.len()];
+        for (idx, neighbors) in found {
+            neighbor_lists[idx] = neighbors;
+        }
+        neighbor_lists
+    }
+
+    /// Update H crystallization (gas/liquid/solid phase transitions)
+    /// Universal 8-Phase Framework for H element
+    /// Creates simple hexagons: 1 center + 6 sides arranged equidistantly
+    fn update_h_crystallization(&mut self, delta_time: f32) {
+        // ===== PHASE 1: Collect all H atoms =====
+        let mut h_protons: Vec<(usize, Vec2, Vec2)> = Vec::new();
+        for (i, proton_opt) in self.protons.iter().enumerate() {
+            if let Some(proton) = proton_opt {
+                if proton.is_alive() && proton.charge() == 0 && proton.neutron_count() == 1 {
+                    h_protons.push((i, proton.position(), proton.velocity()));
+                }
+            }
+        }
+
+        // ===== PHASE 2: Check evaporation (velocity-based phase change) =====
+        for (idx, pos, vel) in &h_protons {
+            let speed = vel.length();
+
+            // Use different evaporation thresholds for crystallized vs gas/liquid H
+            let evaporation_threshold = if let Some(proton) = &self.protons[*idx] {
+                if proton.is_crystallized() {
+                    pm::H_FROZEN_EVAPORATION_SPEED  // Crystallized H is much harder to evaporate
+                } else {
+                    pm::H_EVAPORATION_SPEED
+                }
+            } else {
+                pm::H_EVAPORATION_SPEED
+            };
+
+            if speed > evaporation_threshold * self.evaporation_scale_at(*pos) || self.is_hot_slot(*idx) {
+                // Moving too fast - break all bonds (evaporation/sublimation)
+                if let Some(proton) = &mut self.protons[*idx] {
+                    proton.set_crystallized(false);
+                    proton.clear_crystal_bonds();
+                    proton.reset_red_wave_hits();
+                    proton.set_h_crystal_group(None);
+                }
+            }
+        }
+
+        // ===== PHASE 3: Clear old bonds (for non-crystallized or cooldown particles) =====
+        for (idx, _, _) in &h_protons {
+            if let Some(proton) = &self.protons[*idx] {
+                // Skip if on cooldown - these can't form new bonds
+                if proton.freeze_cooldown() > 0.0 {
+                    if let Some(p) = &mut self.protons[*idx] {
+                        p.set_crystallized(false);
+                        p.clear_crystal_bonds();
+                        p.set_h_crystal_group(None);
+                    }
+                    continue;
+                }
+
+                // Crystallized H keeps bonds (acts as seed crystal)
+                // Non-crystallized H clears bonds each frame to rebuild
+                if !proton.is_crystallized() {
+                    if let Some(p) = &mut self.protons[*idx] {
+                        p.clear_crystal_bonds();
+                        p.set_h_crystal_group(None);
+                    }
+                }
+            }
+        }
+
+        // ===== PHASE 4: Form new bonds (neighbor detection and cluster formation) =====
+        // Build neighbor lists for each H (with minimum spacing filter)
+        let neighbor_lists = self.crystal_neighbor_lists(&h_protons, pm::H_CRYSTAL_MIN_SPACING, pm::H_CRYSTAL_NEIGHBOR_DISTANCE);
+
+        // Find clusters of exactly 7 H particles and assign center + 6 sides
+        let mut is_center: Vec<bool> = vec![false; self.protons.len()];
+        let mut center_bonds: Vec<Vec<usize>> = vec![Vec::new(); self.protons.len()];
+
+        for (idx, pos, _) in &h_protons {
+            // Skip if on cooldown (already handled in Phase 3)
+            let on_cooldown = if let Some(proton) = &self.protons[*idx] {
+                proton.freeze_cooldown() > 0.0 || proton.is_hot()
+            } else {
+                false
+            };
+
+            if on_cooldown {
+                continue;
+            }
+
+            let neighbors = &neighbor_lists[*idx];
 
             // Need exactly 6 or 7 neighbors to form a hexagon
             if neighbors.len() >= 6 {
@@ -955,7 +2236,7 @@ impl ProtonManager {
                     })
                     .collect();
 
-                neighbors_with_dist.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+                neighbors_with_dist.sort_by(|a, b| nan_last(a.1, b.1));
                 let six_nearest: Vec<usize> = neighbors_with_dist
                     .iter()
                     .take(6)
@@ -982,6 +2263,17 @@ impl ProtonManager {
         }
 
         // ===== PHASE 5: Apply alignment forces (hexagonal arrangement) =====
+        // Snap over-stretched bonds first - a center that loses a side stops being a center
+        self.break_stretched_bonds(&h_protons, pm::H_CRYSTAL_BREAKOFF_DISTANCE, Proton::crystal_bonds, Proton::set_crystal_bonds, Proton::set_crystallized);
+        for (idx, _, _) in &h_protons {
+            if is_center[*idx] {
+                if let Some(proton) = &self.protons[*idx] {
+                    is_center[*idx] = proton.is_crystallized();
+                    center_bonds[*idx] = proton.crystal_bonds().clone();
+                }
+            }
+        }
+
         let mut forces: Vec<Vec2> = vec![Vec2::ZERO; self.protons.len()];
 
         for (idx, pos, _) in &h_protons {
@@ -1107,8 +2399,7 @@ impl ProtonManager {
 
                             if force_magnitude > 0.0001 {
                                 // Still arranging
-                                let acceleration = *force / proton.mass();
-                                proton.add_velocity(acceleration * delta_time);
+                                proton.apply_force(*force, delta_time);
                             } else {
                                 // Settled - freeze in position
                                 proton.set_velocity(Vec2::ZERO);
@@ -1207,7 +2498,7 @@ impl ProtonManager {
         }
 
         // ===== PHASE 2: Check evaporation (velocity-based phase change) =====
-        for (idx, _, vel) in &ne20_atoms {
+        for (idx, pos, vel) in &ne20_atoms {
             let speed = vel.length();
             let evaporation_threshold = if let Some(proton) = &self.protons[*idx] {
                 if proton.is_ne20_crystallized() {
@@ -1219,7 +2510,7 @@ impl ProtonManager {
                 pm::NE20_EVAPORATION_SPEED
             };
 
-            if speed > evaporation_threshold {
+            if speed > evaporation_threshold * self.evaporation_scale_at(*pos) || self.is_hot_slot(*idx) {
                 if let Some(proton) = &mut self.protons[*idx] {
                     proton.set_ne20_crystallized(false);
                     proton.clear_ne20_crystal_bonds();
@@ -1249,24 +2540,12 @@ impl ProtonManager {
         }
 
         // ===== PHASE 4: Form new bonds (neighbor detection - cubic coordination) =====
-        let mut neighbor_lists: Vec<Vec<usize>> = vec![Vec::new(); self.protons.len()];
-        for i in 0..ne20_atoms.len() {
-            for j in (i + 1)..ne20_atoms.len() {
-                let (idx1, pos1, _) = ne20_atoms[i];
-                let (idx2, pos2, _) = ne20_atoms[j];
-                let dist = pos1.distance(pos2);
-
-                if dist >= pm::NE20_MIN_SPACING && dist < pm::NE20_NEIGHBOR_DISTANCE {
-                    neighbor_lists[idx1].push(idx2);
-                    neighbor_lists[idx2].push(idx1);
-                }
-            }
-        }
+        let neighbor_lists = self.crystal_neighbor_lists(&ne20_atoms, pm::NE20_MIN_SPACING, pm::NE20_NEIGHBOR_DISTANCE);
 
         // Noble gas: close-packed coordination (6-8 neighbors, weakly bonded)
         for (idx, pos, _) in &ne20_atoms {
             let on_cooldown = if let Some(proton) = &self.protons[*idx] {
-                proton.ne20_freeze_cooldown() > 0.0
+                proton.ne20_freeze_cooldown() > 0.0 || proton.is_hot()
             } else {
                 false
             };
@@ -1289,7 +2568,7 @@ impl ProtonManager {
                     })
                     .collect();
 
-                neighbors_with_dist.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+                neighbors_with_dist.sort_by(|a, b| nan_last(a.1, b.1));
                 // Take up to 8 closest neighbors (close-packing)
                 let nearest: Vec<usize> = neighbors_with_dist
                     .iter()
@@ -1310,6 +2589,8 @@ impl ProtonManager {
         }
 
         // ===== PHASE 5: Apply weak distance-based forces (noble gas - no strict angles) =====
+        self.break_stretched_bonds(&ne20_atoms, pm::NE20_BOND_BREAKING_DISTANCE, Proton::ne20_crystal_bonds, Proton::set_ne20_crystal_bonds, Proton::set_ne20_crystallized);
+
         let mut forces: Vec<Vec2> = vec![Vec2::ZERO; self.protons.len()];
         for (idx, pos, _) in &ne20_atoms {
             if let Some(proton) = &self.protons[*idx] {
@@ -1342,8 +2623,7 @@ impl ProtonManager {
                 if proton.is_alive() && proton.is_neon20() && proton.is_ne20_crystallized() {
                     let force_magnitude = force.length();
                     if force_magnitude > 0.0001 {
-                        let acceleration = *force / proton.mass();
-                        proton.add_velocity(acceleration * delta_time);
+                        proton.apply_force(*force, delta_time);
                     } else {
                         proton.set_velocity(Vec2::ZERO);
                     }
@@ -1419,7 +2699,7 @@ impl ProtonManager {
         }
 
         // ===== PHASE 2: Check evaporation =====
-        for (idx, _, vel) in &c12_atoms {
+        for (idx, pos, vel) in &c12_atoms {
             let speed = vel.length();
             let evaporation_threshold = if let Some(proton) = &self.protons[*idx] {
                 if proton.is_c12_crystallized() {
@@ -1431,7 +2711,7 @@ impl ProtonManager {
                 pm::C12_EVAPORATION_SPEED
             };
 
-            if speed > evaporation_threshold {
+            if speed > evaporation_threshold * self.evaporation_scale_at(*pos) || self.is_hot_slot(*idx) {
                 if let Some(proton) = &mut self.protons[*idx] {
                     proton.set_c12_crystallized(false);
                     proton.clear_c12_crystal_bonds();
@@ -1461,22 +2741,10 @@ impl ProtonManager {
         }
 
         // ===== PHASE 4: Form new bonds (DUAL MODE: graphite OR diamond based on pressure) =====
-        let mut neighbor_lists: Vec<Vec<usize>> = vec![Vec::new(); self.protons.len()];
         let mut pressure_counts: Vec<usize> = vec![0; self.protons.len()];
 
-        // Build neighbor lists for bonding distance
-        for i in 0..c12_atoms.len() {
-            for j in (i + 1)..c12_atoms.len() {
-                let (idx1, pos1, _) = c12_atoms[i];
-                let (idx2, pos2, _) = c12_atoms[j];
-                let dist = pos1.distance(pos2);
-
-                if dist >= pm::C12_MIN_SPACING && dist < pm::C12_NEIGHBOR_DISTANCE {
-                    neighbor_lists[idx1].push(idx2);
-                    neighbor_lists[idx2].push(idx1);
-                }
-            }
-        }
+        // Neighbor lists for bonding distance
+        let neighbor_lists = self.crystal_neighbor_lists(&c12_atoms, pm::C12_MIN_SPACING, pm::C12_NEIGHBOR_DISTANCE);
 
         // Detect pressure (count carbons in wider radius for graphite->diamond transition)
         for (idx, pos, _) in &c12_atoms {
@@ -1495,7 +2763,7 @@ impl ProtonManager {
         // Form bonds - choose graphite (3) or diamond (4) mode based on pressure
         for (idx, pos, _) in &c12_atoms {
             let on_cooldown = if let Some(proton) = &self.protons[*idx] {
-                proton.c12_freeze_cooldown() > 0.0
+                proton.c12_freeze_cooldown() > 0.0 || proton.is_hot()
             } else {
                 false
             };
@@ -1524,7 +2792,7 @@ impl ProtonManager {
                     })
                     .collect();
 
-                neighbors_with_dist.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+                neighbors_with_dist.sort_by(|a, b| nan_last(a.1, b.1));
                 let nearest: Vec<usize> = neighbors_with_dist
                     .iter()
                     .take(min_bonds)
@@ -1544,6 +2812,8 @@ impl ProtonManager {
         }
 
         // ===== PHASE 5: Apply alignment forces (GRAPHITE 120° OR DIAMOND 90° tetrahedral) =====
+        self.break_stretched_bonds(&c12_atoms, pm::C12_BOND_BREAKING_DISTANCE, Proton::c12_crystal_bonds, Proton::set_c12_crystal_bonds, Proton::set_c12_crystallized);
+
         let mut forces: Vec<Vec2> = vec![Vec2::ZERO; self.protons.len()];
         for (idx, pos, _) in &c12_atoms {
             if let Some(proton) = &self.protons[*idx] {
@@ -1570,7 +2840,7 @@ impl ProtonManager {
                     }
 
                     if neighbor_data.len() == 3 {
-                        neighbor_data.sort_by(|a, b| a.3.partial_cmp(&b.3).unwrap());
+                        neighbor_data.sort_by(|a, b| nan_last(a.3, b.3));
                         let start_angle = neighbor_data[0].3;
                         for i in 0..neighbor_data.len() {
                             let (neighbor_idx, _current_pos, _current_dist, _current_angle) = neighbor_data[i];
@@ -1613,7 +2883,7 @@ impl ProtonManager {
                     }
 
                     if neighbor_data.len() == 4 {
-                        neighbor_data.sort_by(|a, b| a.3.partial_cmp(&b.3).unwrap());
+                        neighbor_data.sort_by(|a, b| nan_last(a.3, b.3));
                         let start_angle = neighbor_data[0].3;
                         for i in 0..neighbor_data.len() {
                             let (neighbor_idx, _current_pos, _current_dist, _current_angle) = neighbor_data[i];
@@ -1668,8 +2938,7 @@ impl ProtonManager {
                 if proton.is_alive() && proton.is_stable_carbon12() && proton.is_c12_crystallized() {
                     let force_magnitude = force.length();
                     if force_magnitude > 0.0001 {
-                        let acceleration = *force / proton.mass();
-                        proton.add_velocity(acceleration * delta_time);
+                        proton.apply_force(*force, delta_time);
                     } else {
                         proton.set_velocity(Vec2::ZERO);
                     }
@@ -1743,7 +3012,7 @@ impl ProtonManager {
         }
 
         // ===== PHASE 2: Check evaporation =====
-        for (idx, _, vel) in &si28_atoms {
+        for (idx, pos, vel) in &si28_atoms {
             let speed = vel.length();
             let evaporation_threshold = if let Some(proton) = &self.protons[*idx] {
                 if proton.is_si28_crystallized() {
@@ -1755,7 +3024,7 @@ impl ProtonManager {
                 pm::SI28_EVAPORATION_SPEED
             };
 
-            if speed > evaporation_threshold {
+            if speed > evaporation_threshold * self.evaporation_scale_at(*pos) || self.is_hot_slot(*idx) {
                 if let Some(proton) = &mut self.protons[*idx] {
                     proton.set_si28_crystallized(false);
                     proton.clear_si28_crystal_bonds();
@@ -1785,23 +3054,11 @@ impl ProtonManager {
         }
 
         // ===== PHASE 4: Form new bonds (4-fold tetrahedral diamond cubic) =====
-        let mut neighbor_lists: Vec<Vec<usize>> = vec![Vec::new(); self.protons.len()];
-        for i in 0..si28_atoms.len() {
-            for j in (i + 1)..si28_atoms.len() {
-                let (idx1, pos1, _) = si28_atoms[i];
-                let (idx2, pos2, _) = si28_atoms[j];
-                let dist = pos1.distance(pos2);
-
-                if dist >= pm::SI28_MIN_SPACING && dist < pm::SI28_NEIGHBOR_DISTANCE {
-                    neighbor_lists[idx1].push(idx2);
-                    neighbor_lists[idx2].push(idx1);
-                }
-            }
-        }
+        let neighbor_lists = self.crystal_neighbor_lists(&si28_atoms, pm::SI28_MIN_SPACING, pm::SI28_NEIGHBOR_DISTANCE);
 
         for (idx, pos, _) in &si28_atoms {
             let on_cooldown = if let Some(proton) = &self.protons[*idx] {
-                proton.si28_freeze_cooldown() > 0.0
+                proton.si28_freeze_cooldown() > 0.0 || proton.is_hot()
             } else {
                 false
             };
@@ -1823,7 +3080,7 @@ impl ProtonManager {
                     })
                     .collect();
 
-                neighbors_with_dist.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+                neighbors_with_dist.sort_by(|a, b| nan_last(a.1, b.1));
                 let four_nearest: Vec<usize> = neighbors_with_dist
                     .iter()
                     .take(pm::SI28_MIN_NEIGHBORS)
@@ -1843,6 +3100,8 @@ impl ProtonManager {
         }
 
         // ===== PHASE 5: Apply alignment forces (diamond cubic - 90° tetrahedral) =====
+        self.break_stretched_bonds(&si28_atoms, pm::SI28_BOND_BREAKING_DISTANCE, Proton::si28_crystal_bonds, Proton::set_si28_crystal_bonds, Proton::set_si28_crystallized);
+
         let mut forces: Vec<Vec2> = vec![Vec2::ZERO; self.protons.len()];
         for (idx, pos, _) in &si28_atoms {
             if let Some(proton) = &self.protons[*idx] {
@@ -1871,7 +3130,7 @@ impl ProtonManager {
 
                     if neighbor_data.len() == 4 {
                         // Sort by angle
-                        neighbor_data.sort_by(|a, b| a.3.partial_cmp(&b.3).unwrap());
+                        neighbor_data.sort_by(|a, b| nan_last(a.3, b.3));
 
                         // Calculate ideal positions for 90° spacing (square/diamond)
                         let start_angle = neighbor_data[0].3; // Use first neighbor as reference
@@ -1928,8 +3187,7 @@ impl ProtonManager {
                 if proton.is_alive() && proton.is_silicon28() && proton.is_si28_crystallized() {
                     let force_magnitude = force.length();
                     if force_magnitude > 0.0001 {
-                        let acceleration = *force / proton.mass();
-                        proton.add_velocity(acceleration * delta_time);
+                        proton.apply_force(*force, delta_time);
                     } else {
                         proton.set_velocity(Vec2::ZERO);
                     }
@@ -2003,7 +3261,7 @@ impl ProtonManager {
         }
 
         // ===== PHASE 2: Check evaporation =====
-        for (idx, _, vel) in &mg24_atoms {
+        for (idx, pos, vel) in &mg24_atoms {
             let speed = vel.length();
             let evaporation_threshold = if let Some(proton) = &self.protons[*idx] {
                 if proton.is_mg24_crystallized() {
@@ -2015,7 +3273,7 @@ impl ProtonManager {
                 pm::MG24_EVAPORATION_SPEED
             };
 
-            if speed > evaporation_threshold {
+            if speed > evaporation_threshold * self.evaporation_scale_at(*pos) || self.is_hot_slot(*idx) {
                 if let Some(proton) = &mut self.protons[*idx] {
                     proton.set_mg24_crystallized(false);
                     proton.clear_mg24_crystal_bonds();
@@ -2045,23 +3303,11 @@ impl ProtonManager {
         }
 
         // ===== PHASE 4: Form new bonds (6-fold hexagonal close-packed) =====
-        let mut neighbor_lists: Vec<Vec<usize>> = vec![Vec::new(); self.protons.len()];
-        for i in 0..mg24_atoms.len() {
-            for j in (i + 1)..mg24_atoms.len() {
-                let (idx1, pos1, _) = mg24_atoms[i];
-                let (idx2, pos2, _) = mg24_atoms[j];
-                let dist = pos1.distance(pos2);
-
-                if dist >= pm::MG24_MIN_SPACING && dist < pm::MG24_NEIGHBOR_DISTANCE {
-                    neighbor_lists[idx1].push(idx2);
-                    neighbor_lists[idx2].push(idx1);
-                }
-            }
-        }
+        let neighbor_lists = self.crystal_neighbor_lists(&mg24_atoms, pm::MG24_MIN_SPACING, pm::MG24_NEIGHBOR_DISTANCE);
 
         for (idx, pos, _) in &mg24_atoms {
             let on_cooldown = if let Some(proton) = &self.protons[*idx] {
-                proton.mg24_freeze_cooldown() > 0.0
+                proton.mg24_freeze_cooldown() > 0.0 || proton.is_hot()
             } else {
                 false
             };
@@ -2083,7 +3329,7 @@ impl ProtonManager {
                     })
                     .collect();
 
-                neighbors_with_dist.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+                neighbors_with_dist.sort_by(|a, b| nan_last(a.1, b.1));
                 let six_nearest: Vec<usize> = neighbors_with_dist
                     .iter()
                     .take(pm::MG24_MIN_NEIGHBORS)
@@ -2103,6 +3349,8 @@ impl ProtonManager {
         }
 
         // ===== PHASE 5: Apply alignment forces (hexagonal arrangement - 60° spacing) =====
+        self.break_stretched_bonds(&mg24_atoms, pm::MG24_BOND_BREAKING_DISTANCE, Proton::mg24_crystal_bonds, Proton::set_mg24_crystal_bonds, Proton::set_mg24_crystallized);
+
         let mut forces: Vec<Vec2> = vec![Vec2::ZERO; self.protons.len()];
         for (idx, pos, _) in &mg24_atoms {
             if let Some(proton) = &self.protons[*idx] {
@@ -2131,7 +3379,7 @@ impl ProtonManager {
 
                     if neighbor_data.len() == 6 {
                         // Sort by angle
-                        neighbor_data.sort_by(|a, b| a.3.partial_cmp(&b.3).unwrap());
+                        neighbor_data.sort_by(|a, b| nan_last(a.3, b.3));
 
                         // Calculate ideal positions for 60° spacing (hexagon)
                         let start_angle = neighbor_data[0].3; // Use first neighbor as reference
@@ -2188,8 +3436,7 @@ impl ProtonManager {
                 if proton.is_alive() && proton.is_magnesium24() && proton.is_mg24_crystallized() {
                     let force_magnitude = force.length();
                     if force_magnitude > 0.0001 {
-                        let acceleration = *force / proton.mass();
-                        proton.add_velocity(acceleration * delta_time);
+                        proton.apply_force(*force, delta_time);
                     } else {
                         proton.set_velocity(Vec2::ZERO);
                     }
@@ -2263,7 +3510,7 @@ impl ProtonManager {
         }
 
         // ===== PHASE 2: Check evaporation =====
-        for (idx, _, vel) in &s32_atoms {
+        for (idx, pos, vel) in &s32_atoms {
             let speed = vel.length();
             let evaporation_threshold = if let Some(proton) = &self.protons[*idx] {
                 if proton.is_s32_crystallized() {
@@ -2275,7 +3522,7 @@ impl ProtonManager {
                 pm::S32_EVAPORATION_SPEED
             };
 
-            if speed > evaporation_threshold {
+            if speed > evaporation_threshold * self.evaporation_scale_at(*pos) || self.is_hot_slot(*idx) {
                 if let Some(proton) = &mut self.protons[*idx] {
                     proton.set_s32_crystallized(false);
                     proton.clear_s32_crystal_bonds();
@@ -2306,24 +3553,12 @@ impl ProtonManager {
 
         // ===== PHASE 4: Form S₈ RINGS (each sulfur wants EXACTLY 2 bonds) =====
         // Build neighbor lists (potential bonding partners)
-        let mut neighbor_lists: Vec<Vec<usize>> = vec![Vec::new(); self.protons.len()];
-        for i in 0..s32_atoms.len() {
-            for j in (i + 1)..s32_atoms.len() {
-                let (idx1, pos1, _) = s32_atoms[i];
-                let (idx2, pos2, _) = s32_atoms[j];
-                let dist = pos1.distance(pos2);
-
-                if dist >= pm::S32_MIN_SPACING && dist < pm::S32_NEIGHBOR_DISTANCE {
-                    neighbor_lists[idx1].push(idx2);
-                    neighbor_lists[idx2].push(idx1);
-                }
-            }
-        }
+        let neighbor_lists = self.crystal_neighbor_lists(&s32_atoms, pm::S32_MIN_SPACING, pm::S32_NEIGHBOR_DISTANCE);
 
         // Form bonds - each sulfur gets exactly 2 bonds (for S₈ rings)
         for (idx, pos, _) in &s32_atoms {
             let on_cooldown = if let Some(proton) = &self.protons[*idx] {
-                proton.s32_freeze_cooldown() > 0.0
+                proton.s32_freeze_cooldown() > 0.0 || proton.is_hot()
             } else {
                 false
             };
@@ -2366,7 +3601,7 @@ impl ProtonManager {
                     .collect();
 
                 if available_neighbors.len() > 0 {
-                    available_neighbors.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+                    available_neighbors.sort_by(|a, b| nan_last(a.1, b.1));
 
                     // Take up to `bonds_needed` nearest neighbors
                     let new_bonds: Vec<usize> = available_neighbors
@@ -2421,6 +3656,8 @@ impl ProtonManager {
         }
 
         // ===== PHASE 5: Apply ring-maintaining forces (2 bonds per atom, flexible angles) =====
+        self.break_stretched_bonds(&s32_atoms, pm::S32_BOND_BREAKING_DISTANCE, Proton::s32_crystal_bonds, Proton::set_s32_crystal_bonds, Proton::set_s32_crystallized);
+
         let mut forces: Vec<Vec2> = vec![Vec2::ZERO; self.protons.len()];
         for (idx, pos, _) in &s32_atoms {
             if let Some(proton) = &self.protons[*idx] {
@@ -2501,8 +3738,7 @@ impl ProtonManager {
                 if proton.is_alive() && proton.is_sulfur32() && proton.is_s32_crystallized() {
                     let force_magnitude = force.length();
                     if force_magnitude > 0.0001 {
-                        let acceleration = *force / proton.mass();
-                        proton.add_velocity(acceleration * delta_time);
+                        proton.apply_force(*force, delta_time);
                     } else {
                         proton.set_velocity(Vec2::ZERO);
                     }
@@ -2575,7 +3811,7 @@ impl ProtonManager {
         }
 
         // ===== PHASE 2: Check evaporation (ultra-low threshold) =====
-        for (idx, _, vel) in &he3_atoms {
+        for (idx, pos, vel) in &he3_atoms {
             let speed = vel.length();
             let evaporation_threshold = if let Some(proton) = &self.protons[*idx] {
                 if proton.is_he3_crystallized() {
@@ -2587,7 +3823,7 @@ impl ProtonManager {
                 pm::HE3_EVAPORATION_SPEED
             };
 
-            if speed > evaporation_threshold {
+            if speed > evaporation_threshold * self.evaporation_scale_at(*pos) || self.is_hot_slot(*idx) {
                 if let Some(proton) = &mut self.protons[*idx] {
                     proton.set_he3_crystallized(false);
                     proton.clear_he3_crystal_bonds();
@@ -2610,23 +3846,11 @@ impl ProtonManager {
         }
 
         // ===== PHASE 4: Form new bonds (close-packed, 6-8 neighbors) =====
-        let mut neighbor_lists: Vec<Vec<usize>> = vec![Vec::new(); self.protons.len()];
-        for i in 0..he3_atoms.len() {
-            for j in (i + 1)..he3_atoms.len() {
-                let (idx1, pos1, _) = he3_atoms[i];
-                let (idx2, pos2, _) = he3_atoms[j];
-                let dist = pos1.distance(pos2);
-
-                if dist >= pm::HE3_MIN_SPACING && dist < pm::HE3_NEIGHBOR_DISTANCE {
-                    neighbor_lists[idx1].push(idx2);
-                    neighbor_lists[idx2].push(idx1);
-                }
-            }
-        }
+        let neighbor_lists = self.crystal_neighbor_lists(&he3_atoms, pm::HE3_MIN_SPACING, pm::HE3_NEIGHBOR_DISTANCE);
 
         for (idx, _, _) in &he3_atoms {
             if let Some(proton) = &self.protons[*idx] {
-                if proton.he3_freeze_cooldown() > 0.0 {
+                if proton.he3_freeze_cooldown() > 0.0 || proton.is_hot() {
                     continue;
                 }
             }
@@ -2646,7 +3870,7 @@ impl ProtonManager {
                     })
                     .collect();
 
-                neighbors_with_dist.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+                neighbors_with_dist.sort_by(|a, b| nan_last(a.1, b.1));
                 let nearest: Vec<usize> = neighbors_with_dist
                     .iter()
                     .take(8.min(neighbors_with_dist.len()))
@@ -2693,7 +3917,7 @@ impl ProtonManager {
                 if proton.is_alive() && proton.charge() == 1 && proton.neutron_count() == 2 && proton.is_he3_crystallized() {
                     let force_magnitude = force.length();
                     if force_magnitude > 0.0001 {
-                        proton.add_velocity((*force / proton.mass()) * delta_time);
+                        proton.apply_force(*force, delta_time);
                     }
                 }
             }
@@ -2713,7 +3937,7 @@ impl ProtonManager {
         }
 
         // ===== PHASE 2: Check evaporation =====
-        for (idx, _, vel) in &he4_atoms {
+        for (idx, pos, vel) in &he4_atoms {
             let speed = vel.length();
             let evaporation_threshold = if let Some(proton) = &self.protons[*idx] {
                 if proton.is_he4_crystallized() {
@@ -2725,7 +3949,7 @@ impl ProtonManager {
                 pm::HE4_EVAPORATION_SPEED
             };
 
-            if speed > evaporation_threshold {
+            if speed > evaporation_threshold * self.evaporation_scale_at(*pos) || self.is_hot_slot(*idx) {
                 if let Some(proton) = &mut self.protons[*idx] {
                     proton.set_he4_crystallized(false);
                     proton.clear_he4_crystal_bonds();
@@ -2748,23 +3972,11 @@ impl ProtonManager {
         }
 
         // ===== PHASE 4: Form new bonds =====
-        let mut neighbor_lists: Vec<Vec<usize>> = vec![Vec::new(); self.protons.len()];
-        for i in 0..he4_atoms.len() {
-            for j in (i + 1)..he4_atoms.len() {
-                let (idx1, pos1, _) = he4_atoms[i];
-                let (idx2, pos2, _) = he4_atoms[j];
-                let dist = pos1.distance(pos2);
-
-                if dist >= pm::HE4_MIN_SPACING && dist < pm::HE4_NEIGHBOR_DISTANCE {
-                    neighbor_lists[idx1].push(idx2);
-                    neighbor_lists[idx2].push(idx1);
-                }
-            }
-        }
+        let neighbor_lists = self.crystal_neighbor_lists(&he4_atoms, pm::HE4_MIN_SPACING, pm::HE4_NEIGHBOR_DISTANCE);
 
         for (idx, _, _) in &he4_atoms {
             if let Some(proton) = &self.protons[*idx] {
-                if proton.he4_freeze_cooldown() > 0.0 {
+                if proton.he4_freeze_cooldown() > 0.0 || proton.is_hot() {
                     continue;
                 }
             }
@@ -2784,7 +3996,7 @@ impl ProtonManager {
                     })
                     .collect();
 
-                neighbors_with_dist.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+                neighbors_with_dist.sort_by(|a, b| nan_last(a.1, b.1));
                 let nearest: Vec<usize> = neighbors_with_dist
                     .iter()
                     .take(8.min(neighbors_with_dist.len()))
@@ -2831,7 +4043,7 @@ impl ProtonManager {
                 if proton.is_alive() && proton.is_stable_helium4() && proton.is_he4_crystallized() {
                     let force_magnitude = force.length();
                     if force_magnitude > 0.0001 {
-                        proton.add_velocity((*force / proton.mass()) * delta_time);
+                        proton.apply_force(*force, delta_time);
                     }
                 }
             }
@@ -2841,7 +4053,7 @@ impl ProtonManager {
     /// Update O16 molecular bonds (spring forces and breaking)
     fn update_oxygen_bonds(&mut self, delta_time: f32) {
         // Collect all O16 bonded pairs
-        let mut bonded_pairs: Vec<(usize, usize, Vec2, Vec2, f32, f32, f32)> = Vec::new();
+        let mut bonded_pairs: Vec<(usize, usize, Vec2, Vec2, f32)> = Vec::new();
 
         for i in 0..self.protons.len() {
             if let Some(proton) = &self.protons[i] {
@@ -2856,8 +4068,6 @@ impl ProtonManager {
                                         partner_idx,
                                         proton.position(),
                                         partner.position(),
-                                        proton.mass(),
-                                        partner.mass(),
                                         proton.oxygen_bond_rest_length(),
                                     ));
                                 }
@@ -2871,8 +4081,10 @@ impl ProtonManager {
         // Apply spring forces to maintain bonds and check for breaking
         let mut bonds_to_break: Vec<(usize, usize)> = Vec::new();
 
-        for (idx1, idx2, pos1, pos2, m1, m2, rest_length) in bonded_pairs {
-            let delta = pos2 - pos1;
+        let mut surviving_pairs: Vec<(usize, usize)> = Vec::new();
+
+        for (idx1, idx2, pos1, pos2, rest_length) in bonded_pairs {
+            let delta = self.separation(pos1, pos2);
             let dist = delta.length();
 
             // Check if bond should break
@@ -2880,6 +4092,7 @@ impl ProtonManager {
                 bonds_to_break.push((idx1, idx2));
                 continue;
             }
+            surviving_pairs.push((idx1, idx2));
 
             // Apply spring force to maintain bond distance
             if dist > 0.1 {
@@ -2890,32 +4103,195 @@ impl ProtonManager {
 
                 // Apply forces to both particles
                 if let Some(p1) = &mut self.protons[idx1] {
-                    let acc1 = force / m1;
-                    p1.add_velocity(acc1 * delta_time);
+                    p1.apply_force(force, delta_time);
                 }
                 if let Some(p2) = &mut self.protons[idx2] {
-                    let acc2 = -force / m2;
-                    p2.add_velocity(acc2 * delta_time);
+                    p2.apply_force(-force, delta_time);
                 }
             }
         }
 
         // Break bonds that are too stretched
-        for (idx1, idx2) in bonds_to_break {
-            if let Some(p1) = &mut self.protons[idx1] {
+        for (idx1, idx2) in &bonds_to_break {
+            if let Some(p1) = &mut self.protons[*idx1] {
                 p1.clear_oxygen_bond();
             }
-            if let Some(p2) = &mut self.protons[idx2] {
+            if let Some(p2) = &mut self.protons[*idx2] {
                 p2.clear_oxygen_bond();
             }
         }
-    }
 
-    /// Update water hydrogen bonds - simple geometric ice formation
-    /// 3 bonds = triangles, 4 bonds = squares, 5 bonds = hexagons
-    fn update_water_hydrogen_bonds(&mut self, delta_time: f32) {
+        // Age surviving bonds and collapse long-lived pairs into a single O16
+        for (idx1, idx2) in surviving_pairs {
+            let age = match &mut self.protons[idx1] {
+                Some(p1) => {
+                    p1.age_oxygen_bond(delta_time);
+                    p1.oxygen_bond_age()
+                }
+                None => continue,
+            };
+
+            if age >= proton::O16_CONSOLIDATION_TIME {
+                self.consolidate_oxygen16(idx1, idx2);
+            }
+        }
+    }
+
+    /// Replace a bonded (C12, He4) pair with one O16 particle in the first slot, freeing the second
+    fn consolidate_oxygen16(&mut self, idx1: usize, idx2: usize) {
+        let (Some(p1), Some(p2)) = (&self.protons[idx1], &self.protons[idx2]) else {
+            return;
+        };
+
+        let total_mass = p1.mass() + p2.mass();
+        let center_of_mass = (p1.position() * p1.mass() + p2.position() * p2.mass()) / total_mass;
+        let combined_vel = (p1.velocity() * p1.mass() + p2.velocity() * p2.mass()) / total_mass;
+        let combined_energy = p1.energy() + p2.energy();
+
+        let mut o16 = Proton::new(
+            center_of_mass,
+            combined_vel,
+            Color::from_rgba(100, 180, 255, 255),
+            combined_energy,
+            8, // Total charge: 6 (C) + 2 (He) = 8
+        );
+        o16.set_neutron_count(8); // Total neutrons: 6 (C) + 2 (He) = 8
+        o16.set_max_lifetime(-1.0); // O16 is stable
+        o16.set_oxygen16(true);
+        self.transform_slot(idx1, o16);
+        self.remove_slot(idx2);
+    }
+
+    /// Bonds an H2O may hold - the configured value, never more than a hexagon's 6
+    fn water_ice_max_bonds(&self) -> usize {
+        (self.config.water_ice_max_bonds as usize).clamp(1, proton::WATER_ICE_MAX_BONDS)
+    }
+
+    /// Update water hydrogen bonds - simple geometric ice formation
+    /// 3 bonds = triangles, 4 bonds = squares, 5-6 bonds = hexagons
+    /// Hydrogen bonds to add this frame, as slot pairs - read-only, so it can't depend on the order it visits molecules
+    ///
+    /// Every pair in bond range with at least one liquid molecule is a candidate. Candidates touching ice go
+    /// first (seed growth), then shorter ones, then by position - so ties are broken the same way whatever
+    /// slots the molecules sit in. A candidate is taken if both ends have a free bond and it lands in a
+    /// hexagonal slot (~60° steps) around each liquid end, given the bonds taken before it.
+    fn plan_water_hydrogen_bonds(&self, water_molecules: &[(usize, Vec2, Vec2)], max_bonds: usize) -> Vec<(usize, usize)> {
+        use std::f32::consts::PI;
+
+        struct Member {
+            slot: usize,
+            position: Vec2,
+            frozen: bool,
+            bonds: Vec<usize>, // Slots, including bonds taken earlier in this plan
+            angles: Vec<f32>,  // Directions of every live bond, existing ones included
+        }
+
+        let mut members: Vec<Member> = water_molecules
+            .iter()
+            .filter_map(|&(slot, position, _)| {
+                let proton = self.protons[slot].as_ref()?;
+                let angles = proton
+                    .water_h_bonds()
+                    .iter()
+                    .filter_map(|&partner| self.protons[partner].as_ref())
+                    .filter(|partner| partner.is_alive() && partner.is_h2o())
+                    .map(|partner| {
+                        let delta = partner.position() - position;
+                        delta.y.atan2(delta.x)
+                    })
+                    .collect();
+                Some(Member { slot, position, frozen: proton.is_water_frozen(), bonds: proton.water_h_bonds().clone(), angles })
+            })
+            .collect();
+
+        // (member a, member b, distance, touches ice)
+        let mut candidates: Vec<(usize, usize, f32, bool)> = Vec::new();
+        for a in 0..members.len() {
+            for b in (a + 1)..members.len() {
+                if members[a].frozen && members[b].frozen {
+                    continue; // Frozen molecules don't form new bonds
+                }
+                let dist = members[a].position.distance(members[b].position);
+                if dist < proton::WATER_H_BOND_RANGE && dist > 20.0 {  // Minimum distance to prevent overlap
+                    candidates.push((a, b, dist, members[a].frozen || members[b].frozen));
+                }
+            }
+        }
+
+        // Ice first, then distance, then the pair's positions (lower end first)
+        let ends = |&(a, b, _, _): &(usize, usize, f32, bool)| {
+            let (p, q) = (members[a].position, members[b].position);
+            let key = |v: Vec2| (v.x, v.y);
+            if key(p) <= key(q) { [p.x, p.y, q.x, q.y] } else { [q.x, q.y, p.x, p.y] }
+        };
+        candidates.sort_by(|x, y| {
+            y.3.cmp(&x.3)
+                .then(x.2.total_cmp(&y.2))
+                .then_with(|| ends(x).iter().zip(ends(y)).map(|(u, v)| u.total_cmp(&v)).find(|o| o.is_ne()).unwrap_or(std::cmp::Ordering::Equal))
+        });
+
+        // Does a bond at `angle` fit a hexagonal slot around the bonds at `existing`?
+        let fits_hexagon = |existing: &[f32], angle: f32, tolerance: f32| -> bool {
+            // First bond - always accept closest neighbor
+            let Some(&base_angle) = existing.first() else { return true };
+            let wrapped = |diff: f32| {
+                let mut diff = diff.abs();
+                while diff > PI {
+                    diff -= 2.0 * PI;
+                }
+                diff.abs()
+            };
+            // Ideal hexagonal positions: 0°, 60°, 120°, 180°, 240°, 300° relative to the first bond,
+            // and not too close to an existing bond (~17° minimum separation)
+            (0..6).any(|i| wrapped(angle - (base_angle + i as f32 * PI / 3.0)) < tolerance)
+                && existing.iter().all(|&e| wrapped(angle - e) >= 0.3)
+        };
+
+        let mut planned = Vec::new();
+        for (a, b, _, _) in candidates {
+            let (slot_a, slot_b) = (members[a].slot, members[b].slot);
+            if members[a].bonds.contains(&slot_b) && members[b].bonds.contains(&slot_a) {
+                continue; // Already bonded both ways
+            }
+
+            // Each end needs a free bond (unless it already holds this one)
+            if [(a, slot_b), (b, slot_a)].iter().any(|&(m, other)| members[m].bonds.len() >= max_bonds && !members[m].bonds.contains(&other)) {
+                continue;
+            }
+
+            // Each liquid end needs the bond in one of its hexagonal slots
+            // Tolerance is more relaxed when bonding to frozen neighbors (promotes seed growth)
+            let delta = members[b].position - members[a].position;
+            let angle_ab = delta.y.atan2(delta.x);
+            let angle_ba = (-delta).y.atan2(-delta.x);
+            let fits = [(a, b, angle_ab), (b, a, angle_ba)].iter().all(|&(m, other, angle)| {
+                let tolerance = if members[other].frozen {
+                    proton::WATER_ICE_ANGLE_TOLERANCE_TO_FROZEN
+                } else {
+                    proton::WATER_ICE_ANGLE_TOLERANCE
+                };
+                members[m].frozen || fits_hexagon(&members[m].angles, angle, tolerance)
+            });
+            if !fits {
+                continue;
+            }
+
+            for (m, other, angle) in [(a, slot_b, angle_ab), (b, slot_a, angle_ba)] {
+                if !members[m].bonds.contains(&other) {
+                    members[m].bonds.push(other);
+                }
+                members[m].angles.push(angle);
+            }
+            planned.push((slot_a, slot_b));
+        }
+        planned
+    }
+
+    fn update_water_hydrogen_bonds(&mut self, delta_time: f32) {
         use std::f32::consts::PI;
 
+        let max_bonds = self.water_ice_max_bonds();
+
         // PHASE 1: Collect all H2O molecules
         let mut water_molecules: Vec<(usize, Vec2, Vec2)> = Vec::new();
 
@@ -2932,7 +4308,7 @@ impl ProtonManager {
         }
 
         // PHASE 2: Check for evaporation (too much speed breaks bonds)
-        for (idx, _, vel) in &water_molecules {
+        for (idx, pos, vel) in &water_molecules {
             let speed = vel.length();
 
             // Use different evaporation thresholds for frozen vs liquid water
@@ -2946,7 +4322,7 @@ impl ProtonManager {
                 proton::WATER_EVAPORATION_SPEED
             };
 
-            if speed > evaporation_threshold {
+            if speed > evaporation_threshold * self.evaporation_scale_at(*pos) || self.is_hot_slot(*idx) {
                 // Moving too fast - break all bonds (evaporation)
                 if let Some(proton) = &mut self.protons[*idx] {
                     proton.clear_water_h_bonds();
@@ -2969,178 +4345,27 @@ impl ProtonManager {
 
         // PHASE 4: Form bonds with angular constraints for perfect hexagonal geometry
         // This enforces 60° spacing between neighbors for perfect hexagons
-        for i in 0..water_molecules.len() {
-            let (idx_a, pos_a, _) = water_molecules[i];
-
-            // Skip frozen molecules - they keep their existing bonds
-            // Liquid molecules can still bond TO frozen ones
-            let is_frozen = if let Some(p) = &self.protons[idx_a] {
-                p.is_water_frozen()
-            } else {
-                continue;
-            };
-
-            if is_frozen {
-                continue;  // Frozen molecules don't form new bonds
-            }
-
-            // Get current bonds and their angles (clone to avoid borrow issues)
-            let existing_bonds = if let Some(proton_a) = &self.protons[idx_a] {
-                proton_a.water_h_bonds().clone()
-            } else {
-                continue;
-            };
-
-            // Skip if already at max bonds
-            if existing_bonds.len() >= proton::WATER_ICE_MAX_BONDS {
-                continue;
-            }
-
-            // Calculate existing bond angles
-            let mut existing_angles: Vec<f32> = Vec::new();
-            for bond_idx in &existing_bonds {
-                if let Some(partner) = &self.protons[*bond_idx] {
-                    if partner.is_alive() && partner.is_h2o() {
-                        let delta = partner.position() - pos_a;
-                        let angle = delta.y.atan2(delta.x);
-                        existing_angles.push(angle);
-                    }
-                }
-            }
-
-            // Find potential neighbors with angular positions
-            // Prioritize frozen neighbors to enable seed crystal growth
-            let mut neighbors: Vec<(usize, f32, f32, bool)> = Vec::new(); // (index, distance, angle, is_frozen)
-
-            for j in 0..water_molecules.len() {
-                if i == j {
-                    continue;
-                }
-                let (idx_b, pos_b, _) = water_molecules[j];
-                let delta = pos_b - pos_a;
-                let dist = delta.length();
-
-                if dist < proton::WATER_H_BOND_RANGE && dist > 20.0 {  // Minimum distance to prevent overlap
-                    let angle = delta.y.atan2(delta.x);
-                    let is_frozen = if let Some(p) = &self.protons[idx_b] {
-                        p.is_water_frozen()
-                    } else {
-                        false
-                    };
-                    neighbors.push((idx_b, dist, angle, is_frozen));
-                }
-            }
-
-            // Sort by priority: frozen molecules first, then by distance
-            neighbors.sort_by(|a, b| {
-                match (a.3, b.3) {
-                    (true, false) => std::cmp::Ordering::Less,   // Frozen comes first
-                    (false, true) => std::cmp::Ordering::Greater, // Non-frozen comes later
-                    _ => a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal), // Same frozen status, sort by distance
-                }
-            });
-
-            // For each potential neighbor, check if it fits into a valid hexagonal position
-            for (neighbor_idx, dist, neighbor_angle, _is_frozen) in neighbors {
-                // Check if neighbor has capacity
-                let neighbor_bonds = if let Some(p) = &self.protons[neighbor_idx] {
-                    p.water_h_bonds().len()
-                } else {
-                    continue;
-                };
-
-                if neighbor_bonds >= proton::WATER_ICE_MAX_BONDS {
-                    continue;
-                }
-
-                // Check if we already have this bond
-                if existing_bonds.contains(&neighbor_idx) {
-                    continue;
-                }
-
-                // Determine if this neighbor fits a valid hexagonal slot
-                let mut is_valid_position = false;
-
-                if existing_angles.is_empty() {
-                    // First bond - always accept closest neighbor
-                    is_valid_position = true;
-                } else {
-                    // Check if neighbor is at ~60° intervals from existing bonds
-                    // Ideal hexagonal positions: 0°, 60°, 120°, 180°, 240°, 300° relative to first bond
-                    let base_angle = existing_angles[0];
-
-                    // Calculate ideal hexagonal slots relative to base angle
-                    let ideal_slots: Vec<f32> = (0..6)
-                        .map(|i| base_angle + (i as f32) * PI / 3.0)
-                        .collect();
-
-                    // Use more relaxed angle tolerance when bonding to frozen neighbors (promotes seed growth)
-                    let angle_tolerance = if _is_frozen {
-                        proton::WATER_ICE_ANGLE_TOLERANCE_TO_FROZEN
-                    } else {
-                        proton::WATER_ICE_ANGLE_TOLERANCE
-                    };
-
-                    // Check if neighbor angle matches any ideal slot
-                    for ideal_angle in ideal_slots {
-                        let mut angle_diff = (neighbor_angle - ideal_angle).abs();
-
-                        // Normalize to [-π, π]
-                        while angle_diff > PI {
-                            angle_diff -= 2.0 * PI;
-                        }
-
-                        if angle_diff.abs() < angle_tolerance {
-                            // Also check it's not too close to existing bonds
-                            let mut too_close_to_existing = false;
-                            for existing_angle in &existing_angles {
-                                let mut diff = (neighbor_angle - existing_angle).abs();
-                                while diff > PI {
-                                    diff -= 2.0 * PI;
-                                }
-                                if diff.abs() < 0.3 {  // ~17° minimum separation
-                                    too_close_to_existing = true;
-                                    break;
-                                }
-                            }
-
-                            if !too_close_to_existing {
-                                is_valid_position = true;
-                                break;
-                            }
-                        }
-                    }
-                }
-
-                // Form bond if position is valid
-                if is_valid_position {
-                    if let Some(proton_a) = &mut self.protons[idx_a] {
-                        proton_a.add_water_h_bond(neighbor_idx, proton::WATER_H_BOND_REST_LENGTH);
-                        existing_angles.push(neighbor_angle);  // Update for next iteration
-                    }
-                    if let Some(proton_b) = &mut self.protons[neighbor_idx] {
-                        if !proton_b.water_h_bonds().contains(&idx_a) {
-                            proton_b.add_water_h_bond(idx_a, proton::WATER_H_BOND_REST_LENGTH);
-                        }
-                    }
-
-                    // Check if we've reached max bonds
-                    if existing_angles.len() >= proton::WATER_ICE_MAX_BONDS {
-                        break;
+        // Two-phase so the result never depends on slot order: every bond is chosen from this frame's
+        // positions first (candidates ranked by position, never by index), then all are applied at once
+        for (idx_a, idx_b) in self.plan_water_hydrogen_bonds(&water_molecules, max_bonds) {
+            for (from, to) in [(idx_a, idx_b), (idx_b, idx_a)] {
+                if let Some(proton) = &mut self.protons[from] {
+                    if !proton.water_h_bonds().contains(&to) {
+                        proton.add_water_h_bond(to, proton::WATER_H_BOND_REST_LENGTH);
                     }
                 }
             }
         }
 
         // PHASE 4.5: Apply strong alignment forces to enforce perfect geometric patterns
-        // 3 bonds = 120° spacing (triangle), 4 bonds = 90° spacing (square), 5 bonds = 60° spacing (hexagon)
+        // 3 bonds = 120° spacing (triangle), 4 bonds = 90° spacing (square), 5-6 bonds = 60° spacing (hexagon)
         for (idx, pos, _) in &water_molecules {
             if let Some(proton) = &self.protons[*idx] {
                 let bonds = proton.water_h_bonds();
                 let bond_count = bonds.len();
 
-                // Only apply alignment for 3, 4, or 5 bonds
-                if bond_count < 3 || bond_count > 5 {
+                // Only apply alignment for 3 to 6 bonds
+                if !(3..=6).contains(&bond_count) {
                     continue;
                 }
 
@@ -3163,14 +4388,14 @@ impl ProtonManager {
                 }
 
                 // Sort by angle
-                neighbor_data.sort_by(|a, b| a.3.partial_cmp(&b.3).unwrap());
+                neighbor_data.sort_by(|a, b| nan_last(a.3, b.3));
 
                 // Calculate ideal angle spacing and parameters based on bond count
                 // Reduced forces to prevent bonds from breaking
                 let (angle_spacing, target_distance, alignment_strength) = match bond_count {
                     3 => (2.0 * PI / 3.0, 75.0, 3.0),  // 120° for triangle - gentle force
                     4 => (PI / 2.0, 75.0, 3.0),        // 90° for square - 80% weaker force
-                    5 => (PI / 3.0, proton::WATER_ICE_FROZEN_REST_LENGTH, proton::WATER_ICE_ALIGNMENT_STRENGTH),  // 60° for hexagon - use constant
+                    5 | 6 => (PI / 3.0, proton::WATER_ICE_FROZEN_REST_LENGTH, proton::WATER_ICE_ALIGNMENT_STRENGTH),  // 60° for hexagon - use constant
                     _ => (0.0, 75.0, 6.0),
                 };
 
@@ -3197,8 +4422,7 @@ impl ProtonManager {
                         // Only apply forces to non-frozen molecules
                         // Once frozen, stop applying alignment forces to prevent oscillations
                         if !neighbor.is_water_frozen() {
-                            let acc = force / neighbor.mass();
-                            neighbor.add_velocity(acc * delta_time);
+                            neighbor.apply_force(force, delta_time);
                         }
                     }
                 }
@@ -3206,7 +4430,7 @@ impl ProtonManager {
         }
 
         // PHASE 5: Check geometry and freeze appropriate formations
-        // 3 bonds = triangle, 4 bonds = square, 5 bonds = hexagon
+        // 3 bonds = triangle, 4 bonds = square, 5-6 bonds = hexagon
         // SEED CRYSTAL GROWTH: Molecules with 2+ frozen neighbors freeze more easily
         for (idx, pos, _) in &water_molecules {
             if let Some(proton) = &self.protons[*idx] {
@@ -3254,12 +4478,12 @@ impl ProtonManager {
                             // Square: Check if 4 bonded neighbors form roughly equal distances
                             should_freeze = self.check_square_formation(*idx, *pos, bonds);
                         }
-                        5 => {
-                            // Hexagon: Check if 5 bonded neighbors are properly aligned at ~60° intervals
+                        5 | 6 => {
+                            // Hexagon: Check if 5-6 bonded neighbors are properly aligned at ~60° intervals
                             should_freeze = self.check_hexagon_formation(*idx, *pos, bonds);
                         }
                         _ => {
-                            // 0-2 bonds or 6+ bonds: liquid state
+                            // 0-2 bonds: liquid state
                             should_freeze = false;
                         }
                     }
@@ -3271,7 +4495,7 @@ impl ProtonManager {
                     let damping_factor = match bond_count {
                         3 => 0.95,  // Light damping for triangles
                         4 => 0.90,  // Moderate damping for squares
-                        5 => 0.85,  // Strong damping for hexagons
+                        5 | 6 => 0.85,  // Strong damping for hexagons
                         _ => 1.0,   // No damping for 0-2 bonds
                     };
 
@@ -3280,11 +4504,14 @@ impl ProtonManager {
                         p.set_velocity(current_vel * damping_factor);
                     }
 
-                    // Update frozen state
+                    // Update frozen state - ice melted by red waves can't refreeze until its cooldown runs out
+                    let was_frozen = p.is_water_frozen();
+                    let should_freeze = should_freeze && p.freeze_cooldown() <= 0.0;
                     p.set_water_frozen(should_freeze);
 
-                    // Freeze movement if properly formed
-                    if should_freeze {
+                    // Stop a molecule as it settles into ice - already-frozen members keep
+                    // their velocity so their crystal group can drift and spin as a unit
+                    if should_freeze && !was_frozen {
                         p.set_velocity(Vec2::ZERO);
                     }
                 }
@@ -3296,8 +4523,8 @@ impl ProtonManager {
         self.detect_and_mark_ice_crystals();
 
         // PHASE 7: Apply rigid body movement to crystal groups
-        // All molecules in the same group move together as a unit
-        self.apply_crystal_group_rigid_movement();
+        // All molecules in the same group move (and rotate) together as a unit
+        self.apply_crystal_group_rigid_movement(delta_time);
     }
 
     /// Check if 3-bonded H2O forms a valid triangle
@@ -3327,7 +4554,7 @@ impl ProtonManager {
         }
 
         // Sort by angle
-        neighbors.sort_by(|a, b| a.2.partial_cmp(&b.2).unwrap());
+        neighbors.sort_by(|a, b| nan_last(a.2, b.2));
 
         // Check if all distances are similar
         let avg_dist = neighbors.iter().map(|(_, d, _)| d).sum::<f32>() / 3.0;
@@ -3385,7 +4612,7 @@ impl ProtonManager {
         }
 
         // Sort by angle
-        neighbors.sort_by(|a, b| a.2.partial_cmp(&b.2).unwrap());
+        neighbors.sort_by(|a, b| nan_last(a.2, b.2));
 
         // Check if all distances are similar
         let avg_dist = neighbors.iter().map(|(_, d, _)| d).sum::<f32>() / 4.0;
@@ -3418,13 +4645,14 @@ impl ProtonManager {
         avg_dist < proton::WATER_ICE_COMPRESSION_DISTANCE
     }
 
-    /// Check if 5-bonded H2O forms a valid hexagon
+    /// Check if 5- or 6-bonded H2O forms a valid hexagon (a 5-bond center leaves one corner empty)
     fn check_hexagon_formation(&self, _idx: usize, pos: Vec2, bonds: &Vec<usize>) -> bool {
-        if bonds.len() != 5 {
+        let bond_count = bonds.len();
+        if bond_count != 5 && bond_count != 6 {
             return false;
         }
 
-        // Get positions and angles of all 5 neighbors
+        // Get positions and angles of all neighbors
         let mut neighbors: Vec<(Vec2, f32, f32)> = Vec::new(); // (position, distance, angle)
         for bond_idx in bonds {
             if let Some(partner) = &self.protons[*bond_idx] {
@@ -3438,15 +4666,15 @@ impl ProtonManager {
             }
         }
 
-        if neighbors.len() != 5 {
+        if neighbors.len() != bond_count {
             return false;
         }
 
         // Sort by angle
-        neighbors.sort_by(|a, b| a.2.partial_cmp(&b.2).unwrap());
+        neighbors.sort_by(|a, b| nan_last(a.2, b.2));
 
         // Check if all distances are similar and close to ideal frozen ice length
-        let avg_dist = neighbors.iter().map(|(_, d, _)| d).sum::<f32>() / 5.0;
+        let avg_dist = neighbors.iter().map(|(_, d, _)| d).sum::<f32>() / bond_count as f32;
         let dist_tolerance = 20.0;  // Relaxed tolerance to allow realistic imperfect geometry
 
         for (_, dist, _) in &neighbors {
@@ -3460,28 +4688,36 @@ impl ProtonManager {
             return false;
         }
 
-        // For hexagon with 5 bonds, we expect 60 degree spacing (hexagon alignment)
+        // Every gap between neighbors should be a whole number of 60 degree hexagon corners,
+        // together going once around - all six filled, or one gap of 120 degrees for 5 bonds
         let expected_angle = PI / 3.0; // 60 degrees for hexagon
+        let mut corners = 0;
 
-        for k in 0..5 {
-            let next_k = (k + 1) % 5;
+        for k in 0..bond_count {
+            let next_k = (k + 1) % bond_count;
             let mut angle_diff = neighbors[next_k].2 - neighbors[k].2;
 
             // Normalize angle difference to [0, 2π]
-            if angle_diff < 0.0 {
+            if angle_diff <= 0.0 {
                 angle_diff += 2.0 * PI;
             }
 
-            if (angle_diff - expected_angle).abs() > proton::WATER_ICE_ANGLE_TOLERANCE {
+            let steps = (angle_diff / expected_angle).round().max(1.0);
+            if (angle_diff - steps * expected_angle).abs() > proton::WATER_ICE_ANGLE_TOLERANCE {
                 return false;
             }
+            corners += steps as usize;
+        }
+
+        if corners != 6 {
+            return false;
         }
 
         avg_dist < proton::WATER_ICE_COMPRESSION_DISTANCE
     }
 
     /// Detect hexagonal ice crystals and assign group IDs for collective movement
-    /// When a center molecule has 5 properly-aligned bonds (perfect hexagon), all 6 molecules turn white
+    /// When a center molecule has 5 or 6 properly-aligned bonds (hexagon), the whole tile turns white
     fn detect_and_mark_ice_crystals(&mut self) {
         // First, clear all existing crystal group assignments
         for proton_opt in &mut self.protons {
@@ -3492,7 +4728,7 @@ impl ProtonManager {
             }
         }
 
-        // Find all H2O molecules that form perfect hexagons (5 bonds + frozen state)
+        // Find all H2O molecules that form hexagons (5-6 bonds + frozen state)
         let mut next_group_id = 0;
         let mut assigned_groups: Vec<Option<usize>> = vec![None; self.protons.len()];
 
@@ -3502,11 +4738,11 @@ impl ProtonManager {
                     continue;
                 }
 
-                // Check if this molecule forms a perfect hexagon (5 bonds, frozen state)
+                // Check if this molecule forms a hexagon (5-6 bonds, frozen state)
                 let bonds = proton.water_h_bonds();
-                if bonds.len() == 5 && proton.is_water_frozen() {
-                    // This is a perfect hexagon center!
-                    // Assign this molecule and all 5 neighbors to the same crystal group
+                if bonds.len() >= 5 && proton.is_water_frozen() {
+                    // This is a hexagon center!
+                    // Assign this molecule and all of its neighbors (7 molecules for a full tile) to the same crystal group
 
                     // Check if any of these molecules are already in a group
                     let mut existing_group = assigned_groups[i];
@@ -3529,7 +4765,7 @@ impl ProtonManager {
                     // Assign group to center
                     assigned_groups[i] = Some(group_id);
 
-                    // Assign group to all 5 neighbors
+                    // Assign group to all neighbors
                     for &neighbor_idx in bonds {
                         assigned_groups[neighbor_idx] = Some(group_id);
                     }
@@ -3537,10 +4773,10 @@ impl ProtonManager {
             }
         }
 
-        // Apply the group assignments to all protons
+        // Apply the group assignments to all protons - melted ice stays out until its freeze cooldown runs out
         for (i, proton_opt) in self.protons.iter_mut().enumerate() {
             if let Some(proton) = proton_opt {
-                if let Some(group_id) = assigned_groups[i] {
+                if let Some(group_id) = assigned_groups[i].filter(|_| proton.freeze_cooldown() <= 0.0) {
                     proton.set_ice_crystal_group(Some(group_id));
                     proton.set_water_frozen(true);  // Ensure frozen state
                 }
@@ -3550,7 +4786,7 @@ impl ProtonManager {
 
     /// Apply rigid body movement to ice crystal groups
     /// All molecules in the same crystal group move together with averaged velocity
-    fn apply_crystal_group_rigid_movement(&mut self) {
+    fn apply_crystal_group_rigid_movement(&mut self, delta_time: f32) {
         use std::collections::HashMap;
 
         // Collect molecules by crystal group
@@ -3566,38 +4802,55 @@ impl ProtonManager {
             }
         }
 
-        // For each group, calculate average velocity and apply to all members
+        // For each group, replace member velocities with one rigid-body motion:
+        // centroid translation plus a spin that conserves the group's angular momentum
         for (_group_id, member_indices) in groups {
-            if member_indices.is_empty() {
+            let members: Vec<(usize, Vec2, Vec2, f32)> = member_indices
+                .iter()
+                .filter_map(|&idx| self.protons[idx].as_ref().map(|p| (idx, p.position(), p.velocity(), p.mass())))
+                .collect();
+
+            let total_mass: f32 = members.iter().map(|m| m.3).sum();
+            if members.is_empty() || total_mass <= 0.0 {
                 continue;
             }
 
-            // Calculate average velocity of the group
-            let mut avg_velocity = Vec2::ZERO;
-            let mut count = 0;
+            // Centroid and linear velocity of the group
+            let centroid = members.iter().fold(Vec2::ZERO, |acc, m| acc + m.1 * m.3) / total_mass;
+            let avg_velocity = members.iter().fold(Vec2::ZERO, |acc, m| acc + m.2 * m.3) / total_mass;
 
-            for &idx in &member_indices {
-                if let Some(proton) = &self.protons[idx] {
-                    avg_velocity += proton.velocity();
-                    count += 1;
-                }
+            // Angular momentum and moment of inertia about the centroid
+            let mut angular_momentum = 0.0;
+            let mut inertia = 0.0;
+            for (_, pos, vel, mass) in &members {
+                let offset = *pos - centroid;
+                angular_momentum += mass * offset.perp_dot(*vel - avg_velocity);
+                inertia += mass * offset.length_squared();
             }
+            let angular_velocity = if inertia > EPSILON { angular_momentum / inertia } else { 0.0 };
 
-            if count > 0 {
-                avg_velocity /= count as f32;
+            // Linear integration moves a member along its tangent; this correction keeps it on the arc
+            let rotation = Vec2::from_angle(angular_velocity * delta_time);
 
-                // Apply average velocity to all members
-                for &idx in &member_indices {
-                    if let Some(proton) = &mut self.protons[idx] {
-                        proton.set_velocity(avg_velocity);
-                    }
+            for (idx, pos, _, _) in members {
+                let offset = pos - centroid;
+                let spin_velocity = offset.perp() * angular_velocity;
+                let arc_correction = rotation.rotate(offset) - offset - spin_velocity * delta_time;
+
+                if let Some(proton) = &mut self.protons[idx] {
+                    proton.set_position(pos + arc_correction);
+                    proton.set_velocity(avg_velocity + spin_velocity);
                 }
             }
         }
     }
 
     /// Handle solid collisions between H, He4, C12, O16 bonded particles, H2O, and hydrogen compound molecules
+    /// A frozen ice crystal group collides as one rigid body - summed mass, one shared velocity - so the
+    /// impulse moves the whole sheet and the rigid step that follows doesn't average the bounce away
     fn handle_solid_collisions(&mut self) {
+        use std::collections::HashMap;
+
         // Collect solid proton data (H, He4, C12, O16 bonded, H2O, and hydrogen compounds)
         let mut solid_protons: Vec<(usize, Vec2, Vec2, f32, f32)> = Vec::new();
 
@@ -3607,8 +4860,8 @@ impl ProtonManager {
                     let charge = proton.charge();
                     let neutron_count = proton.neutron_count();
 
-                    // Hydrogen compound molecules are solid
-                    if proton.is_sih4() {
+                    // Molecules (hydrogen compounds and SO2) are solid
+                    if proton.is_sih4() || proton.is_so2() {
                         solid_protons.push((
                             i,
                             proton.position(),
@@ -3712,8 +4965,8 @@ impl ProtonManager {
                         continue;
                     }
 
-                    // O16 bonded particles are solid
-                    if proton.is_oxygen16_bonded() {
+                    // O16 (bonded pairs and consolidated) are solid
+                    if proton.is_oxygen16_bonded() || proton.is_oxygen16() {
                         solid_protons.push((
                             i,
                             proton.position(),
@@ -3743,13 +4996,48 @@ impl ProtonManager {
             }
         }
 
+        // One body per free particle and per ice crystal group: (velocity, mass, member slots)
+        // Velocities are kept live, so a body touching several others can't be bounced twice for one approach
+        let mut bodies: Vec<(Vec2, f32, Vec<usize>)> = Vec::new();
+        let mut group_bodies: HashMap<usize, usize> = HashMap::new();
+        let mut body_of: Vec<usize> = Vec::with_capacity(solid_protons.len());
+        for &(idx, _, vel, _, mass) in &solid_protons {
+            let group = self.protons[idx].as_ref().filter(|p| p.is_h2o()).and_then(|p| p.ice_crystal_group());
+            let body = match group {
+                Some(group_id) => *group_bodies.entry(group_id).or_insert_with(|| {
+                    bodies.push((Vec2::ZERO, 0.0, Vec::new()));
+                    bodies.len() - 1
+                }),
+                None => {
+                    bodies.push((Vec2::ZERO, 0.0, Vec::new()));
+                    bodies.len() - 1
+                }
+            };
+            // Momentum for now - divided by the summed mass below
+            bodies[body].0 += vel * mass;
+            bodies[body].1 += mass;
+            bodies[body].2.push(idx);
+            body_of.push(body);
+        }
+        for (velocity, mass, _) in &mut bodies {
+            if *mass > 0.0 {
+                *velocity /= *mass;
+            }
+        }
+
         // Check all pairs for collisions
         for i in 0..solid_protons.len() {
             for j in (i + 1)..solid_protons.len() {
-                let (idx1, pos1, vel1, r1, m1) = solid_protons[i];
-                let (idx2, pos2, vel2, r2, m2) = solid_protons[j];
+                let (body1, body2) = (body_of[i], body_of[j]);
+                if body1 == body2 {
+                    continue; // Members of one crystal hold their shape through its bonds, not collisions
+                }
+                let (idx1, pos1, _, r1, _) = solid_protons[i];
+                let (idx2, pos2, _, r2, _) = solid_protons[j];
+                let (vel1, m1) = (bodies[body1].0, bodies[body1].1);
+                let (vel2, m2) = (bodies[body2].0, bodies[body2].1);
 
-                let delta = pos2 - pos1;
+                let delta = self.separation(pos1, pos2);
                 let dist = delta.length();
 
                 // Bounce distance = radii sum + extra bounce distance (1-2 pixels)
@@ -3768,46 +5056,58 @@ impl ProtonManager {
                         continue;
                     }
 
-                    // Use proton bounce dampening for close-range bounces (like a wall)
-                    let elasticity = pm::PROTON_BOUNCE_DAMPENING;
+                    // Bounce depends on what the two are made of (per material pair, see SimConfig::elasticity)
+                    let material = |idx: usize| self.protons[idx].as_ref().map_or(Material::Gas, |p| p.material());
+                    let elasticity = self.config.elasticity(material(idx1), material(idx2));
                     let impulse_magnitude = -(1.0 + elasticity) * vel_along_normal / (1.0 / m1 + 1.0 / m2);
                     let impulse = normal * impulse_magnitude;
 
-                    // Apply impulse to both protons (impulse points from p2 to p1)
-                    // p1 should be pushed in direction of impulse (away from p2)
-                    // p2 should be pushed opposite to impulse (away from p1)
-                    if let Some(p1) = &mut self.protons[idx1] {
-                        p1.add_velocity(impulse / m1);
+                    // Apply impulse to both bodies (impulse points from p2 to p1)
+                    // body 1 should be pushed in direction of impulse (away from p2)
+                    // body 2 should be pushed opposite to impulse (away from p1)
+                    for (body, change) in [(body1, impulse / m1), (body2, -impulse / m2)] {
+                        bodies[body].0 += change;
+                        for &slot in &bodies[body].2 {
+                            if let Some(p) = &mut self.protons[slot] {
+                                p.add_velocity(change);
+                            }
+                        }
                     }
-                    if let Some(p2) = &mut self.protons[idx2] {
-                        p2.add_velocity(-impulse / m2);
+
+                    // Hard impacts heat both particles
+                    let heat = (vel_along_normal - proton::COLLISION_HEAT_MIN_SPEED) * proton::COLLISION_HEAT_PER_SPEED;
+                    if heat > 0.0 {
+                        self.heat_slot(idx1, heat);
+                        self.heat_slot(idx2, heat);
                     }
                 }
             }
         }
     }
 
-    /// Check if proton is near any atom
-    fn is_near_atom(&self, proton_pos: Vec2, atom_manager: &AtomManager) -> bool {
-        // Simple distance check - 50px proximity threshold
-        let atoms = atom_manager.get_atoms();
-
-        for atom_opt in atoms {
-            if let Some(atom) = atom_opt {
-                if atom.is_alive() {
-                    let atom_pos = atom.get_position();
-                    let dx = proton_pos.x - atom_pos.x;
-                    let dy = proton_pos.y - atom_pos.y;
-                    let dist_squared = dx * dx + dy * dy;
+    /// Turn H+ that has lingered within neutron_formation_range of an atom into deuterium
+    fn form_neutrons(&mut self, delta_time: f32, atom_positions: &[Vec2]) {
+        let probability = self.config.neutron_formation_probability;
+        for slot in 0..self.protons.len() {
+            let proton_pos = match &self.protons[slot] {
+                Some(proton) if proton.is_alive() && proton.charge() == 1 => proton.position(),
+                _ => continue,
+            };
 
-                    if dist_squared < 50.0 * 50.0 {
-                        return true;
-                    }
+            let near_atom = self.is_near_atom(proton_pos, atom_positions);
+            if let Some(proton) = &mut self.protons[slot] {
+                if proton.try_neutron_formation(delta_time, near_atom, probability) {
+                    self.atom_events.neutron_formations += 1;
+                    self.neutron_formation_count += 1;
                 }
             }
         }
+    }
 
-        false
+    /// Check if proton is within neutron_formation_range of any atom
+    fn is_near_atom(&self, proton_pos: Vec2, atom_positions: &[Vec2]) -> bool {
+        let range = self.config.neutron_formation_range;
+        atom_positions.iter().any(|atom_pos| proton_pos.distance_squared(*atom_pos) < range * range)
     }
 
     /// Find nearby atom position for electron capture
@@ -3841,20 +5141,65 @@ impl ProtonManager {
         atom_manager.mark_atom_at_position(atom_pos);
     }
 
+    /// Resolve isotopes whose lifetime ran out: tritium beta-decays into He3 + e- and an energy ring
+    fn handle_radioactive_decay(&mut self, ring_manager: &mut RingManager) {
+        for i in 0..self.protons.len() {
+            let (position, velocity, energy) = match &self.protons[i] {
+                Some(p) if p.is_alive() && p.is_decay_pending() => (p.position(), p.velocity(), p.energy()),
+                _ => continue,
+            };
+
+            // Daughter He3 takes the parent's slot
+            let mut he3 = Proton::new(position, velocity, Color::from_rgba(255, 200, 100, 255), energy, 1);
+            he3.set_neutron_count(2);
+            self.transform_slot(i, he3);
+            self.push_event(SimEventKind::Decay, i);
+
+            // Beta electron leaves in a random direction (skipped once the slot cap is reached)
+            use macroquad::rand::gen_range;
+            let emit_dir = Vec2::from_angle(gen_range(0.0, std::f32::consts::TAU));
+            let electron = Self::new_lepton(position + emit_dir * 10.0, velocity + emit_dir * proton::LEPTON_RELEASE_SPEED, -1);
+            self.insert_proton(electron);
+
+            ring_manager.add_energy_ring(position, energy);
+            self.decay_count += 1;
+        }
+    }
+
     /// Handle nuclear fusion between protons
+    /// Debug builds check that every reaction, ejected particles included, conserved charge and nucleons
     fn handle_nuclear_fusion(&mut self, ring_manager: &mut RingManager) {
-        // Check all proton pairs for fusion conditions
+        let before = cfg!(debug_assertions).then(|| self.conserved_totals());
+
+        self.resolve_reactions(ring_manager);
+
+        if let Some(before) = before {
+            assert_eq!(self.conserved_totals(), before, "fusion changed (charge, nucleons)");
+        }
+    }
+
+    /// Run this frame's reactions, best candidates first, within the per-frame budget
+    fn resolve_reactions(&mut self, ring_manager: &mut RingManager) {
+        // Up to max_reactions_per_frame reactions run per frame, each on slots no earlier reaction this frame
+        // has used - a candidate touching a consumed slot is skipped, so it never acts on a stale snapshot
+        let mut consumed: std::collections::HashSet<usize> = std::collections::HashSet::new();
+        let mut budget = self.max_reactions_per_frame();
+
+        // Collect every colliding pair that meets its reaction condition
+        // Candidates are resolved best-first (see ReactionPriority) so the outcome does not depend on slot order
+        let mut pair_candidates: Vec<(usize, usize, PairReaction, ReactionPriority)> = Vec::new();
+
         for i in 0..self.protons.len() {
             if self.protons[i].is_none() {
                 continue;
             }
 
-            let (pos1, vel1, charge1, neutron1, radius1, mass1, energy1) = {
+            let (pos1, vel1, charge1, neutron1, radius1) = {
                 let p = self.protons[i].as_ref().unwrap();
-                if !p.is_alive() || p.is_stable_hydrogen() || p.is_stable_helium4() || p.is_stable_carbon12() {
+                if !p.is_alive() || p.is_stable_hydrogen() || p.is_stable_helium4() || p.is_stable_carbon12() || p.is_lepton() {
                     continue;
                 }
-                (p.position(), p.velocity(), p.charge(), p.neutron_count(), p.radius(), p.mass(), p.energy())
+                (p.position(), p.velocity(), p.charge(), p.neutron_count(), p.radius())
             };
 
             for j in (i + 1)..self.protons.len() {
@@ -3862,12 +5207,12 @@ impl ProtonManager {
                     continue;
                 }
 
-                let (pos2, vel2, charge2, neutron2, radius2, mass2, energy2) = {
+                let (pos2, vel2, charge2, neutron2, radius2) = {
                     let p = self.protons[j].as_ref().unwrap();
-                    if !p.is_alive() || p.is_stable_hydrogen() || p.is_stable_helium4() || p.is_stable_carbon12() {
+                    if !p.is_alive() || p.is_stable_hydrogen() || p.is_stable_helium4() || p.is_stable_carbon12() || p.is_lepton() {
                         continue;
                     }
-                    (p.position(), p.velocity(), p.charge(), p.neutron_count(), p.radius(), p.mass(), p.energy())
+                    (p.position(), p.velocity(), p.charge(), p.neutron_count(), p.radius())
                 };
 
                 // Calculate distance
@@ -3880,119 +5225,162 @@ impl ProtonManager {
                 }
 
                 // Calculate relative velocity
-                let rel_vel = vel1 - vel2;
-                let rel_speed = rel_vel.length();
+                let rel_speed = (vel1 - vel2).length();
 
-                // FUSION CASE 1: Deuterium (0, neutron=1) + Proton (+1, neutron=0) → Helium-3
-                if (charge1 == 0 && neutron1 == 1 && charge2 == 1 && neutron2 == 0) ||
-                   (charge2 == 0 && neutron2 == 1 && charge1 == 1 && neutron1 == 0)
+                let reaction = if charge1 == 1 && neutron1 == 0 && charge2 == 1 && neutron2 == 0 {
+                    // FUSION CASE 0: Proton + Proton → Deuterium + positron (first step of the pp-chain)
+                    // Needs a hard collision to overcome the charge repulsion
+                    if rel_speed > self.config.pp_chain_velocity_threshold {
+                        Some(PairReaction::ProtonProton)
+                    } else {
+                        None
+                    }
+                } else if charge1 == 0 && neutron1 == 1 && charge2 == 0 && neutron2 == 1 {
+                    // FUSION CASE 0.5: Deuterium + Deuterium → Tritium + proton
+                    if rel_speed > self.config.dd_fusion_velocity_threshold {
+                        Some(PairReaction::DeuteriumDeuterium)
+                    } else {
+                        None
+                    }
+                } else if (charge1 == 0 && neutron1 == 1 && charge2 == 1 && neutron2 == 0) ||
+                                  (charge2 == 0 && neutron2 == 1 && charge1 == 1 && neutron1 == 0)
                 {
-                    if rel_speed > proton::DEUTERIUM_FUSION_VELOCITY_THRESHOLD {
-                        // Calculate center of mass
-                        let total_mass = mass1 + mass2;
-                        let center_of_mass = (pos1 * mass1 + pos2 * mass2) / total_mass;
-                        let combined_vel = (vel1 * mass1 + vel2 * mass2) / total_mass;
-
-                        // Create Helium-3 in first slot
-                        let combined_energy = energy1 + energy2;
-                        let mut he3 = Proton::new(
-                            center_of_mass,
-                            combined_vel,
-                            Color::from_rgba(255, 200, 100, 255),
-                            combined_energy,
-                            1,
-                        );
-                        he3.set_neutron_count(2);
-                        self.protons[i] = Some(he3);
+                    // FUSION CASE 1: Deuterium (0, neutron=1) + Proton (+1, neutron=0) → Helium-3
+                    if rel_speed > self.config.deuterium_fusion_velocity_threshold {
+                        Some(PairReaction::DeuteriumProton)
+                    } else {
+                        None
+                    }
+                } else if charge1 == 1 && neutron1 == 2 && charge2 == 1 && neutron2 == 2 {
+                    // FUSION CASE 2: Helium-3 + Helium-3 → Helium-4 + 2 protons
+                    if rel_speed > self.config.helium3_fusion_velocity_threshold {
+                        Some(PairReaction::Helium3Helium3)
+                    } else {
+                        None
+                    }
+                } else if (charge1 == -1 && neutron1 == 0 && charge2 == 1 && neutron2 == 0) ||
+                          (charge2 == -1 && neutron2 == 0 && charge1 == 1 && neutron1 == 0)
+                {
+                    // FUSION CASE 3: H- (charge=-1) + H+ (charge=1) → Deuterium + electron
+                    // No velocity threshold - attraction brings them together naturally
+                    Some(PairReaction::NegativeProton)
+                } else {
+                    None
+                };
 
-                        // Spawn energy wave (D + H+ → He3) with dark red to yellow color
-                        use macroquad::rand::gen_range;
-                        let t: f32 = gen_range(0.0, 1.0);
-                        let t = t.powf(3.0);
-                        ring_manager.add_ring_with_color(center_of_mass, Color::new(0.17 + 0.83*t, 0.8*t, 0.0, 1.0));
+                // Skip reactions switched off in the reaction flags
+                let reaction = reaction.filter(|r| self.reaction_flags.contains(r.flag()));
 
-                        // Delete second proton
-                        self.protons[j] = None;
-                        break;
-                    }
+                if let Some(reaction) = reaction {
+                    let priority = ReactionPriority::new(rel_speed, distance_sq.sqrt(), (pos1 + pos2) / 2.0);
+                    pair_candidates.push((i, j, reaction, priority));
                 }
-                // FUSION CASE 2: Helium-3 + Helium-3 → Helium-4 + 2 protons
-                else if charge1 == 1 && neutron1 == 2 && charge2 == 1 && neutron2 == 2 {
-                    if rel_speed > proton::HELIUM3_FUSION_VELOCITY_THRESHOLD {
-                        // Calculate center of mass
-                        let total_mass = mass1 + mass2;
-                        let center_of_mass = (pos1 * mass1 + pos2 * mass2) / total_mass;
-                        let combined_vel = (vel1 * mass1 + vel2 * mass2) / total_mass;
-
-                        // Create Helium-4 in first slot
-                        let combined_energy = energy1 + energy2;
-                        let mut he4 = Proton::new(
-                            center_of_mass,
-                            combined_vel,
-                            Color::from_rgba(255, 255, 100, 255),
-                            combined_energy * 0.5,
-                            2,
-                        );
-                        he4.set_neutron_count(2);
-                        he4.set_max_lifetime(-1.0); // Helium-4 is stable
-                        self.protons[i] = Some(he4);
+            }
+        }
 
-                        // Spawn BIG energy waves with random colors between dark red and almost yellow
-                        // Dark red = (0.17,0,0), Almost yellow = (1.0,0.8,0)
-                        // Use cubic bias to favor dark red: t^3 keeps most values near 0
-                        use macroquad::rand::gen_range;
-                        let t1: f32 = gen_range(0.0, 1.0);
-                        let t1 = t1.powf(3.0);
-                        let color1 = Color::new(0.17 + 0.83*t1, 0.8*t1, 0.0, 1.0);
-                        ring_manager.add_ring_with_color(center_of_mass, color1);
-
-                        let t2: f32 = gen_range(0.0, 1.0);
-                        let t2 = t2.powf(3.0);
-                        let color2 = Color::new(0.17 + 0.83*t2, 0.8*t2, 0.0, 1.0);
-                        ring_manager.add_ring_with_color(center_of_mass, color2);
-
-                        // Spawn 2 high-energy protons
-                        let release_speed = 200.0;
-                        let perp_vel = vec2(-rel_vel.y, rel_vel.x);
-                        let perp_len = perp_vel.length();
-                        let perp_dir = if perp_len > 0.001 {
-                            perp_vel / perp_len
-                        } else {
-                            vec2(1.0, 0.0)
-                        };
+        // Resolve best candidates first - each proton takes part in at most one reaction per frame
+        pair_candidates.sort_by(|a, b| b.3.compare(&a.3));
 
-                        self.spawn_proton(
-                            center_of_mass + perp_dir * 10.0,
-                            perp_dir * release_speed,
-                            WHITE,
-                            combined_energy * 0.25,
-                            1,
-                        );
-                        self.spawn_proton(
-                            center_of_mass - perp_dir * 10.0,
-                            -perp_dir * release_speed,
-                            WHITE,
-                            combined_energy * 0.25,
-                            1,
-                        );
+        for (i, j, reaction, _) in pair_candidates {
+            if budget == 0 {
+                return;
+            }
+            if consumed.contains(&i) || consumed.contains(&j) {
+                continue;
+            }
+            // He3 + He3 needs one slot beyond its own two for the second proton - without it the pair waits
+            if reaction == PairReaction::Helium3Helium3 && self.free_slot_count() == 0 {
+                continue;
+            }
+            consumed.extend([i, j]);
+            budget -= 1;
+            self.record_reaction(reaction.kind());
 
-                        // Delete second He3
-                        self.protons[j] = None;
-                        break;
-                    }
-                }
-                // FUSION CASE 3: H- (charge=-1) + H+ (charge=1) → He3 + energy
-                else if (charge1 == -1 && neutron1 == 0 && charge2 == 1 && neutron2 == 0) ||
-                        (charge2 == -1 && neutron2 == 0 && charge1 == 1 && neutron1 == 0)
-                {
-                    // No velocity threshold - attraction brings them together naturally
-                    // Calculate center of mass
-                    let total_mass = mass1 + mass2;
-                    let center_of_mass = (pos1 * mass1 + pos2 * mass2) / total_mass;
-                    let combined_vel = (vel1 * mass1 + vel2 * mass2) / total_mass;
+            let (pos1, vel1, mass1, energy1) = {
+                let p = self.protons[i].as_ref().unwrap();
+                (p.position(), p.velocity(), p.mass(), p.energy())
+            };
+            let (pos2, vel2, mass2, energy2) = {
+                let p = self.protons[j].as_ref().unwrap();
+                (p.position(), p.velocity(), p.mass(), p.energy())
+            };
+            let rel_vel = vel1 - vel2;
+
+            // Calculate center of mass
+            let total_mass = mass1 + mass2;
+            let center_of_mass = (pos1 * mass1 + pos2 * mass2) / total_mass;
+            let combined_vel = (vel1 * mass1 + vel2 * mass2) / total_mass;
+            let combined_energy = energy1 + energy2;
+
+            match reaction {
+                PairReaction::ProtonProton | PairReaction::NegativeProton => {
+                    // Create Deuterium in first slot
+                    let mut deuterium = Proton::new(
+                        center_of_mass,
+                        combined_vel,
+                        Color::from_rgba(200, 200, 200, 255),
+                        combined_energy,
+                        1,
+                    );
+                    deuterium.become_deuterium();
+                    deuterium.set_max_lifetime(proton::INFINITE_LIFETIME);
+                    self.transform_slot(i, deuterium);
+
+                    // Spawn energy wave with dark red to yellow color
+                    use macroquad::rand::gen_range;
+                    let t: f32 = gen_range(0.0, 1.0);
+                    let t = t.powf(3.0);
+                    ring_manager.add_ring_with_color(center_of_mass, Color::new(0.17 + 0.83*t, 0.8*t, 0.0, 1.0), RingOrigin::Fusion);
+
+                    // Second proton's slot becomes the emitted lepton - a positron from H+ + H+, the spare electron from H- + H+
+                    let rel_len = rel_vel.length();
+                    let emit_dir = if rel_len > 0.001 {
+                        vec2(-rel_vel.y, rel_vel.x) / rel_len
+                    } else {
+                        vec2(1.0, 0.0)
+                    };
+                    let charge = if reaction == PairReaction::ProtonProton { 1 } else { -1 };
+                    let lepton = Self::new_lepton(center_of_mass + emit_dir * 10.0, combined_vel + emit_dir * proton::LEPTON_RELEASE_SPEED, charge);
+                    self.protons[j] = Some(lepton);
+                }
+                PairReaction::DeuteriumDeuterium => {
+                    // Create Tritium in first slot - unstable, beta-decays to He3 (see handle_radioactive_decay)
+                    let (r, g, b) = proton::TRITIUM_COLOR;
+                    let mut tritium = Proton::new(
+                        center_of_mass,
+                        combined_vel,
+                        Color::from_rgba(r, g, b, 255),
+                        combined_energy * 0.5,
+                        0,
+                    );
+                    tritium.set_neutron_count(2);
+                    tritium.set_max_lifetime(proton::TRITIUM_LIFETIME);
+                    self.transform_slot(i, tritium);
+
+                    use macroquad::rand::gen_range;
+                    let t: f32 = gen_range(0.0, 1.0);
+                    let t = t.powf(3.0);
+                    ring_manager.add_ring_with_color(center_of_mass, Color::new(0.17 + 0.83*t, 0.8*t, 0.0, 1.0), RingOrigin::Fusion);
 
+                    // Second slot becomes the ejected proton
+                    let rel_len = rel_vel.length();
+                    let emit_dir = if rel_len > 0.001 {
+                        vec2(-rel_vel.y, rel_vel.x) / rel_len
+                    } else {
+                        vec2(1.0, 0.0)
+                    };
+                    let mut ejected = Proton::new(
+                        center_of_mass + emit_dir * 10.0,
+                        combined_vel + emit_dir * 200.0,
+                        WHITE,
+                        combined_energy * 0.5,
+                        1,
+                    );
+                    ejected.set_max_lifetime(proton::INFINITE_LIFETIME);
+                    self.protons[j] = Some(ejected);
+                }
+                PairReaction::DeuteriumProton => {
                     // Create Helium-3 in first slot
-                    let combined_energy = energy1 + energy2;
                     let mut he3 = Proton::new(
                         center_of_mass,
                         combined_vel,
@@ -4001,991 +5389,944 @@ impl ProtonManager {
                         1,
                     );
                     he3.set_neutron_count(2);
-                    self.protons[i] = Some(he3);
+                    self.transform_slot(i, he3);
 
-                    // Spawn energy wave (H- + H+ → He3) with dark red to yellow color
+                    // Spawn energy wave with dark red to yellow color
                     use macroquad::rand::gen_range;
                     let t: f32 = gen_range(0.0, 1.0);
                     let t = t.powf(3.0);
-                    ring_manager.add_ring_with_color(center_of_mass, Color::new(0.17 + 0.83*t, 0.8*t, 0.0, 1.0));
+                    ring_manager.add_ring_with_color(center_of_mass, Color::new(0.17 + 0.83*t, 0.8*t, 0.0, 1.0), RingOrigin::Fusion);
 
                     // Delete second proton
-                    self.protons[j] = None;
-                    break;
+                    self.remove_slot(j);
                 }
-            }
-        }
-
-        // FUSION CASE 4: Triple-alpha process - Three He4 → C12
-        // Collect all He4 particles
-        let mut he4_particles: Vec<(usize, Vec2, Vec2, f32, f32, f32)> = Vec::new();
-        for i in 0..self.protons.len() {
-            if let Some(proton) = &self.protons[i] {
-                if proton.is_alive() && proton.is_stable_helium4() {
-                    he4_particles.push((
-                        i,
-                        proton.position(),
-                        proton.velocity(),
-                        proton.radius(),
-                        proton.mass(),
-                        proton.energy(),
-                    ));
-                }
-            }
-        }
-
-        // Check all combinations of three He4 particles
-        for i in 0..he4_particles.len() {
-            for j in (i + 1)..he4_particles.len() {
-                for k in (j + 1)..he4_particles.len() {
-                    let (idx1, pos1, vel1, r1, m1, e1) = he4_particles[i];
-                    let (idx2, pos2, vel2, r2, m2, e2) = he4_particles[j];
-                    let (idx3, pos3, vel3, r3, m3, e3) = he4_particles[k];
-
-                    // Check if all three are within collision range of each other
-                    let dist12_sq = pos1.distance_squared(pos2);
-                    let dist13_sq = pos1.distance_squared(pos3);
-                    let dist23_sq = pos2.distance_squared(pos3);
-
-                    let collision_dist12 = r1 + r2;
-                    let collision_dist13 = r1 + r3;
-                    let collision_dist23 = r2 + r3;
-
-                    // All three must be colliding with each other
-                    if dist12_sq <= collision_dist12 * collision_dist12 &&
-                       dist13_sq <= collision_dist13 * collision_dist13 &&
-                       dist23_sq <= collision_dist23 * collision_dist23
-                    {
-                        // Calculate combined energy
-                        let combined_energy = e1 + e2 + e3;
-
-                        // Check energy threshold
-                        if combined_energy < proton::TRIPLE_ALPHA_ENERGY_THRESHOLD {
-                            continue;
-                        }
-
-                        // Calculate average relative velocity
-                        let rel_vel12 = vel1 - vel2;
-                        let rel_vel13 = vel1 - vel3;
-                        let rel_vel23 = vel2 - vel3;
-                        let avg_rel_speed = (rel_vel12.length() + rel_vel13.length() + rel_vel23.length()) / 3.0;
+                PairReaction::Helium3Helium3 => {
+                    // Create Helium-4 in first slot
+                    let mut he4 = Proton::new(
+                        center_of_mass,
+                        combined_vel,
+                        Color::from_rgba(255, 255, 100, 255),
+                        combined_energy * proton::HELIUM3_FUSION_HE4_ENERGY_FRACTION,
+                        2,
+                    );
+                    he4.set_neutron_count(2);
+                    he4.set_max_lifetime(-1.0); // Helium-4 is stable
+                    self.transform_slot(i, he4);
 
-                        // Check velocity threshold
-                        if avg_rel_speed < proton::TRIPLE_ALPHA_VELOCITY_THRESHOLD {
-                            continue;
-                        }
+                    // Spawn BIG energy waves with random colors between dark red and almost yellow
+                    // Dark red = (0.17,0,0), Almost yellow = (1.0,0.8,0)
+                    // Use cubic bias to favor dark red: t^3 keeps most values near 0
+                    use macroquad::rand::gen_range;
+                    let t1: f32 = gen_range(0.0, 1.0);
+                    let t1 = t1.powf(3.0);
+                    let color1 = Color::new(0.17 + 0.83*t1, 0.8*t1, 0.0, 1.0);
+                    ring_manager.add_ring_with_color(center_of_mass, color1, RingOrigin::Fusion);
+
+                    let t2: f32 = gen_range(0.0, 1.0);
+                    let t2 = t2.powf(3.0);
+                    let color2 = Color::new(0.17 + 0.83*t2, 0.8*t2, 0.0, 1.0);
+                    ring_manager.add_ring_with_color(center_of_mass, color2, RingOrigin::Fusion);
+
+                    // Spawn 2 high-energy protons, back to back in the center-of-mass frame - the first takes the
+                    // second He3's slot, the other the free slot checked for above
+                    let perp_vel = vec2(-rel_vel.y, rel_vel.x);
+                    let perp_len = perp_vel.length();
+                    let perp_dir = if perp_len > 0.001 {
+                        perp_vel / perp_len
+                    } else {
+                        vec2(1.0, 0.0)
+                    };
 
-                        // FUSION OCCURS!
-                        // Calculate center of mass
-                        let total_mass = m1 + m2 + m3;
-                        let center_of_mass = (pos1 * m1 + pos2 * m2 + pos3 * m3) / total_mass;
-                        let combined_vel = (vel1 * m1 + vel2 * m2 + vel3 * m3) / total_mass;
-
-                        // Create Carbon-12 in first slot
-                        let mut c12 = Proton::new(
-                            center_of_mass,
-                            combined_vel,
-                            Color::from_rgba(100, 100, 100, 255),
-                            combined_energy,
-                            6,
+                    let mut ejected_momentum = Vec2::ZERO;
+                    for side in [1.0, -1.0] {
+                        let mut ejected = Proton::new(
+                            center_of_mass + perp_dir * side * proton::HELIUM3_FUSION_EJECT_OFFSET,
+                            combined_vel + perp_dir * side * proton::HELIUM3_FUSION_RELEASE_SPEED,
+                            WHITE,
+                            combined_energy * proton::HELIUM3_FUSION_PROTON_ENERGY_FRACTION,
+                            1,
                         );
-                        c12.set_neutron_count(6);
-                        c12.set_max_lifetime(-1.0); // Carbon-12 is stable
-                        self.protons[idx1] = Some(c12);
-
-                        // Spawn energy wave with dark red to almost yellow (favoring dark red)
-                        // Dark red = (0.17,0,0), Almost yellow = (1.0,0.8,0)
-                        // Use cubic bias to favor dark red: t^3 keeps most values near 0
-                        use macroquad::rand::gen_range;
-                        let t: f32 = gen_range(0.0, 1.0);
-                        let t = t.powf(3.0);
-                        let fusion_color = Color::new(0.17 + 0.83*t, 0.8*t, 0.0, 1.0);
-                        ring_manager.add_ring_with_color(center_of_mass, fusion_color);
-
-                        // Delete the other two He4 particles
-                        self.protons[idx2] = None;
-                        self.protons[idx3] = None;
+                        ejected.set_max_lifetime(proton::INFINITE_LIFETIME);
+                        ejected_momentum += ejected.velocity() * ejected.mass();
+                        if side > 0.0 {
+                            self.protons[j] = Some(ejected);
+                        } else {
+                            self.insert_proton(ejected);
+                        }
+                    }
 
-                                // Only perform one fusion per update cycle
-                        return;
+                    // The He4 recoils with whatever momentum the protons did not carry off
+                    if let Some(he4) = &mut self.protons[i] {
+                        let recoil = (vel1 * mass1 + vel2 * mass2 - ejected_momentum) / he4.mass();
+                        he4.set_velocity(recoil);
                     }
                 }
             }
-        }
 
-        // BONDING CASE: C12 + He4 → O16 bonded pair (alpha capture on carbon)
-        // This MUST happen before Ne20 formation check!
-        // Collect all unbonded C12 and He4 particles
-        let mut c12_particles: Vec<(usize, Vec2, Vec2, f32)> = Vec::new();
-        let mut he4_particles: Vec<(usize, Vec2, Vec2, f32)> = Vec::new();
+            // The product (always left in the first slot) starts hot and resists freezing
+            self.heat_slot(i, proton::FUSION_HEAT);
+            self.push_event(SimEventKind::Fusion, i);
+        }
 
-        for i in 0..self.protons.len() {
-            if let Some(proton) = &self.protons[i] {
-                if proton.is_alive() && !proton.is_oxygen16_bonded() {
-                    if proton.is_stable_carbon12() {
-                        c12_particles.push((i, proton.position(), proton.velocity(), proton.radius()));
-                    } else if proton.is_stable_helium4() {
-                        he4_particles.push((i, proton.position(), proton.velocity(), proton.radius()));
+        // FUSION CASE 4: Triple-alpha process - Three He4 → C12
+        if self.reaction_flags.contains(ReactionFlags::TRIPLE_ALPHA) {
+            // Collect all He4 particles
+            let mut he4_particles: Vec<(usize, Vec2, Vec2, f32, f32, f32)> = Vec::new();
+            for i in 0..self.protons.len() {
+                if let Some(proton) = &self.protons[i] {
+                    if proton.is_alive() && proton.is_stable_helium4() {
+                        he4_particles.push((
+                            i,
+                            proton.position(),
+                            proton.velocity(),
+                            proton.radius(),
+                            proton.mass(),
+                            proton.energy(),
+                        ));
                     }
                 }
             }
-        }
 
-        // Check all C12-He4 pairs for bonding
-        for (c12_idx, c12_pos, c12_vel, c12_r) in &c12_particles {
-            for (he4_idx, he4_pos, he4_vel, he4_r) in &he4_particles {
-                let dist_sq = c12_pos.distance_squared(*he4_pos);
-                let collision_dist = c12_r + he4_r;
+            // Every candidate triple, best first - only pairs from each He4's neighborhood are checked
+            let neighborhoods = Self::triple_alpha_neighborhoods(&he4_particles.iter().map(|p| (p.1, p.3)).collect::<Vec<_>>());
+            let mut triples: Vec<(usize, usize, usize, ReactionPriority)> = Vec::new();
+            for (i, near) in neighborhoods.iter().enumerate() {
+                for (a, &j) in near.iter().enumerate() {
+                    for &k in &near[a + 1..] {
+                        let (_, pos1, vel1, r1, _, e1) = he4_particles[i];
+                        let (_, pos2, vel2, r2, _, e2) = he4_particles[j];
+                        let (_, pos3, vel3, r3, _, e3) = he4_particles[k];
 
-                // Check if colliding
-                if dist_sq <= collision_dist * collision_dist {
-                    let dist = dist_sq.sqrt();
+                        // Check if all three are within collision range of each other
+                        let dist12_sq = pos1.distance_squared(pos2);
+                        let dist13_sq = pos1.distance_squared(pos3);
+                        let dist23_sq = pos2.distance_squared(pos3);
 
-                    // Calculate relative velocity
-                    let rel_vel = *c12_vel - *he4_vel;
-                    let rel_speed = rel_vel.length();
-
-                    // Check velocity threshold
-                    if rel_speed >= proton::OXYGEN16_CAPTURE_VELOCITY_THRESHOLD {
-                        // BONDING OCCURS!
-                        // Calculate bond rest length
-                        let bond_rest_length = dist.max(1.0);
-
-                        // Calculate midpoint for energy wave
-                        let midpoint = (*c12_pos + *he4_pos) / 2.0;
-
-                        // Set bonding on both particles
-                        if let Some(c12) = &mut self.protons[*c12_idx] {
-                            c12.set_oxygen16_bonded(true);
-                            c12.set_oxygen_bond_partner(Some(*he4_idx));
-                            c12.set_oxygen_bond_rest_length(bond_rest_length);
-                        }
-                        if let Some(he4) = &mut self.protons[*he4_idx] {
-                            he4.set_oxygen16_bonded(true);
-                            he4.set_oxygen_bond_partner(Some(*c12_idx));
-                            he4.set_oxygen_bond_rest_length(bond_rest_length);
-                        }
+                        let collision_dist12 = r1 + r2;
+                        let collision_dist13 = r1 + r3;
+                        let collision_dist23 = r2 + r3;
 
-                        // Spawn energy wave at bonding site (dark red to yellow, favoring dark red)
-                        use macroquad::rand::gen_range;
-                        let t: f32 = gen_range(0.0, 1.0);
-                        let t = t.powf(3.0);
-                        ring_manager.add_ring_with_color(midpoint, Color::new(0.17 + 0.83*t, 0.8*t, 0.0, 1.0));
+                        // All three must be colliding with each other
+                        if dist12_sq <= collision_dist12 * collision_dist12 &&
+                           dist13_sq <= collision_dist13 * collision_dist13 &&
+                           dist23_sq <= collision_dist23 * collision_dist23
+                        {
+                            // Calculate combined energy
+                            let combined_energy = e1 + e2 + e3;
 
-                        // Only one bonding per update cycle
-                        return;
-                    }
-                }
-            }
-        }
+                            // Check energy threshold
+                            if combined_energy < proton::TRIPLE_ALPHA_ENERGY_THRESHOLD {
+                                continue;
+                            }
 
-        // FUSION CASE 5: Neon-20 formation - O16 bonded pair + He4 → Ne20
-        // Collect all O16 bonded pairs
-        let mut o16_pairs: Vec<(usize, usize, Vec2, f32, f32, f32, Vec2, Vec2)> = Vec::new();
-        for i in 0..self.protons.len() {
-            if let Some(proton) = &self.protons[i] {
-                if proton.is_alive() && proton.is_oxygen16_bonded() {
-                    if let Some(partner_idx) = proton.oxygen_bond_partner() {
-                        if partner_idx > i {
-                            if let Some(partner) = &self.protons[partner_idx] {
-                                if partner.is_alive() && partner.is_oxygen16_bonded() {
-                                    // Calculate midpoint of O16 pair
-                                    let midpoint = (proton.position() + partner.position()) / 2.0;
-                                    let mass1 = proton.mass();
-                                    let mass2 = partner.mass();
-                                    let energy1 = proton.energy();
-                                    let energy2 = partner.energy();
-                                    let vel1 = proton.velocity();
-                                    let vel2 = partner.velocity();
-                                    let radius1 = proton.radius();
-                                    let radius2 = partner.radius();
-                                    // Use average radius of the pair
-                                    let avg_radius = (radius1 + radius2) / 2.0;
-                                    o16_pairs.push((i, partner_idx, midpoint, mass1 + mass2, energy1 + energy2, avg_radius, vel1, vel2));
-                                }
+                            // Calculate average relative velocity
+                            let rel_vel12 = vel1 - vel2;
+                            let rel_vel13 = vel1 - vel3;
+                            let rel_vel23 = vel2 - vel3;
+                            let avg_rel_speed = (rel_vel12.length() + rel_vel13.length() + rel_vel23.length()) / 3.0;
+
+                            // Check velocity threshold
+                            if avg_rel_speed < self.config.triple_alpha_velocity_threshold {
+                                continue;
                             }
+
+                            let avg_dist = (dist12_sq.sqrt() + dist13_sq.sqrt() + dist23_sq.sqrt()) / 3.0;
+                            let priority = ReactionPriority::new(avg_rel_speed, avg_dist, (pos1 + pos2 + pos3) / 3.0);
+                            triples.push((i, j, k, priority));
                         }
                     }
                 }
             }
-        }
 
-        // Collect all He4 particles (excluding those already bonded in O16 pairs)
-        let mut he4_for_neon: Vec<(usize, Vec2, Vec2, f32, f32, f32)> = Vec::new();
-        for i in 0..self.protons.len() {
-            if let Some(proton) = &self.protons[i] {
-                if proton.is_alive() && proton.is_stable_helium4() && !proton.is_oxygen16_bonded() {
-                    he4_for_neon.push((
-                        i,
-                        proton.position(),
-                        proton.velocity(),
-                        proton.radius(),
-                        proton.mass(),
-                        proton.energy(),
-                    ));
+            triples.sort_by(|a, b| b.3.compare(&a.3));
+            for (i, j, k, _) in triples {
+                if budget == 0 {
+                    return;
+                }
+                let (idx1, pos1, vel1, _, m1, e1) = he4_particles[i];
+                let (idx2, pos2, vel2, _, m2, e2) = he4_particles[j];
+                let (idx3, pos3, vel3, _, m3, e3) = he4_particles[k];
+                if [idx1, idx2, idx3].iter().any(|slot| consumed.contains(slot)) {
+                    continue;
                 }
-            }
-        }
 
-        // Check for O16 + He4 collisions to form Ne20
-        for (o16_idx1, o16_idx2, o16_midpoint, o16_mass, o16_energy, o16_radius, o16_vel1, o16_vel2) in o16_pairs {
-            for (he4_idx, he4_pos, he4_vel, he4_radius, he4_mass, he4_energy) in &he4_for_neon {
-                // Calculate distance from He4 to O16 midpoint
-                let dist_sq = o16_midpoint.distance_squared(*he4_pos);
-                let collision_dist = o16_radius + he4_radius;
-
-                // Check if colliding
-                if dist_sq <= collision_dist * collision_dist {
-                    // Calculate relative velocity (use average O16 velocity)
-                    let o16_avg_vel = (o16_vel1 + o16_vel2) / 2.0;
-                    let rel_vel = o16_avg_vel - *he4_vel;
-                    let rel_speed = rel_vel.length();
-
-                    // Check velocity threshold
-                    if rel_speed >= proton::NEON20_CAPTURE_VELOCITY_THRESHOLD {
-                        // NEON-20 FORMATION OCCURS!
-                        // Calculate center of mass and combined velocity
-                        let total_mass = o16_mass + *he4_mass;
-                        let combined_momentum = o16_vel1 * (o16_mass / 2.0) + o16_vel2 * (o16_mass / 2.0) + *he4_vel * *he4_mass;
-                        let combined_vel = combined_momentum / total_mass;
-                        let combined_energy = o16_energy + *he4_energy;
-
-                        // Calculate center of mass position
-                        let (o16_pos1, o16_pos2) = {
-                            let p1 = self.protons[o16_idx1].as_ref().unwrap().position();
-                            let p2 = self.protons[o16_idx2].as_ref().unwrap().position();
-                            (p1, p2)
-                        };
-                        let center_of_mass = (o16_pos1 * (o16_mass / 2.0) + o16_pos2 * (o16_mass / 2.0) + *he4_pos * *he4_mass) / total_mass;
-
-                        // Create Ne20 in first O16 slot
-                        let mut ne20 = Proton::new(
-                            center_of_mass,
-                            combined_vel,
-                            Color::from_rgba(255, 100, 150, 255),
-                            combined_energy,
-                            10, // Total charge: 6 (C) + 2 (He from O16) + 2 (He4) = 10
-                        );
-                        ne20.set_neutron_count(10); // Total neutrons: 6 (C) + 2 (He from O16) + 2 (He4) = 10
-                        ne20.set_max_lifetime(-1.0); // Ne20 is stable
-                        ne20.set_neon20(true);
-                        self.protons[o16_idx1] = Some(ne20);
+                // FUSION OCCURS!
+                // Calculate center of mass
+                let combined_energy = e1 + e2 + e3;
+                let total_mass = m1 + m2 + m3;
+                let center_of_mass = (pos1 * m1 + pos2 * m2 + pos3 * m3) / total_mass;
+                let combined_vel = (vel1 * m1 + vel2 * m2 + vel3 * m3) / total_mass;
 
-                        // Delete the other particles
-                        self.protons[o16_idx2] = None;
-                        self.protons[*he4_idx] = None;
+                // Create Carbon-12 in first slot
+                let mut c12 = Proton::new(
+                    center_of_mass,
+                    combined_vel,
+                    Color::from_rgba(100, 100, 100, 255),
+                    combined_energy,
+                    6,
+                );
+                c12.set_neutron_count(6);
+                c12.set_max_lifetime(-1.0); // Carbon-12 is stable
+                self.transform_slot(idx1, c12);
 
-                        // Spawn energy wave (dark red to yellow, favoring dark red)
-                        use macroquad::rand::gen_range;
-                        let t: f32 = gen_range(0.0, 1.0);
-                        let t = t.powf(3.0);
-                        ring_manager.add_ring_with_color(center_of_mass, Color::new(0.17 + 0.83*t, 0.8*t, 0.0, 1.0));
+                // Spawn energy wave with dark red to almost yellow (favoring dark red)
+                // Dark red = (0.17,0,0), Almost yellow = (1.0,0.8,0)
+                // Use cubic bias to favor dark red: t^3 keeps most values near 0
+                use macroquad::rand::gen_range;
+                let t: f32 = gen_range(0.0, 1.0);
+                let t = t.powf(3.0);
+                let fusion_color = Color::new(0.17 + 0.83*t, 0.8*t, 0.0, 1.0);
+                ring_manager.add_ring_with_color(center_of_mass, fusion_color, RingOrigin::Fusion);
 
-                        // Only one neon formation per update cycle
-                        return;
-                    }
-                }
-            }
-        }
+                // Delete the other two He4 particles
+                self.remove_slot(idx2);
+                self.remove_slot(idx3);
 
-        // FUSION CASE 6: Magnesium-24 formation - Ne20 + He4 → Mg24
-        // Collect all Ne20 particles
-        let mut ne20_particles: Vec<(usize, Vec2, Vec2, f32, f32, f32)> = Vec::new();
-        for i in 0..self.protons.len() {
-            if let Some(proton) = &self.protons[i] {
-                if proton.is_alive() && proton.is_neon20() {
-                    ne20_particles.push((
-                        i,
-                        proton.position(),
-                        proton.velocity(),
-                        proton.radius(),
-                        proton.mass(),
-                        proton.energy(),
-                    ));
-                }
+                self.heat_slot(idx1, proton::FUSION_HEAT);
+                self.push_event(SimEventKind::Fusion, idx1);
+                self.record_reaction(ReactionKind::TripleAlpha);
+                consumed.extend([idx1, idx2, idx3]);
+                budget -= 1;
             }
         }
 
-        // Collect He4 particles (excluding those already bonded in O16 pairs)
-        let mut he4_for_mg: Vec<(usize, Vec2, Vec2, f32, f32, f32)> = Vec::new();
-        for i in 0..self.protons.len() {
-            if let Some(proton) = &self.protons[i] {
-                if proton.is_alive() && proton.is_stable_helium4() && !proton.is_oxygen16_bonded() {
-                    he4_for_mg.push((
-                        i,
-                        proton.position(),
-                        proton.velocity(),
-                        proton.radius(),
-                        proton.mass(),
-                        proton.energy(),
-                    ));
+        // BONDING CASE: C12 + He4 → O16 bonded pair (alpha capture on carbon)
+        if self.reaction_flags.contains(ReactionFlags::ALPHA_CAPTURE) {
+            // This MUST happen before Ne20 formation check!
+            // Collect all unbonded C12 and He4 particles
+            let mut c12_particles: Vec<(usize, Vec2, Vec2, f32)> = Vec::new();
+            let mut he4_particles: Vec<(usize, Vec2, Vec2, f32)> = Vec::new();
+
+            for i in 0..self.protons.len() {
+                if let Some(proton) = &self.protons[i] {
+                    if proton.is_alive() && !proton.is_oxygen16_bonded() {
+                        if proton.is_stable_carbon12() {
+                            c12_particles.push((i, proton.position(), proton.velocity(), proton.radius()));
+                        } else if proton.is_stable_helium4() {
+                            he4_particles.push((i, proton.position(), proton.velocity(), proton.radius()));
+                        }
+                    }
                 }
             }
-        }
-
-        // Check for Ne20 + He4 collisions to form Mg24
-        for (ne20_idx, ne20_pos, ne20_vel, ne20_radius, ne20_mass, ne20_energy) in &ne20_particles {
-            for (he4_idx, he4_pos, he4_vel, he4_radius, he4_mass, he4_energy) in &he4_for_mg {
-                let dist_sq = ne20_pos.distance_squared(*he4_pos);
-                let collision_dist = ne20_radius + he4_radius;
-
-                if dist_sq <= collision_dist * collision_dist {
-                    let rel_vel = *ne20_vel - *he4_vel;
-                    let rel_speed = rel_vel.length();
-
-                    if rel_speed >= proton::MAGNESIUM24_CAPTURE_VELOCITY_THRESHOLD {
-                        // Mg24 formation!
-                        let total_mass = ne20_mass + he4_mass;
-                        let combined_momentum = *ne20_vel * *ne20_mass + *he4_vel * *he4_mass;
-                        let combined_vel = combined_momentum / total_mass;
-                        let combined_energy = ne20_energy + he4_energy;
-                        let center_of_mass = (*ne20_pos * *ne20_mass + *he4_pos * *he4_mass) / total_mass;
-
-                        let mut mg24 = Proton::new(
-                            center_of_mass,
-                            combined_vel,
-                            Color::from_rgba(200, 200, 220, 255),
-                            combined_energy,
-                            12,
-                        );
-                        mg24.set_neutron_count(12);
-                        mg24.set_max_lifetime(-1.0);
-                        mg24.set_magnesium24(true);
-                        self.protons[*ne20_idx] = Some(mg24);
 
-                        self.protons[*he4_idx] = None;
+            // Check all C12-He4 pairs for bonding - every candidate pair, best first
+            let mut pairs: Vec<(usize, usize, ReactionPriority)> = Vec::new();
+            for (a, (_, c12_pos, c12_vel, c12_r)) in c12_particles.iter().enumerate() {
+                for (b, (_, he4_pos, he4_vel, he4_r)) in he4_particles.iter().enumerate() {
+                    let dist_sq = c12_pos.distance_squared(*he4_pos);
+                    let collision_dist = c12_r + he4_r;
 
-                        use macroquad::rand::gen_range;
-                        let t: f32 = gen_range(0.0, 1.0);
-                        let t = t.powf(3.0);
-                        ring_manager.add_ring_with_color(center_of_mass, Color::new(0.17 + 0.83*t, 0.8*t, 0.0, 1.0));
+                    // Check if colliding
+                    if dist_sq <= collision_dist * collision_dist {
+                        // Calculate relative velocity
+                        let rel_vel = *c12_vel - *he4_vel;
+                        let rel_speed = rel_vel.length();
 
-                        return;
+                        // Check velocity threshold
+                        if rel_speed >= proton::OXYGEN16_CAPTURE_VELOCITY_THRESHOLD {
+                            let priority = ReactionPriority::new(rel_speed, dist_sq.sqrt(), (*c12_pos + *he4_pos) / 2.0);
+                            pairs.push((a, b, priority));
+                        }
                     }
                 }
             }
-        }
 
-        // FUSION CASE 7: Silicon-28 formation - Mg24 + He4 → Si28
-        // Collect all Mg24 particles
-        let mut mg24_particles: Vec<(usize, Vec2, Vec2, f32, f32, f32)> = Vec::new();
-        for i in 0..self.protons.len() {
-            if let Some(proton) = &self.protons[i] {
-                if proton.is_alive() && proton.is_magnesium24() {
-                    mg24_particles.push((
-                        i,
-                        proton.position(),
-                        proton.velocity(),
-                        proton.radius(),
-                        proton.mass(),
-                        proton.energy(),
-                    ));
+            pairs.sort_by(|a, b| b.2.compare(&a.2));
+            for (a, b, _) in pairs {
+                if budget == 0 {
+                    return;
                 }
-            }
-        }
-
-        // Collect He4 particles (excluding those already bonded in O16 pairs)
-        let mut he4_for_si: Vec<(usize, Vec2, Vec2, f32, f32, f32)> = Vec::new();
-        for i in 0..self.protons.len() {
-            if let Some(proton) = &self.protons[i] {
-                if proton.is_alive() && proton.is_stable_helium4() && !proton.is_oxygen16_bonded() {
-                    he4_for_si.push((
-                        i,
-                        proton.position(),
-                        proton.velocity(),
-                        proton.radius(),
-                        proton.mass(),
-                        proton.energy(),
-                    ));
+                let (c12_idx, c12_pos, c12_vel, _) = c12_particles[a];
+                let (he4_idx, he4_pos, he4_vel, _) = he4_particles[b];
+                if consumed.contains(&c12_idx) || consumed.contains(&he4_idx) {
+                    continue;
                 }
-            }
-        }
-
-        // Check for Mg24 + He4 collisions to form Si28
-        for (mg24_idx, mg24_pos, mg24_vel, mg24_radius, mg24_mass, mg24_energy) in &mg24_particles {
-            for (he4_idx, he4_pos, he4_vel, he4_radius, he4_mass, he4_energy) in &he4_for_si {
-                let dist_sq = mg24_pos.distance_squared(*he4_pos);
-                let collision_dist = mg24_radius + he4_radius;
+                let mass_of = |slot: usize| self.protons[slot].as_ref().map_or(0.0, Proton::mass);
+                let momentum_before = c12_vel * mass_of(c12_idx) + he4_vel * mass_of(he4_idx);
 
-                if dist_sq <= collision_dist * collision_dist {
-                    let rel_vel = *mg24_vel - *he4_vel;
-                    let rel_speed = rel_vel.length();
+                // BONDING OCCURS!
+                // Calculate bond rest length
+                let bond_rest_length = c12_pos.distance(he4_pos).max(1.0);
 
-                    if rel_speed >= proton::SILICON28_CAPTURE_VELOCITY_THRESHOLD {
-                        // Si28 formation!
-                        let total_mass = mg24_mass + he4_mass;
-                        let combined_momentum = *mg24_vel * *mg24_mass + *he4_vel * *he4_mass;
-                        let combined_vel = combined_momentum / total_mass;
-                        let combined_energy = mg24_energy + he4_energy;
-                        let center_of_mass = (*mg24_pos * *mg24_mass + *he4_pos * *he4_mass) / total_mass;
+                // Calculate midpoint for energy wave
+                let midpoint = (c12_pos + he4_pos) / 2.0;
 
-                        let mut si28 = Proton::new(
-                            center_of_mass,
-                            combined_vel,
-                            Color::from_rgba(160, 130, 90, 255),
-                            combined_energy,
-                            14,
-                        );
-                        si28.set_neutron_count(14);
-                        si28.set_max_lifetime(-1.0);
-                        si28.set_silicon28(true);
-                        self.protons[*mg24_idx] = Some(si28);
+                // Set bonding on both particles - either may have just been fused into a reused slot
+                self.claim_bond_slot(c12_idx);
+                self.claim_bond_slot(he4_idx);
+                if let Some(c12) = &mut self.protons[c12_idx] {
+                    c12.set_oxygen16_bonded(true);
+                    c12.set_oxygen_bond_partner(Some(he4_idx));
+                    c12.set_oxygen_bond_rest_length(bond_rest_length);
+                }
+                if let Some(he4) = &mut self.protons[he4_idx] {
+                    he4.set_oxygen16_bonded(true);
+                    he4.set_oxygen_bond_partner(Some(c12_idx));
+                    he4.set_oxygen_bond_rest_length(bond_rest_length);
+                }
 
-                        self.protons[*he4_idx] = None;
+                // Spawn energy wave at bonding site (dark red to yellow, favoring dark red)
+                use macroquad::rand::gen_range;
+                let t: f32 = gen_range(0.0, 1.0);
+                let t = t.powf(3.0);
+                ring_manager.add_ring_with_color(midpoint, Color::new(0.17 + 0.83*t, 0.8*t, 0.0, 1.0), RingOrigin::Fusion);
 
-                        use macroquad::rand::gen_range;
-                        let t: f32 = gen_range(0.0, 1.0);
-                        let t = t.powf(3.0);
-                        ring_manager.add_ring_with_color(center_of_mass, Color::new(0.17 + 0.83*t, 0.8*t, 0.0, 1.0));
+                // Recoil - a slow e+ e- pair leaves along the alpha's path
+                let approach = alpha_approach(he4_pos, he4_vel, c12_pos, c12_vel);
+                self.emit_capture_pair(&[c12_idx, he4_idx], momentum_before, approach);
 
-                        return;
-                    }
-                }
+                self.heat_slot(c12_idx, proton::FUSION_HEAT);
+                self.heat_slot(he4_idx, proton::FUSION_HEAT);
+                self.push_event(SimEventKind::Fusion, c12_idx);
+                self.record_reaction(ReactionKind::CarbonAlpha);
+                consumed.extend([c12_idx, he4_idx]);
+                budget -= 1;
             }
         }
 
-        // FUSION CASE 8: Sulfur-32 formation - Si28 + He4 → S32
-        // Collect all Si28 particles
-        let mut si28_particles: Vec<(usize, Vec2, Vec2, f32, f32, f32)> = Vec::new();
-        for i in 0..self.protons.len() {
-            if let Some(proton) = &self.protons[i] {
-                if proton.is_alive() && proton.is_silicon28() {
-                    si28_particles.push((
-                        i,
-                        proton.position(),
-                        proton.velocity(),
-                        proton.radius(),
-                        proton.mass(),
-                        proton.energy(),
-                    ));
-                }
-            }
-        }
+        // FUSION CASE 5: Neon-20 formation - O16 (bonded pair or consolidated) + He4 → Ne20
+        if self.reaction_flags.contains(ReactionFlags::ALPHA_CAPTURE) {
+            let o16_nuclei = self.collect_oxygen16();
 
-        // Collect He4 particles (excluding those already bonded in O16 pairs)
-        let mut he4_for_s: Vec<(usize, Vec2, Vec2, f32, f32, f32)> = Vec::new();
-        for i in 0..self.protons.len() {
-            if let Some(proton) = &self.protons[i] {
-                if proton.is_alive() && proton.is_stable_helium4() && !proton.is_oxygen16_bonded() {
-                    he4_for_s.push((
-                        i,
-                        proton.position(),
-                        proton.velocity(),
-                        proton.radius(),
-                        proton.mass(),
-                        proton.energy(),
-                    ));
+            // Collect all He4 particles (excluding those already bonded in O16 pairs)
+            let mut he4_for_neon: Vec<(usize, Vec2, Vec2, f32, f32, f32)> = Vec::new();
+            for i in 0..self.protons.len() {
+                if let Some(proton) = &self.protons[i] {
+                    if proton.is_alive() && proton.is_stable_helium4() && !proton.is_oxygen16_bonded() {
+                        he4_for_neon.push((
+                            i,
+                            proton.position(),
+                            proton.velocity(),
+                            proton.radius(),
+                            proton.mass(),
+                            proton.energy(),
+                        ));
+                    }
                 }
             }
-        }
-
-        // Check for Si28 + He4 collisions to form S32
-        for (si28_idx, si28_pos, si28_vel, si28_radius, si28_mass, si28_energy) in &si28_particles {
-            for (he4_idx, he4_pos, he4_vel, he4_radius, he4_mass, he4_energy) in &he4_for_s {
-                let dist_sq = si28_pos.distance_squared(*he4_pos);
-                let collision_dist = si28_radius + he4_radius;
 
-                if dist_sq <= collision_dist * collision_dist {
-                    let rel_vel = *si28_vel - *he4_vel;
-                    let rel_speed = rel_vel.length();
-
-                    if rel_speed >= proton::SULFUR32_CAPTURE_VELOCITY_THRESHOLD {
-                        // S32 formation!
-                        let total_mass = si28_mass + he4_mass;
-                        let combined_momentum = *si28_vel * *si28_mass + *he4_vel * *he4_mass;
-                        let combined_vel = combined_momentum / total_mass;
-                        let combined_energy = si28_energy + he4_energy;
-                        let center_of_mass = (*si28_pos * *si28_mass + *he4_pos * *he4_mass) / total_mass;
-
-                        let mut s32 = Proton::new(
-                            center_of_mass,
-                            combined_vel,
-                            Color::from_rgba(220, 220, 80, 255),
-                            combined_energy,
-                            16,
-                        );
-                        s32.set_neutron_count(16);
-                        s32.set_max_lifetime(-1.0);
-                        s32.set_sulfur32(true);
-                        self.protons[*si28_idx] = Some(s32);
+            // Check for O16 + He4 collisions to form Ne20 - every candidate pair, best first
+            let mut pairs: Vec<(usize, usize, ReactionPriority)> = Vec::new();
+            for (a, o16) in o16_nuclei.iter().enumerate() {
+                for (b, (_, he4_pos, he4_vel, he4_radius, _, _)) in he4_for_neon.iter().enumerate() {
+                    // Calculate distance from He4 to O16 center
+                    let dist_sq = o16.center.distance_squared(*he4_pos);
+                    let collision_dist = o16.radius + he4_radius;
 
-                        self.protons[*he4_idx] = None;
+                    // Check if colliding
+                    if dist_sq <= collision_dist * collision_dist {
+                        // Calculate relative velocity (use average O16 velocity)
+                        let o16_avg_vel = (o16.vel1 + o16.vel2) / 2.0;
+                        let rel_vel = o16_avg_vel - *he4_vel;
+                        let rel_speed = rel_vel.length();
 
-                        use macroquad::rand::gen_range;
-                        let t: f32 = gen_range(0.0, 1.0);
-                        let t = t.powf(3.0);
-                        ring_manager.add_ring_with_color(center_of_mass, Color::new(0.17 + 0.83*t, 0.8*t, 0.0, 1.0));
-
-                        return;
-                    }
-                }
-            }
-        }
-
-        // WATER FORMATION: O16 bonded pair + 2 H atoms → H2O molecule
-        // Collect all O16 bonded pairs
-        let mut o16_pairs: Vec<(usize, usize, Vec2, f32, f32, f32, Vec2, Vec2)> = Vec::new();
-        for i in 0..self.protons.len() {
-            if let Some(proton) = &self.protons[i] {
-                if proton.is_alive() && proton.is_oxygen16_bonded() {
-                    if let Some(partner_idx) = proton.oxygen_bond_partner() {
-                        if partner_idx > i {
-                            if let Some(partner) = &self.protons[partner_idx] {
-                                if partner.is_alive() && partner.is_oxygen16_bonded() {
-                                    // Calculate midpoint of O16 pair
-                                    let midpoint = (proton.position() + partner.position()) / 2.0;
-                                    let mass1 = proton.mass();
-                                    let mass2 = partner.mass();
-                                    let energy1 = proton.energy();
-                                    let energy2 = partner.energy();
-                                    let vel1 = proton.velocity();
-                                    let vel2 = partner.velocity();
-                                    o16_pairs.push((i, partner_idx, midpoint, mass1 + mass2, energy1 + energy2, 0.0, vel1, vel2));
-                                }
-                            }
+                        // Check velocity threshold
+                        if rel_speed >= proton::NEON20_CAPTURE_VELOCITY_THRESHOLD {
+                            let priority = ReactionPriority::new(rel_speed, dist_sq.sqrt(), (o16.center + *he4_pos) / 2.0);
+                            pairs.push((a, b, priority));
                         }
                     }
                 }
             }
-        }
 
-        // Collect all available H atoms (not crystallized)
-        let mut h_atoms: Vec<(usize, Vec2, f32, f32, Vec2)> = Vec::new();
-        for i in 0..self.protons.len() {
-            if let Some(proton) = &self.protons[i] {
-                if proton.is_alive() && proton.charge() == 0 && proton.neutron_count() == 1 && !proton.is_crystallized() {
-                    h_atoms.push((i, proton.position(), proton.mass(), proton.energy(), proton.velocity()));
+            pairs.sort_by(|a, b| b.2.compare(&a.2));
+            for (a, b, _) in pairs {
+                if budget == 0 {
+                    return;
                 }
-            }
-        }
-
-        // Check each O16 pair for nearby H atoms
-        for (o16_idx1, o16_idx2, o16_midpoint, o16_mass, o16_energy, _, o16_vel1, o16_vel2) in o16_pairs {
-            // Find two H atoms near the O16 midpoint
-            let mut nearby_h: Vec<(usize, f32, f32, f32, Vec2)> = Vec::new();
-            for (h_idx, h_pos, h_mass, h_energy, h_vel) in &h_atoms {
-                let dist = o16_midpoint.distance(*h_pos);
-                if dist < proton::WATER_CAPTURE_RANGE {
-                    nearby_h.push((*h_idx, *h_mass, *h_energy, dist, *h_vel));
+                let Oxygen16 { slot: o16_idx1, partner: o16_idx2, center: o16_center, mass: o16_mass, energy: o16_energy, vel1: o16_vel1, vel2: o16_vel2, .. } = o16_nuclei[a];
+                let (he4_idx, he4_pos, he4_vel, _, he4_mass, he4_energy) = he4_for_neon[b];
+                if [Some(o16_idx1), o16_idx2, Some(he4_idx)].iter().flatten().any(|slot| consumed.contains(slot)) {
+                    continue;
                 }
-            }
-
-            // Need at least 2 H atoms
-            if nearby_h.len() >= 2 {
-                // Sort by distance and take the two closest
-                nearby_h.sort_by(|a, b| a.3.partial_cmp(&b.3).unwrap());
-                let h1_idx = nearby_h[0].0;
-                let h1_mass = nearby_h[0].1;
-                let h1_energy = nearby_h[0].2;
-                let h1_vel = nearby_h[0].4;
-
-                let h2_idx = nearby_h[1].0;
-                let h2_mass = nearby_h[1].1;
-                let h2_energy = nearby_h[1].2;
-                let h2_vel = nearby_h[1].4;
 
-                // WATER FORMATION OCCURS!
+                // NEON-20 FORMATION OCCURS!
                 // Calculate center of mass and combined velocity
-                let total_mass = o16_mass + h1_mass + h2_mass;
-                let o16_com_mass = o16_mass / 2.0;
-                let combined_momentum = o16_vel1 * o16_com_mass + o16_vel2 * o16_com_mass + h1_vel * h1_mass + h2_vel * h2_mass;
+                let total_mass = o16_mass + he4_mass;
+                let combined_momentum = o16_vel1 * (o16_mass / 2.0) + o16_vel2 * (o16_mass / 2.0) + he4_vel * he4_mass;
                 let combined_vel = combined_momentum / total_mass;
-                let combined_energy = o16_energy + h1_energy + h2_energy;
-
-                // Calculate center of mass position (weighted average)
-                // Get O16 positions for accurate COM calculation
-                let (o16_pos1, o16_pos2) = {
-                    let p1 = self.protons[o16_idx1].as_ref().unwrap().position();
-                    let p2 = self.protons[o16_idx2].as_ref().unwrap().position();
-                    (p1, p2)
-                };
-                let (h1_pos, h2_pos) = {
-                    let h1p = self.protons[h1_idx].as_ref().unwrap().position();
-                    let h2p = self.protons[h2_idx].as_ref().unwrap().position();
-                    (h1p, h2p)
-                };
+                let combined_energy = o16_energy + he4_energy;
 
-                let center_of_mass = (o16_pos1 * o16_com_mass + o16_pos2 * o16_com_mass + h1_pos * h1_mass + h2_pos * h2_mass) / total_mass;
+                // Calculate center of mass position
+                let (o16_pos1, o16_pos2) = self.oxygen16_positions(o16_idx1, o16_idx2);
+                let center_of_mass = (o16_pos1 * (o16_mass / 2.0) + o16_pos2 * (o16_mass / 2.0) + he4_pos * he4_mass) / total_mass;
 
-                // Create H2O molecule in first O16 slot
-                let mut h2o = Proton::new(
+                // Create Ne20 in first O16 slot
+                let mut ne20 = Proton::new(
                     center_of_mass,
                     combined_vel,
-                    Color::from_rgba(40, 100, 180, 255),
+                    Color::from_rgba(255, 100, 150, 255),
                     combined_energy,
-                    10, // Total charge: 6 (C) + 2 (He) + 1 (H) + 1 (H) = 10
+                    10, // Total charge: 6 (C) + 2 (He from O16) + 2 (He4) = 10
                 );
-                h2o.set_neutron_count(8); // Total neutrons: 6 (C) + 2 (He) = 8
-                h2o.set_max_lifetime(-1.0); // Water is stable
-                h2o.set_h2o(true);
-                self.protons[o16_idx1] = Some(h2o);
+                ne20.set_neutron_count(10); // Total neutrons: 6 (C) + 2 (He from O16) + 2 (He4) = 10
+                ne20.set_max_lifetime(-1.0); // Ne20 is stable
+                ne20.set_neon20(true);
+                self.transform_slot(o16_idx1, ne20);
 
                 // Delete the other particles
-                self.protons[o16_idx2] = None;
-                self.protons[h1_idx] = None;
-                self.protons[h2_idx] = None;
+                if let Some(idx) = o16_idx2 {
+                    self.remove_slot(idx);
+                }
+                self.remove_slot(he4_idx);
+
+                // Recoil - a slow e+ e- pair leaves along the alpha's path
+                let approach = alpha_approach(he4_pos, he4_vel, o16_center, (o16_vel1 + o16_vel2) / 2.0);
+                self.emit_capture_pair(&[o16_idx1], combined_momentum, approach);
 
-                // Spawn wave at formation site (dark red to yellow, favoring dark red)
+                // Spawn energy wave (dark red to yellow, favoring dark red)
                 use macroquad::rand::gen_range;
                 let t: f32 = gen_range(0.0, 1.0);
                 let t = t.powf(3.0);
-                ring_manager.add_ring_with_color(center_of_mass, Color::new(0.17 + 0.83*t, 0.8*t, 0.0, 1.0));
+                ring_manager.add_ring_with_color(center_of_mass, Color::new(0.17 + 0.83*t, 0.8*t, 0.0, 1.0), RingOrigin::Fusion);
 
-                // Only one water formation per update cycle
-                return;
+                self.heat_slot(o16_idx1, proton::FUSION_HEAT);
+                self.push_event(SimEventKind::Fusion, o16_idx1);
+                self.record_reaction(ReactionKind::OxygenAlpha);
+                consumed.extend([Some(o16_idx1), o16_idx2, Some(he4_idx)].into_iter().flatten());
+                budget -= 1;
             }
         }
 
-        // H2S FORMATION: S32 + 2 H atoms → H2S molecule
-        // Collect all S32 particles
-        let mut s32_particles: Vec<(usize, Vec2, f32, f32, Vec2)> = Vec::new();
-        for i in 0..self.protons.len() {
-            if let Some(proton) = &self.protons[i] {
-                if proton.is_alive() && proton.is_sulfur32() {
-                    s32_particles.push((i, proton.position(), proton.mass(), proton.energy(), proton.velocity()));
+        // FUSION CASE 6: Magnesium-24 formation - Ne20 + He4 → Mg24
+        if self.reaction_flags.contains(ReactionFlags::ALPHA_CAPTURE) {
+            // Collect all Ne20 particles
+            let mut ne20_particles: Vec<(usize, Vec2, Vec2, f32, f32, f32)> = Vec::new();
+            for i in 0..self.protons.len() {
+                if let Some(proton) = &self.protons[i] {
+                    if proton.is_alive() && proton.is_neon20() {
+                        ne20_particles.push((
+                            i,
+                            proton.position(),
+                            proton.velocity(),
+                            proton.radius(),
+                            proton.mass(),
+                            proton.energy(),
+                        ));
+                    }
                 }
             }
-        }
 
-        // Collect all available H atoms (not crystallized)
-        let mut h_atoms: Vec<(usize, Vec2, f32, f32, Vec2)> = Vec::new();
-        for i in 0..self.protons.len() {
-            if let Some(proton) = &self.protons[i] {
-                if proton.is_alive() && proton.charge() == 0 && proton.neutron_count() == 1 && !proton.is_crystallized() {
-                    h_atoms.push((i, proton.position(), proton.mass(), proton.energy(), proton.velocity()));
+            // Collect He4 particles (excluding those already bonded in O16 pairs)
+            let mut he4_for_mg: Vec<(usize, Vec2, Vec2, f32, f32, f32)> = Vec::new();
+            for i in 0..self.protons.len() {
+                if let Some(proton) = &self.protons[i] {
+                    if proton.is_alive() && proton.is_stable_helium4() && !proton.is_oxygen16_bonded() {
+                        he4_for_mg.push((
+                            i,
+                            proton.position(),
+                            proton.velocity(),
+                            proton.radius(),
+                            proton.mass(),
+                            proton.energy(),
+                        ));
+                    }
                 }
             }
-        }
 
-        // Check each S32 for nearby H atoms
-        for (s32_idx, s32_pos, s32_mass, s32_energy, s32_vel) in s32_particles {
-            // Find two H atoms near the S32
-            let mut nearby_h: Vec<(usize, f32, f32, f32, Vec2)> = Vec::new();
-            for (h_idx, h_pos, h_mass, h_energy, h_vel) in &h_atoms {
-                let dist = s32_pos.distance(*h_pos);
-                if dist < proton::H2S_CAPTURE_RANGE {
-                    nearby_h.push((*h_idx, *h_mass, *h_energy, dist, *h_vel));
+            // Check for Ne20 + He4 collisions to form Mg24 - every candidate pair, best first
+            let mut pairs: Vec<(usize, usize, ReactionPriority)> = Vec::new();
+            for (a, (_, ne20_pos, ne20_vel, ne20_radius, _, _)) in ne20_particles.iter().enumerate() {
+                for (b, (_, he4_pos, he4_vel, he4_radius, _, _)) in he4_for_mg.iter().enumerate() {
+                    let dist_sq = ne20_pos.distance_squared(*he4_pos);
+                    let collision_dist = ne20_radius + he4_radius;
+
+                    if dist_sq <= collision_dist * collision_dist {
+                        let rel_vel = *ne20_vel - *he4_vel;
+                        let rel_speed = rel_vel.length();
+
+                        if rel_speed >= proton::MAGNESIUM24_CAPTURE_VELOCITY_THRESHOLD {
+                            let priority = ReactionPriority::new(rel_speed, dist_sq.sqrt(), (*ne20_pos + *he4_pos) / 2.0);
+                            pairs.push((a, b, priority));
+                        }
+                    }
                 }
             }
 
-            // Need at least 2 H atoms
-            if nearby_h.len() >= 2 {
-                // Sort by distance and take the two closest
-                nearby_h.sort_by(|a, b| a.3.partial_cmp(&b.3).unwrap());
-                let h1_idx = nearby_h[0].0;
-                let h1_mass = nearby_h[0].1;
-                let h1_energy = nearby_h[0].2;
-                let h1_vel = nearby_h[0].4;
-
-                let h2_idx = nearby_h[1].0;
-                let h2_mass = nearby_h[1].1;
-                let h2_energy = nearby_h[1].2;
-                let h2_vel = nearby_h[1].4;
+            pairs.sort_by(|a, b| b.2.compare(&a.2));
+            for (a, b, _) in pairs {
+                if budget == 0 {
+                    return;
+                }
+                let (ne20_idx, ne20_pos, ne20_vel, _, ne20_mass, ne20_energy) = ne20_particles[a];
+                let (he4_idx, he4_pos, he4_vel, _, he4_mass, he4_energy) = he4_for_mg[b];
+                if consumed.contains(&ne20_idx) || consumed.contains(&he4_idx) {
+                    continue;
+                }
 
-                // H2S FORMATION OCCURS!
-                let total_mass = s32_mass + h1_mass + h2_mass;
-                let combined_momentum = s32_vel * s32_mass + h1_vel * h1_mass + h2_vel * h2_mass;
+                // Mg24 formation!
+                let total_mass = ne20_mass + he4_mass;
+                let combined_momentum = ne20_vel * ne20_mass + he4_vel * he4_mass;
                 let combined_vel = combined_momentum / total_mass;
-                let combined_energy = s32_energy + h1_energy + h2_energy;
-                let center_of_mass = (s32_pos * s32_mass + {
-                    let h1p = self.protons[h1_idx].as_ref().unwrap().position();
-                    let h2p = self.protons[h2_idx].as_ref().unwrap().position();
-                    h1p * h1_mass + h2p * h2_mass
-                }) / total_mass;
-
-                // Create H2S molecule
-                let mut h2s = Proton::new(
+                let combined_energy = ne20_energy + he4_energy;
+                let center_of_mass = (ne20_pos * ne20_mass + he4_pos * he4_mass) / total_mass;
+
+                let mut mg24 = Proton::new(
                     center_of_mass,
                     combined_vel,
-                    Color::from_rgba(200, 220, 80, 255),
+                    Color::from_rgba(200, 200, 220, 255),
                     combined_energy,
-                    18, // S32 has 16 protons + 2 from H = 18
+                    12,
                 );
-                h2s.set_neutron_count(18); // S32 has 16 neutrons + 2 from H = 18
-                h2s.set_max_lifetime(-1.0); // H2S is stable
-                h2s.set_h2s(true);
-                self.protons[s32_idx] = Some(h2s);
+                mg24.set_neutron_count(12);
+                mg24.set_max_lifetime(-1.0);
+                mg24.set_magnesium24(true);
+                self.transform_slot(ne20_idx, mg24);
 
-                // Delete the H atoms
-                self.protons[h1_idx] = None;
-                self.protons[h2_idx] = None;
+                self.remove_slot(he4_idx);
+
+                // Recoil - a slow e+ e- pair leaves along the alpha's path
+                let approach = alpha_approach(he4_pos, he4_vel, ne20_pos, ne20_vel);
+                self.emit_capture_pair(&[ne20_idx], combined_momentum, approach);
 
-                // Spawn energy wave
                 use macroquad::rand::gen_range;
                 let t: f32 = gen_range(0.0, 1.0);
                 let t = t.powf(3.0);
-                ring_manager.add_ring_with_color(center_of_mass, Color::new(0.17 + 0.83*t, 0.8*t, 0.0, 1.0));
+                ring_manager.add_ring_with_color(center_of_mass, Color::new(0.17 + 0.83*t, 0.8*t, 0.0, 1.0), RingOrigin::Fusion);
 
-                return;
+                self.heat_slot(ne20_idx, proton::FUSION_HEAT);
+                self.push_event(SimEventKind::Fusion, ne20_idx);
+                self.record_reaction(ReactionKind::NeonAlpha);
+                consumed.extend([ne20_idx, he4_idx]);
+                budget -= 1;
             }
         }
 
-        // MGH2 FORMATION: Mg24 + 2 H atoms → MgH2 molecule
-        // Collect all Mg24 particles
-        let mut mg24_particles: Vec<(usize, Vec2, f32, f32, Vec2)> = Vec::new();
-        for i in 0..self.protons.len() {
-            if let Some(proton) = &self.protons[i] {
-                if proton.is_alive() && proton.is_magnesium24() {
-                    mg24_particles.push((i, proton.position(), proton.mass(), proton.energy(), proton.velocity()));
+        // FUSION CASE 7: Silicon-28 formation - Mg24 + He4 → Si28
+        if self.reaction_flags.contains(ReactionFlags::ALPHA_CAPTURE) {
+            // Collect all Mg24 particles
+            let mut mg24_particles: Vec<(usize, Vec2, Vec2, f32, f32, f32)> = Vec::new();
+            for i in 0..self.protons.len() {
+                if let Some(proton) = &self.protons[i] {
+                    if proton.is_alive() && proton.is_magnesium24() {
+                        mg24_particles.push((
+                            i,
+                            proton.position(),
+                            proton.velocity(),
+                            proton.radius(),
+                            proton.mass(),
+                            proton.energy(),
+                        ));
+                    }
                 }
             }
-        }
 
-        // Reuse h_atoms from above
-        let mut h_atoms: Vec<(usize, Vec2, f32, f32, Vec2)> = Vec::new();
-        for i in 0..self.protons.len() {
-            if let Some(proton) = &self.protons[i] {
-                if proton.is_alive() && proton.charge() == 0 && proton.neutron_count() == 1 && !proton.is_crystallized() {
-                    h_atoms.push((i, proton.position(), proton.mass(), proton.energy(), proton.velocity()));
+            // Collect He4 particles (excluding those already bonded in O16 pairs)
+            let mut he4_for_si: Vec<(usize, Vec2, Vec2, f32, f32, f32)> = Vec::new();
+            for i in 0..self.protons.len() {
+                if let Some(proton) = &self.protons[i] {
+                    if proton.is_alive() && proton.is_stable_helium4() && !proton.is_oxygen16_bonded() {
+                        he4_for_si.push((
+                            i,
+                            proton.position(),
+                            proton.velocity(),
+                            proton.radius(),
+                            proton.mass(),
+                            proton.energy(),
+                        ));
+                    }
                 }
             }
-        }
 
-        // Check each Mg24 for nearby H atoms
-        for (mg24_idx, mg24_pos, mg24_mass, mg24_energy, mg24_vel) in mg24_particles {
-            let mut nearby_h: Vec<(usize, f32, f32, f32, Vec2)> = Vec::new();
-            for (h_idx, h_pos, h_mass, h_energy, h_vel) in &h_atoms {
-                let dist = mg24_pos.distance(*h_pos);
-                if dist < proton::MGH2_CAPTURE_RANGE {
-                    nearby_h.push((*h_idx, *h_mass, *h_energy, dist, *h_vel));
+            // Check for Mg24 + He4 collisions to form Si28 - every candidate pair, best first
+            let mut pairs: Vec<(usize, usize, ReactionPriority)> = Vec::new();
+            for (a, (_, mg24_pos, mg24_vel, mg24_radius, _, _)) in mg24_particles.iter().enumerate() {
+                for (b, (_, he4_pos, he4_vel, he4_radius, _, _)) in he4_for_si.iter().enumerate() {
+                    let dist_sq = mg24_pos.distance_squared(*he4_pos);
+                    let collision_dist = mg24_radius + he4_radius;
+
+                    if dist_sq <= collision_dist * collision_dist {
+                        let rel_vel = *mg24_vel - *he4_vel;
+                        let rel_speed = rel_vel.length();
+
+                        if rel_speed >= proton::SILICON28_CAPTURE_VELOCITY_THRESHOLD {
+                            let priority = ReactionPriority::new(rel_speed, dist_sq.sqrt(), (*mg24_pos + *he4_pos) / 2.0);
+                            pairs.push((a, b, priority));
+                        }
+                    }
                 }
             }
 
-            if nearby_h.len() >= 2 {
-                nearby_h.sort_by(|a, b| a.3.partial_cmp(&b.3).unwrap());
-                let h1_idx = nearby_h[0].0;
-                let h1_mass = nearby_h[0].1;
-                let h1_energy = nearby_h[0].2;
-                let h1_vel = nearby_h[0].4;
-
-                let h2_idx = nearby_h[1].0;
-                let h2_mass = nearby_h[1].1;
-                let h2_energy = nearby_h[1].2;
-                let h2_vel = nearby_h[1].4;
+            pairs.sort_by(|a, b| b.2.compare(&a.2));
+            for (a, b, _) in pairs {
+                if budget == 0 {
+                    return;
+                }
+                let (mg24_idx, mg24_pos, mg24_vel, _, mg24_mass, mg24_energy) = mg24_particles[a];
+                let (he4_idx, he4_pos, he4_vel, _, he4_mass, he4_energy) = he4_for_si[b];
+                if consumed.contains(&mg24_idx) || consumed.contains(&he4_idx) {
+                    continue;
+                }
 
-                // MgH2 FORMATION OCCURS!
-                let total_mass = mg24_mass + h1_mass + h2_mass;
-                let combined_momentum = mg24_vel * mg24_mass + h1_vel * h1_mass + h2_vel * h2_mass;
+                // Si28 formation!
+                let total_mass = mg24_mass + he4_mass;
+                let combined_momentum = mg24_vel * mg24_mass + he4_vel * he4_mass;
                 let combined_vel = combined_momentum / total_mass;
-                let combined_energy = mg24_energy + h1_energy + h2_energy;
-                let center_of_mass = (mg24_pos * mg24_mass + {
-                    let h1p = self.protons[h1_idx].as_ref().unwrap().position();
-                    let h2p = self.protons[h2_idx].as_ref().unwrap().position();
-                    h1p * h1_mass + h2p * h2_mass
-                }) / total_mass;
-
-                let mut mgh2 = Proton::new(
+                let combined_energy = mg24_energy + he4_energy;
+                let center_of_mass = (mg24_pos * mg24_mass + he4_pos * he4_mass) / total_mass;
+
+                let mut si28 = Proton::new(
                     center_of_mass,
                     combined_vel,
-                    Color::from_rgba(180, 180, 190, 255),
+                    Color::from_rgba(160, 130, 90, 255),
                     combined_energy,
-                    14, // Mg24 has 12 protons + 2 from H = 14
+                    14,
                 );
-                mgh2.set_neutron_count(14); // Mg24 has 12 neutrons + 2 from H = 14
-                mgh2.set_max_lifetime(-1.0);
-                mgh2.set_mgh2(true);
-                self.protons[mg24_idx] = Some(mgh2);
+                si28.set_neutron_count(14);
+                si28.set_max_lifetime(-1.0);
+                si28.set_silicon28(true);
+                self.transform_slot(mg24_idx, si28);
+
+                self.remove_slot(he4_idx);
 
-                self.protons[h1_idx] = None;
-                self.protons[h2_idx] = None;
+                // Recoil - a slow e+ e- pair leaves along the alpha's path
+                let approach = alpha_approach(he4_pos, he4_vel, mg24_pos, mg24_vel);
+                self.emit_capture_pair(&[mg24_idx], combined_momentum, approach);
 
                 use macroquad::rand::gen_range;
                 let t: f32 = gen_range(0.0, 1.0);
                 let t = t.powf(3.0);
-                ring_manager.add_ring_with_color(center_of_mass, Color::new(0.17 + 0.83*t, 0.8*t, 0.0, 1.0));
-
-                return;
-            }
-        }
+                ring_manager.add_ring_with_color(center_of_mass, Color::new(0.17 + 0.83*t, 0.8*t, 0.0, 1.0), RingOrigin::Fusion);
 
-        // CH4 FORMATION: C12 + 4 H atoms → CH4 molecule
-        // Collect all C12 particles (not bonded)
-        let mut c12_particles: Vec<(usize, Vec2, f32, f32, Vec2)> = Vec::new();
-        for i in 0..self.protons.len() {
-            if let Some(proton) = &self.protons[i] {
-                if proton.is_alive() && proton.is_stable_carbon12() && !proton.is_oxygen16_bonded() {
-                    c12_particles.push((i, proton.position(), proton.mass(), proton.energy(), proton.velocity()));
-                }
+                self.heat_slot(mg24_idx, proton::FUSION_HEAT);
+                self.push_event(SimEventKind::Fusion, mg24_idx);
+                self.record_reaction(ReactionKind::MagnesiumAlpha);
+                consumed.extend([mg24_idx, he4_idx]);
+                budget -= 1;
             }
         }
 
-        // Reuse h_atoms
-        let mut h_atoms: Vec<(usize, Vec2, f32, f32, Vec2)> = Vec::new();
-        for i in 0..self.protons.len() {
-            if let Some(proton) = &self.protons[i] {
-                if proton.is_alive() && proton.charge() == 0 && proton.neutron_count() == 1 && !proton.is_crystallized() {
-                    h_atoms.push((i, proton.position(), proton.mass(), proton.energy(), proton.velocity()));
+        // FUSION CASE 8: Sulfur-32 formation - Si28 + He4 → S32
+        if self.reaction_flags.contains(ReactionFlags::ALPHA_CAPTURE) {
+            // Collect all Si28 particles
+            let mut si28_particles: Vec<(usize, Vec2, Vec2, f32, f32, f32)> = Vec::new();
+            for i in 0..self.protons.len() {
+                if let Some(proton) = &self.protons[i] {
+                    if proton.is_alive() && proton.is_silicon28() {
+                        si28_particles.push((
+                            i,
+                            proton.position(),
+                            proton.velocity(),
+                            proton.radius(),
+                            proton.mass(),
+                            proton.energy(),
+                        ));
+                    }
                 }
             }
-        }
 
-        // Check each C12 for nearby H atoms
-        for (c12_idx, c12_pos, c12_mass, c12_energy, c12_vel) in c12_particles {
-            let mut nearby_h: Vec<(usize, f32, f32, f32, Vec2)> = Vec::new();
-            for (h_idx, h_pos, h_mass, h_energy, h_vel) in &h_atoms {
-                let dist = c12_pos.distance(*h_pos);
-                if dist < proton::CH4_CAPTURE_RANGE {
-                    nearby_h.push((*h_idx, *h_mass, *h_energy, dist, *h_vel));
+            // Collect He4 particles (excluding those already bonded in O16 pairs)
+            let mut he4_for_s: Vec<(usize, Vec2, Vec2, f32, f32, f32)> = Vec::new();
+            for i in 0..self.protons.len() {
+                if let Some(proton) = &self.protons[i] {
+                    if proton.is_alive() && proton.is_stable_helium4() && !proton.is_oxygen16_bonded() {
+                        he4_for_s.push((
+                            i,
+                            proton.position(),
+                            proton.velocity(),
+                            proton.radius(),
+                            proton.mass(),
+                            proton.energy(),
+                        ));
+                    }
                 }
             }
 
-            // Need at least 4 H atoms for methane
-            if nearby_h.len() >= 4 {
-                nearby_h.sort_by(|a, b| a.3.partial_cmp(&b.3).unwrap());
-                let h1_idx = nearby_h[0].0;
-                let h2_idx = nearby_h[1].0;
-                let h3_idx = nearby_h[2].0;
-                let h4_idx = nearby_h[3].0;
+            // Check for Si28 + He4 collisions to form S32 - every candidate pair, best first
+            let mut pairs: Vec<(usize, usize, ReactionPriority)> = Vec::new();
+            for (a, (_, si28_pos, si28_vel, si28_radius, _, _)) in si28_particles.iter().enumerate() {
+                for (b, (_, he4_pos, he4_vel, he4_radius, _, _)) in he4_for_s.iter().enumerate() {
+                    let dist_sq = si28_pos.distance_squared(*he4_pos);
+                    let collision_dist = si28_radius + he4_radius;
 
-                // CH4 FORMATION OCCURS!
-                let h1_mass = nearby_h[0].1;
-                let h2_mass = nearby_h[1].1;
-                let h3_mass = nearby_h[2].1;
-                let h4_mass = nearby_h[3].1;
+                    if dist_sq <= collision_dist * collision_dist {
+                        let rel_vel = *si28_vel - *he4_vel;
+                        let rel_speed = rel_vel.length();
 
-                let h1_energy = nearby_h[0].2;
-                let h2_energy = nearby_h[1].2;
-                let h3_energy = nearby_h[2].2;
-                let h4_energy = nearby_h[3].2;
+                        if rel_speed >= proton::SULFUR32_CAPTURE_VELOCITY_THRESHOLD {
+                            let priority = ReactionPriority::new(rel_speed, dist_sq.sqrt(), (*si28_pos + *he4_pos) / 2.0);
+                            pairs.push((a, b, priority));
+                        }
+                    }
+                }
+            }
 
-                let h1_vel = nearby_h[0].4;
-                let h2_vel = nearby_h[1].4;
-                let h3_vel = nearby_h[2].4;
-                let h4_vel = nearby_h[3].4;
+            pairs.sort_by(|a, b| b.2.compare(&a.2));
+            for (a, b, _) in pairs {
+                if budget == 0 {
+                    return;
+                }
+                let (si28_idx, si28_pos, si28_vel, _, si28_mass, si28_energy) = si28_particles[a];
+                let (he4_idx, he4_pos, he4_vel, _, he4_mass, he4_energy) = he4_for_s[b];
+                if consumed.contains(&si28_idx) || consumed.contains(&he4_idx) {
+                    continue;
+                }
 
-                let total_mass = c12_mass + h1_mass + h2_mass + h3_mass + h4_mass;
-                let combined_momentum = c12_vel * c12_mass + h1_vel * h1_mass + h2_vel * h2_mass + h3_vel * h3_mass + h4_vel * h4_mass;
+                // S32 formation!
+                let total_mass = si28_mass + he4_mass;
+                let combined_momentum = si28_vel * si28_mass + he4_vel * he4_mass;
                 let combined_vel = combined_momentum / total_mass;
-                let combined_energy = c12_energy + h1_energy + h2_energy + h3_energy + h4_energy;
-
-                let h_positions_mass = {
-                    let h1p = self.protons[h1_idx].as_ref().unwrap().position();
-                    let h2p = self.protons[h2_idx].as_ref().unwrap().position();
-                    let h3p = self.protons[h3_idx].as_ref().unwrap().position();
-                    let h4p = self.protons[h4_idx].as_ref().unwrap().position();
-                    h1p * h1_mass + h2p * h2_mass + h3p * h3_mass + h4p * h4_mass
-                };
-                let center_of_mass = (c12_pos * c12_mass + h_positions_mass) / total_mass;
+                let combined_energy = si28_energy + he4_energy;
+                let center_of_mass = (si28_pos * si28_mass + he4_pos * he4_mass) / total_mass;
 
-                let mut ch4 = Proton::new(
+                let mut s32 = Proton::new(
                     center_of_mass,
                     combined_vel,
-                    Color::from_rgba(120, 200, 150, 255),
+                    Color::from_rgba(220, 220, 80, 255),
                     combined_energy,
-                    10, // C12 has 6 protons + 4 from H = 10
+                    16,
                 );
-                ch4.set_neutron_count(10); // C12 has 6 neutrons + 4 from H = 10
-                ch4.set_max_lifetime(-1.0);
-                ch4.set_ch4(true);
-                self.protons[c12_idx] = Some(ch4);
+                s32.set_neutron_count(16);
+                s32.set_max_lifetime(-1.0);
+                s32.set_sulfur32(true);
+                self.transform_slot(si28_idx, s32);
+
+                self.remove_slot(he4_idx);
 
-                self.protons[h1_idx] = None;
-                self.protons[h2_idx] = None;
-                self.protons[h3_idx] = None;
-                self.protons[h4_idx] = None;
+                // Recoil - a slow e+ e- pair leaves along the alpha's path
+                let approach = alpha_approach(he4_pos, he4_vel, si28_pos, si28_vel);
+                self.emit_capture_pair(&[si28_idx], combined_momentum, approach);
 
                 use macroquad::rand::gen_range;
                 let t: f32 = gen_range(0.0, 1.0);
                 let t = t.powf(3.0);
-                ring_manager.add_ring_with_color(center_of_mass, Color::new(0.17 + 0.83*t, 0.8*t, 0.0, 1.0));
+                ring_manager.add_ring_with_color(center_of_mass, Color::new(0.17 + 0.83*t, 0.8*t, 0.0, 1.0), RingOrigin::Fusion);
 
-                return;
+                self.heat_slot(si28_idx, proton::FUSION_HEAT);
+                self.push_event(SimEventKind::Fusion, si28_idx);
+                self.record_reaction(ReactionKind::SiliconAlpha);
+                consumed.extend([si28_idx, he4_idx]);
+                budget -= 1;
             }
         }
 
-        // SIH4 FORMATION: Si28 + 4 H atoms → SiH4 molecule
-        // Collect all Si28 particles
-        let mut si28_particles: Vec<(usize, Vec2, f32, f32, Vec2)> = Vec::new();
-        for i in 0..self.protons.len() {
-            if let Some(proton) = &self.protons[i] {
-                if proton.is_alive() && proton.is_silicon28() {
-                    si28_particles.push((i, proton.position(), proton.mass(), proton.energy(), proton.velocity()));
-                }
+        // MOLECULE FORMATION: a central nucleus captures nearby ligands (H2O, hydrides, SO2)
+        for recipe in &MOLECULES {
+            if !self.reaction_flags.contains(recipe.flag) {
+                continue;
+            }
+            while budget > 0 {
+                let Some(slot) = self.try_form_molecule(recipe, ring_manager, &mut consumed) else { break };
+                self.push_event(SimEventKind::MoleculeFormed, slot);
+                self.record_reaction(recipe.kind);
+                budget -= 1;
             }
         }
+    }
+
+    /// Reactions handle_nuclear_fusion may run in one frame - the configured budget, at least one
+    fn max_reactions_per_frame(&self) -> usize {
+        (self.config.max_reactions_per_frame as usize).max(1)
+    }
+
+    /// Gather every particle matching `pred` as a molecule part
+    /// A bonded O16 pair becomes one part (recorded from its lower slot) so it is captured whole
+    fn collect_molecule_parts(&self, pred: fn(&Proton) -> bool) -> Vec<MoleculePart> {
+        let mut parts = Vec::new();
 
-        // Reuse h_atoms
-        let mut h_atoms: Vec<(usize, Vec2, f32, f32, Vec2)> = Vec::new();
         for i in 0..self.protons.len() {
-            if let Some(proton) = &self.protons[i] {
-                if proton.is_alive() && proton.charge() == 0 && proton.neutron_count() == 1 && !proton.is_crystallized() {
-                    h_atoms.push((i, proton.position(), proton.mass(), proton.energy(), proton.velocity()));
+            let Some(proton) = &self.protons[i] else { continue };
+            if !proton.is_alive() || !pred(proton) {
+                continue;
+            }
+
+            if proton.is_oxygen16_bonded() {
+                let Some(partner_idx) = proton.oxygen_bond_partner() else { continue };
+                if partner_idx <= i {
+                    continue;
+                }
+                if let Some(partner) = &self.protons[partner_idx] {
+                    if partner.is_alive() && pred(partner) {
+                        // The pair counts as one nucleus with its mass split evenly between the halves
+                        let mass = proton.mass() + partner.mass();
+                        parts.push(MoleculePart {
+                            slot: i,
+                            partner: Some(partner_idx),
+                            position: (proton.position() + partner.position()) / 2.0,
+                            mass,
+                            energy: proton.energy() + partner.energy(),
+                            momentum: (proton.velocity() + partner.velocity()) * (mass / 2.0),
+                        });
+                    }
                 }
+            } else {
+                parts.push(MoleculePart {
+                    slot: i,
+                    partner: None,
+                    position: proton.position(),
+                    mass: proton.mass(),
+                    energy: proton.energy(),
+                    momentum: proton.velocity() * proton.mass(),
+                });
             }
         }
 
-        // Check each Si28 for nearby H atoms
-        for (si28_idx, si28_pos, si28_mass, si28_energy, si28_vel) in si28_particles {
-            let mut nearby_h: Vec<(usize, f32, f32, f32, Vec2)> = Vec::new();
-            for (h_idx, h_pos, h_mass, h_energy, h_vel) in &h_atoms {
-                let dist = si28_pos.distance(*h_pos);
-                if dist < proton::SIH4_CAPTURE_RANGE {
-                    nearby_h.push((*h_idx, *h_mass, *h_energy, dist, *h_vel));
+        parts
+    }
+
+    /// Let the best-placed center capture its `ligand_count` closest ligands within range
+    /// The molecule takes the center's slot with the combined momentum, energy and center of mass
+    /// Parts touching a `consumed` slot are left out, and the slots this molecule used are added to it
+    /// Returns the molecule's slot if one formed
+    fn try_form_molecule(
+        &mut self,
+        recipe: &MoleculeRecipe,
+        ring_manager: &mut RingManager,
+        consumed: &mut std::collections::HashSet<usize>,
+    ) -> Option<usize> {
+        let is_free = |part: &MoleculePart| !consumed.contains(&part.slot) && part.partner.is_none_or(|p| !consumed.contains(&p));
+        let mut centers: Vec<MoleculePart> = self.collect_molecule_parts(recipe.center).into_iter().filter(is_free).collect();
+        let ligands: Vec<MoleculePart> = self.collect_molecule_parts(recipe.ligand).into_iter().filter(is_free).collect();
+        let ligand_positions: Vec<Vec2> = ligands.iter().map(|l| l.position).collect();
+
+        // Rank candidates by how tightly their ligands are clustered so the first match is the best one
+        Self::sort_by_capture(&mut centers, |c| c.position, &ligand_positions, recipe.capture_range, recipe.ligand_count);
+
+        for center in centers {
+            let mut nearby: Vec<(f32, &MoleculePart)> = ligands
+                .iter()
+                .map(|l| (center.position.distance(l.position), l))
+                .filter(|(dist, l)| *dist < recipe.capture_range && l.slot != center.slot)
+                .collect();
+
+            if nearby.len() < recipe.ligand_count {
+                continue;
+            }
+
+            // Take the closest ligands
+            nearby.sort_by(|a, b| nan_last(a.0, b.0));
+            let captured: Vec<MoleculePart> = nearby.iter().take(recipe.ligand_count).map(|(_, l)| **l).collect();
+
+            let mut total_mass = center.mass;
+            let mut momentum = center.momentum;
+            let mut weighted_position = center.position * center.mass;
+            let mut energy = center.energy;
+            for ligand in &captured {
+                total_mass += ligand.mass;
+                momentum += ligand.momentum;
+                weighted_position += ligand.position * ligand.mass;
+                energy += ligand.energy;
+            }
+            let center_of_mass = weighted_position / total_mass;
+
+            // Molecule takes the center slot, everything else is consumed
+            self.transform_slot(center.slot, recipe.build(center_of_mass, momentum / total_mass, energy));
+            for part in std::iter::once(&center).chain(captured.iter()) {
+                if let Some(partner) = part.partner {
+                    self.remove_slot(partner);
                 }
             }
+            for ligand in &captured {
+                self.remove_slot(ligand.slot);
+            }
+            for part in std::iter::once(&center).chain(captured.iter()) {
+                consumed.insert(part.slot);
+                consumed.extend(part.partner);
+            }
 
-            // Need at least 4 H atoms for silane
-            if nearby_h.len() >= 4 {
-                nearby_h.sort_by(|a, b| a.3.partial_cmp(&b.3).unwrap());
-                let h1_idx = nearby_h[0].0;
-                let h2_idx = nearby_h[1].0;
-                let h3_idx = nearby_h[2].0;
-                let h4_idx = nearby_h[3].0;
+            // Spawn wave at formation site (dark red to yellow, favoring dark red)
+            use macroquad::rand::gen_range;
+            let t: f32 = gen_range(0.0, 1.0);
+            let t = t.powf(3.0);
+            ring_manager.add_ring_with_color(center_of_mass, Color::new(0.17 + 0.83*t, 0.8*t, 0.0, 1.0), RingOrigin::Fusion);
 
-                // SiH4 FORMATION OCCURS!
-                let h1_mass = nearby_h[0].1;
-                let h2_mass = nearby_h[1].1;
-                let h3_mass = nearby_h[2].1;
-                let h4_mass = nearby_h[3].1;
+            return Some(center.slot);
+        }
 
-                let h1_energy = nearby_h[0].2;
-                let h2_energy = nearby_h[1].2;
-                let h3_energy = nearby_h[2].2;
-                let h4_energy = nearby_h[3].2;
+        None
+    }
 
-                let h1_vel = nearby_h[0].4;
-                let h2_vel = nearby_h[1].4;
-                let h3_vel = nearby_h[2].4;
-                let h4_vel = nearby_h[3].4;
+    /// Collect every O16 nucleus: legacy bonded (C12, He4) pairs and consolidated O16 particles
+    fn collect_oxygen16(&self) -> Vec<Oxygen16> {
+        let mut nuclei = Vec::new();
 
-                let total_mass = si28_mass + h1_mass + h2_mass + h3_mass + h4_mass;
-                let combined_momentum = si28_vel * si28_mass + h1_vel * h1_mass + h2_vel * h2_mass + h3_vel * h3_mass + h4_vel * h4_mass;
-                let combined_vel = combined_momentum / total_mass;
-                let combined_energy = si28_energy + h1_energy + h2_energy + h3_energy + h4_energy;
-
-                let h_positions_mass = {
-                    let h1p = self.protons[h1_idx].as_ref().unwrap().position();
-                    let h2p = self.protons[h2_idx].as_ref().unwrap().position();
-                    let h3p = self.protons[h3_idx].as_ref().unwrap().position();
-                    let h4p = self.protons[h4_idx].as_ref().unwrap().position();
-                    h1p * h1_mass + h2p * h2_mass + h3p * h3_mass + h4p * h4_mass
-                };
-                let center_of_mass = (si28_pos * si28_mass + h_positions_mass) / total_mass;
+        for i in 0..self.protons.len() {
+            let Some(proton) = &self.protons[i] else { continue };
+            if !proton.is_alive() {
+                continue;
+            }
 
-                let mut sih4 = Proton::new(
-                    center_of_mass,
-                    combined_vel,
-                    Color::from_rgba(220, 100, 50, 255),
-                    combined_energy,
-                    18, // Si28 has 14 protons + 4 from H = 18
-                );
-                sih4.set_neutron_count(18); // Si28 has 14 neutrons + 4 from H = 18
-                sih4.set_max_lifetime(-1.0);
-                sih4.set_sih4(true);
-                self.protons[si28_idx] = Some(sih4);
+            if proton.is_oxygen16() {
+                nuclei.push(Oxygen16 {
+                    slot: i,
+                    partner: None,
+                    center: proton.position(),
+                    mass: proton.mass(),
+                    energy: proton.energy(),
+                    radius: proton.radius(),
+                    vel1: proton.velocity(),
+                    vel2: proton.velocity(),
+                });
+            } else if proton.is_oxygen16_bonded() {
+                // Only record each pair once, from its lower slot
+                let Some(partner_idx) = proton.oxygen_bond_partner() else { continue };
+                if partner_idx <= i {
+                    continue;
+                }
+                if let Some(partner) = &self.protons[partner_idx] {
+                    if partner.is_alive() && partner.is_oxygen16_bonded() {
+                        nuclei.push(Oxygen16 {
+                            slot: i,
+                            partner: Some(partner_idx),
+                            center: (proton.position() + partner.position()) / 2.0,
+                            mass: proton.mass() + partner.mass(),
+                            energy: proton.energy() + partner.energy(),
+                            radius: (proton.radius() + partner.radius()) / 2.0, // Use average radius of the pair
+                            vel1: proton.velocity(),
+                            vel2: partner.velocity(),
+                        });
+                    }
+                }
+            }
+        }
 
-                self.protons[h1_idx] = None;
-                self.protons[h2_idx] = None;
-                self.protons[h3_idx] = None;
-                self.protons[h4_idx] = None;
+        nuclei
+    }
 
-                use macroquad::rand::gen_range;
-                let t: f32 = gen_range(0.0, 1.0);
-                let t = t.powf(3.0);
-                ring_manager.add_ring_with_color(center_of_mass, Color::new(0.17 + 0.83*t, 0.8*t, 0.0, 1.0));
+    /// Positions of the two halves of an O16 (both the same for a consolidated O16)
+    fn oxygen16_positions(&self, slot: usize, partner: Option<usize>) -> (Vec2, Vec2) {
+        let p1 = self.protons[slot].as_ref().unwrap().position();
+        let p2 = partner.map_or(p1, |idx| self.protons[idx].as_ref().unwrap().position());
+        (p1, p2)
+    }
 
-                return;
+    /// Order capture candidates by the summed distance to their `count` closest ligands within `range`
+    /// Candidates without enough ligands go last; ties fall back to position so slot order never decides
+    fn sort_by_capture<T: Copy>(
+        candidates: &mut [T],
+        position_of: impl Fn(&T) -> Vec2,
+        ligand_positions: &[Vec2],
+        range: f32,
+        count: usize,
+    ) {
+        let capture_distance = |center: Vec2| -> f32 {
+            let mut dists: Vec<f32> = ligand_positions
+                .iter()
+                .map(|pos| center.distance(*pos))
+                .filter(|dist| *dist < range)
+                .collect();
+            if dists.len() < count {
+                return f32::INFINITY;
             }
+            dists.sort_by(|a, b| a.total_cmp(b));
+            dists.iter().take(count).sum()
+        };
+
+        let mut ranked: Vec<(f32, Vec2, T)> = candidates
+            .iter()
+            .map(|c| {
+                let pos = position_of(c);
+                (capture_distance(pos), pos, *c)
+            })
+            .collect();
+        ranked.sort_by(|a, b| {
+            a.0.total_cmp(&b.0)
+                .then_with(|| a.1.x.total_cmp(&b.1.x))
+                .then_with(|| a.1.y.total_cmp(&b.1.y))
+        });
+
+        for (slot, (_, _, candidate)) in candidates.iter_mut().zip(ranked) {
+            *slot = candidate;
         }
     }
 
@@ -5079,8 +6420,11 @@ impl ProtonManager {
                             -1  // H-
                         };
 
-                        // Spawn the proton
-                        self.spawn_proton(spawn_pos, velocity, proton_color, combined_energy, charge);
+                        // Spawn the proton - at capacity nothing is spawned, so leave the site
+                        // off cooldown and let the collision produce a proton once a slot frees up
+                        if !self.spawn_proton(spawn_pos, velocity, proton_color, combined_energy, charge) {
+                            return;
+                        }
 
                         // 5. Add cooldown to prevent duplicate spawns
                         self.spawn_cooldowns.push((spawn_pos, pm::SPAWN_COOLDOWN_TIME));
@@ -5090,31 +6434,161 @@ impl ProtonManager {
         }
     }
 
-    /// Spawn a new proton
-    fn spawn_proton(&mut self, position: Vec2, velocity: Vec2, color: Color, energy: f32, charge: i32) {
-        // Check if at capacity
-        if self.get_proton_count() >= self.max_protons {
+    /// Spawn a new proton, returning false once the slot cap is reached
+    fn spawn_proton(&mut self, position: Vec2, velocity: Vec2, color: Color, energy: f32, charge: i32) -> bool {
+        let mut proton = Proton::new(position, velocity, color, energy, charge);
+
+        // Make H+ protons permanent (infinite lifetime)
+        // H- decays like He3 (default 20s lifetime)
+        if charge == 1 {
+            proton.set_max_lifetime(proton::INFINITE_LIFETIME);
+        }
+
+        self.insert_proton(proton)
+    }
+
+    /// Alpha-capture recoil: an e+ e- pair (internal pair formation) takes a share of the product's energy
+    /// off along `approach`, one lepton either side of it, and the product slots recoil the other way so the
+    /// total momentum stays `momentum_before`. The pair is neutral, so charge and nucleons are untouched
+    /// With no room for both leptons nothing is emitted and there is no recoil
+    fn emit_capture_pair(&mut self, product_slots: &[usize], momentum_before: Vec2, approach: Vec2) {
+        if self.free_slot_count() < 2 {
             return;
         }
+        let mut pair_energy = 0.0;
+        for &slot in product_slots {
+            if let Some(product) = &mut self.protons[slot] {
+                let share = product.energy() * proton::ALPHA_CAPTURE_EJECT_ENERGY_FRACTION;
+                product.set_energy(product.energy() - share);
+                pair_energy += share;
+            }
+        }
 
-        // Find first empty slot
-        for i in 0..self.protons.len() {
-            if self.protons[i].is_none() || !self.protons[i].as_ref().unwrap().is_alive() {
-                let mut proton = Proton::new(position, velocity, color, energy, charge);
+        let products: Vec<&Proton> = product_slots.iter().filter_map(|&slot| self.protons[slot].as_ref()).collect();
+        let product_mass: f32 = products.iter().map(|p| p.mass()).sum();
+        if product_mass <= 0.0 {
+            return;
+        }
+        let product_momentum: Vec2 = products.iter().map(|p| p.velocity() * p.mass()).sum();
+        let center = products.iter().map(|p| p.position() * p.mass()).sum::<Vec2>() / product_mass;
+        let reach = products.iter().map(|p| p.position().distance(center) + p.radius()).fold(0.0, f32::max);
 
-                // Make H+ protons permanent (infinite lifetime)
-                // H- decays like He3 (default 20s lifetime)
-                if charge == 1 {
-                    proton.set_max_lifetime(proton::INFINITE_LIFETIME);
-                }
+        let mut pair_momentum = Vec2::ZERO;
+        for charge in [1, -1] {
+            let direction = Vec2::from_angle(charge as f32 * proton::ALPHA_CAPTURE_EJECT_SPREAD).rotate(approach);
+            let mut lepton = Self::new_lepton(
+                center + direction * (reach + proton::ALPHA_CAPTURE_EJECT_GAP),
+                product_momentum / product_mass + direction * proton::ALPHA_CAPTURE_EJECT_SPEED,
+                charge,
+            );
+            lepton.set_energy(pair_energy / 2.0);
+            pair_momentum += lepton.velocity() * lepton.mass();
+            self.insert_proton(lepton);
+        }
 
-                self.protons[i] = Some(proton);
+        let kick = (momentum_before - product_momentum - pair_momentum) / product_mass;
+        for &slot in product_slots {
+            if let Some(product) = &mut self.protons[slot] {
+                product.set_velocity(product.velocity() + kick);
+            }
+        }
+    }
 
-                break;
+    /// For each He4 (position, radius), the later ones it could share a triple with, in order
+    /// All three must touch each other, so both partners of a triple lie within two radii of its first member -
+    /// the He4 are bucketed and only that neighborhood is searched. Later members only, so each triple is found once
+    fn triple_alpha_neighborhoods(he4: &[(Vec2, f32)]) -> Vec<Vec<usize>> {
+        let reach = 2.0 * he4.iter().map(|&(_, radius)| radius).fold(0.0, f32::max);
+        let grid = NeighborGrid::from_points(he4.iter().map(|&(position, _)| position).enumerate(), reach.max(1.0));
+        (0..he4.len())
+            .map(|i| {
+                let mut near: Vec<usize> = grid.candidates(he4[i].0, reach).filter(|&n| n > i).collect();
+                near.sort_unstable();
+                near
+            })
+            .collect()
+    }
+
+    /// Store a reaction product in the slot of the reactant it grew from, keeping that reactant's id
+    /// (to the bond integrity check it is still a new particle)
+    fn transform_slot(&mut self, slot: usize, mut product: Proton) {
+        if let Some(reactant) = &self.protons[slot] {
+            product.inherit_id(reactant);
+        }
+        self.protons[slot] = Some(product);
+    }
+
+    /// Empty slot `idx` and hand it to the free list
+    ///
+    /// Slot reuse contract: a slot only goes back into use through insert_proton, and bonds never
+    /// follow an index alone - the bond integrity check (see repair_dangling_bonds) compares the
+    /// occupant's serial, so a bond to a reused slot is dropped rather than joined to the newcomer.
+    fn remove_slot(&mut self, idx: usize) {
+        if self.protons[idx].take().is_some() {
+            self.free_slots.push(idx);
+        }
+    }
+
+    /// Place a particle in a free slot - the most recently emptied one first
+    ///
+    /// Overflow policy: when the free list runs dry the slot vector doubles (new slots are appended,
+    /// so existing indices and crystal bonds stay valid) up to the capacity limit. At the limit a
+    /// dead particle's slot is taken over if there is one, otherwise the spawn is refused and false
+    /// is returned - nothing alive is ever evicted to make room.
+    fn insert_proton(&mut self, proton: Proton) -> bool {
+        // Entries can be stale when a reaction refilled its own freed slot - skip those
+        while let Some(slot) = self.free_slots.pop() {
+            if self.protons.get(slot).is_some_and(|p| p.as_ref().is_none_or(|p| !p.is_alive())) {
+                self.protons[slot] = Some(proton);
+                return true;
+            }
+        }
+
+        if self.protons.len() < self.capacity_limit {
+            let slot = self.protons.len();
+            let grown = (slot * 2).clamp(slot + 1, self.capacity_limit);
+            self.protons.resize_with(grown, || None);
+            self.free_slots.extend((slot + 1..grown).rev());
+            self.protons[slot] = Some(proton);
+            return true;
+        }
+
+        match self.protons.iter().position(|p| p.as_ref().is_some_and(|p| !p.is_alive())) {
+            Some(slot) => {
+                self.protons[slot] = Some(proton);
+                true
             }
+            None => false,
         }
     }
 
+    /// How many particles insert_proton can still place - every empty slot is on the free list, and dead
+    /// particles' slots are taken over once the vector is at the cap
+    fn free_slot_count(&self) -> usize {
+        let open = self.protons.iter().filter(|p| p.as_ref().is_none_or(|p| !p.is_alive())).count();
+        open + self.capacity_limit.saturating_sub(self.protons.len())
+    }
+
+    /// Raise or lower the hard slot cap - never below the slots already allocated, so no index is invalidated
+    pub fn set_capacity(&mut self, limit: usize) {
+        self.capacity_limit = limit.max(self.protons.len());
+    }
+
+    /// (occupied slots, allocated slots, hard cap) for the Controls menu
+    pub fn capacity_usage(&self) -> (usize, usize, usize) {
+        let used = self.protons.iter().flatten().filter(|p| p.is_alive()).count();
+        (used, self.protons.len(), self.capacity_limit)
+    }
+
+    /// Short-lived e+ (charge 1) or e- (charge -1) thrown out by a reaction or decay
+    fn new_lepton(position: Vec2, velocity: Vec2, charge: i32) -> Proton {
+        let (r, g, b) = if charge > 0 { proton::POSITRON_COLOR } else { proton::ELECTRON_COLOR };
+        let mut lepton = Proton::new(position, velocity, Color::from_rgba(r, g, b, 255), proton::LEPTON_ENERGY, charge);
+        lepton.set_lepton(true);
+        lepton.set_max_lifetime(proton::LEPTON_LIFETIME);
+        lepton
+    }
+
     /// Update spawn cooldowns
     fn update_cooldowns(&mut self, delta_time: f32) {
         // Decrease all cooldown timers
@@ -5136,38 +6610,7 @@ impl ProtonManager {
                     continue;
                 }
 
-                // Track all stable elements and compounds (not O16 bonded pairs)
-                let element = if proton.is_sih4() {
-                    Some("SiH4")
-                } else if proton.is_ch4() {
-                    Some("CH4")
-                } else if proton.is_h2s() {
-                    Some("H2S")
-                } else if proton.is_mgh2() {
-                    Some("MgH2")
-                } else if proton.is_h2o() {
-                    Some("H2O")
-                } else if proton.is_sulfur32() {
-                    Some("S32")
-                } else if proton.is_silicon28() {
-                    Some("Si28")
-                } else if proton.is_magnesium24() {
-                    Some("Mg24")
-                } else if proton.is_neon20() {
-                    Some("Ne20")
-                } else if proton.charge() == 6 && proton.neutron_count() == 6 {
-                    Some("C12")
-                } else if proton.charge() == 2 && proton.neutron_count() == 2 {
-                    Some("He4")
-                } else if proton.charge() == 1 && proton.neutron_count() == 2 {
-                    Some("He3")
-                } else if proton.is_stable_hydrogen() {
-                    Some("H1")
-                } else {
-                    None
-                };
-
-                if let Some(elem) = element {
+                if let Some(elem) = Self::element_name(proton) {
                     *counts.entry(elem.to_string()).or_insert(0) += 1;
                 }
             }
@@ -5176,127 +6619,256 @@ impl ProtonManager {
         counts
     }
 
+    /// Name a particle is counted (and deleted) under - every stable element and compound,
+    /// consolidated O16 but not O16 bonded pairs
+    pub fn element_name(proton: &Proton) -> Option<&'static str> {
+        proton.element_kind().counted_name()
+    }
+
     /// Spawn a specific element type at a position with velocity
     pub fn spawn_element(&mut self, element_type: &str, position: Vec2, velocity: Vec2) {
-        use crate::constants::proton as pc;
+        self.spawn_built_element(element_type, position, velocity, None);
+    }
 
-        // Check if at capacity
-        if self.get_proton_count() >= self.max_protons {
-            return;
+    /// Spawn with `energy` in place of the element's default - size and mass follow it, and hot alphas
+    /// clear the triple-alpha energy threshold that default ones fall short of
+    pub fn spawn_element_with_energy(&mut self, element_type: &str, position: Vec2, velocity: Vec2, energy: f32) {
+        self.spawn_built_element(element_type, position, velocity, Some(energy.max(pm::MIN_SPAWN_ENERGY)));
+    }
+
+    fn spawn_built_element(&mut self, element_type: &str, position: Vec2, velocity: Vec2, energy: Option<f32>) {
+        // Nudge off any particle already sitting there - stacked spawns would blow apart on the next frame
+        let position = self.spawn_clear_position(position);
+
+        if let Some(proton) = Self::build_element_with_energy(element_type, position, velocity, energy) {
+            self.insert_proton(proton);
         }
+    }
 
-        // Find first empty slot
-        for i in 0..self.protons.len() {
-            if self.protons[i].is_none() || !self.protons[i].as_ref().unwrap().is_alive() {
-                let proton = match element_type {
-                    "H1" => {
-                        // Stable hydrogen
-                        let mut p = Proton::new(position, velocity, Color::from_rgba(255, 255, 255, 255), 1.0, 0);
-                        p.set_neutron_count(1);
-                        p.set_stable_hydrogen(true);
-                        p.set_max_lifetime(pc::INFINITE_LIFETIME);
-                        p
-                    },
-                    "He3" => {
-                        // Helium-3 (charge 1, neutron 2)
-                        let mut p = Proton::new(position, velocity, Color::from_rgba(255, 200, 100, 255), 3.0, 1);
-                        p.set_neutron_count(2);
-                        p.set_max_lifetime(pc::INFINITE_LIFETIME);
-                        p
-                    },
-                    "He4" => {
-                        // Helium-4 (charge 2, neutron 2)
-                        let mut p = Proton::new(position, velocity, Color::from_rgba(255, 255, 100, 255), 4.0, 2);
-                        p.set_neutron_count(2);
-                        p.set_max_lifetime(pc::INFINITE_LIFETIME);
-                        p
-                    },
-                    "C12" => {
-                        // Carbon-12 (charge 6, neutron 6)
-                        let mut p = Proton::new(position, velocity, Color::from_rgba(100, 100, 100, 255), 12.0, 6);
-                        p.set_neutron_count(6);
-                        p.set_max_lifetime(pc::INFINITE_LIFETIME);
-                        p
-                    },
-                    "Ne20" => {
-                        // Neon-20 (charge 10, neutron 10)
-                        let mut p = Proton::new(position, velocity, Color::from_rgba(255, 100, 150, 255), 20.0, 10);
-                        p.set_neutron_count(10);
-                        p.set_neon20(true);
-                        p.set_max_lifetime(pc::INFINITE_LIFETIME);
-                        p
-                    },
-                    "Mg24" => {
-                        // Magnesium-24 (charge 12, neutron 12)
-                        let mut p = Proton::new(position, velocity, Color::from_rgba(200, 200, 220, 255), 24.0, 12);
-                        p.set_neutron_count(12);
-                        p.set_magnesium24(true);
-                        p.set_max_lifetime(pc::INFINITE_LIFETIME);
-                        p
-                    },
-                    "Si28" => {
-                        // Silicon-28 (charge 14, neutron 14)
-                        let mut p = Proton::new(position, velocity, Color::from_rgba(160, 130, 90, 255), 28.0, 14);
-                        p.set_neutron_count(14);
-                        p.set_silicon28(true);
-                        p.set_max_lifetime(pc::INFINITE_LIFETIME);
-                        p
-                    },
-                    "S32" => {
-                        // Sulfur-32 (charge 16, neutron 16)
-                        let mut p = Proton::new(position, velocity, Color::from_rgba(220, 220, 80, 255), 32.0, 16);
-                        p.set_neutron_count(16);
-                        p.set_sulfur32(true);
-                        p.set_max_lifetime(pc::INFINITE_LIFETIME);
-                        p
-                    },
-                    "H2O" => {
-                        // Water molecule (O16 + 2H)
-                        let mut p = Proton::new(position, velocity, Color::from_rgba(40, 100, 180, 255), 18.0, 8);
-                        p.set_neutron_count(10);
-                        p.set_h2o(true);
-                        p.set_max_lifetime(pc::INFINITE_LIFETIME);
-                        p
-                    },
-                    "H2S" => {
-                        // Hydrogen Sulfide (S32 + 2H)
-                        let mut p = Proton::new(position, velocity, Color::from_rgba(200, 220, 80, 255), 34.0, 18);
-                        p.set_neutron_count(18);
-                        p.set_h2s(true);
-                        p.set_max_lifetime(pc::INFINITE_LIFETIME);
-                        p
-                    },
-                    "MgH2" => {
-                        // Magnesium Hydride (Mg24 + 2H)
-                        let mut p = Proton::new(position, velocity, Color::from_rgba(180, 180, 190, 255), 26.0, 14);
-                        p.set_neutron_count(14);
-                        p.set_mgh2(true);
-                        p.set_max_lifetime(pc::INFINITE_LIFETIME);
-                        p
-                    },
-                    "CH4" => {
-                        // Methane (C12 + 4H)
-                        let mut p = Proton::new(position, velocity, Color::from_rgba(120, 200, 150, 255), 16.0, 10);
-                        p.set_neutron_count(10);
-                        p.set_ch4(true);
-                        p.set_max_lifetime(pc::INFINITE_LIFETIME);
-                        p
-                    },
-                    "SiH4" => {
-                        // Silane (Si28 + 4H)
-                        let mut p = Proton::new(position, velocity, Color::from_rgba(220, 100, 50, 255), 32.0, 18);
-                        p.set_neutron_count(18);
-                        p.set_sih4(true);
-                        p.set_max_lifetime(pc::INFINITE_LIFETIME);
-                        p
-                    },
-                    _ => return, // Unknown element type
+    /// Energy a freshly spawned `element_type` starts with (None for unknown names)
+    pub fn default_spawn_energy(element_type: &str) -> Option<f32> {
+        Self::build_element(element_type, Vec2::ZERO, Vec2::ZERO).map(|p| p.energy())
+    }
+
+    /// Stamp a stationary seed crystal of `element_type` around `center`, spaced at the element's bond rest length
+    /// H1 and Mg24 get a hexagon, Ne20/Si28/S32 a square grid and C12 a triangle, each `rings` steps out from the center
+    /// Lattice points already taken by a live particle are skipped. Returns how many particles were placed
+    pub fn stamp_lattice(&mut self, element_type: &str, center: Vec2, rings: usize) -> usize {
+        let rings = rings as i32;
+        let row_height = 3.0_f32.sqrt() / 2.0;
+        let (spacing, offsets): (f32, Vec<Vec2>) = match element_type {
+            "H1" | "Mg24" => {
+                let spacing = if element_type == "H1" { pm::H_CRYSTAL_BOND_REST_LENGTH } else { pm::MG24_BOND_REST_LENGTH };
+                // Axial hex coordinates within `rings` steps of the center
+                let offsets = (-rings..=rings)
+                    .flat_map(|q| (-rings..=rings).map(move |r| (q, r)))
+                    .filter(|&(q, r)| (q + r).abs() <= rings)
+                    .map(|(q, r)| vec2(q as f32 + r as f32 / 2.0, r as f32 * row_height))
+                    .collect();
+                (spacing, offsets)
+            }
+            "Ne20" | "Si28" | "S32" => {
+                let spacing = match element_type {
+                    "Ne20" => pm::NE20_BOND_REST_LENGTH,
+                    "Si28" => pm::SI28_BOND_REST_LENGTH,
+                    _ => pm::S32_BOND_REST_LENGTH,
                 };
+                let offsets = (-rings..=rings)
+                    .flat_map(|x| (-rings..=rings).map(move |y| vec2(x as f32, y as f32)))
+                    .collect();
+                (spacing, offsets)
+            }
+            "C12" => {
+                // Rows of 1, 2, ... 2 * rings + 1 points, centered on the triangle's centroid
+                let side = 2 * rings;
+                let centroid_y = side as f32 * row_height * 2.0 / 3.0;
+                let offsets = (0..=side)
+                    .flat_map(|row| (0..=row).map(move |i| vec2(i as f32 - row as f32 / 2.0, row as f32 * row_height - centroid_y)))
+                    .collect();
+                (pm::C12_BOND_REST_LENGTH, offsets)
+            }
+            _ => return 0, // No seed lattice for this element
+        };
+
+        let mut placed = 0;
+        for offset in offsets {
+            let position = center + offset * spacing;
+            let occupied = self.protons.iter().flatten().any(|p| {
+                p.is_alive() && p.position().distance_squared(position) < pm::SPAWN_MIN_SPACING * pm::SPAWN_MIN_SPACING
+            });
+            if occupied {
+                continue;
+            }
+            let Some(proton) = Self::build_element(element_type, position, Vec2::ZERO) else { break };
+            if !self.insert_proton(proton) {
+                break; // Slot cap reached
+            }
+            placed += 1;
+        }
+        placed
+    }
+
+    /// A fresh particle of a spawnable element type (None for unknown names)
+    fn build_element(element_type: &str, position: Vec2, velocity: Vec2) -> Option<Proton> {
+        Self::build_element_with_energy(element_type, position, velocity, None)
+    }
 
-                self.protons[i] = Some(proton);
-                break;
-            }
+    /// Like `build_element`, starting at `energy` instead of the element's default when given
+    fn build_element_with_energy(element_type: &str, position: Vec2, velocity: Vec2, energy: Option<f32>) -> Option<Proton> {
+        use crate::constants::proton as pc;
+
+        let proton = match element_type {
+            "H1" => {
+                // Stable hydrogen
+                let mut p = Proton::new(position, velocity, Color::from_rgba(255, 255, 255, 255), energy.unwrap_or(1.0), 0);
+                p.set_neutron_count(1);
+                p.set_stable_hydrogen(true);
+                p.set_max_lifetime(pc::INFINITE_LIFETIME);
+                p
+            },
+            "T" => {
+                // Tritium (charge 0, neutron 2) - decays to He3 + e- after TRITIUM_LIFETIME
+                let (r, g, b) = pc::TRITIUM_COLOR;
+                let mut p = Proton::new(position, velocity, Color::from_rgba(r, g, b, 255), energy.unwrap_or(2.0), 0);
+                p.set_neutron_count(2);
+                p.set_max_lifetime(pc::TRITIUM_LIFETIME);
+                p
+            },
+            "He3" => {
+                // Helium-3 (charge 1, neutron 2)
+                let mut p = Proton::new(position, velocity, Color::from_rgba(255, 200, 100, 255), energy.unwrap_or(3.0), 1);
+                p.set_neutron_count(2);
+                p.set_max_lifetime(pc::INFINITE_LIFETIME);
+                p
+            },
+            "He4" => {
+                // Helium-4 (charge 2, neutron 2)
+                let mut p = Proton::new(position, velocity, Color::from_rgba(255, 255, 100, 255), energy.unwrap_or(4.0), 2);
+                p.set_neutron_count(2);
+                p.set_max_lifetime(pc::INFINITE_LIFETIME);
+                p
+            },
+            "C12" => {
+                // Carbon-12 (charge 6, neutron 6)
+                let mut p = Proton::new(position, velocity, Color::from_rgba(100, 100, 100, 255), energy.unwrap_or(12.0), 6);
+                p.set_neutron_count(6);
+                p.set_max_lifetime(pc::INFINITE_LIFETIME);
+                p
+            },
+            "O16" => {
+                // Oxygen-16 (charge 8, neutron 8) - consolidated, as formed from C12 + He4
+                let mut p = Proton::new(position, velocity, Color::from_rgba(100, 180, 255, 255), energy.unwrap_or(16.0), 8);
+                p.set_neutron_count(8);
+                p.set_oxygen16(true);
+                p.set_max_lifetime(pc::INFINITE_LIFETIME);
+                p
+            },
+            "Ne20" => {
+                // Neon-20 (charge 10, neutron 10)
+                let mut p = Proton::new(position, velocity, Color::from_rgba(255, 100, 150, 255), energy.unwrap_or(20.0), 10);
+                p.set_neutron_count(10);
+                p.set_neon20(true);
+                p.set_max_lifetime(pc::INFINITE_LIFETIME);
+                p
+            },
+            "Mg24" => {
+                // Magnesium-24 (charge 12, neutron 12)
+                let mut p = Proton::new(position, velocity, Color::from_rgba(200, 200, 220, 255), energy.unwrap_or(24.0), 12);
+                p.set_neutron_count(12);
+                p.set_magnesium24(true);
+                p.set_max_lifetime(pc::INFINITE_LIFETIME);
+                p
+            },
+            "Si28" => {
+                // Silicon-28 (charge 14, neutron 14)
+                let mut p = Proton::new(position, velocity, Color::from_rgba(160, 130, 90, 255), energy.unwrap_or(28.0), 14);
+                p.set_neutron_count(14);
+                p.set_silicon28(true);
+                p.set_max_lifetime(pc::INFINITE_LIFETIME);
+                p
+            },
+            "S32" => {
+                // Sulfur-32 (charge 16, neutron 16)
+                let mut p = Proton::new(position, velocity, Color::from_rgba(220, 220, 80, 255), energy.unwrap_or(32.0), 16);
+                p.set_neutron_count(16);
+                p.set_sulfur32(true);
+                p.set_max_lifetime(pc::INFINITE_LIFETIME);
+                p
+            },
+            "H2O" => {
+                // Water molecule (O16 + 2H) - same nucleus counts as water formed by fusion
+                let mut p = Proton::new(position, velocity, Color::from_rgba(40, 100, 180, 255), energy.unwrap_or(18.0), 8);
+                p.set_neutron_count(10);
+                p.set_h2o(true);
+                p.set_max_lifetime(pc::INFINITE_LIFETIME);
+                p
+            },
+            "H2S" => {
+                // Hydrogen Sulfide (S32 + 2H)
+                let mut p = Proton::new(position, velocity, Color::from_rgba(200, 220, 80, 255), energy.unwrap_or(34.0), 16);
+                p.set_neutron_count(18);
+                p.set_h2s(true);
+                p.set_max_lifetime(pc::INFINITE_LIFETIME);
+                p
+            },
+            "MgH2" => {
+                // Magnesium Hydride (Mg24 + 2H)
+                let mut p = Proton::new(position, velocity, Color::from_rgba(180, 180, 190, 255), energy.unwrap_or(26.0), 12);
+                p.set_neutron_count(14);
+                p.set_mgh2(true);
+                p.set_max_lifetime(pc::INFINITE_LIFETIME);
+                p
+            },
+            "CH4" => {
+                // Methane (C12 + 4H)
+                let mut p = Proton::new(position, velocity, Color::from_rgba(120, 200, 150, 255), energy.unwrap_or(16.0), 6);
+                p.set_neutron_count(10);
+                p.set_ch4(true);
+                p.set_max_lifetime(pc::INFINITE_LIFETIME);
+                p
+            },
+            "SiH4" => {
+                // Silane (Si28 + 4H)
+                let mut p = Proton::new(position, velocity, Color::from_rgba(220, 100, 50, 255), energy.unwrap_or(32.0), 14);
+                p.set_neutron_count(18);
+                p.set_sih4(true);
+                p.set_max_lifetime(pc::INFINITE_LIFETIME);
+                p
+            },
+            "SO2" => {
+                // Sulfur dioxide (S32 + 2 O16)
+                let mut p = Proton::new(position, velocity, Color::from_rgba(230, 200, 140, 255), energy.unwrap_or(64.0), 32);
+                p.set_neutron_count(32);
+                p.set_so2(true);
+                p.set_max_lifetime(pc::INFINITE_LIFETIME);
+                p
+            },
+            _ => return None, // Unknown element type
+        };
+
+        Some(proton)
+    }
+
+    /// Closest point to `position` that is at least SPAWN_MIN_SPACING from every live particle
+    /// Searches outward on a golden-angle spiral; falls back to `position` if the area is packed
+    fn spawn_clear_position(&self, position: Vec2) -> Vec2 {
+        let is_clear = |candidate: Vec2| {
+            self.protons.iter().flatten().all(|p| {
+                !p.is_alive() || p.position().distance_squared(candidate) >= pm::SPAWN_MIN_SPACING * pm::SPAWN_MIN_SPACING
+            })
+        };
+
+        if is_clear(position) {
+            return position;
         }
+
+        (1..=pm::SPAWN_SPACING_ATTEMPTS)
+            .map(|step| {
+                let angle = step as f32 * pm::SPAWN_SPIRAL_ANGLE;
+                position + Vec2::from_angle(angle) * pm::SPAWN_MIN_SPACING * (step as f32).sqrt()
+            })
+            .find(|&candidate| is_clear(candidate))
+            .unwrap_or(position)
     }
 
     // === BIOLOGICAL ELEMENTS CRYSTALLIZATION METHODS ===
@@ -5314,7 +6886,7 @@ impl ProtonManager {
         }
 
         // ===== PHASE 2: Check evaporation =====
-        for (idx, _, vel) in &n14_atoms {
+        for (idx, pos, vel) in &n14_atoms {
             let speed = vel.length();
             let evaporation_threshold = if let Some(proton) = &self.protons[*idx] {
                 if proton.is_n14_crystallized() {
@@ -5326,7 +6898,7 @@ impl ProtonManager {
                 pm::N14_EVAPORATION_SPEED
             };
 
-            if speed > evaporation_threshold {
+            if speed > evaporation_threshold * self.evaporation_scale_at(*pos) || self.is_hot_slot(*idx) {
                 if let Some(proton) = &mut self.protons[*idx] {
                     proton.set_n14_crystallized(false);
                     proton.clear_n14_crystal_bonds();
@@ -5349,23 +6921,11 @@ impl ProtonManager {
         }
 
         // ===== PHASE 4: Form new bonds =====
-        let mut neighbor_lists: Vec<Vec<usize>> = vec![Vec::new(); self.protons.len()];
-        for i in 0..n14_atoms.len() {
-            for j in (i + 1)..n14_atoms.len() {
-                let (idx1, pos1, _) = n14_atoms[i];
-                let (idx2, pos2, _) = n14_atoms[j];
-                let dist = pos1.distance(pos2);
-
-                if dist >= pm::N14_MIN_SPACING && dist < pm::N14_NEIGHBOR_DISTANCE {
-                    neighbor_lists[idx1].push(idx2);
-                    neighbor_lists[idx2].push(idx1);
-                }
-            }
-        }
+        let neighbor_lists = self.crystal_neighbor_lists(&n14_atoms, pm::N14_MIN_SPACING, pm::N14_NEIGHBOR_DISTANCE);
 
         for (idx, _, _) in &n14_atoms {
             if let Some(proton) = &self.protons[*idx] {
-                if proton.n14_freeze_cooldown() > 0.0 {
+                if proton.n14_freeze_cooldown() > 0.0 || proton.is_hot() {
                     continue;
                 }
             }
@@ -5385,7 +6945,7 @@ impl ProtonManager {
                     })
                     .collect();
 
-                neighbors_with_dist.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+                neighbors_with_dist.sort_by(|a, b| nan_last(a.1, b.1));
                 let nearest: Vec<usize> = neighbors_with_dist
                     .iter()
                     .take(8.min(neighbors_with_dist.len()))
@@ -5432,7 +6992,7 @@ impl ProtonManager {
                 if proton.is_alive() && proton.charge() == 7 && proton.neutron_count() == 7 && proton.is_n14_crystallized() {
                     let force_magnitude = force.length();
                     if force_magnitude > 0.0001 {
-                        proton.add_velocity((*force / proton.mass()) * delta_time);
+                        proton.apply_force(*force, delta_time);
                     }
                 }
             }
@@ -5452,7 +7012,7 @@ impl ProtonManager {
         }
 
         // ===== PHASE 2: Check evaporation =====
-        for (idx, _, vel) in &p31_atoms {
+        for (idx, pos, vel) in &p31_atoms {
             let speed = vel.length();
             let evaporation_threshold = if let Some(proton) = &self.protons[*idx] {
                 if proton.is_p31_crystallized() {
@@ -5464,7 +7024,7 @@ impl ProtonManager {
                 pm::P31_EVAPORATION_SPEED
             };
 
-            if speed > evaporation_threshold {
+            if speed > evaporation_threshold * self.evaporation_scale_at(*pos) || self.is_hot_slot(*idx) {
                 if let Some(proton) = &mut self.protons[*idx] {
                     proton.set_p31_crystallized(false);
                     proton.clear_p31_crystal_bonds();
@@ -5487,23 +7047,11 @@ impl ProtonManager {
         }
 
         // ===== PHASE 4: Form new bonds =====
-        let mut neighbor_lists: Vec<Vec<usize>> = vec![Vec::new(); self.protons.len()];
-        for i in 0..p31_atoms.len() {
-            for j in (i + 1)..p31_atoms.len() {
-                let (idx1, pos1, _) = p31_atoms[i];
-                let (idx2, pos2, _) = p31_atoms[j];
-                let dist = pos1.distance(pos2);
-
-                if dist >= pm::P31_MIN_SPACING && dist < pm::P31_NEIGHBOR_DISTANCE {
-                    neighbor_lists[idx1].push(idx2);
-                    neighbor_lists[idx2].push(idx1);
-                }
-            }
-        }
+        let neighbor_lists = self.crystal_neighbor_lists(&p31_atoms, pm::P31_MIN_SPACING, pm::P31_NEIGHBOR_DISTANCE);
 
         for (idx, _, _) in &p31_atoms {
             if let Some(proton) = &self.protons[*idx] {
-                if proton.p31_freeze_cooldown() > 0.0 {
+                if proton.p31_freeze_cooldown() > 0.0 || proton.is_hot() {
                     continue;
                 }
             }
@@ -5523,7 +7071,7 @@ impl ProtonManager {
                     })
                     .collect();
 
-                neighbors_with_dist.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+                neighbors_with_dist.sort_by(|a, b| nan_last(a.1, b.1));
                 let nearest: Vec<usize> = neighbors_with_dist
                     .iter()
                     .take(6.min(neighbors_with_dist.len()))
@@ -5570,7 +7118,7 @@ impl ProtonManager {
                 if proton.is_alive() && proton.charge() == 15 && proton.neutron_count() == 16 && proton.is_p31_crystallized() {
                     let force_magnitude = force.length();
                     if force_magnitude > 0.0001 {
-                        proton.add_velocity((*force / proton.mass()) * delta_time);
+                        proton.apply_force(*force, delta_time);
                     }
                 }
             }
@@ -5590,7 +7138,7 @@ impl ProtonManager {
         }
 
         // ===== PHASE 2: Check evaporation =====
-        for (idx, _, vel) in &na23_atoms {
+        for (idx, pos, vel) in &na23_atoms {
             let speed = vel.length();
             let evaporation_threshold = if let Some(proton) = &self.protons[*idx] {
                 if proton.is_na23_crystallized() {
@@ -5602,7 +7150,7 @@ impl ProtonManager {
                 pm::NA23_EVAPORATION_SPEED
             };
 
-            if speed > evaporation_threshold {
+            if speed > evaporation_threshold * self.evaporation_scale_at(*pos) || self.is_hot_slot(*idx) {
                 if let Some(proton) = &mut self.protons[*idx] {
                     proton.set_na23_crystallized(false);
                     proton.clear_na23_crystal_bonds();
@@ -5625,23 +7173,11 @@ impl ProtonManager {
         }
 
         // ===== PHASE 4: Form new bonds =====
-        let mut neighbor_lists: Vec<Vec<usize>> = vec![Vec::new(); self.protons.len()];
-        for i in 0..na23_atoms.len() {
-            for j in (i + 1)..na23_atoms.len() {
-                let (idx1, pos1, _) = na23_atoms[i];
-                let (idx2, pos2, _) = na23_atoms[j];
-                let dist = pos1.distance(pos2);
-
-                if dist >= pm::NA23_MIN_SPACING && dist < pm::NA23_NEIGHBOR_DISTANCE {
-                    neighbor_lists[idx1].push(idx2);
-                    neighbor_lists[idx2].push(idx1);
-                }
-            }
-        }
+        let neighbor_lists = self.crystal_neighbor_lists(&na23_atoms, pm::NA23_MIN_SPACING, pm::NA23_NEIGHBOR_DISTANCE);
 
         for (idx, _, _) in &na23_atoms {
             if let Some(proton) = &self.protons[*idx] {
-                if proton.na23_freeze_cooldown() > 0.0 {
+                if proton.na23_freeze_cooldown() > 0.0 || proton.is_hot() {
                     continue;
                 }
             }
@@ -5661,7 +7197,7 @@ impl ProtonManager {
                     })
                     .collect();
 
-                neighbors_with_dist.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+                neighbors_with_dist.sort_by(|a, b| nan_last(a.1, b.1));
                 let nearest: Vec<usize> = neighbors_with_dist
                     .iter()
                     .take(8.min(neighbors_with_dist.len()))
@@ -5708,7 +7244,7 @@ impl ProtonManager {
                 if proton.is_alive() && proton.charge() == 11 && proton.neutron_count() == 12 && proton.is_na23_crystallized() {
                     let force_magnitude = force.length();
                     if force_magnitude > 0.0001 {
-                        proton.add_velocity((*force / proton.mass()) * delta_time);
+                        proton.apply_force(*force, delta_time);
                     }
                 }
             }
@@ -5728,7 +7264,7 @@ impl ProtonManager {
         }
 
         // ===== PHASE 2: Check evaporation =====
-        for (idx, _, vel) in &k39_atoms {
+        for (idx, pos, vel) in &k39_atoms {
             let speed = vel.length();
             let evaporation_threshold = if let Some(proton) = &self.protons[*idx] {
                 if proton.is_k39_crystallized() {
@@ -5740,7 +7276,7 @@ impl ProtonManager {
                 pm::K39_EVAPORATION_SPEED
             };
 
-            if speed > evaporation_threshold {
+            if speed > evaporation_threshold * self.evaporation_scale_at(*pos) || self.is_hot_slot(*idx) {
                 if let Some(proton) = &mut self.protons[*idx] {
                     proton.set_k39_crystallized(false);
                     proton.clear_k39_crystal_bonds();
@@ -5763,23 +7299,11 @@ impl ProtonManager {
         }
 
         // ===== PHASE 4: Form new bonds =====
-        let mut neighbor_lists: Vec<Vec<usize>> = vec![Vec::new(); self.protons.len()];
-        for i in 0..k39_atoms.len() {
-            for j in (i + 1)..k39_atoms.len() {
-                let (idx1, pos1, _) = k39_atoms[i];
-                let (idx2, pos2, _) = k39_atoms[j];
-                let dist = pos1.distance(pos2);
-
-                if dist >= pm::K39_MIN_SPACING && dist < pm::K39_NEIGHBOR_DISTANCE {
-                    neighbor_lists[idx1].push(idx2);
-                    neighbor_lists[idx2].push(idx1);
-                }
-            }
-        }
+        let neighbor_lists = self.crystal_neighbor_lists(&k39_atoms, pm::K39_MIN_SPACING, pm::K39_NEIGHBOR_DISTANCE);
 
         for (idx, _, _) in &k39_atoms {
             if let Some(proton) = &self.protons[*idx] {
-                if proton.k39_freeze_cooldown() > 0.0 {
+                if proton.k39_freeze_cooldown() > 0.0 || proton.is_hot() {
                     continue;
                 }
             }
@@ -5799,7 +7323,7 @@ impl ProtonManager {
                     })
                     .collect();
 
-                neighbors_with_dist.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+                neighbors_with_dist.sort_by(|a, b| nan_last(a.1, b.1));
                 let nearest: Vec<usize> = neighbors_with_dist
                     .iter()
                     .take(8.min(neighbors_with_dist.len()))
@@ -5846,7 +7370,7 @@ impl ProtonManager {
                 if proton.is_alive() && proton.charge() == 19 && proton.neutron_count() == 20 && proton.is_k39_crystallized() {
                     let force_magnitude = force.length();
                     if force_magnitude > 0.0001 {
-                        proton.add_velocity((*force / proton.mass()) * delta_time);
+                        proton.apply_force(*force, delta_time);
                     }
                 }
             }
@@ -5866,7 +7390,7 @@ impl ProtonManager {
         }
 
         // ===== PHASE 2: Check evaporation =====
-        for (idx, _, vel) in &ca40_atoms {
+        for (idx, pos, vel) in &ca40_atoms {
             let speed = vel.length();
             let evaporation_threshold = if let Some(proton) = &self.protons[*idx] {
                 if proton.is_ca40_crystallized() {
@@ -5878,7 +7402,7 @@ impl ProtonManager {
                 pm::CA40_EVAPORATION_SPEED
             };
 
-            if speed > evaporation_threshold {
+            if speed > evaporation_threshold * self.evaporation_scale_at(*pos) || self.is_hot_slot(*idx) {
                 if let Some(proton) = &mut self.protons[*idx] {
                     proton.set_ca40_crystallized(false);
                     proton.clear_ca40_crystal_bonds();
@@ -5901,23 +7425,11 @@ impl ProtonManager {
         }
 
         // ===== PHASE 4: Form new bonds =====
-        let mut neighbor_lists: Vec<Vec<usize>> = vec![Vec::new(); self.protons.len()];
-        for i in 0..ca40_atoms.len() {
-            for j in (i + 1)..ca40_atoms.len() {
-                let (idx1, pos1, _) = ca40_atoms[i];
-                let (idx2, pos2, _) = ca40_atoms[j];
-                let dist = pos1.distance(pos2);
-
-                if dist >= pm::CA40_MIN_SPACING && dist < pm::CA40_NEIGHBOR_DISTANCE {
-                    neighbor_lists[idx1].push(idx2);
-                    neighbor_lists[idx2].push(idx1);
-                }
-            }
-        }
+        let neighbor_lists = self.crystal_neighbor_lists(&ca40_atoms, pm::CA40_MIN_SPACING, pm::CA40_NEIGHBOR_DISTANCE);
 
         for (idx, _, _) in &ca40_atoms {
             if let Some(proton) = &self.protons[*idx] {
-                if proton.ca40_freeze_cooldown() > 0.0 {
+                if proton.ca40_freeze_cooldown() > 0.0 || proton.is_hot() {
                     continue;
                 }
             }
@@ -5937,7 +7449,7 @@ impl ProtonManager {
                     })
                     .collect();
 
-                neighbors_with_dist.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+                neighbors_with_dist.sort_by(|a, b| nan_last(a.1, b.1));
                 let nearest: Vec<usize> = neighbors_with_dist
                     .iter()
                     .take(8.min(neighbors_with_dist.len()))
@@ -5984,10 +7496,1754 @@ impl ProtonManager {
                 if proton.is_alive() && proton.charge() == 20 && proton.neutron_count() == 20 && proton.is_ca40_crystallized() {
                     let force_magnitude = force.length();
                     if force_magnitude > 0.0001 {
-                        proton.add_velocity((*force / proton.mass()) * delta_time);
+                        proton.apply_force(*force, delta_time);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn he4(position: Vec2, velocity: Vec2) -> Proton {
+        let mut p = Proton::new(position, velocity, Color::from_rgba(255, 255, 100, 255), 30.0, 2);
+        p.set_neutron_count(2);
+        p.set_max_lifetime(-1.0);
+        p
+    }
+
+    /// Run one fusion pass with the given He4 placed in the given slots
+    fn fuse_with_slots(particles: &[(Vec2, Vec2)], slots: &[usize]) -> ProtonManager {
+        let mut manager = ProtonManager::new(16, &SimConfig::default());
+        let mut ring_manager = RingManager::new();
+        for (&(pos, vel), &slot) in particles.iter().zip(slots) {
+            manager.protons[slot] = Some(he4(pos, vel));
+        }
+        manager.handle_nuclear_fusion(&mut ring_manager);
+        manager
+    }
+
+    #[test]
+    fn triple_alpha_choice_ignores_slot_order() {
+        // Rhombus of two equilateral triangles: ABC and BCD are both valid triples
+        let h = 5.0 * 3.0_f32.sqrt() / 2.0;
+        let a = (vec2(100.0, 100.0), vec2(20.0, 0.0));
+        let b = (vec2(105.0, 100.0), vec2(0.0, 0.0));
+        let c = (vec2(102.5, 100.0 + h), vec2(0.0, 0.0));
+        let d = (vec2(107.5, 100.0 + h), vec2(-20.0, 0.0));
+        let particles = [a, b, c, d];
+
+        let slot_layouts: [[usize; 4]; 5] = [
+            [0, 1, 2, 3],
+            [3, 2, 1, 0],
+            [9, 2, 14, 5],
+            [1, 7, 0, 4],
+            [12, 11, 3, 8],
+        ];
+
+        let expected_c12 = (a.0 + b.0 + c.0) / 3.0;
+
+        for slots in &slot_layouts {
+            let manager = fuse_with_slots(&particles, slots);
+            let alive: Vec<&Proton> = manager.protons.iter().flatten().collect();
+
+            assert_eq!(alive.len(), 2, "slots {:?}", slots);
+            let c12 = alive.iter().find(|p| p.is_stable_carbon12()).expect("C12 formed");
+            let leftover = alive.iter().find(|p| p.is_stable_helium4()).expect("one He4 left");
+
+            assert!(c12.position().distance(expected_c12) < 1e-3, "slots {:?}", slots);
+            assert_eq!(leftover.position(), d.0, "slots {:?}", slots);
+        }
+    }
+
+    #[test]
+    fn disjoint_triples_fuse_in_one_frame_up_to_the_reaction_budget() {
+        let h = 5.0 * 3.0_f32.sqrt() / 2.0;
+        let triple = |origin: Vec2| [
+            (origin, vec2(20.0, 0.0)),
+            (origin + vec2(5.0, 0.0), Vec2::ZERO),
+            (origin + vec2(2.5, h), Vec2::ZERO),
+        ];
+        let carbon_count = |manager: &ProtonManager| manager.protons.iter().flatten().filter(|p| p.is_stable_carbon12()).count();
+
+        // Two triples far apart both become carbon in the same frame
+        let particles: Vec<(Vec2, Vec2)> = [triple(vec2(100.0, 100.0)), triple(vec2(300.0, 300.0))].concat();
+        let manager = fuse_with_slots(&particles, &[0, 1, 2, 3, 4, 5]);
+        assert_eq!(carbon_count(&manager), 2);
+        assert_eq!(manager.protons.iter().flatten().count(), 2);
+
+        // Two candidate triples sharing two He4 (a rhombus) - only the better one fuses
+        let mut rhombus = triple(vec2(100.0, 100.0)).to_vec();
+        rhombus.push((vec2(107.5, 100.0 + h), vec2(-20.0, 0.0)));
+        let manager = fuse_with_slots(&rhombus, &[0, 1, 2, 3]);
+        assert_eq!(carbon_count(&manager), 1);
+        assert_eq!(manager.protons.iter().flatten().filter(|p| p.is_stable_helium4()).count(), 1);
+
+        // A budget of one holds the disjoint triples back to a single carbon
+        let config = SimConfig { max_reactions_per_frame: 1.0, ..SimConfig::default() };
+        let mut manager = ProtonManager::new(16, &config);
+        for (slot, &(pos, vel)) in particles.iter().enumerate() {
+            manager.protons[slot] = Some(he4(pos, vel));
+        }
+        manager.handle_nuclear_fusion(&mut RingManager::new());
+        assert_eq!(carbon_count(&manager), 1);
+    }
+
+    #[test]
+    fn update_reports_each_steps_fusion_events_and_then_forgets_them() {
+        let h = 5.0 * 3.0_f32.sqrt() / 2.0;
+        let mut manager = ProtonManager::new(16, &SimConfig::default());
+        let mut atom_manager = AtomManager::new(0);
+        let mut ring_manager = RingManager::new();
+        let triangle = [
+            (vec2(100.0, 100.0), vec2(20.0, 0.0)),
+            (vec2(105.0, 100.0), vec2(0.0, 0.0)),
+            (vec2(102.5, 100.0 + h), vec2(-20.0, 0.0)),
+        ];
+        for (slot, &(pos, vel)) in triangle.iter().enumerate() {
+            manager.protons[slot] = Some(he4(pos, vel));
+        }
+
+        let window = (1280.0, 720.0);
+        manager.update(1.0 / 120.0, window, &mut atom_manager, &mut ring_manager);
+        let c12 = manager.protons.iter().flatten().find(|p| p.is_stable_carbon12()).expect("C12 formed");
+        let fusion = SimEvent { kind: SimEventKind::Fusion, position: c12.position(), element: "C12".to_string() };
+        assert!(manager.events.contains(&fusion), "{:?}", manager.events);
+
+        // Left undrained, the next step starts over
+        manager.update(1.0 / 120.0, window, &mut atom_manager, &mut ring_manager);
+        assert!(manager.events.iter().all(|e| e.kind != SimEventKind::Fusion), "{:?}", manager.events);
+    }
+
+    #[test]
+    fn fused_particle_keeps_a_reactant_id() {
+        let h = 5.0 * 3.0_f32.sqrt() / 2.0;
+        let mut manager = ProtonManager::new(16, &SimConfig::default());
+        let mut ring_manager = RingManager::new();
+        let triangle = [
+            (vec2(100.0, 100.0), vec2(20.0, 0.0)),
+            (vec2(105.0, 100.0), vec2(0.0, 0.0)),
+            (vec2(102.5, 100.0 + h), vec2(-20.0, 0.0)),
+        ];
+        let mut ids = Vec::new();
+        for (slot, &(pos, vel)) in triangle.iter().enumerate() {
+            let alpha = he4(pos, vel);
+            ids.push(alpha.id());
+            manager.protons[slot] = Some(alpha);
+        }
+        assert!(ids[0] != ids[1] && ids[1] != ids[2] && ids[0] != ids[2]);
+
+        manager.handle_nuclear_fusion(&mut ring_manager);
+
+        // Exactly one reactant id lives on, now on the C12
+        let followed: Vec<&Proton> = ids.iter().filter_map(|&id| manager.find_by_id(id)).collect();
+        assert_eq!(followed.len(), 1);
+        assert!(followed[0].is_stable_carbon12());
+    }
+
+    #[test]
+    fn triple_alpha_shows_up_in_reaction_rates_until_it_leaves_the_window() {
+        let h = 5.0 * 3.0_f32.sqrt() / 2.0;
+        let triangle = [
+            (vec2(100.0, 100.0), vec2(20.0, 0.0)),
+            (vec2(105.0, 100.0), vec2(0.0, 0.0)),
+            (vec2(102.5, 100.0 + h), vec2(-20.0, 0.0)),
+        ];
+        let mut manager = fuse_with_slots(&triangle, &[0, 1, 2]);
+        let rate_of = |manager: &ProtonManager, wanted: &str| {
+            manager.reaction_rates().into_iter().find(|(label, _)| *label == wanted).unwrap().1
+        };
+
+        assert_eq!(rate_of(&manager, "3 He4 -> C12"), 1.0 / pm::REACTION_RATE_WINDOW);
+        let total: f32 = manager.reaction_rates().iter().map(|(_, rate)| rate).sum();
+        assert_eq!(total, 1.0 / pm::REACTION_RATE_WINDOW);
+
+        manager.elapsed_time += pm::REACTION_RATE_WINDOW + 1.0;
+        assert_eq!(rate_of(&manager, "3 He4 -> C12"), 0.0);
+    }
+
+    #[test]
+    fn alpha_capture_ejects_a_pair_ahead_and_recoils_the_product() {
+        let mut manager = ProtonManager::new(16, &SimConfig::default());
+        let mut ring_manager = RingManager::new();
+        let mut ne20 = Proton::new(vec2(300.0, 300.0), vec2(0.0, 5.0), Color::from_rgba(255, 100, 150, 255), 200.0, 10);
+        ne20.set_neutron_count(10);
+        ne20.set_max_lifetime(-1.0);
+        ne20.set_neon20(true);
+        manager.protons[3] = Some(ne20);
+        manager.protons[7] = Some(he4(vec2(294.0, 300.0), vec2(40.0, 0.0)));
+
+        let momentum = |m: &ProtonManager| m.protons.iter().flatten().map(|p| p.velocity() * p.mass()).sum::<Vec2>();
+        let before = momentum(&manager);
+        manager.handle_nuclear_fusion(&mut ring_manager);
+
+        let mg24 = manager.protons[3].as_ref().expect("Mg24 formed");
+        assert!(mg24.is_magnesium24());
+        let pair: Vec<&Proton> = manager.protons.iter().flatten().filter(|p| p.is_lepton()).collect();
+        let mut charges: Vec<i32> = pair.iter().map(|p| p.charge()).collect();
+        charges.sort();
+        assert_eq!(charges, vec![-1, 1], "one e+ and one e-");
+
+        // The pair flies on along the alpha's path (+x) while the product is pushed back
+        for lepton in &pair {
+            assert!(lepton.velocity().x > mg24.velocity().x);
+            assert!(mg24.position().x < lepton.position().x);
+        }
+        assert!((momentum(&manager) - before).length() < 1e-2, "{:?} vs {:?}", momentum(&manager), before);
+    }
+
+    #[test]
+    fn helium3_pair_fusion_conserves_momentum() {
+        let mut manager = ProtonManager::new(16, &SimConfig::default());
+        let mut ring_manager = RingManager::new();
+        let mut he3_slow = ProtonManager::build_element("He3", vec2(300.0, 300.0), vec2(-10.0, 15.0)).unwrap();
+        he3_slow.set_energy(5.0); // Unequal masses - the products must not just take the average velocity
+        manager.protons[2] = Some(he3_slow);
+        manager.protons[5] = Some(ProtonManager::build_element("He3", vec2(303.0, 302.0), vec2(60.0, -5.0)).unwrap());
+
+        let momentum = |m: &ProtonManager| m.protons.iter().flatten().map(|p| p.velocity() * p.mass()).sum::<Vec2>();
+        let before = momentum(&manager);
+        manager.handle_nuclear_fusion(&mut ring_manager);
+
+        let alive: Vec<&Proton> = manager.protons.iter().flatten().collect();
+        assert_eq!(alive.iter().filter(|p| p.is_stable_helium4()).count(), 1);
+        assert_eq!(alive.iter().filter(|p| (p.charge(), p.neutron_count()) == (1, 0)).count(), 2);
+        assert!((momentum(&manager) - before).length() < 1e-3, "{:?} vs {:?}", momentum(&manager), before);
+
+        // Its energy waves are tagged as fusion rings
+        let rings = ring_manager.get_all_rings();
+        assert_eq!(rings.len(), 2);
+        assert!(rings.iter().all(|r| r.origin() == RingOrigin::Fusion));
+        assert_eq!(ring_manager.count_from(RingOrigin::Fusion), 2);
+        assert_eq!(ring_manager.count_from(RingOrigin::Click), 0);
+    }
+
+    #[test]
+    fn collision_bounce_follows_the_configured_material_pair() {
+        let config = SimConfig::parse("[elasticity]\nelasticity_ice_ice = 0\nelasticity_metal_metal = 0.5\n", "test");
+        assert_eq!(config.elasticity(Material::Ice, Material::Gas), SimConfig::default().elasticity(Material::Ice, Material::Gas));
+
+        // Two equal particles meeting head-on: the closing speed is left reversed and scaled by the elasticity
+        let bounce = |name: &str| {
+            let mut manager = ProtonManager::new(16, &config);
+            let a = ProtonManager::build_element(name, vec2(100.0, 100.0), vec2(30.0, 0.0)).unwrap();
+            let gap = a.radius() * 2.0;
+            manager.protons[0] = Some(a);
+            manager.protons[1] = ProtonManager::build_element(name, vec2(100.0 + gap, 100.0), vec2(-30.0, 0.0));
+            manager.handle_solid_collisions();
+            let (a, b) = (manager.protons[0].as_ref().unwrap(), manager.protons[1].as_ref().unwrap());
+            (b.velocity().x - a.velocity().x) / 60.0
+        };
+
+        assert!(bounce("H2O").abs() < 1e-4, "ice sticks");
+        assert!((bounce("Mg24") - 0.5).abs() < 1e-4, "metal keeps half");
+        assert!((bounce("He4") - pm::PROTON_BOUNCE_DAMPENING).abs() < 1e-4, "unset pairs keep the default");
+    }
+
+    #[test]
+    #[should_panic(expected = "flagged Ne20")]
+    fn mismatched_neutron_count_is_caught_at_the_setter() {
+        let mut ne20 = ProtonManager::build_element("Ne20", Vec2::ZERO, Vec2::ZERO).unwrap();
+        ne20.set_neutron_count(8);
+    }
+
+    #[test]
+    fn water_bonds_do_not_depend_on_slot_order() {
+        // Liquid cloud with exact ties (a lattice row) and scattered molecules, plus one frozen seed
+        let mut cloud: Vec<(Vec2, bool)> = (0..5).map(|column| (vec2(200.0 + column as f32 * 70.0, 200.0), column == 2)).collect();
+        let mut seed = 12345_u32;
+        let mut next = || {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            (seed >> 16) as f32 / 65_536.0
+        };
+        while cloud.len() < 24 {
+            let pos = vec2(180.0 + next() * 340.0, 240.0 + next() * 220.0);
+            if cloud.iter().all(|&(other, _)| other.distance(pos) > 40.0) {
+                cloud.push((pos, false));
+            }
+        }
+
+        let bond_graph = |stride: usize, shift: usize| {
+            let slots = 64;
+            let mut manager = ProtonManager::new(slots, &SimConfig::default());
+            for (k, &(pos, frozen)) in cloud.iter().enumerate() {
+                let mut water = ProtonManager::build_element("H2O", pos, Vec2::ZERO).unwrap();
+                water.set_water_frozen(frozen);
+                manager.protons[(k * stride + shift) % slots] = Some(water);
+            }
+            manager.update_water_hydrogen_bonds(1.0 / 120.0);
+
+            // Bonds as pairs of positions, so slot numbers drop out
+            let key = |p: &Proton| (p.position().x.to_bits(), p.position().y.to_bits());
+            let mut graph = std::collections::BTreeSet::new();
+            for p in manager.protons.iter().flatten() {
+                for &partner in p.water_h_bonds() {
+                    let q = manager.protons[partner].as_ref().unwrap();
+                    graph.insert((key(p).min(key(q)), key(p).max(key(q))));
+                }
+            }
+            graph
+        };
+
+        let reference = bond_graph(1, 0);
+        assert!(reference.len() >= cloud.len(), "cloud should bond: {} bonds", reference.len());
+        for (stride, shift) in [(3, 5), (7, 1), (13, 40), (63, 9)] {
+            assert_eq!(bond_graph(stride, shift), reference, "stride {} shift {}", stride, shift);
+        }
+    }
+
+    #[test]
+    fn stamped_hexagon_sits_at_the_bond_rest_length() {
+        let mut manager = ProtonManager::new(16, &SimConfig::default());
+        let center = vec2(400.0, 400.0);
+        assert_eq!(manager.stamp_lattice("H1", center, 1), 7);
+
+        let positions: Vec<Vec2> = manager.protons.iter().flatten().map(|p| p.position()).collect();
+        for (i, a) in positions.iter().enumerate() {
+            let nearest = positions.iter().enumerate().filter(|&(j, _)| j != i).map(|(_, b)| a.distance(*b)).fold(f32::MAX, f32::min);
+            assert!((nearest - pm::H_CRYSTAL_BOND_REST_LENGTH).abs() < 1e-3, "nearest neighbor at {}", nearest);
+        }
+        assert!(manager.protons.iter().flatten().all(|p| p.is_stable_hydrogen() && p.velocity() == Vec2::ZERO));
+
+        // Stamping again on top of the seed finds every lattice point taken
+        assert_eq!(manager.stamp_lattice("H1", center, 1), 0);
+        assert_eq!(manager.stamp_lattice("He4", center, 1), 0);
+    }
+
+    #[test]
+    fn blue_waves_heat_and_red_waves_damp_unfrozen_particles() {
+        let run = |ring_speed: f32| {
+            let mut manager = ProtonManager::new(4, &SimConfig::default());
+            let mut ring_manager = RingManager::new();
+            let center = vec2(300.0, 300.0);
+            ring_manager.add_ring_with_speed(center, ring_speed);
+            let on_edge = center + vec2(crate::constants::ring::INITIAL_RADIUS, 0.0);
+            manager.protons[0] = Some(he4(on_edge, vec2(0.0, 50.0)));
+            manager.apply_wave_thermal_effects(0.1, &ring_manager);
+            manager.protons[0].take().unwrap()
+        };
+
+        let blue = run(pm::BLUE_WAVE_HEAT_SPEED_THRESHOLD + 20.0);
+        assert!(blue.temperature() > 0.0);
+        assert!(blue.velocity().x > 0.0, "pushed outward: {:?}", blue.velocity());
+
+        let red = run(pm::RED_WAVE_INTERACTION_THRESHOLD - 20.0);
+        assert_eq!(red.temperature(), 0.0);
+        assert!(red.velocity().length() < 50.0);
+
+        // Between the thresholds nothing happens
+        let middle = run((pm::RED_WAVE_INTERACTION_THRESHOLD + pm::BLUE_WAVE_HEAT_SPEED_THRESHOLD) / 2.0);
+        assert_eq!(middle.velocity(), vec2(0.0, 50.0));
+    }
+
+    #[test]
+    fn red_wave_push_is_capped_and_leaves_frozen_ice_in_place() {
+        let mut manager = ProtonManager::new(4, &SimConfig::default());
+        let mut ring_manager = RingManager::new();
+        let center = vec2(300.0, 300.0);
+        for _ in 0..20 {
+            ring_manager.add_ring_with_speed(center, pm::RED_WAVE_INTERACTION_THRESHOLD - 20.0);
+        }
+        let radius = crate::constants::ring::INITIAL_RADIUS;
+
+        let mut negative = Proton::new(center + vec2(radius, 0.0), Vec2::ZERO, WHITE, 30.0, -1);
+        negative.set_max_lifetime(-1.0);
+        let mass = negative.mass();
+        manager.protons[0] = Some(negative);
+
+        let mut ice = Proton::new(center - vec2(radius, 0.0), Vec2::ZERO, WHITE, 30.0, 8);
+        ice.set_neutron_count(10);
+        ice.set_h2o(true);
+        ice.set_water_frozen(true);
+        manager.protons[1] = Some(ice);
+
+        let dt = 1.0 / 120.0;
+        manager.apply_red_wave_repulsion(dt, &ring_manager);
+
+        // Twenty stacked rings push no harder than the cap
+        let pushed = manager.protons[0].as_ref().unwrap().velocity();
+        assert!(pushed.x > 0.0, "pushed outward: {:?}", pushed);
+        assert!(pushed.length() <= pm::RED_WAVE_MAX_REPULSION_FORCE / mass * dt + 1e-3, "{:?}", pushed);
+
+        assert_eq!(manager.protons[1].as_ref().unwrap().velocity(), Vec2::ZERO);
+    }
+
+    #[test]
+    fn nudged_ice_hexagon_rotates_as_a_unit() {
+        // Frozen hexagon ring in one crystal group, one edge molecule nudged tangentially
+        let center = vec2(300.0, 300.0);
+        let radius = 40.0;
+        let mut manager = ProtonManager::new(16, &SimConfig::default());
+        for i in 0..6 {
+            let offset = Vec2::from_angle(i as f32 * std::f32::consts::TAU / 6.0) * radius;
+            let velocity = if i == 0 { offset.perp().normalize() * 30.0 } else { Vec2::ZERO };
+            let mut water = Proton::new(center + offset, velocity, Color::from_rgba(255, 255, 255, 255), 30.0, 8);
+            water.set_neutron_count(10);
+            water.set_h2o(true);
+            water.set_water_frozen(true);
+            water.set_ice_crystal_group(Some(0));
+            manager.protons[i] = Some(water);
+        }
+
+        manager.apply_crystal_group_rigid_movement(1.0 / 60.0);
+
+        // The nudge's linear momentum is shared as a drift of 30 / 6 along +y
+        let drift = vec2(0.0, 30.0 / 6.0);
+
+        // On top of the drift every member spins at the same rate about the centroid, with no radial motion
+        let mut angular_rates = Vec::new();
+        for water in manager.protons.iter().flatten() {
+            let offset = water.position() - center;
+            let spin = water.velocity() - drift;
+            assert!(offset.dot(spin).abs() < 1e-2, "radial velocity {:?}", spin);
+            assert!((offset.length() - radius).abs() < 1e-3, "ring deformed: {}", offset.length());
+            angular_rates.push(offset.perp_dot(spin) / offset.length_squared());
+        }
+
+        // Angular momentum of the nudged molecule is shared evenly: 30 / (6 * 40) rad/s
+        let expected_rate = 30.0 / (6.0 * radius);
+        for rate in angular_rates {
+            assert!((rate - expected_rate).abs() < 1e-3, "rate {} vs {}", rate, expected_rate);
+        }
+    }
+
+    #[test]
+    fn slot_churn_keeps_indices_stable_and_never_aliases_a_bond() {
+        let mut manager = ProtonManager::new(4, &SimConfig::default());
+        let mut seed = 11u32;
+        let mut next = |n: usize| {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            (seed >> 8) as usize % n
+        };
+        let mut made_bonds = std::collections::HashSet::new(); // (serial, partner serial) at the moment of bonding
+        let live = |manager: &ProtonManager| -> Vec<(usize, u64)> {
+            manager.protons.iter().enumerate().filter_map(|(i, p)| p.as_ref().map(|p| (i, p.serial()))).collect()
+        };
+
+        let mut most_alive = 0;
+        for round in 0..150 {
+            let before = live(&manager);
+            for k in 0..3 {
+                let position = vec2(40.0 + 30.0 * ((round * 3 + k) % 40) as f32, 40.0 + 30.0 * (round / 10) as f32);
+                manager.spawn_element("H2O", position, Vec2::ZERO);
+            }
+            // Spawns only fill empty slots - nobody already alive moves or is overwritten
+            let after = live(&manager);
+            assert_eq!(after.len(), before.len() + 3);
+            assert!(before.iter().all(|entry| after.contains(entry)));
+            most_alive = most_alive.max(after.len());
+
+            // Bond two waters, then delete two others
+            let (i, j) = (after[next(after.len())].0, after[next(after.len())].0);
+            if i != j {
+                manager.claim_bond_slot(i);
+                manager.claim_bond_slot(j);
+                for (from, to) in [(i, j), (j, i)] {
+                    manager.protons[from].as_mut().unwrap().add_water_h_bond(to, 1.0);
+                    made_bonds.insert((manager.slot_serial(from), manager.slot_serial(to)));
+                }
+            }
+            for _ in 0..2 {
+                let alive = live(&manager);
+                let (_, victim) = alive[next(alive.len())];
+                assert_eq!(manager.delete_element(|p| p.serial() == victim), 1);
+            }
+            manager.repair_dangling_bonds();
+
+            // Every surviving bond still joins the two particles that made it
+            for (slot, serial) in live(&manager) {
+                for &partner in manager.protons[slot].as_ref().unwrap().water_h_bonds() {
+                    assert!(made_bonds.contains(&(serial, manager.slot_serial(partner))), "round {}: slot {} -> {}", round, slot, partner);
+                }
+            }
+        }
+        // Freed slots were reused - the vector only doubled when every slot was taken
+        assert!(manager.protons.len() <= 2 * most_alive);
+    }
+
+    #[test]
+    fn oscillation_is_flagged_only_while_it_does_not_die_down() {
+        let dt = 1.0 / 120.0;
+        let steps = (proton::OSCILLATION_WINDOW / dt).ceil() as usize * 3;
+        let run = |speed_at: &dyn Fn(usize) -> f32, bonded: bool| {
+            let mut p = Proton::new(Vec2::ZERO, Vec2::ZERO, WHITE, 1.0, 0);
+            for step in 0..steps {
+                let direction = if step % 6 < 3 { 1.0 } else { -1.0 };
+                p.set_velocity(vec2(direction * speed_at(step), 0.0));
+                p.track_oscillation(dt, bonded);
+            }
+            p.is_oscillating()
+        };
+
+        assert!(run(&|_| 20.0, true));
+        assert!(!run(&|_| 20.0, false), "unbonded particles are not watched");
+        assert!(!run(&|step| 20.0 * 0.98_f32.powi(step as i32), true), "damped vibration settles");
+        assert!(!run(&|_| 0.5, true), "too slow to matter");
+    }
+
+    #[test]
+    fn nucleon_radius_scaling_is_opt_in_and_orders_by_mass_number() {
+        let radii = |config: &SimConfig| {
+            let mut manager = ProtonManager::new(8, config);
+            for (i, name) in ["H1", "He4", "S32"].into_iter().enumerate() {
+                manager.spawn_element(name, vec2(100.0 + 200.0 * i as f32, 100.0), Vec2::ZERO);
+            }
+            manager.refresh_radii();
+            manager.protons.iter().flatten().map(Proton::radius).collect::<Vec<_>>()
+        };
+
+        // Off by default - every radius is the energy-only one from the constructor
+        let default = radii(&SimConfig::default());
+        let expected: Vec<f32> = [1.0_f32, 4.0, 32.0]
+            .iter()
+            .map(|e| (proton::MIN_RADIUS + e * proton::ENERGY_TO_RADIUS_FACTOR).clamp(proton::MIN_RADIUS, proton::MAX_RADIUS))
+            .collect();
+        assert_eq!(default, expected);
+
+        let scaled = radii(&SimConfig::parse("radius_nucleon_exponent = 0.333\nmax_nucleon_radius = 20", "test"));
+        assert!(scaled[0] < scaled[1] && scaled[1] < scaled[2], "{:?}", scaled);
+        assert!(scaled[2] <= 20.0);
+    }
+
+    #[test]
+    fn gravity_floats_hydrogen_and_sinks_heavy_elements() {
+        let mut manager = ProtonManager::new(8, &SimConfig::default());
+        manager.spawn_element("H1", vec2(200.0, 300.0), Vec2::ZERO);
+        manager.spawn_element("S32", vec2(400.0, 300.0), Vec2::ZERO);
+        manager.spawn_element("C12", vec2(600.0, 300.0), Vec2::ZERO);
+        let height = |manager: &ProtonManager, name| {
+            manager.protons.iter().flatten().find(|p| ProtonManager::element_name(p) == Some(name)).unwrap().position().y
+        };
+
+        // Off by default - nothing moves
+        manager.update_proton_physics(0.5, (800.0, 600.0));
+        assert_eq!(height(&manager, "S32"), 300.0);
+
+        manager.set_gravity(true);
+        for _ in 0..60 {
+            manager.update_proton_physics(1.0 / 120.0, (800.0, 600.0));
+        }
+        assert!(height(&manager, "H1") < 300.0);
+        assert!(height(&manager, "S32") > height(&manager, "C12"));
+        assert!(height(&manager, "C12") > 300.0);
+    }
+
+    #[test]
+    fn medium_drag_slows_free_particles_and_leaves_frozen_ones_alone() {
+        let run = |medium: Medium| {
+            let mut manager = ProtonManager::new(4, &SimConfig::default());
+            manager.set_medium(medium);
+            manager.protons[0] = Some(he4(vec2(200.0, 300.0), vec2(100.0, 0.0)));
+            let mut frozen = he4(vec2(400.0, 300.0), vec2(100.0, 0.0));
+            frozen.set_he4_crystallized(true);
+            manager.protons[1] = Some(frozen);
+            for _ in 0..60 {
+                manager.update_proton_physics(1.0 / 60.0, (800.0, 600.0));
+            }
+            let speed = |slot: usize| manager.protons[slot].as_ref().unwrap().velocity().length();
+            (speed(0), speed(1))
+        };
+
+        let (vacuum, _) = run(Medium::Vacuum);
+        let (thin, _) = run(Medium::Thin);
+        let (thick, frozen) = run(Medium::Thick);
+        assert!((vacuum - 100.0).abs() < 1e-3, "{}", vacuum);
+        assert!(thick < thin && thin < vacuum, "{} {} {}", thick, thin, vacuum);
+        assert!((thick - 100.0 * (1.0 - pm::MEDIUM_THICK_DRAG / 60.0).powi(60)).abs() < 0.5, "{}", thick);
+        assert_eq!(frozen, 100.0);
+    }
+
+    #[test]
+    fn svg_export_draws_every_particle_bond_and_label() {
+        let mut manager = ProtonManager::new(4, &SimConfig::default());
+        for (i, x) in [100.0, 140.0].into_iter().enumerate() {
+            let mut carbon = ProtonManager::build_element("C12", vec2(x, 200.0), Vec2::ZERO).unwrap();
+            carbon.set_c12_crystallized(true);
+            manager.protons[i] = Some(carbon);
+        }
+        manager.protons[2] = Some(he4(vec2(300.0, 50.0), Vec2::ZERO));
+        // Record the occupants first so the bond between the two carbons counts as valid
+        manager.repair_dangling_bonds();
+        for i in 0..2 {
+            manager.protons[i].as_mut().unwrap().set_c12_crystal_bonds(vec![1 - i]);
+        }
+
+        let svg = manager.to_svg((800.0, 600.0));
+        assert!(svg.starts_with("<svg ") && svg.ends_with("</svg>\n"));
+        assert!(svg.contains("viewBox=\"0 0 800 600\""));
+        // One line per bond (drawn once), the core and two glow layers per particle, one label each
+        assert_eq!(svg.matches("<line ").count(), 1);
+        assert_eq!(svg.matches("<circle ").count(), 3 * 3);
+        assert_eq!(svg.matches(">C12</text>").count(), 2);
+        assert_eq!(svg.matches(">He4</text>").count(), 1);
+        assert!(svg.contains("x1=\"100.00\" y1=\"200.00\" x2=\"140.00\" y2=\"200.00\""));
+    }
+
+    #[test]
+    fn pair_fusion_threshold_names_the_reaction_either_way_round() {
+        let manager = ProtonManager::new(4, &SimConfig::default());
+        let build = |name| ProtonManager::build_element(name, Vec2::ZERO, Vec2::ZERO).unwrap();
+        let h_plus = Proton::new(Vec2::ZERO, Vec2::ZERO, WHITE, 1.0, 1);
+
+        let pp = manager.pair_fusion_threshold(&h_plus, &h_plus);
+        assert_eq!(pp, Some(("H+ + H+ -> D", SimConfig::default().pp_chain_velocity_threshold)));
+        let (he4, c12) = (build("He4"), build("C12"));
+        assert_eq!(manager.pair_fusion_threshold(&he4, &c12), manager.fusion_threshold("C12"));
+        assert_eq!(manager.pair_fusion_threshold(&c12, &he4), manager.fusion_threshold("C12"));
+        assert_eq!(manager.pair_fusion_threshold(&he4, &build("He3")), None);
+        assert_eq!(manager.pair_fusion_threshold(&build("H1"), &build("H1")), None);
+    }
+
+    #[test]
+    fn freshly_frozen_water_glows_then_fades() {
+        let mut manager = ProtonManager::new(16, &SimConfig::default());
+        let mut atom_manager = AtomManager::new(0);
+        let mut ring_manager = RingManager::new();
+        let center = vec2(300.0, 300.0);
+        for i in 0..7 {
+            let offset = if i == 0 {
+                Vec2::ZERO
+            } else {
+                Vec2::from_angle(i as f32 * std::f32::consts::TAU / 6.0) * proton::WATER_ICE_FROZEN_REST_LENGTH
+            };
+            manager.spawn_element("H2O", center + offset, Vec2::ZERO);
+        }
+        manager.elapsed_time = 10.0;
+
+        let window = (1280.0, 720.0);
+        manager.update(1.0 / 120.0, window, &mut atom_manager, &mut ring_manager);
+        let hub = manager.protons.iter().flatten().find(|p| p.position() == center).unwrap();
+        assert!(hub.is_water_frozen());
+        assert_eq!(hub.crystallized_at(), Some(manager.elapsed_time));
+        assert_eq!(manager.growth_front_glow(hub), 1.0);
+
+        // Still frozen, but no longer part of the growth front
+        manager.elapsed_time += rendering::GROWTH_FRONT_FADE_TIME;
+        let hub = manager.protons.iter().flatten().find(|p| p.position() == center).unwrap();
+        assert_eq!(manager.growth_front_glow(hub), 0.0);
+    }
+
+    #[test]
+    fn water_center_with_six_neighbors_freezes_into_a_full_hexagon_tile() {
+        let center = vec2(300.0, 300.0);
+        let tile = |max_bonds: f32| {
+            let config = SimConfig { water_ice_max_bonds: max_bonds, ..SimConfig::default() };
+            let mut manager = ProtonManager::new(16, &config);
+            for i in 0..7 {
+                let offset = if i == 0 {
+                    Vec2::ZERO
+                } else {
+                    Vec2::from_angle(i as f32 * std::f32::consts::TAU / 6.0) * proton::WATER_ICE_FROZEN_REST_LENGTH
+                };
+                let mut water = Proton::new(center + offset, Vec2::ZERO, Color::from_rgba(40, 100, 180, 255), 30.0, 8);
+                water.set_neutron_count(10);
+                water.set_h2o(true);
+                manager.protons[i] = Some(water);
+            }
+            manager.update_water_hydrogen_bonds(1.0 / 60.0);
+            manager
+        };
+
+        // The center holds all six neighbors and the whole tile moves as one crystal
+        let full = tile(proton::WATER_ICE_MAX_BONDS as f32);
+        let hub = full.protons[0].as_ref().unwrap();
+        assert_eq!(hub.water_h_bonds().len(), 6);
+        let group = hub.ice_crystal_group();
+        assert!(group.is_some());
+        for water in full.protons.iter().flatten() {
+            assert_eq!(water.ice_crystal_group(), group);
+            assert!(water.is_water_frozen());
+        }
+
+        // Tuned down to 5 the center leaves a corner open but still freezes as a hexagon
+        let capped = tile(5.0);
+        let hub = capped.protons[0].as_ref().unwrap();
+        assert_eq!(hub.water_h_bonds().len(), 5);
+        assert!(hub.ice_crystal_group().is_some());
+        assert_eq!(capped.protons.iter().flatten().filter(|w| w.ice_crystal_group().is_some()).count(), 6);
+    }
+
+    #[test]
+    fn dark_red_waves_melt_a_frozen_ice_tile_like_a_hydrogen_crystal() {
+        let center = vec2(300.0, 300.0);
+        let mut manager = ProtonManager::new(16, &SimConfig::default());
+        for i in 0..7 {
+            let offset = if i == 0 {
+                Vec2::ZERO
+            } else {
+                Vec2::from_angle(i as f32 * std::f32::consts::TAU / 6.0) * proton::WATER_ICE_FROZEN_REST_LENGTH
+            };
+            let mut water = Proton::new(center + offset, Vec2::ZERO, Color::from_rgba(40, 100, 180, 255), 30.0, 8);
+            water.set_neutron_count(10);
+            water.set_h2o(true);
+            manager.protons[i] = Some(water);
+        }
+        manager.update_water_hydrogen_bonds(1.0 / 60.0);
+        assert!(manager.protons.iter().flatten().all(|w| w.is_water_frozen()));
+
+        // A dark red wave whose edge sits on the outer molecule at slot 6, just right of the hub
+        let target = manager.protons[6].as_ref().unwrap().position();
+        let mut ring_manager = RingManager::new();
+        ring_manager.add_ring_with_speed(target - vec2(crate::constants::ring::INITIAL_RADIUS, 0.0), pm::DARK_RED_WAVE_SPEED_THRESHOLD);
+
+        let dt = 1.0 / 120.0;
+        for hit in 1..=pm::RED_WAVE_HITS_TO_MELT {
+            assert!(manager.protons[6].as_ref().unwrap().is_water_frozen(), "still ice before hit {}", hit);
+            manager.elapsed_time += pm::RED_WAVE_HIT_COOLDOWN + 0.01;
+            manager.apply_red_wave_repulsion(dt, &ring_manager);
+        }
+
+        // Melted: bonds gone on both sides, out of its crystal group and moving away from the wave
+        let melted = manager.protons[6].as_ref().unwrap();
+        assert!(!melted.is_water_frozen());
+        assert!(melted.water_h_bonds().is_empty());
+        assert_eq!(melted.ice_crystal_group(), None);
+        assert!(melted.velocity().x > 0.0, "{:?}", melted.velocity());
+        assert!(manager.protons.iter().flatten().all(|w| !w.water_h_bonds().contains(&6)));
+        assert!(manager.protons[0].as_ref().unwrap().is_water_frozen());
+
+        // The freeze cooldown keeps it from snapping straight back into the lattice
+        manager.protons[6].as_mut().unwrap().set_velocity(Vec2::ZERO);
+        manager.update_water_hydrogen_bonds(1.0 / 60.0);
+        assert!(!manager.protons[6].as_ref().unwrap().is_water_frozen());
+    }
+
+    #[test]
+    fn bounding_box_and_centroid_cover_only_living_particles() {
+        let mut manager = ProtonManager::new(8, &SimConfig::default());
+        assert_eq!(manager.bounding_box(), None);
+        assert_eq!(manager.centroid(), None);
+
+        manager.protons[0] = Some(he4(vec2(100.0, 200.0), Vec2::ZERO));
+        manager.protons[1] = Some(he4(vec2(300.0, 50.0), Vec2::ZERO));
+        let mut dead = he4(vec2(900.0, 900.0), Vec2::ZERO);
+        dead.mark_for_deletion();
+        manager.protons[2] = Some(dead);
+
+        let r = manager.protons[0].as_ref().unwrap().radius();
+        let bounds = manager.bounding_box().unwrap();
+        assert!(bounds.point().abs_diff_eq(vec2(100.0 - r, 50.0 - r), 1e-3), "{:?}", bounds);
+        assert!(bounds.size().abs_diff_eq(vec2(200.0 + 2.0 * r, 150.0 + 2.0 * r), 1e-3), "{:?}", bounds);
+        assert_eq!(manager.centroid(), Some(vec2(200.0, 125.0)));
+    }
+
+    #[test]
+    fn colliding_protons_fuse_into_deuterium_only_when_fast() {
+        let fuse = |closing_speed: f32| {
+            let mut manager = ProtonManager::new(16, &SimConfig::default());
+            let mut ring_manager = RingManager::new();
+            manager.spawn_proton(vec2(100.0, 100.0), vec2(closing_speed / 2.0, 0.0), WHITE, 1.0, 1);
+            manager.spawn_proton(vec2(101.0, 100.0), vec2(-closing_speed / 2.0, 0.0), WHITE, 1.0, 1);
+            manager.handle_nuclear_fusion(&mut ring_manager);
+            manager
+        };
+
+        let threshold = proton::PP_CHAIN_VELOCITY_THRESHOLD;
+
+        let slow = fuse(threshold * 0.5);
+        assert!(slow.protons.iter().flatten().all(|p| p.charge() == 1 && p.neutron_count() == 0 && !p.is_lepton()));
+
+        let fast = fuse(threshold * 1.5);
+        let alive: Vec<&Proton> = fast.protons.iter().flatten().collect();
+        assert_eq!(alive.len(), 2);
+        let deuterium = alive.iter().find(|p| !p.is_lepton()).expect("deuterium formed");
+        assert_eq!((deuterium.charge(), deuterium.neutron_count()), (0, 1));
+        let positron = alive.iter().find(|p| p.is_lepton()).expect("positron emitted");
+        assert_eq!(positron.charge(), 1);
+        assert!(positron.velocity().length() > proton::LEPTON_RELEASE_SPEED * 0.9);
+    }
+
+    #[test]
+    fn stretched_crystal_bonds_snap_and_uncrystallize() {
+        let mut manager = ProtonManager::new(16, &SimConfig::default());
+        let positions = [vec2(100.0, 100.0), vec2(160.0, 100.0), vec2(100.0 + pm::C12_BOND_BREAKING_DISTANCE + 10.0, 300.0)];
+        for (slot, &pos) in positions.iter().enumerate() {
+            let mut carbon = Proton::new(pos, Vec2::ZERO, Color::from_rgba(100, 100, 100, 255), 30.0, 6);
+            carbon.set_neutron_count(6);
+            carbon.set_c12_crystallized(true);
+            manager.protons[slot] = Some(carbon);
+        }
+        // 0-1 is at rest length, 0-2 and 1-2 are ripped apart
+        manager.protons[0].as_mut().unwrap().set_c12_crystal_bonds(vec![1, 2]);
+        manager.protons[1].as_mut().unwrap().set_c12_crystal_bonds(vec![0]);
+        manager.protons[2].as_mut().unwrap().set_c12_crystal_bonds(vec![1]);
+
+        let atoms: Vec<(usize, Vec2, Vec2)> = positions.iter().enumerate().map(|(i, &p)| (i, p, Vec2::ZERO)).collect();
+        manager.break_stretched_bonds(&atoms, pm::C12_BOND_BREAKING_DISTANCE, Proton::c12_crystal_bonds, Proton::set_c12_crystal_bonds, Proton::set_c12_crystallized);
+
+        let carbon = |slot: usize| manager.protons[slot].as_ref().unwrap();
+        assert_eq!(carbon(0).c12_crystal_bonds(), &vec![1]);
+        assert!(!carbon(0).is_c12_crystallized());
+        assert_eq!(carbon(1).c12_crystal_bonds(), &vec![0]);
+        assert!(carbon(1).is_c12_crystallized());
+        assert!(carbon(2).c12_crystal_bonds().is_empty());
+        assert!(!carbon(2).is_c12_crystallized());
+    }
+
+    #[test]
+    fn reused_slot_does_not_inherit_bonds_to_its_previous_occupant() {
+        let mut manager = ProtonManager::new(16, &SimConfig::default());
+        let mut carbon = Proton::new(vec2(100.0, 100.0), Vec2::ZERO, Color::from_rgba(100, 100, 100, 255), 30.0, 6);
+        carbon.set_neutron_count(6);
+        manager.protons[0] = Some(carbon);
+        manager.protons[1] = Some(he4(vec2(112.0, 100.0), Vec2::ZERO));
+        manager.repair_dangling_bonds();
+        for (slot, partner) in [(0, 1), (1, 0)] {
+            let p = manager.protons[slot].as_mut().unwrap();
+            p.set_oxygen16_bonded(true);
+            p.set_oxygen_bond_partner(Some(partner));
+            p.set_oxygen_bond_rest_length(12.0);
+        }
+        assert_eq!(manager.collect_bond_segments().len(), 1);
+
+        // The He4 is deleted and an unrelated bonded particle is spawned into its slot, well off rest length
+        manager.protons[1] = None;
+        let mut stranger = he4(vec2(200.0, 100.0), Vec2::ZERO);
+        stranger.set_oxygen16_bonded(true);
+        assert!(manager.insert_proton(stranger));
+        assert_eq!(manager.protons[1].as_ref().unwrap().position(), vec2(200.0, 100.0));
+        assert!(manager.collect_bond_segments().is_empty(), "bond drawn to the new occupant");
+
+        manager.repair_dangling_bonds();
+        manager.update_oxygen_bonds(1.0 / 60.0);
+
+        let slot = |idx: usize| manager.protons[idx].as_ref().unwrap();
+        assert_eq!(slot(0).oxygen_bond_partner(), None);
+        assert!(!slot(0).is_oxygen16_bonded());
+        assert_eq!(slot(0).velocity(), Vec2::ZERO);
+        assert_eq!(slot(1).velocity(), Vec2::ZERO);
+    }
+
+    #[test]
+    fn delete_element_removes_only_the_chosen_species() {
+        let mut manager = ProtonManager::new(16, &SimConfig::default());
+        manager.spawn_element("He4", vec2(100.0, 100.0), Vec2::ZERO);
+        manager.spawn_element("He4", vec2(200.0, 100.0), Vec2::ZERO);
+        manager.spawn_element("C12", vec2(300.0, 100.0), Vec2::ZERO);
+        manager.spawn_element("H1", vec2(400.0, 100.0), Vec2::ZERO);
+
+        let deleted = manager.delete_element(|p| ProtonManager::element_name(p) == Some("He4"));
+
+        assert_eq!(deleted, 2);
+        let counts = manager.get_element_counts();
+        assert_eq!(counts.get("He4"), None);
+        assert_eq!(counts.get("C12"), Some(&1));
+        assert_eq!(counts.get("H1"), Some(&1));
+    }
+
+    #[test]
+    fn expired_tritium_decays_into_helium3_and_electron() {
+        let mut manager = ProtonManager::new(16, &SimConfig::default());
+        let mut ring_manager = RingManager::new();
+        let start = vec2(200.0, 200.0);
+        manager.spawn_element("T", start, vec2(10.0, 0.0));
+
+        // Just short of the lifetime nothing happens
+        manager.update_proton_physics(proton::TRITIUM_LIFETIME * 0.5, (800.0, 600.0));
+        manager.handle_radioactive_decay(&mut ring_manager);
+        assert_eq!(manager.decay_count(), 0);
+        assert!(manager.protons.iter().flatten().all(|p| p.is_tritium()));
+
+        // Past it the tritium becomes He3 and throws out an electron instead of vanishing
+        manager.update_proton_physics(proton::TRITIUM_LIFETIME * 0.6, (800.0, 600.0));
+        manager.handle_radioactive_decay(&mut ring_manager);
+        assert_eq!(manager.decay_count(), 1);
+
+        let alive: Vec<&Proton> = manager.protons.iter().flatten().filter(|p| p.is_alive()).collect();
+        assert_eq!(alive.len(), 2);
+        let he3 = alive.iter().find(|p| !p.is_lepton()).expect("daughter He3");
+        assert_eq!((he3.charge(), he3.neutron_count()), (1, 2));
+        let electron = alive.iter().find(|p| p.is_lepton()).expect("beta electron");
+        assert_eq!(electron.charge(), -1);
+        assert_eq!(ring_manager.get_ring_count(), 1);
+    }
+
+    #[test]
+    fn carbon_surrounded_by_four_hydrogen_forms_methane() {
+        let carbon = vec2(300.0, 300.0);
+        let hydrogen: Vec<Vec2> = (0..4)
+            .map(|i| carbon + Vec2::from_angle(i as f32 * std::f32::consts::FRAC_PI_2) * (proton::CH4_CAPTURE_RANGE * 0.6))
+            .collect();
+
+        let slot_layouts: [[usize; 5]; 4] = [
+            [0, 1, 2, 3, 4],
+            [4, 3, 2, 1, 0],
+            [11, 2, 15, 7, 0],
+            [3, 9, 12, 5, 6],
+        ];
+
+        for slots in &slot_layouts {
+            let mut manager = ProtonManager::new(16, &SimConfig::default());
+            let mut ring_manager = RingManager::new();
+            manager.spawn_element("C12", carbon, Vec2::ZERO);
+            for &pos in &hydrogen {
+                manager.spawn_element("H1", pos, Vec2::ZERO);
+            }
+            // Move the spawned particles into the layout's slots
+            let spawned: Vec<Proton> = manager.protons.iter_mut().filter_map(Option::take).collect();
+            for (proton, &slot) in spawned.into_iter().zip(slots) {
+                manager.protons[slot] = Some(proton);
+            }
+
+            manager.handle_nuclear_fusion(&mut ring_manager);
+
+            let alive: Vec<&Proton> = manager.protons.iter().flatten().collect();
+            assert_eq!(alive.len(), 1, "slots {:?}", slots);
+            let methane = alive[0];
+            assert!(methane.is_ch4(), "slots {:?}", slots);
+            assert_eq!((methane.charge(), methane.neutron_count()), (6, 10));
+            assert!(manager.protons[slots[0]].is_some(), "methane takes the carbon slot");
+            assert_eq!(ring_manager.get_ring_count(), 1);
+        }
+    }
+
+    #[test]
+    fn stacked_spawns_are_spread_to_minimum_spacing() {
+        let mut manager = ProtonManager::new(32, &SimConfig::default());
+        let click = vec2(400.0, 300.0);
+        for _ in 0..12 {
+            manager.spawn_element("He4", click, Vec2::ZERO);
+        }
+
+        let positions: Vec<Vec2> = manager.protons.iter().flatten().map(|p| p.position()).collect();
+        assert_eq!(positions.len(), 12);
+        assert_eq!(positions[0], click, "the first spawn lands where clicked");
+        for (a, pos_a) in positions.iter().enumerate() {
+            for pos_b in &positions[a + 1..] {
+                assert!(pos_a.distance(*pos_b) >= pm::SPAWN_MIN_SPACING - 1e-3, "{:?} and {:?} overlap", pos_a, pos_b);
+            }
+            // Still a tight cloud around the click
+            assert!(pos_a.distance(click) < pm::SPAWN_MIN_SPACING * 4.0);
+        }
+    }
+
+    #[test]
+    fn total_energy_sums_internal_and_kinetic() {
+        let mut manager = ProtonManager::new(16, &SimConfig::default());
+        assert_eq!(manager.total_energy(), 0.0);
+
+        manager.spawn_element("He4", vec2(100.0, 100.0), Vec2::ZERO);
+        manager.spawn_element("He4", vec2(300.0, 100.0), vec2(30.0, 40.0));
+        let he4 = manager.protons[0].as_ref().unwrap();
+        let expected = 2.0 * he4.energy() + 0.5 * he4.mass() * 50.0 * 50.0;
+        assert!((manager.total_energy() - expected).abs() < 1e-2);
+    }
+
+    #[test]
+    fn full_slot_vector_grows_in_place_until_capacity_limit() {
+        let mut manager = ProtonManager::new(2, &SimConfig::default());
+        manager.set_capacity(5);
+        for i in 0..5 {
+            assert!(manager.spawn_proton(vec2(100.0 + 50.0 * i as f32, 100.0), Vec2::ZERO, WHITE, 1.0, 1));
+        }
+        assert_eq!(manager.capacity_usage(), (5, 5, 5));
+
+        // Existing slots keep their particles after growing
+        assert_eq!(manager.protons[0].as_ref().unwrap().position(), vec2(100.0, 100.0));
+        assert_eq!(manager.protons[4].as_ref().unwrap().position(), vec2(300.0, 100.0));
+
+        // At the cap spawns are refused, and the cap cannot drop below the allocated slots
+        assert!(!manager.spawn_proton(vec2(600.0, 100.0), Vec2::ZERO, WHITE, 1.0, 1));
+        manager.set_capacity(1);
+        assert_eq!(manager.capacity_usage(), (5, 5, 5));
+    }
+
+    #[test]
+    fn density_grid_counts_particles_per_cell() {
+        let mut manager = ProtonManager::new(16, &SimConfig::default());
+        for pos in [vec2(5.0, 5.0), vec2(30.0, 12.0), vec2(45.0, 5.0), vec2(-5.0, 5.0)] {
+            manager.insert_proton(Proton::new(pos, Vec2::ZERO, WHITE, 1.0, 1));
+        }
+
+        let mut cells = manager.density_grid(40.0);
+        cells.sort();
+        assert_eq!(cells, vec![((-1, 0), 1), ((0, 0), 2), ((1, 0), 1)]);
+    }
+
+    #[test]
+    fn particle_at_picks_nearest_particle_under_cursor() {
+        let mut manager = ProtonManager::new(16, &SimConfig::default());
+        manager.spawn_element("He4", vec2(100.0, 100.0), Vec2::ZERO);
+        manager.spawn_element("C12", vec2(100.0, 100.0), Vec2::ZERO);
+        let carbon = manager.protons[1].as_ref().unwrap().position();
+
+        assert_eq!(manager.particle_at(carbon).map(|p| p.charge()), Some(6));
+        assert_eq!(manager.particle_at(vec2(101.0, 100.0)).map(|p| p.charge()), Some(2));
+        assert!(manager.particle_at(vec2(400.0, 400.0)).is_none());
+    }
+
+    #[test]
+    fn base64_snapshot_round_trips_particles() {
+        let mut manager = ProtonManager::new(16, &SimConfig::default());
+        manager.spawn_element("H2O", vec2(100.0, 120.0), vec2(3.5, -2.25));
+        manager.spawn_element("C12", vec2(300.0, 40.0), Vec2::ZERO);
+        manager.spawn_element("H1", vec2(200.0, 200.0), Vec2::ZERO);
+        manager.spawn_proton(vec2(50.0, 60.0), vec2(-10.0, 0.0), WHITE, 1.0, 1);
+        manager.insert_proton(ProtonManager::new_lepton(vec2(70.0, 80.0), vec2(0.0, 90.0), -1));
+
+        let encoded = manager.to_base64();
+        assert!(encoded.bytes().all(|c| c.is_ascii_alphanumeric() || b"+/=".contains(&c)));
+
+        let mut restored = ProtonManager::new(16, &SimConfig::default());
+        restored.spawn_element("S32", vec2(500.0, 500.0), Vec2::ZERO);
+        assert_eq!(restored.load_base64(&encoded), Ok(5));
+
+        let describe = |m: &ProtonManager| -> Vec<(String, Vec2, Vec2, i32, i32, bool)> {
+            m.protons.iter().flatten()
+                .map(|p| (p.get_element_label(), p.position(), p.velocity(), p.charge(), p.neutron_count(), p.is_stable_hydrogen()))
+                .collect()
+        };
+        assert_eq!(describe(&restored), describe(&manager));
+
+        // A bad string leaves the current state alone
+        assert!(restored.load_base64("not a snapshot").is_err());
+        assert_eq!(restored.protons.iter().flatten().count(), 5);
+    }
+
+    /// Dense mixed cloud of every crystallizing element (fixed pseudo-random layout), at rest
+    fn crystal_cloud() -> ProtonManager {
+        let mut manager = ProtonManager::new(400, &SimConfig::default());
+        let mut seed: u32 = 12345;
+        let mut next = move || {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            (seed >> 8) as f32 / (1u32 << 24) as f32
+        };
+
+        let mut slot = 0;
+        let named = ["H1", "He3", "He4", "C12", "Ne20", "Mg24", "Si28", "S32"];
+        let raw = [(7, 7), (15, 16), (11, 12), (19, 20), (20, 20)];
+        for kind in 0..named.len() + raw.len() {
+            for _ in 0..24 {
+                // Straddle the origin so negative grid cells are exercised too
+                let pos = vec2(next() * 360.0 - 120.0, next() * 360.0 - 120.0);
+                let proton = match named.get(kind) {
+                    Some(name) => ProtonManager::build_element(name, pos, Vec2::ZERO).unwrap(),
+                    None => {
+                        let (charge, neutrons) = raw[kind - named.len()];
+                        let mut p = Proton::new(pos, Vec2::ZERO, WHITE, 30.0, charge);
+                        p.set_neutron_count(neutrons);
+                        p.set_max_lifetime(-1.0);
+                        p
+                    }
+                };
+                manager.protons[slot] = Some(proton);
+                slot += 1;
+            }
+        }
+        manager
+    }
+
+    /// Every crystallization pass in update order; returns each slot's bonds, frozen flag and velocity
+    fn crystallize(manager: &mut ProtonManager) -> Vec<(Vec<Vec<usize>>, bool, Vec2)> {
+        let dt = 1.0 / 120.0;
+        manager.update_h_crystallization(dt);
+        manager.update_ne20_crystallization(dt);
+        manager.update_c12_crystallization(dt);
+        manager.update_si28_crystallization(dt);
+        manager.update_mg24_crystallization(dt);
+        manager.update_s32_crystallization(dt);
+        manager.update_he3_crystallization(dt);
+        manager.update_he4_crystallization(dt);
+        manager.update_n14_crystallization(dt);
+        manager.update_p31_crystallization(dt);
+        manager.update_na23_crystallization(dt);
+        manager.update_k39_crystallization(dt);
+        manager.update_ca40_crystallization(dt);
+
+        manager.protons.iter().flatten().map(|p| {
+            let bonds = vec![
+                p.crystal_bonds().clone(), p.he3_crystal_bonds().clone(), p.he4_crystal_bonds().clone(),
+                p.c12_crystal_bonds().clone(), p.ne20_crystal_bonds().clone(), p.mg24_crystal_bonds().clone(),
+                p.si28_crystal_bonds().clone(), p.s32_crystal_bonds().clone(), p.n14_crystal_bonds().clone(),
+                p.p31_crystal_bonds().clone(), p.na23_crystal_bonds().clone(), p.k39_crystal_bonds().clone(),
+                p.ca40_crystal_bonds().clone(),
+            ];
+            (bonds, p.is_frozen(), p.velocity())
+        }).collect()
+    }
+
+    #[test]
+    fn shared_neighbor_grid_matches_brute_force_pairs() {
+        // Every pass's neighbor lists equal a brute-force check of all pairs of the cloud
+        let cloud = crystal_cloud();
+        let mut gridded = crystal_cloud();
+        gridded.neighbor_grid = NeighborGrid::build(&gridded.protons, pm::NEIGHBOR_GRID_CELL_SIZE);
+        let atoms: Vec<(usize, Vec2, Vec2)> = cloud.protons.iter().enumerate()
+            .filter_map(|(idx, p)| p.as_ref().map(|p| (idx, p.position(), p.velocity())))
+            .collect();
+        let ranges = [
+            (pm::H_CRYSTAL_MIN_SPACING, pm::H_CRYSTAL_NEIGHBOR_DISTANCE),
+            (pm::HE3_MIN_SPACING, pm::HE3_NEIGHBOR_DISTANCE),
+            (pm::HE4_MIN_SPACING, pm::HE4_NEIGHBOR_DISTANCE),
+            (pm::C12_MIN_SPACING, pm::C12_NEIGHBOR_DISTANCE),
+            (pm::NE20_MIN_SPACING, pm::NE20_NEIGHBOR_DISTANCE),
+            (pm::MG24_MIN_SPACING, pm::MG24_NEIGHBOR_DISTANCE),
+            (pm::SI28_MIN_SPACING, pm::SI28_NEIGHBOR_DISTANCE),
+            (pm::S32_MIN_SPACING, pm::S32_NEIGHBOR_DISTANCE),
+            (pm::N14_MIN_SPACING, pm::N14_NEIGHBOR_DISTANCE),
+            (pm::P31_MIN_SPACING, pm::P31_NEIGHBOR_DISTANCE),
+            (pm::NA23_MIN_SPACING, pm::NA23_NEIGHBOR_DISTANCE),
+            (pm::K39_MIN_SPACING, pm::K39_NEIGHBOR_DISTANCE),
+            (pm::CA40_MIN_SPACING, pm::CA40_NEIGHBOR_DISTANCE),
+        ];
+        for (min_spacing, max_distance) in ranges {
+            let mut brute_force = vec![Vec::new(); cloud.protons.len()];
+            for &(idx, pos, _) in &atoms {
+                for &(other, other_pos, _) in &atoms {
+                    let dist = pos.distance(other_pos);
+                    if other != idx && dist >= min_spacing && dist < max_distance {
+                        brute_force[idx].push(other);
+                    }
+                }
+            }
+            assert!(brute_force.iter().any(|neighbors| !neighbors.is_empty()));
+            assert_eq!(gridded.crystal_neighbor_lists(&atoms, min_spacing, max_distance), brute_force, "range {}..{}", min_spacing, max_distance);
+        }
+
+        // And the bonds they lead to match a grid with one giant cell, which hands every pass all pairs
+        let mut pairwise = crystal_cloud();
+        pairwise.neighbor_grid = NeighborGrid::build(&pairwise.protons, 1.0e6);
+        let expected = crystallize(&mut pairwise);
+
+        let actual = crystallize(&mut gridded);
+
+        assert!(expected.iter().any(|(bonds, _, _)| bonds.iter().any(|b| !b.is_empty())), "scene forms no bonds");
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn huge_time_step_keeps_every_particle_finite() {
+        let mut manager = crystal_cloud();
+        let mut atom_manager = AtomManager::new(10);
+        let mut ring_manager = RingManager::new();
+        let window = (1280.0, 720.0);
+
+        // Settle into bonded crystals first so the bond forces are live, then stall for an absurd frame
+        for dt in [1.0 / 120.0, 1.0 / 120.0, 1.0e30, 1.0 / 120.0] {
+            manager.update(dt, window, &mut atom_manager, &mut ring_manager);
+        }
+
+        for proton in manager.protons.iter().flatten() {
+            assert!(proton.position().is_finite(), "position {:?}", proton.position());
+            assert!(proton.velocity().is_finite(), "velocity {:?}", proton.velocity());
+        }
+    }
+
+    #[test]
+    fn nan_distances_sort_after_every_neighbor() {
+        // A poisoned particle's distance can be NaN of either sign - total_cmp alone would put -NaN first
+        let mut neighbors_with_dist: Vec<(usize, f32)> = vec![(0, 3.0), (1, -f32::NAN), (2, 1.0), (3, f32::NAN), (4, 2.0)];
+        neighbors_with_dist.sort_by(|a, b| nan_last(a.1, b.1));
+        let nearest: Vec<usize> = neighbors_with_dist.iter().take(3).map(|(idx, _)| *idx).collect();
+        assert_eq!(nearest, [2, 4, 0]);
+        assert!(neighbors_with_dist[3..].iter().all(|(_, dist)| dist.is_nan()));
+    }
+
+    #[test]
+    fn hot_particles_do_not_crystallize_until_they_cool() {
+        let mut manager = crystal_cloud();
+        manager.neighbor_grid = NeighborGrid::build(&manager.protons, pm::NEIGHBOR_GRID_CELL_SIZE);
+        for p in manager.protons.iter_mut().flatten() {
+            p.add_heat(proton::FUSION_HEAT);
+        }
+        let hot = crystallize(&mut manager);
+        assert!(hot.iter().all(|(bonds, frozen, _)| !frozen && bonds.iter().all(|b| b.is_empty())));
+
+        // Long enough for the heat to decay below the hot threshold
+        for p in manager.protons.iter_mut().flatten() {
+            p.update(10.0, Rect::new(0.0, 0.0, 10_000.0, 10_000.0), BoundaryMode::Open);
+            assert!(!p.is_hot());
+        }
+
+        // Once cool, the same cloud crystallizes again
+        manager.neighbor_grid = NeighborGrid::build(&manager.protons, pm::NEIGHBOR_GRID_CELL_SIZE);
+        let cooled = crystallize(&mut manager);
+        assert!(cooled.iter().any(|(bonds, _, _)| bonds.iter().any(|b| !b.is_empty())), "cooled cloud formed no bonds");
+        let mut cold = crystal_cloud();
+        cold.neighbor_grid = NeighborGrid::build(&cold.protons, pm::NEIGHBOR_GRID_CELL_SIZE);
+        let bonds = |result: Vec<(Vec<Vec<usize>>, bool, Vec2)>| result.into_iter().map(|(bonds, _, _)| bonds).collect::<Vec<_>>();
+        assert_eq!(bonds(cooled), bonds(crystallize(&mut cold)));
+    }
+
+    #[test]
+    fn every_spawnable_element_is_fully_configured() {
+        // (name, charge, neutrons) - tritium is left out as it decays by design
+        let elements = [
+            ("H1", 0, 1), ("He3", 1, 2), ("He4", 2, 2), ("C12", 6, 6), ("O16", 8, 8), ("Ne20", 10, 10),
+            ("Mg24", 12, 12), ("Si28", 14, 14), ("S32", 16, 16), ("H2O", 8, 10), ("H2S", 16, 18),
+            ("MgH2", 12, 14), ("CH4", 6, 10), ("SiH4", 14, 18), ("SO2", 32, 32),
+        ];
+
+        for (name, charge, neutrons) in elements {
+            let mut manager = ProtonManager::new(4, &SimConfig::default());
+            manager.spawn_element(name, vec2(100.0, 100.0), vec2(5.0, -5.0));
+            let p = manager.protons.iter().flatten().next().unwrap_or_else(|| panic!("{} not spawned", name));
+
+            assert_eq!((p.charge(), p.neutron_count()), (charge, neutrons), "{}", name);
+            assert_eq!(p.velocity(), vec2(5.0, -5.0), "{}", name);
+            let expected_label = if name == "H1" { "H" } else { name };
+            assert_eq!(p.get_element_label(), expected_label);
+            assert_eq!(p.validate(), Ok(()), "{}", name);
+            assert_eq!(p.element_kind().counted_name(), Some(name));
+
+            // Survives well past any finite lifetime
+            manager.update_proton_physics(100.0, (10_000.0, 10_000.0));
+            assert!(manager.protons.iter().flatten().any(|p| p.is_alive()), "{} expired", name);
+        }
+    }
+
+    #[test]
+    fn clear_keeps_only_stable_kinds() {
+        let mut manager = ProtonManager::new(8, &SimConfig::default());
+        for (i, name) in ["CH4", "Ne20", "He3", "H1"].into_iter().enumerate() {
+            manager.spawn_element(name, vec2(100.0 * (i + 1) as f32, 100.0), Vec2::ZERO);
+        }
+        manager.spawn_proton(vec2(500.0, 100.0), Vec2::ZERO, WHITE, 1.0, 1);
+
+        manager.clear();
+
+        let mut kinds: Vec<ElementKind> = manager.protons.iter().flatten().map(Proton::element_kind).collect();
+        kinds.sort_by_key(|k| *k as usize);
+        assert_eq!(kinds, [ElementKind::Hydrogen1, ElementKind::Neon20, ElementKind::Methane]);
+        assert_eq!(manager.get_proton_count(), 0);
+    }
+
+    #[test]
+    fn triple_alpha_scenario_loads_its_preset_and_makes_carbon() {
+        let mut manager = ProtonManager::new(16, &SimConfig::default());
+        let mut atom_manager = AtomManager::new(0);
+        let mut ring_manager = RingManager::new();
+        manager.spawn_element("H2O", vec2(10.0, 10.0), Vec2::ZERO);
+
+        assert!(manager.load_scenario("No Such Scenario", vec2(640.0, 360.0)).is_none());
+        assert_eq!(manager.protons.iter().flatten().count(), 1, "unknown name leaves the pond alone");
+
+        for preset in &scenario::SCENARIOS {
+            assert!(ProtonManager::build_element(preset.selected_element, Vec2::ZERO, Vec2::ZERO).is_some(), "{}", preset.name);
+        }
+
+        let loaded = manager.load_scenario("Triple-Alpha Carbon", vec2(640.0, 360.0)).expect("built-in scenario");
+        assert_eq!(loaded.selected_element, "He4");
+        let alphas: Vec<&Proton> = manager.protons.iter().flatten().collect();
+        assert!(!alphas.is_empty() && alphas.iter().all(|p| p.is_stable_helium4()));
+        assert!(alphas.iter().all(|p| p.energy() * 3.0 >= proton::TRIPLE_ALPHA_ENERGY_THRESHOLD));
+        let triads = alphas.len() / 3;
+
+        // Every triad closes fast enough to get past the bounce and fuse
+        for _ in 0..240 {
+            manager.update(crate::constants::timestep::FIXED_DT, (1280.0, 720.0), &mut atom_manager, &mut ring_manager);
+        }
+        assert_eq!(manager.protons.iter().flatten().filter(|p| p.is_stable_carbon12()).count(), triads);
+    }
+
+    #[test]
+    fn population_controller_fills_to_the_target_without_overshooting_then_thins_out() {
+        let mut manager = ProtonManager::new(64, &SimConfig::default());
+        let mut atom_manager = AtomManager::new(0);
+        let mut ring_manager = RingManager::new();
+        let dt = crate::constants::timestep::FIXED_DT;
+        manager.set_population_target(Some(PopulationTarget { element: "He4".to_string(), count: 12 }));
+
+        // Rate limited: nothing close to the full dozen after a fraction of a second
+        for _ in 0..12 {
+            manager.update(dt, (1280.0, 720.0), &mut atom_manager, &mut ring_manager);
+        }
+        assert!(manager.population().unwrap().1 < 3);
+
+        for _ in 0..1200 {
+            manager.update(dt, (1280.0, 720.0), &mut atom_manager, &mut ring_manager);
+            assert!(manager.population().unwrap().1 <= 12);
+        }
+        assert_eq!(manager.population().unwrap().1, 12);
+
+        manager.set_population_target(Some(PopulationTarget { element: "He4".to_string(), count: 4 }));
+        for _ in 0..1200 {
+            manager.update(dt, (1280.0, 720.0), &mut atom_manager, &mut ring_manager);
+        }
+        assert_eq!(manager.population().unwrap().1, 4);
+        assert_eq!(manager.protons.iter().flatten().count(), 4, "only He4 was spawned");
+
+        manager.set_population_target(None);
+        assert!(manager.population().is_none());
+    }
+
+    #[test]
+    fn sulfur_captures_two_oxygen_into_sulfur_dioxide() {
+        let mut manager = ProtonManager::new(16, &SimConfig::default());
+        let mut ring_manager = RingManager::new();
+        manager.spawn_element("S32", vec2(200.0, 200.0), Vec2::ZERO);
+        for x in [170.0, 230.0] {
+            let mut oxygen = Proton::new(vec2(x, 200.0), Vec2::ZERO, Color::from_rgba(100, 200, 255, 255), 16.0, 8);
+            oxygen.set_neutron_count(8);
+            oxygen.set_oxygen16(true);
+            manager.insert_proton(oxygen);
+        }
+
+        manager.handle_nuclear_fusion(&mut ring_manager);
+
+        let alive: Vec<&Proton> = manager.protons.iter().flatten().collect();
+        assert_eq!(alive.len(), 1);
+        assert!(alive[0].is_so2());
+        assert_eq!((alive[0].charge(), alive[0].neutron_count()), (32, 32));
+        assert_eq!(alive[0].get_element_label(), "SO2");
+        let so2_position = alive[0].position();
+        let events: Vec<SimEvent> = manager.drain_events().collect();
+        assert_eq!(events, vec![SimEvent {
+            kind: SimEventKind::MoleculeFormed,
+            position: so2_position,
+            element: "SO2".to_string(),
+        }]);
+        assert_eq!(manager.drain_events().count(), 0);
+    }
+
+    /// Bare nucleus with the given charge and neutron count that never expires
+    fn nucleus(charge: i32, neutron_count: i32, energy: f32, position: Vec2, velocity: Vec2) -> Proton {
+        let mut p = Proton::new(position, velocity, WHITE, energy, charge);
+        p.set_neutron_count(neutron_count);
+        p.set_max_lifetime(-1.0);
+        p
+    }
+
+    /// Run the full update `frames` fixed steps on a window-sized pond with fresh ring and atom managers,
+    /// starting from exactly `particles` - returns the manager for inspection
+    fn run_reaction(particles: Vec<Proton>, frames: usize) -> ProtonManager {
+        let mut manager = ProtonManager::new(16, &SimConfig::default());
+        let mut atom_manager = AtomManager::new(0);
+        let mut ring_manager = RingManager::new();
+        for particle in particles {
+            manager.insert_proton(particle);
+        }
+        for _ in 0..frames {
+            manager.update(timestep::FIXED_DT, (800.0, 600.0), &mut atom_manager, &mut ring_manager);
+        }
+        manager
+    }
+
+    #[test]
+    fn every_reaction_on_the_ladder_fires_through_the_full_update() {
+        let site = vec2(400.0, 300.0);
+        let element = |name: &str, position: Vec2| ProtonManager::build_element(name, position, Vec2::ZERO).unwrap();
+        // Two particles overlapping at the site and closing head-on faster than any pair threshold
+        let closing = proton::PP_CHAIN_VELOCITY_THRESHOLD * 1.2;
+        let pair = |a: fn(Vec2, Vec2) -> Proton, b: fn(Vec2, Vec2) -> Proton| {
+            vec![
+                a(site - vec2(2.0, 0.0), vec2(closing / 2.0, 0.0)),
+                b(site + vec2(2.0, 0.0), vec2(-closing / 2.0, 0.0)),
+            ]
+        };
+        let hydrogen_plus: fn(Vec2, Vec2) -> Proton = |pos, vel| nucleus(1, 0, 30.0, pos, vel);
+        let hydrogen_minus: fn(Vec2, Vec2) -> Proton = |pos, vel| nucleus(-1, 0, 30.0, pos, vel);
+        let deuterium: fn(Vec2, Vec2) -> Proton = |pos, vel| {
+            let mut d = nucleus(1, 0, 30.0, pos, vel);
+            d.become_deuterium();
+            d
+        };
+        let helium3: fn(Vec2, Vec2) -> Proton = |pos, vel| nucleus(1, 2, 30.0, pos, vel);
+        let alpha_onto = |target: &str| vec![element(target, site), he4(site + vec2(3.0, 0.0), vec2(-closing, 0.0))];
+        // A center with `count` ligands spread evenly around it well inside every capture range
+        let molecule = |center: Proton, ligand: &dyn Fn(Vec2) -> Proton, count: usize| {
+            let mut particles = vec![center];
+            particles.extend((0..count).map(|i| ligand(site + Vec2::from_angle(i as f32 * std::f32::consts::TAU / count as f32) * 25.0)));
+            particles
+        };
+        let hydrogen1 = |pos: Vec2| element("H1", pos);
+        let oxygen16 = |pos: Vec2| element("O16", pos);
+
+        let cases: Vec<(ReactionKind, Vec<Proton>, ElementKind)> = vec![
+            (ReactionKind::ProtonProton, pair(hydrogen_plus, hydrogen_plus), ElementKind::Deuterium),
+            (ReactionKind::DeuteriumProton, pair(deuterium, hydrogen_plus), ElementKind::Helium3),
+            (ReactionKind::DeuteriumDeuterium, pair(deuterium, deuterium), ElementKind::Tritium),
+            (ReactionKind::Helium3Helium3, pair(helium3, helium3), ElementKind::Helium4),
+            (ReactionKind::NegativeProton, pair(hydrogen_minus, hydrogen_plus), ElementKind::Deuterium),
+            (
+                ReactionKind::TripleAlpha,
+                (0..3).map(|i| {
+                    let direction = Vec2::from_angle(i as f32 * std::f32::consts::TAU / 3.0);
+                    he4(site + direction * 2.0, -direction * closing)
+                }).collect(),
+                ElementKind::Carbon12,
+            ),
+            (ReactionKind::CarbonAlpha, alpha_onto("C12"), ElementKind::Oxygen16),
+            (ReactionKind::OxygenAlpha, alpha_onto("O16"), ElementKind::Neon20),
+            (ReactionKind::NeonAlpha, alpha_onto("Ne20"), ElementKind::Magnesium24),
+            (ReactionKind::MagnesiumAlpha, alpha_onto("Mg24"), ElementKind::Silicon28),
+            (ReactionKind::SiliconAlpha, alpha_onto("Si28"), ElementKind::Sulfur32),
+            (ReactionKind::Water, molecule(element("O16", site), &hydrogen1, 2), ElementKind::Water),
+            (ReactionKind::HydrogenSulfide, molecule(element("S32", site), &hydrogen1, 2), ElementKind::HydrogenSulfide),
+            (ReactionKind::MagnesiumHydride, molecule(element("Mg24", site), &hydrogen1, 2), ElementKind::MagnesiumHydride),
+            (ReactionKind::Methane, molecule(element("C12", site), &hydrogen1, 4), ElementKind::Methane),
+            (ReactionKind::Silane, molecule(element("Si28", site), &hydrogen1, 4), ElementKind::Silane),
+            (ReactionKind::SulfurDioxide, molecule(element("S32", site), &oxygen16, 2), ElementKind::SulfurDioxide),
+        ];
+        // The table covers the whole ladder - a new reaction kind needs a case here
+        assert_eq!(cases.len(), ReactionKind::ALL.len());
+
+        for (kind, particles, product) in cases {
+            let manager = run_reaction(particles, 3);
+            assert!(manager.reaction_log.iter().any(|&(_, k)| k == kind), "{:?} never fired", kind);
+            // C12 + He4 first forms a bonded (C12, He4) pair, which counts as O16 until it consolidates
+            let is_product = |p: &Proton| p.element_kind() == product || (product == ElementKind::Oxygen16 && p.is_oxygen16_bonded());
+            assert!(
+                manager.protons.iter().flatten().any(|p| p.is_alive() && is_product(p)),
+                "{:?} left no {:?}",
+                kind,
+                product,
+            );
+        }
+    }
+
+    #[test]
+    fn charge_and_nucleon_totals_only_move_with_spawns_and_deletions() {
+        let mut manager = ProtonManager::new(32, &SimConfig::default());
+        let mut atom_manager = AtomManager::new(0);
+        let mut ring_manager = RingManager::new();
+        let site = vec2(400.0, 300.0);
+        let closing = proton::PP_CHAIN_VELOCITY_THRESHOLD * 1.2;
+        let mut spawned = (0, 0);
+        let mut deleted = (0, 0);
+        let spawn = |manager: &mut ProtonManager, spawned: &mut (i64, i64), particle: Proton| {
+            let (charge, nucleons) = ProtonManager::totals_of(&particle);
+            spawned.0 += charge;
+            spawned.1 += nucleons;
+            assert!(manager.insert_proton(particle));
+        };
+
+        for i in 0..3 {
+            let direction = Vec2::from_angle(i as f32 * std::f32::consts::TAU / 3.0);
+            spawn(&mut manager, &mut spawned, he4(site + direction * 2.0, -direction * closing));
+        }
+        let ladder = [
+            ElementKind::Carbon12,
+            ElementKind::Oxygen16,
+            ElementKind::Neon20,
+            ElementKind::Magnesium24,
+            ElementKind::Silicon28,
+            ElementKind::Sulfur32,
+        ];
+        for (stage, &product) in ladder.iter().enumerate() {
+            if stage > 0 {
+                // Fire the next alpha head-on into the heaviest nucleus so far
+                let target = manager.protons.iter().flatten()
+                    .filter(|p| p.is_alive() && !p.is_lepton())
+                    .max_by_key(|p| p.charge() + p.neutron_count())
+                    .unwrap();
+                let alpha = he4(target.position() + vec2(3.0, 0.0), target.velocity() - vec2(closing, 0.0));
+                spawn(&mut manager, &mut spawned, alpha);
+            }
+            let mut frames = 0;
+            while !manager.protons.iter().flatten().any(|p| p.is_alive() && p.element_kind() == product) {
+                manager.update(timestep::FIXED_DT, (800.0, 600.0), &mut atom_manager, &mut ring_manager);
+                frames += 1;
+                assert!(frames < 600, "{:?} never formed", product);
+            }
+            assert_eq!(manager.conserved_totals(), (spawned.0 - deleted.0, spawned.1 - deleted.1), "{:?}", product);
+            // Clear the recoil pairs before they can take part in anything else
+            for lepton in manager.protons.iter().flatten().filter(|p| p.is_alive() && p.is_lepton()) {
+                let (charge, neutrons) = ProtonManager::totals_of(lepton);
+                deleted.0 += charge;
+                deleted.1 += neutrons;
+            }
+            manager.delete_element(|p| p.is_lepton());
+            assert_eq!(manager.conserved_totals(), (spawned.0 - deleted.0, spawned.1 - deleted.1), "{:?}", product);
+        }
+        // Eight alphas in, the S32 they built is all that is left
+        assert_eq!(spawned, (16, 32));
+        assert_eq!(manager.conserved_totals(), spawned);
+        assert!(manager.protons.iter().flatten().any(|p| p.is_alive() && p.element_kind() == ElementKind::Sulfur32));
+    }
+
+    #[test]
+    fn same_kind_molecules_cohere_unless_their_cohesion_is_turned_off() {
+        let approach = |config: &SimConfig, second: &str| {
+            let mut manager = ProtonManager::new(4, config);
+            manager.protons[0] = ProtonManager::build_element("H2O", vec2(100.0, 100.0), Vec2::ZERO);
+            manager.protons[1] = ProtonManager::build_element(second, vec2(160.0, 100.0), Vec2::ZERO);
+            manager.apply_charge_forces(timestep::FIXED_DT);
+            let velocity = |slot: usize| manager.protons[slot].as_ref().unwrap().velocity();
+            (velocity(0).x, velocity(1).x)
+        };
+
+        let (left, right) = approach(&SimConfig::default(), "H2O");
+        assert!(left > 0.0 && right < 0.0, "water should pull together, got {} {}", left, right);
+        // Only the same kind coheres
+        assert_eq!(approach(&SimConfig::default(), "CH4"), (0.0, 0.0));
+        let dry = SimConfig::parse("h2o_cohesion_strength = 0\n", "test");
+        assert_eq!(approach(&dry, "H2O"), (0.0, 0.0));
+    }
+
+    #[test]
+    fn alphas_spawned_hot_make_carbon_where_default_ones_fall_short() {
+        let site = vec2(400.0, 300.0);
+        let closing = proton::PP_CHAIN_VELOCITY_THRESHOLD * 1.2;
+        let carbon_from = |energy: Option<f32>| {
+            let mut manager = ProtonManager::new(16, &SimConfig::default());
+            let mut atom_manager = AtomManager::new(0);
+            let mut ring_manager = RingManager::new();
+            for i in 0..3 {
+                let direction = Vec2::from_angle(i as f32 * std::f32::consts::TAU / 3.0);
+                // Far enough apart that spawning doesn't nudge them off their collision course
+                let (position, velocity) = (site + direction * pm::SPAWN_MIN_SPACING, -direction * closing);
+                match energy {
+                    Some(energy) => manager.spawn_element_with_energy("He4", position, velocity, energy),
+                    None => manager.spawn_element("He4", position, velocity),
+                }
+            }
+            for _ in 0..30 {
+                manager.update(timestep::FIXED_DT, (800.0, 600.0), &mut atom_manager, &mut ring_manager);
+            }
+            manager.protons.iter().flatten().any(|p| p.is_alive() && p.is_stable_carbon12())
+        };
+
+        let default = ProtonManager::default_spawn_energy("He4").unwrap();
+        assert!(default * 3.0 < proton::TRIPLE_ALPHA_ENERGY_THRESHOLD);
+        assert!(!carbon_from(None));
+        assert!(carbon_from(Some(proton::TRIPLE_ALPHA_ENERGY_THRESHOLD / 3.0)));
+
+        // The - / = steps scale the default, so raising never lowers it and the top step reaches the threshold
+        let mut step = crate::DEFAULT_SPAWN_ENERGY_STEP;
+        assert_eq!(crate::SPAWN_ENERGY_SCALES[step], 1.0);
+        loop {
+            let raised = crate::step_spawn_energy(step, 1);
+            assert!(crate::SPAWN_ENERGY_SCALES[raised] >= crate::SPAWN_ENERGY_SCALES[step]);
+            if raised == step {
+                break;
+            }
+            step = raised;
+        }
+        assert!(default * crate::SPAWN_ENERGY_SCALES[step] * 3.0 > proton::TRIPLE_ALPHA_ENERGY_THRESHOLD);
+    }
+
+    #[test]
+    fn triple_alpha_search_in_a_dense_he4_cluster_checks_only_nearby_triples() {
+        let mut manager = ProtonManager::new(256, &SimConfig::default());
+        let mut ring_manager = RingManager::new();
+        let mut seed: u32 = 2024;
+        let mut next = move || {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            (seed >> 8) as f32 / (1u32 << 24) as f32
+        };
+        // 200 hot, jostling alphas packed into a box only a few radii across
+        for slot in 0..200 {
+            let position = vec2(next(), next()) * 120.0;
+            let velocity = (vec2(next(), next()) - 0.5) * 400.0;
+            manager.protons[slot] = Some(he4(position, velocity));
+        }
+
+        // Every pair within an alpha's neighborhood is one candidate triple - far fewer than all 1.3 million
+        let he4: Vec<(Vec2, f32)> = manager.protons.iter().flatten().map(|p| (p.position(), p.radius())).collect();
+        let neighborhoods = ProtonManager::triple_alpha_neighborhoods(&he4);
+        let examined: usize = neighborhoods.iter().map(|near| near.len() * near.len().saturating_sub(1) / 2).sum();
+        let all_triples = 200 * 199 * 198 / 6;
+        assert!(examined * 100 < all_triples, "{} candidate triples examined", examined);
+
+        // Yet no triple that touches all round is left out
+        let touching = |a: usize, b: usize| he4[a].0.distance(he4[b].0) <= he4[a].1 + he4[b].1;
+        for (i, near) in neighborhoods.iter().enumerate() {
+            for j in i + 1..he4.len() {
+                if !touching(i, j) {
+                    continue;
+                }
+                for k in j + 1..he4.len() {
+                    if touching(i, k) && touching(j, k) {
+                        assert!(near.contains(&j) && near.contains(&k), "missed ({}, {}, {})", i, j, k);
                     }
                 }
             }
         }
+
+        manager.handle_nuclear_fusion(&mut ring_manager);
+        assert!(manager.protons.iter().flatten().any(|p| p.is_alive() && p.is_stable_carbon12()));
+    }
+
+    #[test]
+    fn clear_and_cleanup_keep_exactly_the_immortal_kinds() {
+        let names = [
+            "H1", "T", "He3", "He4", "C12", "O16", "Ne20", "Mg24", "Si28", "S32", "H2O", "H2S", "MgH2", "CH4", "SiH4", "SO2",
+        ];
+        for name in names {
+            let mut manager = ProtonManager::new(4, &SimConfig::default());
+            manager.spawn_element(name, vec2(100.0, 100.0), Vec2::ZERO);
+            let immortal = manager.protons.iter().flatten().next().unwrap().is_immortal();
+            manager.clear();
+            assert_eq!(manager.protons.iter().flatten().count(), immortal as usize, "{} after clear", name);
+
+            // The dead-particle cleanup spares the same kinds, even when they are marked for deletion
+            let mut manager = ProtonManager::new(4, &SimConfig::default());
+            manager.spawn_element(name, vec2(100.0, 100.0), Vec2::ZERO);
+            manager.protons.iter_mut().flatten().for_each(Proton::mark_for_deletion);
+            manager.update(timestep::FIXED_DT, (800.0, 600.0), &mut AtomManager::new(0), &mut RingManager::new());
+            assert_eq!(manager.protons.iter().flatten().count(), immortal as usize, "{} after cleanup", name);
+
+            manager.clear_all();
+            assert_eq!(manager.protons.iter().flatten().count(), 0, "{} after clear_all", name);
+        }
+        // Never decaying alone doesn't make a particle immortal - He3 outlives any lifetime but is still cleared
+        let mut manager = ProtonManager::new(4, &SimConfig::default());
+        manager.spawn_element("He3", vec2(100.0, 100.0), Vec2::ZERO);
+        manager.update_proton_physics(100.0, (10_000.0, 10_000.0));
+        let he3 = manager.protons.iter().flatten().next().unwrap();
+        assert!(he3.is_alive() && !he3.is_immortal());
+    }
+
+    #[test]
+    fn custom_arena_walls_bounce_particles_inside_the_window() {
+        let mut manager = ProtonManager::new(4, &SimConfig::default());
+        manager.set_arena(Some(Rect::new(200.0, 100.0, 300.0, 200.0)));
+        manager.spawn_element("He4", vec2(480.0, 200.0), vec2(400.0, 0.0));
+        for _ in 0..30 {
+            manager.update_proton_physics(timestep::FIXED_DT, (800.0, 600.0));
+        }
+        let he4 = manager.protons.iter().flatten().next().unwrap();
+        assert!(he4.position().x + he4.radius() <= 500.0 + 0.01, "escaped the arena at x = {}", he4.position().x);
+        assert!(he4.velocity().x < 0.0, "should be heading back in after the bounce");
+
+        manager.set_arena(None);
+        assert_eq!(manager.arena_rect((800.0, 600.0)), Rect::new(0.0, 0.0, 800.0, 600.0));
+    }
+
+    #[test]
+    fn loaded_particles_flash_for_a_second() {
+        let mut manager = ProtonManager::new(8, &SimConfig::default());
+        manager.spawn_element("He4", vec2(100.0, 100.0), Vec2::ZERO);
+        let saved = manager.to_base64();
+        manager.elapsed_time = 5.0;
+        manager.spawn_element("C12", vec2(300.0, 100.0), Vec2::ZERO);
+        assert_eq!(manager.load_flash(manager.protons.iter().flatten().next().unwrap()), 0.0, "spawned, not loaded");
+
+        assert_eq!(manager.load_base64(&saved), Ok(1));
+        let loaded = manager.protons.iter().flatten().next().unwrap();
+        assert_eq!(loaded.spawned_at(), Some(5.0));
+        assert_eq!(manager.load_flash(loaded), 1.0);
+
+        manager.elapsed_time += rendering::LOAD_FLASH_TIME;
+        assert_eq!(manager.load_flash(manager.protons.iter().flatten().next().unwrap()), 0.0);
+    }
+
+    #[test]
+    fn neutron_formation_rate_follows_the_configured_probability() {
+        // Every H+ sits on an atom for exactly the formation time, so each gets one roll at `probability`
+        let converted = |probability: f32| {
+            let config = SimConfig::parse(&format!("neutron_formation_probability = {}", probability), "test");
+            let mut manager = ProtonManager::new(400, &config);
+            for i in 0..400 {
+                let pos = vec2(100.0 + (i % 20) as f32, 100.0 + (i / 20) as f32);
+                manager.insert_proton(Proton::new(pos, Vec2::ZERO, WHITE, 5.0, 1));
+            }
+            manager.form_neutrons(proton::NEUTRON_FORMATION_TIME, &[vec2(110.0, 110.0)]);
+            let deuterium = manager.protons.iter().flatten().filter(|p| p.neutron_count() == 1).count();
+            assert_eq!(manager.neutron_formation_count() as usize, deuterium);
+            deuterium
+        };
+        assert_eq!(converted(1.0), 400);
+        assert_eq!(converted(0.0), 0);
+        let quarter = converted(0.25);
+        assert!((60..=140).contains(&quarter), "{} of 400 converted at 0.25", quarter);
+
+        // Nothing converts outside the configured range
+        let config = SimConfig::parse("neutron_formation_range = 5", "test");
+        let mut manager = ProtonManager::new(4, &config);
+        manager.insert_proton(Proton::new(vec2(100.0, 100.0), Vec2::ZERO, WHITE, 5.0, 1));
+        manager.form_neutrons(proton::NEUTRON_FORMATION_TIME, &[vec2(110.0, 100.0)]);
+        assert_eq!(manager.neutron_formation_count(), 0);
+    }
+
+    #[test]
+    fn frozen_ice_tiles_bounce_off_each_other_as_solids() {
+        // Two frozen 7-molecule hexagon tiles, each its own crystal group, drifting head-on
+        let spacing = 30.0;
+        let mut manager = ProtonManager::new(16, &SimConfig::default());
+        for (group, center, velocity) in [(0, vec2(200.0, 300.0), vec2(60.0, 0.0)), (1, vec2(400.0, 300.0), vec2(-60.0, 0.0))] {
+            let offsets = std::iter::once(Vec2::ZERO)
+                .chain((0..6).map(|i| Vec2::from_angle(i as f32 * std::f32::consts::TAU / 6.0) * spacing));
+            for offset in offsets {
+                let mut water = Proton::new(center + offset, velocity, WHITE, 30.0, 8);
+                water.set_neutron_count(10);
+                water.set_h2o(true);
+                water.set_water_frozen(true);
+                water.set_ice_crystal_group(Some(group));
+                manager.insert_proton(water);
+            }
+        }
+
+        // Same order as update: move, rigid groups, then solid collisions
+        let dt = timestep::FIXED_DT;
+        for _ in 0..600 {
+            manager.update_proton_physics(dt, (800.0, 600.0));
+            manager.apply_crystal_group_rigid_movement(dt);
+            manager.handle_solid_collisions();
+
+            let waters: Vec<&Proton> = manager.protons.iter().flatten().collect();
+            for a in waters.iter().filter(|p| p.ice_crystal_group() == Some(0)) {
+                for b in waters.iter().filter(|p| p.ice_crystal_group() == Some(1)) {
+                    let gap = a.position().distance(b.position()) - a.radius() - b.radius();
+                    assert!(gap > -0.5, "tiles overlap by {}", -gap);
+                }
+            }
+        }
+
+        // They met and are now heading apart, each still moving as one piece
+        let drift = |group: usize| {
+            let velocities: Vec<Vec2> =
+                manager.protons.iter().flatten().filter(|p| p.ice_crystal_group() == Some(group)).map(|p| p.velocity()).collect();
+            assert!(velocities.iter().all(|v| v.distance(velocities[0]) < 1e-3), "group {} lost its shape", group);
+            velocities[0].x
+        };
+        assert!(drift(0) < 0.0 && drift(1) > 0.0, "tiles should separate: {} {}", drift(0), drift(1));
+    }
+
+    #[test]
+    fn bonds_of_gathers_every_bond_type_once() {
+        let mut manager = ProtonManager::new(8, &SimConfig::default());
+        for x in [100.0, 130.0, 160.0, 190.0] {
+            manager.spawn_element("H2O", vec2(x, 100.0), Vec2::ZERO);
+        }
+        let water = manager.protons[0].as_mut().unwrap();
+        water.add_water_h_bond(1, 30.0);
+        water.add_crystal_bond(2);
+        water.add_crystal_bond(1); // Bonded two ways, still one partner
+        water.set_oxygen_bond_partner(Some(3));
+        assert_eq!(manager.bonds_of(0), vec![1, 2, 3]);
+
+        // Dead or emptied partners and empty slots have no bonds to show
+        manager.remove_slot(2);
+        assert_eq!(manager.bonds_of(0), vec![1, 3]);
+        assert!(manager.bonds_of(2).is_empty());
+        assert_eq!(manager.slot_at(vec2(130.0, 100.0)), Some(1));
+    }
+
+    #[test]
+    fn every_alpha_capture_keeps_charge_and_neutrons_and_ejects_a_neutral_pair() {
+        let captures = [
+            ("C12", ElementKind::Oxygen16),
+            ("O16", ElementKind::Neon20),
+            ("Ne20", ElementKind::Magnesium24),
+            ("Mg24", ElementKind::Silicon28),
+            ("Si28", ElementKind::Sulfur32),
+        ];
+        for (target, product) in captures {
+            let mut manager = ProtonManager::new(8, &SimConfig::default());
+            let mut atom_manager = AtomManager::new(0);
+            let mut ring_manager = RingManager::new();
+            let (_, threshold) = manager.fusion_threshold(target).unwrap();
+            manager.spawn_element(target, vec2(400.0, 300.0), Vec2::ZERO);
+            assert!(manager.insert_proton(he4(vec2(403.0, 300.0), vec2(-threshold * 1.2, 0.0))));
+            let before = manager.conserved_totals();
+
+            let mut frames = 0;
+            while !manager.protons.iter().flatten().any(|p| p.is_alive() && p.is_lepton()) {
+                manager.update(timestep::FIXED_DT, (800.0, 600.0), &mut atom_manager, &mut ring_manager);
+                frames += 1;
+                assert!(frames < 600, "{} never captured its alpha", target);
+            }
+            // C12 + He4 first bonds into an O16 pair, consolidated later
+            assert!(manager.protons.iter().flatten().any(|p| p.is_alive() && (p.element_kind() == product || p.is_oxygen16_bonded())));
+            assert_eq!(manager.conserved_totals(), before, "{} + He4", target);
+            let mut leptons: Vec<i32> = manager.protons.iter().flatten().filter(|p| p.is_alive() && p.is_lepton()).map(|p| p.charge()).collect();
+            leptons.sort();
+            assert_eq!(leptons, [-1, 1], "{} + He4 should eject one e+ e- pair", target);
+        }
     }
 }
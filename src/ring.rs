@@ -31,6 +31,20 @@ struct BounceShape {
     color: Color,
 }
 
+/// What made a ring - fusion waves are drawn with a second inner line so they stand out from spawned ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RingOrigin {
+    Click,  // Spawned by the player
+    Fusion, // Released by a reaction or molecule formation
+}
+
+/// How rings are drawn
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RingStyle {
+    Line,      // A thin circle (default - cheapest)
+    Shockwave, // A bright front trailing a fading wake, broad for slow waves and sharp for fast ones
+}
+
 #[derive(Debug)]
 pub struct Ring {
     center: Vec2,
@@ -38,10 +52,12 @@ pub struct Ring {
     current_radius: f32,
     growth_speed: f32,
     color: Color,
+    energy: f32,
     is_alive: bool,
     thickness: f32,
     bounce_data: BounceData,
     bounce_shapes: Vec<BounceShape>,
+    origin: RingOrigin,
 }
 
 impl Ring {
@@ -57,20 +73,36 @@ impl Ring {
         MIN_RING_SPEED + (speed_factor * (MAX_RING_SPEED - MIN_RING_SPEED))
     }
 
-    /// Create a new ring at the given position with the specified color
-    pub fn new(center: Vec2, color: Color, thickness: f32) -> Self {
-        let growth_speed = Self::calculate_frequency_based_speed(color);
+    /// Palette-style color whose frequency mapping gives `speed` - the inverse of `calculate_frequency_based_speed`
+    /// Slow waves run dark red -> red, middle ones red -> blue, and the fastest blue -> white
+    pub fn color_for_speed(speed: f32) -> Color {
+        let factor = ((speed - MIN_RING_SPEED) / (MAX_RING_SPEED - MIN_RING_SPEED)).clamp(0.0, 1.0);
 
+        if factor <= COLOR_WEIGHT_RED {
+            Color::new(factor / COLOR_WEIGHT_RED, 0.0, 0.0, 1.0)
+        } else if factor <= COLOR_WEIGHT_BLUE {
+            let t = (factor - COLOR_WEIGHT_RED) / (COLOR_WEIGHT_BLUE - COLOR_WEIGHT_RED);
+            Color::new(1.0 - t, 0.0, t, 1.0)
+        } else {
+            let t = (factor - COLOR_WEIGHT_BLUE) / (COLOR_WEIGHT_RED + COLOR_WEIGHT_GREEN);
+            Color::new(t, t, 1.0, 1.0)
+        }
+    }
+
+    /// Create a ring with an explicit growth speed and starting radius (the color is only drawn)
+    pub fn with_speed(center: Vec2, color: Color, thickness: f32, growth_speed: f32, initial_radius: f32, origin: RingOrigin) -> Self {
         Self {
             center,
             original_center: center,
-            current_radius: INITIAL_RING_RADIUS,
+            current_radius: initial_radius,
             growth_speed,
             color,
+            energy: 1.0,
             is_alive: true,
             thickness,
             bounce_data: BounceData::default(),
             bounce_shapes: Vec::new(),
+            origin,
         }
     }
 
@@ -80,34 +112,39 @@ impl Ring {
             return;
         }
 
-        // Grow the ring
+        // Grow the ring - energy spreads thinner as the wave widens
         self.current_radius += self.growth_speed * delta_time;
+        self.energy = Self::energy_at_radius(self.current_radius);
 
         // Update bounce shapes and reflections
         self.update_bounce_shapes(window_size);
 
-        // Kill ring when it gets too large
-        if self.current_radius > MAX_RADIUS_THRESHOLD {
+        // Kill ring when it gets too large (or wider than the screen diagonal) or its energy is spent
+        let (window_width, window_height) = window_size;
+        let diagonal = (window_width * window_width + window_height * window_height).sqrt();
+        if self.current_radius > MAX_RADIUS_THRESHOLD || self.current_radius > diagonal || self.energy <= 0.0 {
             self.is_alive = false;
             return;
         }
 
         // Kill ring early if center is far off-screen
-        let (window_width, window_height) = window_size;
         if self.center.x < -OFF_SCREEN_MARGIN
             || self.center.x > window_width + OFF_SCREEN_MARGIN
             || self.center.y < -OFF_SCREEN_MARGIN
             || self.center.y > window_height + OFF_SCREEN_MARGIN
         {
             self.is_alive = false;
-            return;
         }
+    }
+
+    /// Remaining energy (1.0 = fresh, 0.0 = spent) for a ring of the given radius
+    fn energy_at_radius(radius: f32) -> f32 {
+        (1.0 - radius / RING_ENERGY_DECAY_RADIUS).clamp(0.0, 1.0)
+    }
 
-        // Fade out as ring gets bigger
-        let alpha = (COLOR_MAX
-            * (MINIMUM_ALPHA.max(1.0 - self.current_radius / ALPHA_CALCULATION_DIVISOR)))
-            as u8;
-        self.color.a = alpha as f32 / 255.0;
+    /// Fade a color's alpha by the ring's remaining energy
+    fn faded(&self, color: Color) -> Color {
+        Color::new(color.r, color.g, color.b, color.a * self.energy)
     }
 
     /// Update bounce shapes for wall reflections
@@ -201,18 +238,26 @@ impl Ring {
     }
 
     /// Draw the ring and all bounce reflections
-    pub fn render(&self, segments: u8) {
+    pub fn render(&self, segments: u8, style: RingStyle) {
         if !self.is_alive {
             return;
         }
 
-        // Draw main ring
+        if style == RingStyle::Shockwave {
+            self.draw_wake(self.center, self.faded(self.color), segments);
+            for bounce_shape in &self.bounce_shapes {
+                self.draw_wake(bounce_shape.center, self.faded(bounce_shape.color), segments);
+            }
+        }
+
+        // Draw main ring, fading with remaining energy
+        let color = self.faded(self.color);
         draw_circle_lines(
             self.center.x,
             self.center.y,
             self.current_radius,
             self.thickness,
-            self.color,
+            color,
         );
 
         // Alternative: draw as hollow circle with segments for better quality
@@ -224,7 +269,7 @@ impl Ring {
                 self.current_radius,
                 0.0,
                 self.thickness,
-                self.color,
+                color,
             );
         }
 
@@ -237,9 +282,60 @@ impl Ring {
                 self.current_radius,
                 0.0,
                 self.thickness,
-                bounce_shape.color,
+                self.faded(bounce_shape.color),
             );
         }
+
+        // Fusion rings are doubled - a thinner, fainter line just inside the main one (and each reflection)
+        let inner_radius = self.current_radius - FUSION_RING_LINE_GAP;
+        if self.origin == RingOrigin::Fusion && inner_radius > 0.0 {
+            let inner_thickness = self.thickness * 0.5;
+            let inner = |color: Color| Color::new(color.r, color.g, color.b, color.a * FUSION_RING_LINE_OPACITY);
+            draw_circle_lines(self.center.x, self.center.y, inner_radius, inner_thickness, inner(color));
+            for bounce_shape in &self.bounce_shapes {
+                let (x, y) = (bounce_shape.center.x, bounce_shape.center.y);
+                draw_poly_lines(x, y, segments, inner_radius, 0.0, inner_thickness, inner(self.faded(bounce_shape.color)));
+            }
+        }
+    }
+
+    /// Width of the wake behind the front in the shockwave style - inversely proportional to speed
+    pub fn shockwave_width(&self) -> f32 {
+        (SHOCKWAVE_WIDTH_SPEED / self.growth_speed.max(1.0)).clamp(SHOCKWAVE_MIN_WIDTH, SHOCKWAVE_MAX_WIDTH)
+    }
+
+    /// Fill the annulus just inside the front with bands that fade out towards its back edge
+    fn draw_wake(&self, center: Vec2, color: Color, segments: u8) {
+        let width = self.shockwave_width().min(self.current_radius);
+        let band = width / SHOCKWAVE_BANDS as f32;
+        for k in 0..SHOCKWAVE_BANDS {
+            let depth = (k as f32 + 0.5) / SHOCKWAVE_BANDS as f32;
+            let alpha = color.a * SHOCKWAVE_WAKE_OPACITY * (1.0 - depth);
+            let radius = self.current_radius - width * depth;
+            draw_poly_lines(center.x, center.y, segments.max(3), radius, 0.0, band, Color::new(color.r, color.g, color.b, alpha));
+        }
+    }
+
+    /// Axis-aligned box around the main circle
+    fn bounds(&self) -> Rect {
+        let r = self.current_radius;
+        Rect::new(self.center.x - r, self.center.y - r, 2.0 * r, 2.0 * r)
+    }
+
+    /// The two points where this ring's circle crosses `other`'s (None if they don't touch or share a center)
+    fn intersections(&self, other: &Ring) -> Option<[Vec2; 2]> {
+        let (r1, r2) = (self.current_radius, other.current_radius);
+        let offset = other.center - self.center;
+        let distance = offset.length();
+        if distance < f32::EPSILON || distance > r1 + r2 || distance < (r1 - r2).abs() {
+            return None;
+        }
+
+        let dir = offset / distance;
+        let along = (r1 * r1 - r2 * r2 + distance * distance) / (2.0 * distance);
+        let half_chord = (r1 * r1 - along * along).max(0.0).sqrt();
+        let midpoint = self.center + dir * along;
+        Some([midpoint + dir.perp() * half_chord, midpoint - dir.perp() * half_chord])
     }
 
     // Getters
@@ -255,10 +351,19 @@ impl Ring {
         self.center
     }
 
+    /// Remaining wave energy (1.0 = fresh, 0.0 = fully dissipated)
+    pub fn get_energy(&self) -> f32 {
+        self.energy
+    }
+
     pub fn get_growth_speed(&self) -> f32 {
         self.growth_speed
     }
 
+    pub fn origin(&self) -> RingOrigin {
+        self.origin
+    }
+
     pub fn get_color(&self) -> Color {
         self.color
     }
@@ -274,6 +379,7 @@ impl Ring {
         self.center = new_center;
         self.original_center = new_center;
         self.current_radius = RESET_RING_RADIUS;
+        self.energy = Self::energy_at_radius(self.current_radius);
         self.is_alive = true;
         self.bounce_data = BounceData::default();
         self.bounce_shapes.clear();
@@ -300,10 +406,13 @@ impl Ring {
 
 /// RingManager - Manages lifecycle of all rings
 pub struct RingManager {
-    rings: Vec<Ring>,
+    rings: Vec<Ring>, // Oldest first
+    max_rings: usize, // Adding past this evicts the oldest ring
+    evicted_count: u32, // Rings evicted by the cap since startup
     colors: Vec<Color>,
     current_color: Color,
     current_color_index: usize,
+    style: RingStyle,
 }
 
 impl RingManager {
@@ -313,16 +422,18 @@ impl RingManager {
 
         Self {
             rings: Vec::new(),
+            max_rings: MAX_RING_COUNT,
+            evicted_count: 0,
             colors,
             current_color,
             current_color_index: 0,
+            style: RingStyle::Line,
         }
     }
 
     /// Add a new ring at the given position
     pub fn add_ring(&mut self, position: Vec2) {
-        self.rings
-            .push(Ring::new(position, self.current_color, DEFAULT_RING_THICKNESS));
+        self.add_ring_with_color(position, self.current_color, RingOrigin::Click);
     }
 
     /// Add an energy-based colored ring (red=low energy, white=high energy)
@@ -333,14 +444,52 @@ impl RingManager {
         // Red (low) to white (high)
         let color = Color::new(1.0, normalized, normalized, 1.0);
 
-        self.rings
-            .push(Ring::new(position, color, DEFAULT_RING_THICKNESS));
+        self.add_ring_with_color(position, color, RingOrigin::Fusion);
     }
 
     /// Add a ring with a custom color
-    pub fn add_ring_with_color(&mut self, position: Vec2, color: Color) {
+    pub fn add_ring_with_color(&mut self, position: Vec2, color: Color, origin: RingOrigin) {
+        let speed = Ring::calculate_frequency_based_speed(color);
+        self.add_ring_full(position, color, speed, INITIAL_RING_RADIUS, origin);
+    }
+
+    /// Add a ring of an exact growth speed (frequency), independent of the palette
+    /// It is drawn in the color that the frequency mapping would give that speed
+    pub fn add_ring_with_speed(&mut self, position: Vec2, speed: f32) {
+        self.add_ring_full(position, Ring::color_for_speed(speed), speed, INITIAL_RING_RADIUS, RingOrigin::Click);
+    }
+
+    /// Add a ring with every property explicit - `speed` is not derived from `color`
+    /// At the ring cap the oldest ring is evicted to make room
+    pub fn add_ring_full(&mut self, position: Vec2, color: Color, speed: f32, initial_radius: f32, origin: RingOrigin) {
+        self.evict_down_to(self.max_rings - 1);
         self.rings
-            .push(Ring::new(position, color, DEFAULT_RING_THICKNESS));
+            .push(Ring::with_speed(position, color, DEFAULT_RING_THICKNESS, speed, initial_radius, origin));
+    }
+
+    /// Cap the number of live rings - every wave interaction loops over all of them
+    /// Lowering the cap evicts the oldest rings right away (the cap is at least 1)
+    pub fn set_max_rings(&mut self, max_rings: usize) {
+        self.max_rings = max_rings.max(1);
+        self.evict_down_to(self.max_rings);
+    }
+
+    pub fn max_rings(&self) -> usize {
+        self.max_rings
+    }
+
+    /// Rings evicted by the cap since startup
+    pub fn evicted_count(&self) -> u32 {
+        self.evicted_count
+    }
+
+    /// Drop the oldest rings until at most `count` remain
+    fn evict_down_to(&mut self, count: usize) {
+        if self.rings.len() > count {
+            let excess = self.rings.len() - count;
+            self.rings.drain(..excess);
+            self.evicted_count += excess as u32;
+        }
     }
 
     /// Update all rings
@@ -354,10 +503,46 @@ impl RingManager {
         self.rings.retain(|ring| ring.is_alive());
     }
 
+    pub fn style(&self) -> RingStyle {
+        self.style
+    }
+
+    pub fn set_style(&mut self, style: RingStyle) {
+        self.style = style;
+    }
+
     /// Draw all rings
     pub fn draw(&self, segments: u8) {
         for ring in &self.rings {
-            ring.render(segments);
+            ring.render(segments, self.style);
+        }
+        self.draw_interference();
+    }
+
+    /// Brighter spots where two waves cross - only pairs whose bounding boxes overlap are intersected
+    fn draw_interference(&self) {
+        for (i, a) in self.rings.iter().enumerate() {
+            if !a.is_alive {
+                continue;
+            }
+            let bounds = a.bounds();
+            for b in &self.rings[i + 1..] {
+                if !b.is_alive || !bounds.overlaps(&b.bounds()) {
+                    continue;
+                }
+                let Some(points) = a.intersections(b) else { continue };
+
+                // The two colors add up, as strong as the weaker wave
+                let color = Color::new(
+                    (a.color.r + b.color.r).min(1.0),
+                    (a.color.g + b.color.g).min(1.0),
+                    (a.color.b + b.color.b).min(1.0),
+                    a.energy.min(b.energy) * RING_INTERFERENCE_OPACITY,
+                );
+                for point in points {
+                    draw_circle(point.x, point.y, RING_INTERFERENCE_RADIUS, color);
+                }
+            }
         }
     }
 
@@ -371,6 +556,11 @@ impl RingManager {
         self.rings.len()
     }
 
+    /// Live rings from `origin`
+    pub fn count_from(&self, origin: RingOrigin) -> usize {
+        self.rings.iter().filter(|ring| ring.origin() == origin).count()
+    }
+
     /// Get all rings (for intersection detection)
     pub fn get_all_rings(&self) -> &[Ring] {
         &self.rings
@@ -410,6 +600,28 @@ impl RingManager {
         }
     }
 
+    /// Palette shown on the color slider, slowest first
+    pub fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+
+    /// Replace one palette entry - new rings of that color take its frequency-based speed
+    pub fn set_palette_color(&mut self, index: usize, color: Color) {
+        if index < self.colors.len() {
+            self.colors[index] = color;
+            if index == self.current_color_index {
+                self.current_color = color;
+            }
+        }
+    }
+
+    /// Load a whole palette (e.g. the one from pond.toml)
+    pub fn set_palette(&mut self, colors: &[Color]) {
+        for (i, color) in colors.iter().enumerate() {
+            self.set_palette_color(i, *color);
+        }
+    }
+
     /// Get current color as a string
     pub fn get_current_color_string(&self) -> String {
         format!(
@@ -436,3 +648,53 @@ impl RingManager {
         format!("{} - Speed: {:.1} px/s ({})", color_str, speed, freq_desc)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim_config::SimConfig;
+
+    #[test]
+    fn ring_cap_evicts_the_oldest_rings() {
+        let mut ring_manager = RingManager::new();
+        let config = SimConfig::parse("max_rings = 3.7\n", "test");
+        ring_manager.set_max_rings(config.max_rings as usize);
+        for speed in [20.0, 40.0, 60.0, 80.0, 100.0] {
+            ring_manager.add_ring_with_speed(vec2(100.0, 100.0), speed);
+        }
+
+        let speeds: Vec<f32> = ring_manager.get_all_rings().iter().map(|r| r.get_growth_speed()).collect();
+        assert_eq!(speeds, vec![60.0, 80.0, 100.0]);
+        assert_eq!(ring_manager.evicted_count(), 2);
+
+        // Lowering the cap trims right away
+        ring_manager.set_max_rings(1);
+        assert_eq!(ring_manager.get_all_rings()[0].get_growth_speed(), 100.0);
+        assert_eq!(ring_manager.evicted_count(), 4);
+    }
+
+    #[test]
+    fn ring_spawned_by_speed_keeps_that_speed_and_a_matching_color() {
+        let mut ring_manager = RingManager::new();
+        let speeds = [MIN_RING_SPEED, 25.0, 80.0, 130.0, MAX_RING_SPEED];
+        for &speed in &speeds {
+            ring_manager.add_ring_with_speed(Vec2::ZERO, speed);
+        }
+
+        for (ring, &speed) in ring_manager.get_all_rings().iter().zip(&speeds) {
+            assert_eq!(ring.get_growth_speed(), speed);
+            let color_speed = Ring::calculate_frequency_based_speed(ring.get_color());
+            assert!((color_speed - speed).abs() < 1e-3, "{} drawn as a {} wave", speed, color_speed);
+        }
+    }
+
+    #[test]
+    fn slow_rings_trail_broader_shockwaves_than_fast_ones() {
+        let mut ring_manager = RingManager::new();
+        ring_manager.add_ring_with_speed(Vec2::ZERO, MIN_RING_SPEED);
+        ring_manager.add_ring_with_speed(Vec2::ZERO, MAX_RING_SPEED);
+        let widths: Vec<f32> = ring_manager.get_all_rings().iter().map(|r| r.shockwave_width()).collect();
+        assert!(widths[0] > widths[1], "{:?}", widths);
+        assert!(widths.iter().all(|w| (SHOCKWAVE_MIN_WIDTH..=SHOCKWAVE_MAX_WIDTH).contains(w)));
+    }
+}
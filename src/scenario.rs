@@ -0,0 +1,144 @@
+// Scenario - Built-in starting setups that walk new players through a step of the fusion ladder
+//
+// A scenario is a named preset: the particles to start from (as snapshot records, so they load
+// exactly like a pasted snapshot), the element to select, a camera zoom, and a few lines of guide
+// text shown while it runs. Picked from the Scenarios menu; see ProtonManager::load_scenario.
+
+use macroquad::prelude::{vec2, Vec2};
+
+use crate::constants::proton as pc;
+use crate::constants::timestep;
+use crate::snapshot::ParticleRecord;
+
+/// One built-in preset
+pub struct Scenario {
+    pub name: &'static str,
+    pub summary: &'static str,          // One line for the menu
+    pub guide: &'static [&'static str], // Shown on screen after loading
+    pub selected_element: &'static str, // Element name the spawn tool starts on
+    pub zoom: f32,                      // Camera zoom, centered on the pond
+    pub particles: fn(Vec2) -> Vec<ParticleRecord>, // Starting particles around the given center
+}
+
+pub const SCENARIOS: [Scenario; 3] = [
+    Scenario {
+        name: "Make Helium",
+        summary: "Collide fast protons and follow the pp-chain up to He4",
+        guide: &[
+            "Fast H+ that collide fuse into deuterium (D), and D + D -> T + H+",
+            "Tritium decays into He3 - two He3 that meet make He4",
+            "Right-drag to fire He3 into each other and finish the chain",
+        ],
+        selected_element: "He3",
+        zoom: 1.5,
+        particles: make_helium,
+    },
+    Scenario {
+        name: "Triple-Alpha Carbon",
+        summary: "Smash He4 together three at a time to make C12",
+        guide: &[
+            "Slow He4 just bounce off each other",
+            "Three energetic He4 that hit at once fuse into C12 (triple-alpha)",
+            "Fire He4 at the carbon to climb on to O16 and Ne20",
+        ],
+        selected_element: "He4",
+        zoom: 1.5,
+        particles: triple_alpha_carbon,
+    },
+    Scenario {
+        name: "Grow Ice",
+        summary: "Freeze a patch of water and grow it from the seed",
+        guide: &[
+            "H2O hydrogen-bonds to neighbours 60 degrees apart",
+            "A molecule with 5-6 aligned neighbours freezes into a white hexagon",
+            "Drop H2O next to the ice to grow it - dark red waves melt it",
+        ],
+        selected_element: "H2O",
+        zoom: 2.0,
+        particles: grow_ice,
+    },
+];
+
+// Make Helium - two blocks of protons fired at each other faster than the pp-chain threshold
+const PROTON_BLOCK_COLUMNS: usize = 4;
+const PROTON_BLOCK_ROWS: usize = 5;
+const PROTON_SPACING: f32 = 24.0;
+const PROTON_BLOCK_OFFSET: f32 = 160.0; // Horizontal distance from the center to each block
+const PROTON_ENERGY: f32 = 20.0; // Enough that the He4 at the end of the chain can still triple-alpha
+
+// Triple-Alpha Carbon - triads of He4 above the triple-alpha energy threshold, each closing on its own point
+// Alone, He4 bounce apart before they overlap - a triad has to close the bounce gap within one physics step,
+// so each starts fast and at the distance that puts the three ALPHA_FUSION_SPACING apart on a step boundary
+const ALPHA_TRIADS: usize = 6;
+const ALPHA_TRIAD_SPREAD: f32 = 160.0; // Distance from the center to each triad's meeting point
+const ALPHA_ENERGY: f32 = 30.0;
+const ALPHA_INFALL_SPEED: f32 = pc::MAX_SPEED;
+const ALPHA_INFALL_STEPS: usize = 30; // Physics steps until the first triad meets
+const ALPHA_TRIAD_STAGGER_STEPS: usize = 12; // Each later triad meets this much after the one before, so the carbons form one by one
+const ALPHA_FUSION_SPACING: f32 = 6.0; // Distance between the three when they meet - inside fusion range, and one step earlier still outside bounce range
+
+// Grow Ice - water laid out on the frozen hexagon spacing, two rings out from the center
+const ICE_RINGS: i32 = 2;
+
+/// The built-in scenario called `name`
+pub fn find(name: &str) -> Option<&'static Scenario> {
+    SCENARIOS.iter().find(|s| s.name == name)
+}
+
+fn record(label: &str, charge: i32, neutron_count: i32, energy: f32, position: Vec2, velocity: Vec2) -> ParticleRecord {
+    ParticleRecord {
+        label: label.to_string(),
+        position: position.into(),
+        velocity: velocity.into(),
+        energy,
+        charge,
+        neutron_count,
+    }
+}
+
+fn make_helium(center: Vec2) -> Vec<ParticleRecord> {
+    let speed = pc::PP_CHAIN_VELOCITY_THRESHOLD * 0.75; // Closing speed is twice this
+    let block_size = vec2(PROTON_BLOCK_COLUMNS as f32 - 1.0, PROTON_BLOCK_ROWS as f32 - 1.0) * PROTON_SPACING;
+
+    let mut records = Vec::new();
+    for side in [-1.0, 1.0] {
+        let origin = center + vec2(side * PROTON_BLOCK_OFFSET, 0.0) - block_size / 2.0;
+        for column in 0..PROTON_BLOCK_COLUMNS {
+            for row in 0..PROTON_BLOCK_ROWS {
+                let position = origin + vec2(column as f32, row as f32) * PROTON_SPACING;
+                records.push(record("H+", 1, 0, PROTON_ENERGY, position, vec2(-side * speed, 0.0)));
+            }
+        }
+    }
+    records
+}
+
+fn triple_alpha_carbon(center: Vec2) -> Vec<ParticleRecord> {
+    use std::f32::consts::TAU;
+
+    let mut records = Vec::new();
+    for triad in 0..ALPHA_TRIADS {
+        let steps = ALPHA_INFALL_STEPS + triad * ALPHA_TRIAD_STAGGER_STEPS;
+        let radius = ALPHA_INFALL_SPEED * steps as f32 * timestep::FIXED_DT + ALPHA_FUSION_SPACING / 3.0_f32.sqrt();
+        let meeting_point = center + Vec2::from_angle(triad as f32 * TAU / ALPHA_TRIADS as f32) * ALPHA_TRIAD_SPREAD;
+        for i in 0..3 {
+            let direction = Vec2::from_angle(i as f32 * TAU / 3.0);
+            let position = meeting_point + direction * radius;
+            records.push(record("He4", 2, 2, ALPHA_ENERGY, position, -direction * ALPHA_INFALL_SPEED));
+        }
+    }
+    records
+}
+
+fn grow_ice(center: Vec2) -> Vec<ParticleRecord> {
+    // Axial hex coordinates, like ProtonManager::stamp_lattice
+    let row_height = 3.0_f32.sqrt() / 2.0;
+    (-ICE_RINGS..=ICE_RINGS)
+        .flat_map(|q| (-ICE_RINGS..=ICE_RINGS).map(move |r| (q, r)))
+        .filter(|&(q, r)| (q + r).abs() <= ICE_RINGS)
+        .map(|(q, r)| {
+            let offset = vec2(q as f32 + r as f32 / 2.0, r as f32 * row_height) * pc::WATER_ICE_FROZEN_REST_LENGTH;
+            record("H2O", 10, 8, 18.0, center + offset, Vec2::ZERO)
+        })
+        .collect()
+}
@@ -0,0 +1,403 @@
+// SimConfig - Runtime overrides for physics tuning values
+// Loaded from an optional pond.toml in the working directory.
+//
+// Precedence: a value in the file wins over the compiled default in constants.rs.
+// A missing file, a missing key, or a value that fails validation falls back to the default.
+//
+// The file is a flat subset of TOML - `key = number` lines, `# comments`, and optional
+// `[section]` headers (sections are only for grouping and are ignored):
+//
+//     [forces]
+//     charge_repulsion_strength = 1200.0
+//     h_attraction_range = 900.0
+//
+//     [evaporation]
+//     evaporation_speed_scale = 1.5
+//
+// Ring palette entries are the one non-number value - `ring_color_<index> = [r, g, b]` (0-255, a TOML array;
+// the bare `r, g, b` older builds wrote is still read).
+// The palette editor (K) writes them under a `[palette]` section.
+//
+// Radius normally follows energy alone. `radius_nucleon_exponent` > 0 also scales it by nucleon count
+// (charge + neutrons) to that power, up to `max_nucleon_radius` - 1/3 matches real nuclei (r ~ A^1/3).
+// Collisions, fusion range and drawing all read the same radius, so they stay consistent.
+//
+// Collision elasticity is set per pair of materials (gas, ice, metal, nonmetal), 0 = stick, 1 = perfect bounce.
+// Unset pairs keep the compiled bounce:
+//
+//     [elasticity]
+//     elasticity_metal_metal = 0.95
+//     elasticity_ice_ice = 0.1
+//     elasticity_gas_ice = 0.3
+//
+// Molecules of the same kind cohere into droplets within `molecule_cohesion_range`. Each molecule has its own
+// strength key (`h2o_cohesion_strength`, `ch4_cohesion_strength`, ...) and 0 turns that molecule's cohesion off.
+//
+// Deuterium is made from H+ that stays within `neutron_formation_range` of an atom. Once it has been there long
+// enough, `neutron_formation_probability` (0-1) is its chance of converting on each step - lower it to slow
+// down the whole fusion ladder, which waits on deuterium.
+
+use macroquad::prelude::Color;
+use crate::constants::proton;
+use crate::constants::proton_manager as pm;
+use crate::constants::{MAX_RING_COUNT, RING_COLORS};
+use crate::proton::{ElementKind, Material};
+
+pub const DEFAULT_CONFIG_PATH: &str = "pond.toml";
+const RING_COLOR_KEY_PREFIX: &str = "ring_color_";
+const PALETTE_SECTION: &str = "[palette]";
+const ELASTICITY_KEY_PREFIX: &str = "elasticity_";
+
+/// Physics values that can be tuned without recompiling
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimConfig {
+    // Force strengths (must be >= 0)
+    pub charge_repulsion_strength: f32,
+    pub charge_attraction_strength: f32,
+    pub h_attraction_strength: f32,
+    pub he4_attraction_strength: f32,
+    pub red_wave_repulsion_strength: f32,
+
+    // Interaction ranges (must be > 0)
+    pub charge_interaction_range: f32,
+    pub h_attraction_range: f32,
+    pub he4_attraction_range: f32,
+
+    // Same-molecule cohesion, per molecule kind (must be >= 0, 0 = off), and its range (must be > 0)
+    pub h2o_cohesion_strength: f32,
+    pub h2s_cohesion_strength: f32,
+    pub mgh2_cohesion_strength: f32,
+    pub ch4_cohesion_strength: f32,
+    pub sih4_cohesion_strength: f32,
+    pub so2_cohesion_strength: f32,
+    pub molecule_cohesion_range: f32,
+
+    // H+ -> deuterium next to an atom - proximity (must be > 0) and chance per step once in range long enough (0-1)
+    pub neutron_formation_range: f32,
+    pub neutron_formation_probability: f32,
+
+    // Fusion relative-velocity thresholds (must be >= 0)
+    pub pp_chain_velocity_threshold: f32,
+    pub dd_fusion_velocity_threshold: f32,
+    pub deuterium_fusion_velocity_threshold: f32,
+    pub helium3_fusion_velocity_threshold: f32,
+    pub triple_alpha_velocity_threshold: f32,
+
+    // Multiplier on every element's evaporation speed (must be > 0)
+    pub evaporation_speed_scale: f32,
+
+    // Hard cap on particle slots, rounded down (must be > 0)
+    pub max_protons: f32,
+
+    // Live ring cap, rounded down (must be > 0) - adding past it evicts the oldest ring
+    pub max_rings: f32,
+
+    // Hydrogen bonds per H2O, rounded down and capped at the 6 a hexagon holds (must be > 0)
+    pub water_ice_max_bonds: f32,
+
+    // Non-overlapping fusion and molecule reactions resolved per frame, rounded down (must be > 0)
+    pub max_reactions_per_frame: f32,
+
+    // Nucleon-count radius scaling - 0 keeps the energy-only radius (must be >= 0), and its cap (must be > 0)
+    pub radius_nucleon_exponent: f32,
+    pub max_nucleon_radius: f32,
+
+    // Color slider palette - ring speed is derived from the color, so edits retune the waves too
+    pub ring_colors: Vec<Color>,
+
+    // Solid-collision bounce for each pair of materials, indexed by Material (symmetric, 0-1)
+    pub pair_elasticity: [[f32; Material::ALL.len()]; Material::ALL.len()],
+}
+
+/// Which values a key accepts
+#[derive(Clone, Copy)]
+enum Range {
+    NonNegative,
+    Positive,
+    Probability,
+}
+
+impl Range {
+    fn accepts(self, value: f32) -> bool {
+        value.is_finite()
+            && match self {
+                Range::NonNegative => value >= 0.0,
+                Range::Positive => value > 0.0,
+                Range::Probability => (0.0..=1.0).contains(&value),
+            }
+    }
+
+    fn describe(self) -> &'static str {
+        match self {
+            Range::NonNegative => ">= 0",
+            Range::Positive => "> 0",
+            Range::Probability => "between 0 and 1",
+        }
+    }
+}
+
+impl Default for SimConfig {
+    fn default() -> Self {
+        Self {
+            charge_repulsion_strength: pm::CHARGE_REPULSION_STRENGTH,
+            charge_attraction_strength: pm::CHARGE_ATTRACTION_STRENGTH,
+            h_attraction_strength: pm::H_ATTRACTION_STRENGTH,
+            he4_attraction_strength: pm::HE4_ATTRACTION_STRENGTH,
+            red_wave_repulsion_strength: pm::RED_WAVE_REPULSION_STRENGTH,
+            charge_interaction_range: pm::CHARGE_INTERACTION_RANGE,
+            h_attraction_range: pm::H_ATTRACTION_RANGE,
+            he4_attraction_range: pm::HE4_ATTRACTION_RANGE,
+            h2o_cohesion_strength: pm::H2O_COHESION_STRENGTH,
+            h2s_cohesion_strength: pm::H2S_COHESION_STRENGTH,
+            mgh2_cohesion_strength: pm::MGH2_COHESION_STRENGTH,
+            ch4_cohesion_strength: pm::CH4_COHESION_STRENGTH,
+            sih4_cohesion_strength: pm::SIH4_COHESION_STRENGTH,
+            so2_cohesion_strength: pm::SO2_COHESION_STRENGTH,
+            molecule_cohesion_range: pm::MOLECULE_COHESION_RANGE,
+            neutron_formation_range: pm::NEUTRON_FORMATION_RANGE,
+            neutron_formation_probability: pm::NEUTRON_FORMATION_PROBABILITY,
+            pp_chain_velocity_threshold: proton::PP_CHAIN_VELOCITY_THRESHOLD,
+            dd_fusion_velocity_threshold: proton::DD_FUSION_VELOCITY_THRESHOLD,
+            deuterium_fusion_velocity_threshold: proton::DEUTERIUM_FUSION_VELOCITY_THRESHOLD,
+            helium3_fusion_velocity_threshold: proton::HELIUM3_FUSION_VELOCITY_THRESHOLD,
+            triple_alpha_velocity_threshold: proton::TRIPLE_ALPHA_VELOCITY_THRESHOLD,
+            evaporation_speed_scale: 1.0,
+            max_protons: pm::MAX_PROTON_CAPACITY as f32,
+            max_rings: MAX_RING_COUNT as f32,
+            water_ice_max_bonds: proton::WATER_ICE_MAX_BONDS as f32,
+            max_reactions_per_frame: pm::MAX_REACTIONS_PER_FRAME as f32,
+            radius_nucleon_exponent: 0.0,
+            max_nucleon_radius: proton::MAX_NUCLEON_SCALED_RADIUS,
+            ring_colors: RING_COLORS.to_vec(),
+            pair_elasticity: [[pm::PROTON_BOUNCE_DAMPENING; Material::ALL.len()]; Material::ALL.len()],
+        }
+    }
+}
+
+impl SimConfig {
+    /// Load overrides from `path`, using compiled defaults if the file does not exist
+    pub fn load(path: &str) -> Self {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text, path),
+            Err(_) => Self::default(),
+        }
+    }
+
+    /// Apply every valid `key = value` line in `text` on top of the defaults
+    /// Unknown keys and invalid values are reported (against `source`) and skipped
+    pub fn parse(text: &str, source: &str) -> Self {
+        let mut config = Self::default();
+
+        for (line_number, raw_line) in text.lines().enumerate() {
+            let line = raw_line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() || line.starts_with('[') {
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                eprintln!("{}:{}: expected `key = value`", source, line_number + 1);
+                continue;
+            };
+            let key = key.trim();
+
+            if let Some(index) = key.strip_prefix(RING_COLOR_KEY_PREFIX) {
+                match (index.parse::<usize>(), parse_rgb(value)) {
+                    (Ok(index), Some(color)) if index < config.ring_colors.len() => config.ring_colors[index] = color,
+                    _ => eprintln!("{}:{}: `{}` must be `[r, g, b]` (0-255) for a palette index", source, line_number + 1, key),
+                }
+                continue;
+            }
+
+            if let Some(pair) = key.strip_prefix(ELASTICITY_KEY_PREFIX) {
+                match (parse_material_pair(pair), value.trim().parse::<f32>()) {
+                    (Some((a, b)), Ok(value)) if (0.0..=1.0).contains(&value) => config.set_elasticity(a, b, value),
+                    _ => eprintln!("{}:{}: `{}` must be between 0 and 1 for a pair of materials", source, line_number + 1, key),
+                }
+                continue;
+            }
+
+            let Ok(value) = value.trim().parse::<f32>() else {
+                eprintln!("{}:{}: `{}` is not a number", source, line_number + 1, key);
+                continue;
+            };
+
+            let Some((field, range)) = config.field_mut(key) else {
+                eprintln!("{}:{}: unknown key `{}`", source, line_number + 1, key);
+                continue;
+            };
+
+            if range.accepts(value) {
+                *field = value;
+            } else {
+                eprintln!(
+                    "{}:{}: `{}` must be {} - keeping default",
+                    source,
+                    line_number + 1,
+                    key,
+                    range.describe()
+                );
+            }
+        }
+
+        config
+    }
+
+    /// Store `colors` as the palette in the config file at `path`, keeping every other line as it was
+    /// Only entries that differ from the compiled palette are written
+    pub fn save_ring_colors(path: &str, colors: &[Color]) -> std::io::Result<()> {
+        let existing = std::fs::read_to_string(path).unwrap_or_default();
+        let mut text: String = existing
+            .lines()
+            .filter(|line| {
+                let line = line.trim();
+                !line.starts_with(RING_COLOR_KEY_PREFIX) && line != PALETTE_SECTION
+            })
+            .map(|line| format!("{}\n", line))
+            .collect();
+        text.truncate(text.trim_end().len());
+
+        let changed: Vec<(usize, &Color)> = colors
+            .iter()
+            .enumerate()
+            .filter(|(i, color)| RING_COLORS.get(*i) != Some(*color))
+            .collect();
+        if !changed.is_empty() {
+            if !text.is_empty() {
+                text.push_str("\n\n");
+            }
+            text.push_str(PALETTE_SECTION);
+            for (i, color) in changed {
+                let channel = |value: f32| (value * 255.0).round() as u8;
+                text.push_str(&format!(
+                    "\n{}{} = [{}, {}, {}]",
+                    RING_COLOR_KEY_PREFIX,
+                    i,
+                    channel(color.r),
+                    channel(color.g),
+                    channel(color.b)
+                ));
+            }
+        }
+        if text.is_empty() && existing.is_empty() {
+            return Ok(()); // Nothing to store - don't create an empty file
+        }
+        if !text.is_empty() {
+            text.push('\n');
+        }
+
+        std::fs::write(path, text)
+    }
+
+    /// Bounce factor when materials `a` and `b` collide (1 = perfectly elastic)
+    pub fn elasticity(&self, a: Material, b: Material) -> f32 {
+        self.pair_elasticity[a as usize][b as usize]
+    }
+
+    pub fn set_elasticity(&mut self, a: Material, b: Material, elasticity: f32) {
+        self.pair_elasticity[a as usize][b as usize] = elasticity;
+        self.pair_elasticity[b as usize][a as usize] = elasticity;
+    }
+
+    /// Pull between two molecules of `kind` - 0 for anything that is not a molecule or has cohesion turned off
+    pub fn cohesion_strength(&self, kind: ElementKind) -> f32 {
+        match kind {
+            ElementKind::Water => self.h2o_cohesion_strength,
+            ElementKind::HydrogenSulfide => self.h2s_cohesion_strength,
+            ElementKind::MagnesiumHydride => self.mgh2_cohesion_strength,
+            ElementKind::Methane => self.ch4_cohesion_strength,
+            ElementKind::Silane => self.sih4_cohesion_strength,
+            ElementKind::SulfurDioxide => self.so2_cohesion_strength,
+            _ => 0.0,
+        }
+    }
+
+    /// Collision radius for a particle of `energy` with `nucleons` - None while nucleon scaling is off
+    pub fn nucleon_scaled_radius(&self, energy: f32, nucleons: i32) -> Option<f32> {
+        if self.radius_nucleon_exponent <= 0.0 {
+            return None;
+        }
+        let base = (proton::MIN_RADIUS + energy * proton::ENERGY_TO_RADIUS_FACTOR).clamp(proton::MIN_RADIUS, proton::MAX_RADIUS);
+        let scale = (nucleons.max(1) as f32).powf(self.radius_nucleon_exponent);
+        Some((base * scale).min(self.max_nucleon_radius.max(proton::MIN_RADIUS)))
+    }
+
+    fn field_mut(&mut self, key: &str) -> Option<(&mut f32, Range)> {
+        let entry = match key {
+            "charge_repulsion_strength" => (&mut self.charge_repulsion_strength, Range::NonNegative),
+            "charge_attraction_strength" => (&mut self.charge_attraction_strength, Range::NonNegative),
+            "h_attraction_strength" => (&mut self.h_attraction_strength, Range::NonNegative),
+            "he4_attraction_strength" => (&mut self.he4_attraction_strength, Range::NonNegative),
+            "red_wave_repulsion_strength" => (&mut self.red_wave_repulsion_strength, Range::NonNegative),
+            "charge_interaction_range" => (&mut self.charge_interaction_range, Range::Positive),
+            "h_attraction_range" => (&mut self.h_attraction_range, Range::Positive),
+            "he4_attraction_range" => (&mut self.he4_attraction_range, Range::Positive),
+            "h2o_cohesion_strength" => (&mut self.h2o_cohesion_strength, Range::NonNegative),
+            "h2s_cohesion_strength" => (&mut self.h2s_cohesion_strength, Range::NonNegative),
+            "mgh2_cohesion_strength" => (&mut self.mgh2_cohesion_strength, Range::NonNegative),
+            "ch4_cohesion_strength" => (&mut self.ch4_cohesion_strength, Range::NonNegative),
+            "sih4_cohesion_strength" => (&mut self.sih4_cohesion_strength, Range::NonNegative),
+            "so2_cohesion_strength" => (&mut self.so2_cohesion_strength, Range::NonNegative),
+            "molecule_cohesion_range" => (&mut self.molecule_cohesion_range, Range::Positive),
+            "neutron_formation_range" => (&mut self.neutron_formation_range, Range::Positive),
+            "neutron_formation_probability" => (&mut self.neutron_formation_probability, Range::Probability),
+            "pp_chain_velocity_threshold" => (&mut self.pp_chain_velocity_threshold, Range::NonNegative),
+            "dd_fusion_velocity_threshold" => (&mut self.dd_fusion_velocity_threshold, Range::NonNegative),
+            "deuterium_fusion_velocity_threshold" => (&mut self.deuterium_fusion_velocity_threshold, Range::NonNegative),
+            "helium3_fusion_velocity_threshold" => (&mut self.helium3_fusion_velocity_threshold, Range::NonNegative),
+            "triple_alpha_velocity_threshold" => (&mut self.triple_alpha_velocity_threshold, Range::NonNegative),
+            "evaporation_speed_scale" => (&mut self.evaporation_speed_scale, Range::Positive),
+            "max_protons" => (&mut self.max_protons, Range::Positive),
+            "max_rings" => (&mut self.max_rings, Range::Positive),
+            "water_ice_max_bonds" => (&mut self.water_ice_max_bonds, Range::Positive),
+            "max_reactions_per_frame" => (&mut self.max_reactions_per_frame, Range::Positive),
+            "radius_nucleon_exponent" => (&mut self.radius_nucleon_exponent, Range::NonNegative),
+            "max_nucleon_radius" => (&mut self.max_nucleon_radius, Range::Positive),
+            _ => return None,
+        };
+        Some(entry)
+    }
+}
+
+/// `<material>_<material>`, e.g. `metal_ice`
+fn parse_material_pair(pair: &str) -> Option<(Material, Material)> {
+    let (a, b) = pair.split_once('_')?;
+    let material = |name: &str| Material::ALL.into_iter().find(|m| m.name() == name);
+    Some((material(a)?, material(b)?))
+}
+
+/// `[r, g, b]` with each channel 0-255 - the brackets may be left off
+fn parse_rgb(value: &str) -> Option<Color> {
+    let value = value.trim();
+    let value = value.strip_prefix('[').and_then(|v| v.strip_suffix(']')).unwrap_or(value);
+    let channels: Vec<u8> = value.split(',').map(|c| c.trim().parse().ok()).collect::<Option<_>>()?;
+    match channels[..] {
+        [r, g, b] => Some(Color::from_rgba(r, g, b, 255)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saved_ring_palette_is_valid_toml_that_loads_back() {
+        let path = std::env::temp_dir().join(format!("pond_palette_{}.toml", std::process::id()));
+        let path = path.to_str().unwrap();
+        std::fs::write(path, "max_rings = 40\n").unwrap();
+
+        let mut colors = RING_COLORS.to_vec();
+        colors[1] = Color::from_rgba(12, 34, 56, 255);
+        SimConfig::save_ring_colors(path, &colors).unwrap();
+        let text = std::fs::read_to_string(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+
+        assert!(text.contains("ring_color_1 = [12, 34, 56]"), "{}", text);
+        let config = SimConfig::parse(&text, "test");
+        assert_eq!(config.ring_colors, colors);
+        assert_eq!(config.max_rings, 40.0);
+        // Files written before the array form still load
+        let bare = SimConfig::parse("ring_color_1 = 12, 34, 56\n", "test");
+        assert_eq!(bare.ring_colors, colors);
+    }
+}
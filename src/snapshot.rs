@@ -0,0 +1,110 @@
+// Snapshot - Compact text form of the particle state, shared as base64 (Ctrl+C / Ctrl+V)
+//
+// Decoded, a snapshot is a header line followed by one line per living particle:
+//
+//     pond-snapshot 1
+//     <label> <x> <y> <vx> <vy> <energy> <charge> <neutrons>
+//
+// Labels are Proton::get_element_label names. Spawnable elements are rebuilt from their label and
+// keep their stored energy; anything else (free H+, D, leptons, O16) is rebuilt from its charge,
+// neutrons and energy. Built-in scenarios (scenario.rs) are written as records too.
+// Bonds, crystal groups, rings and atoms are not stored - crystals re-form on their own.
+
+pub const HEADER: &str = "pond-snapshot 1";
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// One particle as stored in a snapshot
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParticleRecord {
+    pub label: String,
+    pub position: (f32, f32),
+    pub velocity: (f32, f32),
+    pub energy: f32,
+    pub charge: i32,
+    pub neutron_count: i32,
+}
+
+/// Encode the records as a single base64 string
+pub fn encode(records: &[ParticleRecord]) -> String {
+    let mut text = String::from(HEADER);
+    for r in records {
+        text.push_str(&format!(
+            "\n{} {} {} {} {} {} {} {}",
+            r.label, r.position.0, r.position.1, r.velocity.0, r.velocity.1, r.energy, r.charge, r.neutron_count
+        ));
+    }
+    base64_encode(text.as_bytes())
+}
+
+/// Decode a string made by `encode` (surrounding whitespace is ignored)
+pub fn decode(encoded: &str) -> Result<Vec<ParticleRecord>, String> {
+    let bytes = base64_decode(encoded.trim()).ok_or("not valid base64")?;
+    let text = String::from_utf8(bytes).map_err(|_| "snapshot is not text")?;
+
+    let mut lines = text.lines();
+    if lines.next() != Some(HEADER) {
+        return Err("not a pond snapshot".to_string());
+    }
+
+    lines
+        .enumerate()
+        .map(|(i, line)| parse_record(line).ok_or_else(|| format!("bad particle on line {}", i + 2)))
+        .collect()
+}
+
+fn parse_record(line: &str) -> Option<ParticleRecord> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    let [label, x, y, vx, vy, energy, charge, neutrons] = fields[..] else { return None };
+    let float = |s: &str| s.parse::<f32>().ok().filter(|v| v.is_finite());
+
+    Some(ParticleRecord {
+        label: label.to_string(),
+        position: (float(x)?, float(y)?),
+        velocity: (float(vx)?, float(vy)?),
+        energy: float(energy)?,
+        charge: charge.parse().ok()?,
+        neutron_count: neutrons.parse().ok()?,
+    })
+}
+
+/// Standard base64 with `=` padding
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for k in 0..4 {
+            if k <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * k) & 63) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+/// Inverse of base64_encode - None on bad length or characters
+fn base64_decode(text: &str) -> Option<Vec<u8>> {
+    let text = text.as_bytes();
+    if !text.len().is_multiple_of(4) {
+        return None;
+    }
+
+    let mut out = Vec::with_capacity(text.len() / 4 * 3);
+    for chunk in text.chunks(4) {
+        let padding = chunk.iter().rev().take_while(|&&c| c == b'=').count();
+        if padding > 2 {
+            return None;
+        }
+        let mut n = 0u32;
+        for &c in &chunk[..4 - padding] {
+            let value = ALPHABET.iter().position(|&a| a == c)? as u32;
+            n = n << 6 | value;
+        }
+        n <<= 6 * padding as u32;
+        out.extend_from_slice(&n.to_be_bytes()[1..4 - padding]);
+    }
+    Some(out)
+}
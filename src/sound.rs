@@ -0,0 +1,95 @@
+// SoundEffects - Short cues for simulation events (fusion, molecule formation, freezing)
+// Only active with the `audio` cargo feature (`cargo run --features audio`). Without it
+// nothing is loaded and playing is a no-op, so the default build needs no audio libraries.
+//
+// The samples are synthesized into in-memory WAV data at startup rather than shipped as files.
+
+use macroquad::audio::{load_sound_from_bytes, play_sound, PlaySoundParams, Sound};
+
+use crate::constants::sound as sc;
+use crate::proton_manager::{SimEvent, SimEventKind};
+
+/// Loaded event sounds plus the mute toggle
+pub struct SoundEffects {
+    pop: Option<Sound>,   // Fusion
+    chime: Option<Sound>, // Molecule formation
+    tick: Option<Sound>,  // Crystal freezing
+    muted: bool,
+}
+
+impl SoundEffects {
+    /// Synthesize and load every sample (nothing is loaded without the audio feature)
+    pub async fn load() -> Self {
+        Self {
+            pop: load_tone(&sc::POP_FREQUENCIES, sc::POP_DURATION).await,
+            chime: load_tone(&sc::CHIME_FREQUENCIES, sc::CHIME_DURATION).await,
+            tick: load_tone(&sc::TICK_FREQUENCIES, sc::TICK_DURATION).await,
+            muted: false,
+        }
+    }
+
+    pub fn is_muted(&self) -> bool {
+        self.muted
+    }
+
+    pub fn toggle_mute(&mut self) {
+        self.muted = !self.muted;
+    }
+
+    /// Play the cue for each kind of event in `events` - at most once per kind, so a busy frame stays one sound
+    pub fn play_events(&self, events: &[SimEvent]) {
+        if self.muted {
+            return;
+        }
+
+        let cues = [
+            (SimEventKind::Fusion, &self.pop, sc::POP_VOLUME),
+            (SimEventKind::MoleculeFormed, &self.chime, sc::CHIME_VOLUME),
+            (SimEventKind::CrystalFrozen, &self.tick, sc::TICK_VOLUME),
+        ];
+        for (kind, sound, volume) in cues {
+            if let Some(sound) = sound {
+                if events.iter().any(|event| event.kind == kind) {
+                    play_sound(sound, PlaySoundParams { looped: false, volume });
+                }
+            }
+        }
+    }
+}
+
+async fn load_tone(frequencies: &[f32], duration: f32) -> Option<Sound> {
+    if !cfg!(feature = "audio") {
+        return None;
+    }
+    load_sound_from_bytes(&tone_wav(frequencies, duration)).await.ok()
+}
+
+/// 16-bit mono WAV of equal sine partials under an exponential decay envelope
+fn tone_wav(frequencies: &[f32], duration: f32) -> Vec<u8> {
+    let sample_count = (duration * sc::SAMPLE_RATE as f32) as u32;
+    let data_len = sample_count * 2;
+
+    let mut wav = Vec::with_capacity(44 + data_len as usize);
+    wav.extend_from_slice(b"RIFF");
+    wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+    wav.extend_from_slice(b"WAVEfmt ");
+    wav.extend_from_slice(&16u32.to_le_bytes()); // fmt chunk size
+    wav.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    wav.extend_from_slice(&1u16.to_le_bytes()); // Mono
+    wav.extend_from_slice(&sc::SAMPLE_RATE.to_le_bytes());
+    wav.extend_from_slice(&(sc::SAMPLE_RATE * 2).to_le_bytes()); // Byte rate
+    wav.extend_from_slice(&2u16.to_le_bytes()); // Block align
+    wav.extend_from_slice(&16u16.to_le_bytes()); // Bits per sample
+    wav.extend_from_slice(b"data");
+    wav.extend_from_slice(&data_len.to_le_bytes());
+
+    for n in 0..sample_count {
+        let t = n as f32 / sc::SAMPLE_RATE as f32;
+        let envelope = (-sc::DECAY_RATE * t / duration).exp();
+        let wave: f32 = frequencies.iter().map(|f| (std::f32::consts::TAU * f * t).sin()).sum::<f32>() / frequencies.len() as f32;
+        let sample = (wave * envelope * i16::MAX as f32) as i16;
+        wav.extend_from_slice(&sample.to_le_bytes());
+    }
+
+    wav
+}